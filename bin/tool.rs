@@ -1,10 +1,13 @@
 //! `tool` is the primary CLI binary.
 
-use clap::{CommandFactory, Parser};
+use clap::{ColorChoice, CommandFactory, FromArgMatches};
 use colored::Colorize;
+use tool_cli::error::EXIT_FAILURE;
 use tool_cli::handlers;
+use tool_cli::output::{self, OutputFormat};
+use tool_cli::styles;
 use tool_cli::tree::try_show_tree;
-use tool_cli::{Cli, Command, SelfCommand, ToolError, ToolResult, self_update};
+use tool_cli::{Cli, Command, SearchOptions, SelfCommand, ToolError, ToolResult, self_update};
 use tracing_subscriber::EnvFilter;
 
 //--------------------------------------------------------------------------------------------------
@@ -13,13 +16,15 @@ use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
 async fn main() {
-    // Initialize tracing - only enable when RUST_LOG is set.
-    // This suppresses rmcp's tracing output by default.
-    init_tracing();
-
     if let Err(e) = run().await {
-        print_error(&e);
-        std::process::exit(1);
+        if matches!(e, ToolError::Reported { .. }) {
+            // Already printed by the handler
+        } else if output::output_format().is_json() {
+            output::print_json_error(&e);
+        } else {
+            print_error(&e);
+        }
+        std::process::exit(e.exit_code());
     }
 }
 
@@ -154,6 +159,22 @@ fn print_error(e: &ToolError) {
         ToolError::Cancelled => {
             println!("  {} Operation cancelled", "✗".bright_red());
         }
+        ToolError::NetworkDisabled { operation } => {
+            println!("  {} Network disabled", "error".bright_red().bold());
+            println!();
+            println!(
+                "    {} needs network access, which {} forbids.",
+                operation,
+                "--frozen".bright_white()
+            );
+            println!();
+            println!(
+                "  · {}: Install from a local path or bundle, or drop {} / {}",
+                "hint".bright_blue(),
+                "--frozen".bright_white(),
+                "TOOL_OFFLINE".bright_white()
+            );
+        }
         // For all other errors, use a consistent styled format
         _ => {
             let msg = e.to_string();
@@ -178,28 +199,31 @@ fn print_error(e: &ToolError) {
     println!();
 }
 
-/// Initialize tracing. Only enables logging when RUST_LOG is set.
-fn init_tracing() {
-    // Check if RUST_LOG is set and non-empty
-    let rust_log_set = std::env::var("RUST_LOG")
-        .ok()
-        .filter(|s| !s.is_empty())
-        .is_some();
-
-    // Only initialize tracing if RUST_LOG is set.
-    // Without a subscriber, all tracing events (including from rmcp) are discarded.
-    if !rust_log_set {
-        return;
-    }
-
-    // Build filter from RUST_LOG, suppress rmcp logs unless explicitly included
-    let base_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+/// Initialize tracing from RUST_LOG or the `-v` count, logging to stderr.
+///
+/// Without either, no subscriber is installed and all tracing events (including
+/// rmcp's) are discarded. `-v` enables info, `-vv` debug, and `-vvv` trace for
+/// tool-cli itself.
+fn init_tracing(verbosity: u8) {
+    let rust_log = std::env::var("RUST_LOG").ok().filter(|s| !s.is_empty());
+
+    let base_filter = match (&rust_log, verbosity) {
+        (Some(_), _) => {
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"))
+        }
+        (None, 0) => return,
+        (None, verbosity) => {
+            let level = match verbosity {
+                1 => "info",
+                2 => "debug",
+                _ => "trace",
+            };
+            EnvFilter::new(format!("tool_cli={level},tool={level}"))
+        }
+    };
 
-    let env_filter = if std::env::var("RUST_LOG")
-        .ok()
-        .map(|s| s.contains("rmcp"))
-        .unwrap_or(false)
-    {
+    // Suppress rmcp logs unless explicitly included
+    let env_filter = if rust_log.is_some_and(|s| s.contains("rmcp")) {
         base_filter
     } else {
         base_filter.add_directive("rmcp=off".parse().expect("valid directive"))
@@ -207,19 +231,40 @@ fn init_tracing() {
 
     tracing_subscriber::fmt()
         .with_env_filter(env_filter)
+        .with_writer(std::io::stderr)
         .with_target(false)
         .without_time()
         .init();
 }
 
 async fn run() -> ToolResult<()> {
+    // Configure colors before anything prints, including --tree and clap's help/errors
+    let no_color = std::env::args_os()
+        .take_while(|arg| arg != "--")
+        .any(|arg| arg == "--no-color");
+    styles::init_colors(no_color);
+    let mut command = Cli::command();
+    if styles::no_color_requested(no_color) {
+        command = command.color(ColorChoice::Never);
+    }
+
     // Check for --tree before parsing (avoids required argument errors)
-    if let Some(tree) = try_show_tree(&Cli::command()) {
+    if let Some(tree) = try_show_tree(&command) {
         println!("{}", tree);
         return Ok(());
     }
 
-    let cli = Cli::parse();
+    let cli = Cli::from_arg_matches(&command.get_matches()).unwrap_or_else(|e| e.exit());
+    init_tracing(cli.verbose);
+
+    if cli.frozen {
+        tool_cli::set_network_frozen(true);
+    }
+    if let Some(proxy) = &cli.proxy {
+        tool_cli::set_proxy(proxy);
+    }
+    tool_cli::progress::set_progress_json(cli.progress == "json");
+    output::set_output_format(OutputFormat::from_flags(cli.json, cli.concise));
 
     match cli.command {
         Command::Detect {
@@ -237,6 +282,7 @@ async fn run() -> ToolResult<()> {
                 transport,
                 name,
                 false,
+                cli.json,
                 cli.concise,
                 cli.no_header,
                 verify,
@@ -260,16 +306,26 @@ async fn run() -> ToolResult<()> {
             transport,
             force,
             verify,
+            interactive,
+            docker,
+            typescript,
+            template,
+            git,
+            list_types,
             command,
             args,
             env,
             url,
             headers,
+            auth,
             oauth_client_id,
             oauth_authorization_url,
             oauth_token_url,
             oauth_scopes,
         } => {
+            if list_types {
+                return handlers::list_init_types(cli.json, cli.concise, cli.no_header);
+            }
             handlers::init_mcpb(
                 path,
                 name,
@@ -285,11 +341,17 @@ async fn run() -> ToolResult<()> {
                 transport,
                 force,
                 verify,
+                interactive,
+                docker,
+                typescript,
+                template,
+                git,
                 command,
                 args,
                 env,
                 url,
                 headers,
+                auth,
                 oauth_client_id,
                 oauth_authorization_url,
                 oauth_token_url,
@@ -301,18 +363,87 @@ async fn run() -> ToolResult<()> {
         Command::Validate {
             path,
             strict,
-            json,
+            format,
             quiet,
-        } => handlers::validate_mcpb(path, strict, json, quiet).await,
+            fix,
+            dry_run,
+            connect,
+        } => {
+            let format = if cli.json {
+                Some("json".to_string())
+            } else {
+                format
+            };
+            handlers::validate_mcpb(
+                path,
+                strict,
+                format.as_deref(),
+                quiet,
+                fix,
+                dry_run,
+                connect,
+            )
+            .await
+        }
+
+        Command::Migrate {
+            paths,
+            schema,
+            dry_run,
+            interactive,
+        } => {
+            if interactive {
+                handlers::migrate_project_interactive(paths).await
+            } else {
+                handlers::migrate_manifests(paths, schema, dry_run, cli.json)
+            }
+        }
+
+        Command::Diff { a, b } => handlers::diff_manifests(&a, &b, cli.json).await,
+
+        Command::Scripts { path } => handlers::list_scripts(path, cli.json).await,
+
+        Command::Vars {
+            tool,
+            config,
+            config_file,
+        } => handlers::tool_vars(&tool, &config, config_file.as_deref(), cli.json).await,
 
         Command::Pack {
             path,
             output,
+            manifest,
             no_validate,
             strict,
-            verbose,
             multi_platform,
-        } => handlers::pack_mcpb(path, output, no_validate, strict, verbose, multi_platform).await,
+            platform,
+            checksums,
+            exclude,
+            include,
+            follow_symlinks,
+        } => {
+            handlers::pack_mcpb(
+                path,
+                output,
+                manifest,
+                no_validate,
+                strict,
+                cli.verbose > 0,
+                multi_platform,
+                platform,
+                checksums,
+                exclude,
+                include,
+                follow_symlinks,
+            )
+            .await
+        }
+
+        Command::Inspect { path } => {
+            handlers::inspect_bundle(path, cli.json, cli.concise, cli.no_header)
+        }
+
+        Command::Unpack { bundle, out, force } => handlers::unpack_bundle(bundle, out, force),
 
         Command::Run {
             tool,
@@ -321,9 +452,10 @@ async fn run() -> ToolResult<()> {
             host,
             config,
             config_file,
+            env_file,
+            cwd,
             no_save,
             yes,
-            verbose,
         } => {
             handlers::tool_run(
                 tool,
@@ -332,13 +464,41 @@ async fn run() -> ToolResult<()> {
                 host,
                 config,
                 config_file,
+                env_file,
+                cwd,
                 no_save,
                 yes,
-                verbose,
+                cli.verbose > 0,
             )
             .await
         }
 
+        Command::Dev {
+            path,
+            config,
+            config_file,
+            no_save,
+            yes,
+            no_build,
+        } => {
+            handlers::tool_dev(
+                path,
+                config,
+                config_file,
+                no_save,
+                yes,
+                no_build,
+                cli.verbose > 0,
+            )
+            .await
+        }
+
+        Command::Logs {
+            tool,
+            follow,
+            lines,
+        } => handlers::tool_logs(tool, follow, lines).await,
+
         Command::Info {
             tool,
             methods,
@@ -349,12 +509,12 @@ async fn run() -> ToolResult<()> {
             prompts,
             resources,
             all,
-            json,
+            config_schema,
             config,
             config_file,
+            cwd,
             no_save,
             yes,
-            verbose,
             level,
         } => {
             handlers::tool_info(
@@ -367,12 +527,14 @@ async fn run() -> ToolResult<()> {
                 prompts,
                 resources,
                 all,
-                json,
+                config_schema,
+                cli.json,
                 config,
                 config_file,
+                cwd,
                 no_save,
                 yes,
-                verbose,
+                cli.verbose > 0,
                 cli.concise,
                 cli.no_header,
                 level,
@@ -387,10 +549,16 @@ async fn run() -> ToolResult<()> {
             args,
             config,
             config_file,
+            env_file,
+            cwd,
             no_save,
             yes,
-            verbose,
-            json,
+            stream,
+            output_dir,
+            raw,
+            params_file,
+            params_stdin,
+            no_validate,
         } => {
             handlers::tool_call(
                 tool,
@@ -399,21 +567,51 @@ async fn run() -> ToolResult<()> {
                 args,
                 config,
                 config_file,
+                env_file,
+                cwd,
                 no_save,
                 yes,
-                verbose,
-                json,
+                cli.verbose > 0,
+                cli.json,
                 cli.concise,
+                stream,
+                output_dir,
+                raw,
+                params_file,
+                params_stdin,
+                no_validate,
             )
             .await
         }
 
-        Command::Config(cmd) => handlers::config_tool(cmd, cli.concise, cli.no_header).await,
+        Command::Config(cmd) => {
+            handlers::config_tool(cmd, cli.json, cli.concise, cli.no_header).await
+        }
 
         Command::Host(cmd) => handlers::handle_host_command(cmd, cli.concise, cli.no_header).await,
 
-        Command::List { filter, json, full } => {
-            handlers::list_tools(filter.as_deref(), json, full, cli.concise, cli.no_header).await
+        Command::Manifest(cmd) => handlers::manifest_command(cmd, cli.json, cli.concise),
+
+        Command::Security(cmd) => handlers::security_command(cmd, cli.json).await,
+
+        Command::Complete { kind, prefix } => handlers::complete(&kind, &prefix).await,
+
+        Command::Version {
+            bump,
+            set,
+            path,
+            git_tag,
+        } => handlers::bump_version(bump, set, &path, git_tag, cli.concise),
+
+        Command::List { filter, full } => {
+            handlers::list_tools(
+                filter.as_deref(),
+                cli.json,
+                full,
+                cli.concise,
+                cli.no_header,
+            )
+            .await
         }
 
         Command::Download {
@@ -422,14 +620,79 @@ async fn run() -> ToolResult<()> {
             platform,
         } => handlers::download_tools(&names, output.as_deref(), platform.as_deref()).await,
 
-        Command::Install { names, platform } => {
-            handlers::add_tools(&names, platform.as_deref()).await
+        Command::Install {
+            names,
+            platform,
+            dry_run,
+            explain,
+            locked,
+        } => {
+            if dry_run {
+                handlers::dry_run_install(&names, platform.as_deref(), explain).await
+            } else if locked {
+                handlers::locked_install(&names, platform.as_deref()).await
+            } else {
+                let summary = handlers::add_tools(&names, platform.as_deref()).await?;
+                if summary.has_failures() {
+                    return Err(ToolError::Reported { code: EXIT_FAILURE });
+                }
+                Ok(())
+            }
         }
 
-        Command::Uninstall { names, all, yes } => handlers::remove_tools(&names, all, yes).await,
+        Command::Uninstall {
+            names,
+            all,
+            yes,
+            path,
+            bundle,
+        } => {
+            let summary =
+                handlers::remove_tools(&names, all, yes, path.as_deref(), bundle.as_deref())
+                    .await?;
+            if summary.has_failures() {
+                return Err(ToolError::Reported { code: EXIT_FAILURE });
+            }
+            Ok(())
+        }
+
+        Command::Doctor => handlers::doctor(cli.json).await,
 
-        Command::Search { query } => {
-            handlers::search_tools(&query, cli.concise, cli.no_header).await
+        Command::Prune { yes } => handlers::prune_tools(yes).await,
+
+        Command::Search {
+            query,
+            category,
+            keyword,
+            limit,
+            page,
+            sort,
+        } => {
+            let options = SearchOptions {
+                categories: category,
+                keywords: keyword,
+                limit: limit as usize,
+                page: page as usize,
+                sort: sort.parse()?,
+            };
+            handlers::search_tools(
+                query.as_deref().unwrap_or_default(),
+                &options,
+                cli.json,
+                cli.concise,
+                cli.no_header,
+            )
+            .await
+        }
+
+        Command::Versions { tool } => {
+            handlers::tool_versions(tool, cli.json, cli.concise, cli.no_header).await
+        }
+
+        Command::Yank { tool } => handlers::yank_version(tool, cli.json).await,
+
+        Command::Deprecate { tool, message } => {
+            handlers::deprecate_version(tool, message, cli.json).await
         }
 
         Command::Preview {
@@ -441,24 +704,39 @@ async fn run() -> ToolResult<()> {
             tools,
             prompts,
             all,
-            json,
             level,
+            files,
+            exclude,
+            include,
+            estimate,
         } => {
-            handlers::tool_preview(
-                tool,
-                methods,
-                input_only,
-                output_only,
-                description_only,
-                tools,
-                prompts,
-                all,
-                json,
-                cli.concise,
-                cli.no_header,
-                level,
-            )
-            .await
+            if files {
+                handlers::preview_bundle_files(
+                    tool,
+                    exclude,
+                    include,
+                    estimate,
+                    cli.json,
+                    cli.concise,
+                    cli.no_header,
+                )
+            } else {
+                handlers::tool_preview(
+                    tool,
+                    methods,
+                    input_only,
+                    output_only,
+                    description_only,
+                    tools,
+                    prompts,
+                    all,
+                    cli.json,
+                    cli.concise,
+                    cli.no_header,
+                    level,
+                )
+                .await
+            }
         }
 
         Command::Publish {
@@ -466,6 +744,7 @@ async fn run() -> ToolResult<()> {
             dry_run,
             strict,
             multi_platform,
+            platforms,
             darwin_arm64,
             darwin_x64,
             linux_x64,
@@ -474,6 +753,11 @@ async fn run() -> ToolResult<()> {
             win32_arm64,
             universal,
             token,
+            access,
+            tag,
+            otp,
+            provenance,
+            require_provenance,
         } => {
             // Collect pre-built artifacts into a map
             let mut prebuilt = std::collections::HashMap::new();
@@ -505,17 +789,23 @@ async fn run() -> ToolResult<()> {
                 strict,
                 multi_platform,
                 prebuilt,
+                &platforms,
                 token.as_deref(),
+                access.as_deref(),
+                &tag,
+                otp.as_deref(),
+                provenance,
+                require_provenance,
             )
             .await
         }
 
         Command::Login { token } => handlers::auth_login(token.as_deref()).await,
 
-        Command::Logout => handlers::auth_logout().await,
+        Command::Logout { registry } => handlers::auth_logout(registry.as_deref()).await,
 
         Command::Whoami { token } => {
-            handlers::auth_status(cli.concise, cli.no_header, token.as_deref()).await
+            handlers::auth_status(cli.json, cli.concise, cli.no_header, token.as_deref()).await
         }
 
         Command::Grep {
@@ -528,7 +818,6 @@ async fn run() -> ToolResult<()> {
             description_only,
             ignore_case,
             list_only,
-            json,
             level,
             live,
         } => {
@@ -542,7 +831,7 @@ async fn run() -> ToolResult<()> {
                 description_only,
                 ignore_case,
                 list_only,
-                json,
+                cli.json,
                 cli.concise,
                 cli.no_header,
                 level,
@@ -552,16 +841,25 @@ async fn run() -> ToolResult<()> {
         }
 
         Command::SelfCmd(subcmd) => match subcmd {
-            SelfCommand::Update { check, version } => {
+            SelfCommand::Update {
+                check,
+                version,
+                channel,
+                force,
+                no_verify,
+                dry_run,
+            } => {
+                let channel = self_update::resolve_channel(channel.as_deref())?;
                 if check {
-                    let result = self_update::check_for_update().await?;
+                    let result = self_update::check_for_update(channel).await?;
                     println!();
                     if result.update_available {
                         println!(
-                            "  {} Update available: {} → {}",
+                            "  {} Update available: {} → {} ({})",
                             "✓".bright_green(),
                             result.current.dimmed(),
-                            result.latest.bright_cyan()
+                            result.latest.bright_cyan(),
+                            result.channel.to_string().bright_white()
                         );
                         println!();
                         println!("  Run {} to update", "tool self update".bright_cyan());
@@ -575,9 +873,11 @@ async fn run() -> ToolResult<()> {
                     println!();
                     Ok(())
                 } else {
-                    self_update::self_update(version.as_deref()).await
+                    self_update::self_update(version.as_deref(), channel, force, no_verify, dry_run)
+                        .await
                 }
             }
+            SelfCommand::Rollback => self_update::self_rollback().await,
             SelfCommand::Uninstall { yes } => self_update::self_uninstall(yes).await,
         },
 
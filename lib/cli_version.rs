@@ -0,0 +1,210 @@
+//! Project-pinned tool-cli version requirements.
+//!
+//! A project declares the tool-cli versions it expects either in a `.toolversion`
+//! file next to `manifest.json` or in `_meta["store.tool.mcpb"].cli_version`.
+//! The value is a semver requirement (e.g., `0.2`, `>=0.2.0, <0.4`). A bare
+//! version like `0.2.0` is treated as `^0.2.0`.
+
+use crate::constants::{MCPB_MANIFEST_FILE, TOOLVERSION_FILE};
+use crate::error::{ToolError, ToolResult};
+use crate::mcpb::{TOOL_STORE_NAMESPACE, find_manifest_path, from_str_relaxed};
+use crate::self_update::VERSION;
+use colored::Colorize;
+use semver::{Version, VersionReq};
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A tool-cli version requirement declared by a project.
+#[derive(Debug, Clone)]
+pub struct CliVersionRequirement {
+    /// Parsed version requirement.
+    pub requirement: VersionReq,
+    /// Where the requirement was declared (e.g., ".toolversion").
+    pub source: String,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl CliVersionRequirement {
+    /// Check whether the given version satisfies this requirement.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.requirement.matches(version)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Find the tool-cli version requirement declared by a project directory.
+///
+/// `.toolversion` takes precedence over the manifest field.
+pub fn find_cli_version_requirement(dir: &Path) -> ToolResult<Option<CliVersionRequirement>> {
+    let toolversion_path = dir.join(TOOLVERSION_FILE);
+    if toolversion_path.is_file() {
+        let content = std::fs::read_to_string(&toolversion_path)?;
+        let Some(line) = content
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty() && !l.starts_with('#'))
+        else {
+            return Ok(None);
+        };
+        return parse_requirement(line, TOOLVERSION_FILE).map(Some);
+    }
+
+    let manifest_path = find_manifest_path(dir);
+    if !manifest_path.is_file() {
+        return Ok(None);
+    }
+
+    // Read raw JSON so a malformed manifest is reported by the command itself
+    let Ok(content) = std::fs::read_to_string(&manifest_path) else {
+        return Ok(None);
+    };
+    let Ok(manifest) = from_str_relaxed::<serde_json::Value>(&content) else {
+        return Ok(None);
+    };
+
+    match manifest
+        .get("_meta")
+        .and_then(|m| m.get(TOOL_STORE_NAMESPACE))
+        .and_then(|r| r.get("cli_version"))
+        .and_then(|v| v.as_str())
+    {
+        Some(req) => parse_requirement(
+            req,
+            &format!(
+                "{}:_meta[\"{}\"].cli_version",
+                MCPB_MANIFEST_FILE, TOOL_STORE_NAMESPACE
+            ),
+        )
+        .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Check the running tool-cli version against the project's requirement.
+///
+/// Prints a warning to stderr when the version is out of range, or fails if `strict` is set.
+/// Projects without a requirement always pass.
+pub fn check_cli_version(dir: &Path, strict: bool) -> ToolResult<()> {
+    let Some(req) = find_cli_version_requirement(dir)? else {
+        return Ok(());
+    };
+
+    let current = Version::parse(VERSION)
+        .map_err(|e| ToolError::Generic(format!("Invalid tool-cli version: {}", e)))?;
+    if req.matches(&current) {
+        return Ok(());
+    }
+
+    if strict {
+        return Err(ToolError::Generic(format!(
+            "tool-cli {} does not satisfy `{}` required by {}. Run `tool self update --version <VERSION>` to install a compatible version",
+            VERSION, req.requirement, req.source
+        )));
+    }
+
+    eprintln!(
+        "  {} tool-cli {} does not satisfy `{}` required by {}",
+        "⚠".bright_yellow(),
+        VERSION.bright_white(),
+        req.requirement.to_string().bright_cyan(),
+        req.source.dimmed()
+    );
+    eprintln!(
+        "  · {}: Run {} to install a compatible version",
+        "hint".bright_blue(),
+        "tool self update --version <VERSION>".bright_white()
+    );
+    eprintln!();
+
+    Ok(())
+}
+
+/// Parse a version requirement string from the given source.
+fn parse_requirement(value: &str, source: &str) -> ToolResult<CliVersionRequirement> {
+    let requirement = VersionReq::parse(value.trim().trim_start_matches('v')).map_err(|e| {
+        ToolError::Generic(format!(
+            "Invalid tool-cli version requirement `{}` in {}: {}",
+            value, source, e
+        ))
+    })?;
+
+    Ok(CliVersionRequirement {
+        requirement,
+        source: source.to_string(),
+    })
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_toolversion_file_takes_precedence() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(TOOLVERSION_FILE),
+            "# pinned for CI\n>=0.2.0, <0.3\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(MCPB_MANIFEST_FILE),
+            r#"{ "_meta": { "store.tool.mcpb": { "cli_version": "^9.0" } } }"#,
+        )
+        .unwrap();
+
+        let req = find_cli_version_requirement(dir.path()).unwrap().unwrap();
+        assert_eq!(req.source, TOOLVERSION_FILE);
+        assert!(req.matches(&Version::parse("0.2.5").unwrap()));
+        assert!(!req.matches(&Version::parse("0.3.0").unwrap()));
+    }
+
+    #[test]
+    fn test_manifest_cli_version() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(MCPB_MANIFEST_FILE),
+            r#"{ "_meta": { "store.tool.mcpb": { "cli_version": "v0.2.0" } } }"#,
+        )
+        .unwrap();
+
+        let req = find_cli_version_requirement(dir.path()).unwrap().unwrap();
+        assert!(req.matches(&Version::parse("0.2.3").unwrap()));
+        assert!(!req.matches(&Version::parse("0.1.0").unwrap()));
+    }
+
+    #[test]
+    fn test_no_requirement() {
+        let dir = TempDir::new().unwrap();
+        assert!(find_cli_version_requirement(dir.path()).unwrap().is_none());
+        assert!(check_cli_version(dir.path(), true).is_ok());
+    }
+
+    #[test]
+    fn test_strict_out_of_range_fails() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(TOOLVERSION_FILE), ">=999.0.0").unwrap();
+        assert!(check_cli_version(dir.path(), true).is_err());
+        assert!(check_cli_version(dir.path(), false).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_requirement() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(TOOLVERSION_FILE), "not a version").unwrap();
+        assert!(find_cli_version_requirement(dir.path()).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::styles::styles;
 use crate::{examples, examples_section};
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand};
 use std::ffi::OsString;
 
 //--------------------------------------------------------------------------------------------------
@@ -16,11 +16,18 @@ const INIT_EXAMPLES: &str = examples![
     "tool init my-tool -t node -y      " # "Skip prompts, use defaults",
     "tool init . --http                " # "Use HTTP transport instead of stdio",
     "tool init existing-project        " # "Detect and migrate existing MCP server",
+    "tool init existing-project -i     " # "Review detected values before migrating",
     "tool init . --reference           " # "Create manifest only (no scaffolding)",
     "tool init . --pm pnpm             " # "Use pnpm as package manager",
+    "tool init my-tool --docker        " # "Scaffold with a Dockerfile",
+    "tool init my-tool -t node --ts    " # "Scaffold a TypeScript server",
+    "tool init my-tool --template ../tpl" # "Scaffold from your own template",
+    "tool init my-tool -t node --git   " # "Scaffold and make an initial commit",
+    "tool init --list-types            " # "Show the types and package managers you can scaffold",
     "tool init . --command npx --args \"@anthropic/mcp-server\"" # "Reference external command",
     "tool init . --url https://api.example.com/mcp/" # "Reference remote HTTP server",
     "tool init . --url https://example.com --oauth-client-id abc" # "HTTP with OAuth",
+    "tool init . --url https://api.example.com/mcp --auth bearer" # "HTTP with a bearer API key",
 ];
 
 const DETECT_EXAMPLES: &str = examples![
@@ -29,6 +36,7 @@ const DETECT_EXAMPLES: &str = examples![
     "tool detect -e src/main.py        " # "Override detected entry point",
     "tool detect --transport http      " # "Override detected transport",
     "tool detect -n custom-name        " # "Override detected package name",
+    "tool detect --json                " # "JSON output for parsing",
 ];
 
 const SEARCH_EXAMPLES: &str = examples![
@@ -36,6 +44,11 @@ const SEARCH_EXAMPLES: &str = examples![
     "tool search weather               " # "Find weather tools",
     "tool search \"database sql\"        " # "Multi-word search",
     "tool search bash -c               " # "Concise output for scripts",
+    "tool search bash --json           " # "JSON output for parsing",
+    "tool search db --limit 50 --page 2" # "Page through results",
+    "tool search db --sort updated     " # "Recently updated first",
+    "tool search --category filesystem " # "Browse a category",
+    "tool search git --keyword github  " # "Combine a query with a keyword",
 ];
 
 const PREVIEW_EXAMPLES: &str = examples![
@@ -45,15 +58,37 @@ const PREVIEW_EXAMPLES: &str = examples![
     "tool preview ns/tool --tools      " # "List tools only",
     "tool preview ns/tool --json       " # "JSON output for parsing",
     "tool preview ns/tool -c           " # "Concise output",
+    "tool preview . --files            " # "Show files a pack would include",
+    "tool preview . --files --exclude 'tests/'" # "Preview with pack overrides",
+    "tool preview . --files --estimate " # "Estimate the compressed bundle size",
+];
+
+const VERSIONS_EXAMPLES: &str = examples![
+    "tool versions appcypher/bash      " # "List published versions",
+    "tool versions ns/tool -c          " # "Concise output for scripts",
+    "tool versions ns/tool --json      " # "JSON output for parsing",
+];
+
+const YANK_EXAMPLES: &str = examples![
+    "tool yank ns/tool@1.2.0           " # "Stop new installs picking 1.2.0",
+    "tool yank ns/tool@1.2.0 --json    " # "JSON output for parsing",
+];
+
+const DEPRECATE_EXAMPLES: &str = examples![
+    "tool deprecate ns/tool@1.2.0 -m \"Use 2.x\"" # "Deprecate with a message",
 ];
 
 const INSTALL_EXAMPLES: &str = examples![
     "tool install appcypher/bash              " # "Install from registry (latest)",
     "tool install appcypher/bash@1.0.0        " # "Install specific version",
+    "tool install appcypher/bash@beta         " # "Install the version tagged beta",
     "tool install ./my-local-tool             " # "Install from local directory",
     "tool install ~/tools/custom              " # "Install from home directory",
     "tool install ./local ns/a ns/b           " # "Install multiple packages",
     "tool install ns/tool --platform=universal" # "Install universal bundle",
+    "tool install ns/tool --dry-run           " # "Resolve without downloading",
+    "tool install ns/tool --dry-run --explain " # "Show how the install was resolved",
+    "tool install ns/a ns/b --locked          " # "Verify tools are installed (CI)",
 ];
 
 const UNINSTALL_EXAMPLES: &str = examples![
@@ -62,6 +97,8 @@ const UNINSTALL_EXAMPLES: &str = examples![
     "tool uninstall tool1 tool2 tool3  " # "Remove multiple tools",
     "tool uninstall --all              " # "Remove all installed tools",
     "tool uninstall --all -y           " # "Remove all without confirmation",
+    "tool uninstall --path ./my-tool   " # "Remove tool linked from a directory",
+    "tool uninstall --bundle t.mcpb    " # "Remove tool installed from a bundle",
 ];
 
 const LIST_EXAMPLES: &str = examples![
@@ -90,6 +127,7 @@ const INFO_EXAMPLES: &str = examples![
     "tool info                         " # "Inspect tool in current directory",
     "tool info appcypher/bash          " # "Inspect installed tool",
     "tool info appcypher/bash -c       " # "Concise output",
+    "tool info acme/weather            " # "Inspect a registry tool without installing",
     "tool info . -m exec               " # "Show specific method details",
     "tool info . -m exec -m read       " # "Show multiple methods",
     "tool info . -m exec --input       " # "Show only input schema",
@@ -101,18 +139,26 @@ const INFO_EXAMPLES: &str = examples![
     "tool info . -a                    " # "Show all capabilities",
     "tool info . --json                " # "JSON output for parsing",
     "tool info . -k API_KEY=xxx        " # "Pass config value",
+    "tool info . --config-schema       " # "Show the config keys a tool expects",
     "tool info . -L 5                  " # "Expand nested types to depth 5",
 ];
 
 const CALL_EXAMPLES: &str = examples![
-    "tool call . -m exec -p command=\"ls\" " # "Call method in current dir",
-    "tool call bash -m exec -p cmd=\"pwd\" " # "Call method with param",
-    "tool call bash -m exec -p cmd=ls    " # "Use -p flag for params",
-    "tool call weather -m get -p loc=NYC " # "Unquoted param value",
-    "tool call api -m query -k KEY=xxx   " # "Pass config inline",
-    "tool call . -m test --config-file   " # "Config from file",
-    "tool call . -m run -y               " # "Skip interactive prompts",
-    "tool call . -m debug -v             " # "Verbose output",
+    "tool call . -m exec -p command=\"ls\"    " # "Call method in current dir",
+    "tool call bash -m exec -p cmd=\"pwd\"    " # "Call method with param",
+    "tool call bash -m exec -p cmd=ls         " # "Use -p flag for params",
+    "tool call weather -m get -p loc=NYC      " # "Unquoted param value",
+    "tool call api -m query -k KEY=xxx        " # "Pass config inline",
+    "tool call . -m test --config-file        " # "Config from file",
+    "tool call . -m test --env-file .env      " # "Server env vars from a .env file",
+    "tool call ./t -m read --cwd .            " # "Run the server in the current dir",
+    "tool call . -m run -y                    " # "Skip interactive prompts",
+    "tool call . -m debug -v                  " # "Verbose output",
+    "tool call . -m dump --stream > out       " # "Write plain results to a file",
+    "tool call . -m draw -o ./images          " # "Save image/audio results to files",
+    "tool call . -m query --raw | jq          " # "Full JSON-RPC result for scripts",
+    "tool call . -m q --params-file a.json    " # "Arguments from a JSON file",
+    "tool call . -m q --params-stdin < a.json " # "Arguments as JSON from stdin",
 ];
 
 const DOWNLOAD_EXAMPLES: &str = examples![
@@ -129,7 +175,46 @@ const VALIDATE_EXAMPLES: &str = examples![
     "tool validate ./my-tool           " # "Validate specific path",
     "tool validate --strict            " # "Treat warnings as errors",
     "tool validate --json              " # "JSON output for CI/CD",
+    "tool validate --format sarif      " # "SARIF output for code scanning",
     "tool validate -q                  " # "Quiet mode (errors only)",
+    "tool validate --fix               " # "Auto-correct common issues",
+    "tool validate --fix --dry-run     " # "Preview fixes without writing",
+    "tool validate --connect           " # "Check declarations against the server",
+];
+
+const MIGRATE_EXAMPLES: &str = examples![
+    "tool migrate --schema             " # "Upgrade the manifest in the current directory",
+    "tool migrate --schema ./a ./b     " # "Upgrade several tools at once",
+    "tool migrate --schema --dry-run   " # "Show changes without writing",
+    "tool migrate -i ./my-server       " # "Convert an MCP server project, reviewing detected values",
+];
+
+const DIFF_EXAMPLES: &str = examples![
+    "tool diff ./v1 ./v2                       " # "Compare two tool directories",
+    "tool diff . appcypher/bash                " # "Compare with the latest published",
+    "tool diff bash@1.0.0.mcpb bash@1.1.0.mcpb " # "Compare two bundles",
+    "tool diff ns/tool@1.0.0 ns/tool@1.1.0     " # "Compare published versions",
+    "tool diff ./v1 ./v2 --json                " # "Structured output",
+];
+
+const VARS_EXAMPLES: &str = examples![
+    "tool vars                      " # "Report variables for current directory",
+    "tool vars appcypher/bash       " # "Report variables for an installed tool",
+    "tool vars -k API_KEY=xxx       " # "Resolve with a config value",
+    "tool vars --config-file c.json " # "Resolve with a config file",
+    "tool vars --json               " # "Structured output",
+];
+
+const SCRIPTS_EXAMPLES: &str = examples![
+    "tool scripts                " # "List scripts in current directory",
+    "tool scripts ./my-tool      " # "List scripts in specific directory",
+    "tool scripts --json         " # "Output as JSON for editors",
+];
+
+const SECURITY_SCAN_EXAMPLES: &str = examples![
+    "tool security scan --all          " # "Scan every installed tool",
+    "tool security scan appcypher/bash " # "Scan a specific tool",
+    "tool security scan --all --json   " # "Structured output for CI",
 ];
 
 const PACK_EXAMPLES: &str = examples![
@@ -139,6 +224,35 @@ const PACK_EXAMPLES: &str = examples![
     "tool pack --no-validate           " # "Skip validation step",
     "tool pack -v                      " # "Show files being added",
     "tool pack --multi-platform        " # "Pack bundles for each platform override",
+    "tool pack --platform linux-x64    " # "Pack the bundle a linux-x64 user would get",
+    "tool pack --checksums             " # "Include SHA-256 checksums.txt in the bundle",
+    "tool pack --exclude 'fixtures/'   " # "Leave out a path for this pack only",
+    "tool pack --manifest pkg/tool.json" # "Bundle a manifest kept in a subfolder",
+];
+
+const INSPECT_EXAMPLES: &str = examples![
+    "tool inspect my-tool-1.0.0.mcpb        " # "Show manifest summary and files",
+    "tool inspect my-tool-1.0.0.mcpb --json " # "Structured output for tooling",
+];
+
+const UNPACK_EXAMPLES: &str = examples![
+    "tool unpack my-tool-1.0.0.mcpb              " # "Unpack to ./my-tool-1.0.0",
+    "tool unpack my-tool-1.0.0.mcpb -o ./inspect " # "Unpack to a chosen directory",
+    "tool unpack my-tool.mcpb -o ./inspect -f    " # "Unpack into a non-empty directory",
+];
+
+const DEV_EXAMPLES: &str = examples![
+    "tool dev                          " # "Watch, rebuild and restart current dir",
+    "tool dev ./my-tool                " # "Watch another directory",
+    "tool dev --no-build               " # "Restart without running the build script",
+    "tool dev -k API_KEY=xxx           " # "Pass config value",
+];
+
+const LOGS_EXAMPLES: &str = examples![
+    "tool logs                 " # "Server logs for current directory",
+    "tool logs appcypher/bash  " # "Server logs for an installed tool",
+    "tool logs bash -n 200     " # "Show the last 200 lines",
+    "tool logs bash -f         " # "Follow new output",
 ];
 
 const RUN_EXAMPLES: &str = examples![
@@ -149,6 +263,8 @@ const RUN_EXAMPLES: &str = examples![
     "tool run . --expose http --host 0 " # "Bind to all interfaces",
     "tool run . -k API_KEY=xxx         " # "Pass config value",
     "tool run . --config-file creds.json" # "Config from file",
+    "tool run . --env-file .env        " # "Server env vars from a .env file",
+    "tool run ./my-tool --cwd .        " # "Run the server in the current dir",
     "tool run . -v                     " # "Verbose output",
 ];
 
@@ -157,7 +273,12 @@ const PUBLISH_EXAMPLES: &str = examples![
     "tool publish ./my-tool                                       " # "Publish specific directory",
     "tool publish --dry-run                                       " # "Preview without uploading",
     "tool publish --token \"your-token\"                            " # "Publish with explicit token",
+    "tool publish --access private                                " # "Publish a new tool as private",
+    "tool publish --tag beta                                      " # "Publish without moving latest",
+    "tool publish --otp 123456                                    " # "Publish with a one-time password",
+    "tool publish --require-provenance                            " # "Publish from CI with build provenance",
     "tool publish --multi-platform                                " # "Publish bundles for each platform",
+    "tool publish --platform linux-x64 --platform darwin-arm64    " # "Publish only the selected platforms",
     "tool publish --multi-platform --darwin-arm64 ./dist/mac.mcpb " # "Use pre-built bundle",
     "tool publish --multi-platform --universal ./dist/all.mcpb    " # "Specify universal bundle",
 ];
@@ -167,15 +288,29 @@ const LOGIN_EXAMPLES: &str = examples![
     "tool login --token \"your-token\"   " # "Non-interactive login",
 ];
 
+const LOGOUT_EXAMPLES: &str = examples![
+    "tool logout                       " # "Remove stored credentials",
+    "tool logout --registry https://tool.store" # "Only if stored for this registry",
+];
+
 const WHOAMI_EXAMPLES: &str = examples![
     "tool whoami                       " # "Show current auth status",
     "tool whoami --token \"your-token\"  " # "Validate a specific token",
+    "tool whoami --json                " # "Show auth status as JSON",
 ];
 
 const SELF_UPDATE_EXAMPLES: &str = examples![
     "tool self update                  " # "Update to latest version",
     "tool self update --check          " # "Check for updates only",
     "tool self update --version 0.2.0  " # "Install specific version",
+    "tool self update --dry-run        " # "Show what would be installed",
+    "tool self update --channel beta   " # "Follow the beta channel from now on",
+    "tool self update --channel stable --force" # "Return to stable, downgrading if needed",
+    "tool self update --no-verify      " # "Skip verification (trusted local testing only)",
+];
+
+const SELF_ROLLBACK_EXAMPLES: &str = examples![
+    "tool self rollback                " # "Restore the binary replaced by the last update",
 ];
 
 const SELF_UNINSTALL_EXAMPLES: &str = examples![
@@ -183,6 +318,16 @@ const SELF_UNINSTALL_EXAMPLES: &str = examples![
     "tool self uninstall -y            " # "Uninstall without confirmation",
 ];
 
+const PRUNE_EXAMPLES: &str = examples![
+    "tool prune                        " # "Review and remove orphaned entries",
+    "tool prune -y                     " # "Remove without confirmation",
+];
+
+const DOCTOR_EXAMPLES: &str = examples![
+    "tool doctor                       " # "Check your setup",
+    "tool doctor --json                " # "JSON output for CI",
+];
+
 const CONFIG_LIST_EXAMPLES: &str = examples![
     "tool config list                  " # "List all tools with saved config",
     "tool config list appcypher/bash   " # "Show config schema for a tool",
@@ -200,14 +345,37 @@ const CONFIG_SET_EXAMPLES: &str = examples![
 ];
 
 const CONFIG_GET_EXAMPLES: &str = examples![
-    "tool config get bash              " # "Show all config for tool",
-    "tool config get bash API_KEY      " # "Show specific key",
+    "tool config get bash                  " # "Show all config for tool",
+    "tool config get bash API_KEY          " # "Show specific key",
+    "tool config show bash                 " # "Same as get",
+    "tool config get bash API_KEY --reveal " # "Print a sensitive value unmasked",
 ];
 
 const CONFIG_UNSET_EXAMPLES: &str = examples![
     "tool config unset bash            " # "Remove config and credentials",
     "tool config unset --all           " # "Remove for all tools",
     "tool config unset --all -y        " # "Skip confirmation prompt",
+    "tool config clear bash            " # "Same as unset",
+];
+
+const VERSION_EXAMPLES: &str = examples![
+    "tool version patch                " # "1.2.3 → 1.2.4",
+    "tool version minor                " # "1.2.3 → 1.3.0",
+    "tool version prerelease           " # "1.2.3 → 1.2.4-0",
+    "tool version --set 2.0.0          " # "Set an explicit version",
+    "tool version patch --git-tag      " # "Bump and create tag v1.2.4",
+];
+
+const MANIFEST_GET_EXAMPLES: &str = examples![
+    "tool manifest get version                 " # "Print a field",
+    "tool manifest get server.mcp_config       " # "Print an object as JSON",
+    "tool manifest get name -C ./my-tool       " # "Read from another directory",
+];
+
+const MANIFEST_SET_EXAMPLES: &str = examples![
+    "tool manifest set version 1.2.3           " # "Bump the version",
+    "tool manifest set keywords '[\"mcp\"]'      " # "Set a JSON value",
+    "tool manifest set _meta.store.tool.mcpb.cli_version '^0.5'" # "Namespaced field",
 ];
 
 const HOST_ADD_EXAMPLES: &str = examples![
@@ -269,10 +437,37 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub concise: bool,
 
+    /// Output results as JSON (`{ ok, data, error }`) for scripts.
+    #[arg(long, global = true)]
+    pub json: bool,
+
     /// Suppress header line in concise mode (requires -c).
     #[arg(short = 'H', long, global = true)]
     pub no_header: bool,
 
+    /// Forbid all network access; installs must come from local sources (or set TOOL_OFFLINE=1).
+    #[arg(long, global = true)]
+    pub frozen: bool,
+
+    /// Progress reporting for install, download, and publish: `human` bars or
+    /// `json` events (one JSON object per line on stderr).
+    #[arg(long, global = true, value_name = "MODE", value_parser = ["human", "json"], default_value = "human")]
+    pub progress: String,
+
+    /// Proxy for registry requests (or set TOOL_PROXY). Without it, HTTPS_PROXY,
+    /// HTTP_PROXY, and NO_PROXY are honored.
+    #[arg(long, global = true, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// Disable colored output (or set NO_COLOR=1).
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// More detail: `-v` for verbose command output and info logs, `-vv` for debug
+    /// logs, `-vvv` for trace logs. Logs go to stderr; RUST_LOG overrides the level.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
     /// Subcommand to run.
     #[command(subcommand)]
     pub command: Command,
@@ -340,6 +535,33 @@ pub enum Command {
         #[arg(long)]
         verify: bool,
 
+        /// Review and edit detected values before migrating an existing project.
+        #[arg(short, long, conflicts_with = "yes")]
+        interactive: bool,
+
+        /// Also write a Dockerfile and run the server as a container over HTTP.
+        #[arg(long, conflicts_with = "reference")]
+        docker: bool,
+
+        /// Scaffold a Node.js server in TypeScript (src/index.ts compiled to dist/).
+        #[arg(long, alias = "ts", conflicts_with = "reference")]
+        typescript: bool,
+
+        /// Scaffold from a template directory or git URL instead of the built-in templates.
+        ///
+        /// The template must contain a manifest.json at its root. Files and paths may use
+        /// ${name}, ${name_snake}, ${author}, ${description}, ${license} and ${year}.
+        #[arg(long, conflicts_with_all = ["reference", "docker", "typescript"])]
+        template: Option<String>,
+
+        /// Initialize a git repository and commit the scaffolded files.
+        #[arg(long, conflicts_with = "reference")]
+        git: bool,
+
+        /// List the server types, transports, and package managers `tool init` can scaffold.
+        #[arg(long)]
+        list_types: bool,
+
         // === Reference mode options (mcp_config overrides) ===
         /// Command to execute (implies reference mode for stdio).
         #[arg(long)]
@@ -361,6 +583,10 @@ pub enum Command {
         #[arg(long = "header")]
         headers: Vec<String>,
 
+        /// Authentication for an HTTP server: none, bearer (API key from user config), or oauth.
+        #[arg(long, value_parser = ["none", "bearer", "oauth"])]
+        auth: Option<String>,
+
         /// OAuth client ID.
         #[arg(long = "oauth-client-id")]
         oauth_client_id: Option<String>,
@@ -409,14 +635,60 @@ pub enum Command {
     /// Search for tools in the registry.
     #[command(after_help = SEARCH_EXAMPLES)]
     Search {
-        /// Search query.
-        query: String,
+        /// Search query (optional when filtering by category or keyword).
+        #[arg(required_unless_present_any = ["category", "keyword"])]
+        query: Option<String>,
+
+        /// Only tools in this category (can be repeated; all must match).
+        #[arg(long)]
+        category: Vec<String>,
+
+        /// Only tools tagged with this keyword (can be repeated; all must match).
+        #[arg(long)]
+        keyword: Vec<String>,
+
+        /// Results per page.
+        #[arg(long, default_value = "20", value_parser = clap::value_parser!(u16).range(1..=100))]
+        limit: u16,
+
+        /// Page of results to show, starting at 1.
+        #[arg(long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..))]
+        page: u32,
+
+        /// Result order.
+        #[arg(long, default_value = "downloads", value_parser = ["downloads", "name", "updated"])]
+        sort: String,
+    },
+
+    /// List all published versions of a registry tool.
+    #[command(after_help = VERSIONS_EXAMPLES)]
+    Versions {
+        /// Tool reference (`namespace/name`).
+        tool: String,
+    },
+
+    /// Yank a published version so new installs skip it.
+    #[command(after_help = YANK_EXAMPLES)]
+    Yank {
+        /// Version reference (`namespace/name@version`).
+        tool: String,
+    },
+
+    /// Deprecate a published version with a message shown on install.
+    #[command(after_help = DEPRECATE_EXAMPLES)]
+    Deprecate {
+        /// Version reference (`namespace/name@version`).
+        tool: String,
+
+        /// Why the version is deprecated and what to use instead.
+        #[arg(short, long)]
+        message: String,
     },
 
     /// Preview a tool from the registry without installing.
     #[command(after_help = PREVIEW_EXAMPLES)]
     Preview {
-        /// Tool reference (`namespace/name[@version]`).
+        /// Tool reference (`namespace/name[@version]`), or a tool directory with --files.
         tool: String,
 
         /// Focus on specific methods by name (can be repeated).
@@ -447,25 +719,49 @@ pub enum Command {
         #[arg(short, long)]
         all: bool,
 
-        /// Output as JSON.
-        #[arg(long)]
-        json: bool,
-
         /// Max depth for expanding nested types in output schemas (default: 3).
         #[arg(short = 'L', long, default_value = "3")]
         level: usize,
+
+        /// Show the files `tool pack` would include from a local directory, without packing.
+        #[arg(long, conflicts_with_all = ["methods", "tools", "prompts", "all"])]
+        files: bool,
+
+        /// Exclude paths matching a gitignore-style glob (repeatable, requires --files).
+        #[arg(long, value_name = "GLOB", requires = "files")]
+        exclude: Vec<String>,
+
+        /// Re-include paths matching a glob (repeatable, requires --files).
+        #[arg(long, value_name = "GLOB", requires = "files")]
+        include: Vec<String>,
+
+        /// Estimate the compressed bundle size by deflating files in memory (requires --files).
+        #[arg(long, requires = "files")]
+        estimate: bool,
     },
 
     /// Install tools from the registry or local paths.
     #[command(after_help = INSTALL_EXAMPLES)]
     Install {
-        /// Tool references (`namespace/name[@version]`) or local paths.
+        /// Tool references (`namespace/name[@version|tag]`, default tag `latest`) or local paths.
         #[arg(required = true)]
         names: Vec<String>,
 
         /// Override platform detection (use "universal" for universal bundle).
         #[arg(long)]
         platform: Option<String>,
+
+        /// Resolve what would be installed without downloading or linking.
+        #[arg(long)]
+        dry_run: bool,
+
+        /// With --dry-run, print each resolution decision.
+        #[arg(long, requires = "dry_run")]
+        explain: bool,
+
+        /// Fail instead of installing when a tool isn't installed at the requested version.
+        #[arg(long, conflicts_with = "dry_run")]
+        locked: bool,
     },
 
     /// Uninstall installed tools.
@@ -481,6 +777,26 @@ pub enum Command {
         /// Skip confirmation prompt.
         #[arg(short, long)]
         yes: bool,
+
+        /// Uninstall the tool installed from this source directory.
+        #[arg(long, conflicts_with_all = ["names", "all", "bundle"])]
+        path: Option<String>,
+
+        /// Uninstall the tool installed from this bundle file.
+        #[arg(long, conflicts_with_all = ["names", "all"])]
+        bundle: Option<String>,
+    },
+
+    /// Diagnose common setup problems.
+    #[command(after_help = DOCTOR_EXAMPLES)]
+    Doctor,
+
+    /// Clean up broken symlinks and stale directories in the tools directory.
+    #[command(after_help = PRUNE_EXAMPLES)]
+    Prune {
+        /// Skip confirmation prompt.
+        #[arg(short, long)]
+        yes: bool,
     },
 
     /// List installed tools.
@@ -489,10 +805,6 @@ pub enum Command {
         /// Filter by name pattern.
         filter: Option<String>,
 
-        /// Output as JSON.
-        #[arg(long)]
-        json: bool,
-
         /// Include full tool info (tools, prompts, resources) for each server.
         #[arg(long)]
         full: bool,
@@ -535,10 +847,6 @@ pub enum Command {
         #[arg(short = 'l', long = "list")]
         list_only: bool,
 
-        /// Output as JSON.
-        #[arg(long)]
-        json: bool,
-
         /// Max depth for expanding nested types in output schemas (default: 3).
         #[arg(short = 'L', long, default_value = "3")]
         level: usize,
@@ -587,18 +895,22 @@ pub enum Command {
         #[arg(short, long)]
         all: bool,
 
-        /// Output as JSON.
+        /// Print the user_config and system_config schema instead of connecting.
         #[arg(long)]
-        json: bool,
+        config_schema: bool,
 
         /// Configuration values (KEY=VALUE).
         #[arg(short = 'k', long)]
         config: Vec<String>,
 
-        /// Path to config file (JSON).
+        /// Path to config file (JSON, TOML, or YAML).
         #[arg(long)]
         config_file: Option<String>,
 
+        /// Working directory for the server process (defaults to the tool's directory).
+        #[arg(long, value_name = "PATH")]
+        cwd: Option<String>,
+
         /// Don't auto-save config values for future use.
         #[arg(long)]
         no_save: bool,
@@ -607,10 +919,6 @@ pub enum Command {
         #[arg(short, long)]
         yes: bool,
 
-        /// Show verbose output.
-        #[arg(short, long)]
-        verbose: bool,
-
         /// Max depth for expanding nested types in output schemas (default: 3).
         #[arg(short = 'L', long, default_value = "3")]
         level: usize,
@@ -639,10 +947,18 @@ pub enum Command {
         #[arg(short = 'k', long)]
         config: Vec<String>,
 
-        /// Path to config file (JSON).
+        /// Path to config file (JSON, TOML, or YAML).
         #[arg(long)]
         config_file: Option<String>,
 
+        /// Load environment variables for the server from a .env file.
+        #[arg(long, value_name = "PATH")]
+        env_file: Option<String>,
+
+        /// Working directory for the server process (defaults to the tool's directory).
+        #[arg(long, value_name = "PATH")]
+        cwd: Option<String>,
+
         /// Don't auto-save config values for future use.
         #[arg(long)]
         no_save: bool,
@@ -651,13 +967,31 @@ pub enum Command {
         #[arg(short = 'y', long)]
         yes: bool,
 
-        /// Show verbose output.
+        /// Write the result to stdout as plain content, skipping the formatted view.
+        ///
+        /// The full result is still received before anything is written.
+        #[arg(long)]
+        stream: bool,
+
+        /// Save image, audio and resource results as files in this directory.
         #[arg(short, long)]
-        verbose: bool,
+        output_dir: Option<String>,
+
+        /// Output the complete, unformatted call result as JSON.
+        #[arg(long, conflicts_with_all = ["json", "stream"])]
+        raw: bool,
 
-        /// Output raw content without decorations.
+        /// Load method parameters from a JSON object file (KEY=VALUE params override).
+        #[arg(long, conflicts_with = "params_stdin")]
+        params_file: Option<String>,
+
+        /// Read method parameters as a JSON object from stdin (KEY=VALUE params override).
+        #[arg(long)]
+        params_stdin: bool,
+
+        /// Skip checking parameters against the input schema the server lists for the method.
         #[arg(long)]
-        json: bool,
+        no_validate: bool,
     },
 
     /// Download tools from the registry.
@@ -687,16 +1021,85 @@ pub enum Command {
         #[arg(long)]
         strict: bool,
 
-        /// Output as JSON.
-        #[arg(long)]
-        json: bool,
+        /// Machine-readable output format (`json` or `sarif`).
+        #[arg(long, value_parser = ["json", "sarif"], conflicts_with = "json")]
+        format: Option<String>,
 
         /// Show only errors, no details.
         #[arg(short, long)]
         quiet: bool,
+
+        /// Auto-correct safe, mechanical manifest issues before validating.
+        #[arg(long)]
+        fix: bool,
+
+        /// With --fix, show the changes without writing them.
+        #[arg(long, requires = "fix")]
+        dry_run: bool,
+
+        /// Start the server and check declared tools, prompts and resources against it.
+        #[arg(long)]
+        connect: bool,
+    },
+
+    /// Upgrade tool manifests to the current schema version.
+    #[command(after_help = MIGRATE_EXAMPLES)]
+    Migrate {
+        /// Tool directories (defaults to current directory).
+        paths: Vec<String>,
+
+        /// Migrate manifest.json to the current MCPB schema.
+        #[arg(long)]
+        schema: bool,
+
+        /// Show the changes without writing them.
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Convert an existing MCP server project, reviewing detected values first
+        /// (same as `tool init -i`).
+        #[arg(short, long, conflicts_with_all = ["schema", "dry_run"])]
+        interactive: bool,
+    },
+
+    /// Compare the manifests of two tools.
+    #[command(after_help = DIFF_EXAMPLES)]
+    Diff {
+        /// First tool: directory, .mcpb/.mcpbx bundle or `namespace/name[@version]`.
+        a: String,
+
+        /// Second tool: directory, .mcpb/.mcpbx bundle or `namespace/name[@version]`.
+        b: String,
+    },
+
+    /// Show how each `${...}` variable in a tool's server config resolves.
+    #[command(after_help = VARS_EXAMPLES)]
+    Vars {
+        /// Tool directory or installed tool reference.
+        #[arg(default_value = ".")]
+        tool: String,
+
+        /// Configuration values (KEY=VALUE).
+        #[arg(short = 'k', long)]
+        config: Vec<String>,
+
+        /// Path to config file (JSON, TOML, or YAML).
+        #[arg(long)]
+        config_file: Option<String>,
+    },
+
+    /// List the scripts defined in manifest.json.
+    ///
+    /// Exits with status 1 if no scripts are defined and 3 if there is no manifest.
+    #[command(after_help = SCRIPTS_EXAMPLES)]
+    Scripts {
+        /// Path to tool directory (defaults to current directory).
+        path: Option<String>,
     },
 
     /// Pack a tool into an .mcpb bundle.
+    ///
+    /// Runs the `prepack` and `postpack` scripts, if defined, around bundling.
     #[command(after_help = PACK_EXAMPLES)]
     Pack {
         /// Path to tool directory (defaults to current directory).
@@ -706,6 +1109,10 @@ pub enum Command {
         #[arg(short, long)]
         output: Option<String>,
 
+        /// Bundle this manifest instead of <PATH>/manifest.json (must be inside <PATH>).
+        #[arg(long, value_name = "FILE")]
+        manifest: Option<String>,
+
         /// Skip validation before packing.
         #[arg(long)]
         no_validate: bool,
@@ -714,15 +1121,53 @@ pub enum Command {
         #[arg(long)]
         strict: bool,
 
-        /// Show files being added.
-        #[arg(short, long)]
-        verbose: bool,
-
         /// Create bundles for each platform override (+ universal bundle).
         /// Checks _meta["store.tool.mcpb"].mcp_config.platform_overrides first,
         /// then falls back to server.mcp_config.platform_overrides.
         #[arg(long)]
         multi_platform: bool,
+
+        /// Pack a single bundle for one platform (e.g. "linux-x64"), with its overrides applied.
+        #[arg(long, value_name = "OS-ARCH", conflicts_with = "multi_platform")]
+        platform: Option<String>,
+
+        /// Add a checksums.txt listing each file's SHA-256 to the bundle.
+        #[arg(long)]
+        checksums: bool,
+
+        /// Exclude paths matching a gitignore-style glob (repeatable, applied after .mcpbignore).
+        #[arg(long, value_name = "GLOB")]
+        exclude: Vec<String>,
+
+        /// Re-include paths matching a glob (repeatable, applied after --exclude).
+        #[arg(long, value_name = "GLOB")]
+        include: Vec<String>,
+
+        /// Include files behind symlinks that point outside the tool directory.
+        #[arg(long)]
+        follow_symlinks: bool,
+    },
+
+    /// Show a bundle's manifest summary, contents, and checksum without installing it.
+    #[command(after_help = INSPECT_EXAMPLES)]
+    Inspect {
+        /// Bundle file (.mcpb or .mcpbx).
+        path: String,
+    },
+
+    /// Expand a bundle into a directory without installing it.
+    #[command(alias = "extract", after_help = UNPACK_EXAMPLES)]
+    Unpack {
+        /// Bundle file (.mcpb or .mcpbx).
+        bundle: String,
+
+        /// Directory to unpack into (defaults to the bundle name without its extension).
+        #[arg(short, long, value_name = "DIR")]
+        out: Option<String>,
+
+        /// Unpack into a non-empty directory, overwriting files with the same name.
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Run an MCP server in proxy mode.
@@ -748,10 +1193,18 @@ pub enum Command {
         #[arg(short = 'k', long)]
         config: Vec<String>,
 
-        /// Path to config file (JSON).
+        /// Path to config file (JSON, TOML, or YAML).
         #[arg(long)]
         config_file: Option<String>,
 
+        /// Load environment variables for the server from a .env file.
+        #[arg(long, value_name = "PATH")]
+        env_file: Option<String>,
+
+        /// Working directory for the server process (defaults to the tool's directory).
+        #[arg(long, value_name = "PATH")]
+        cwd: Option<String>,
+
         /// Don't auto-save config values for future use.
         #[arg(long)]
         no_save: bool,
@@ -759,13 +1212,74 @@ pub enum Command {
         /// Skip interactive prompts (error if required config missing).
         #[arg(short, long)]
         yes: bool,
+    },
 
-        /// Show verbose output.
+    /// Rebuild and restart a local tool whenever its sources change.
+    #[command(after_help = DEV_EXAMPLES)]
+    Dev {
+        /// Tool directory (default: current directory).
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Configuration values (KEY=VALUE).
+        #[arg(short = 'k', long)]
+        config: Vec<String>,
+
+        /// Path to config file (JSON, TOML, or YAML).
+        #[arg(long)]
+        config_file: Option<String>,
+
+        /// Don't auto-save config values for future use.
+        #[arg(long)]
+        no_save: bool,
+
+        /// Skip interactive prompts (error if required config missing).
         #[arg(short, long)]
-        verbose: bool,
+        yes: bool,
+
+        /// Don't run the build script before each restart.
+        #[arg(long)]
+        no_build: bool,
+    },
+
+    /// Show the captured stderr of a tool's server.
+    #[command(after_help = LOGS_EXAMPLES)]
+    Logs {
+        /// Tool directory or installed tool reference.
+        #[arg(default_value = ".")]
+        tool: String,
+
+        /// Keep printing new output as it's written.
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Number of trailing lines to show.
+        #[arg(short = 'n', long, default_value_t = 50)]
+        lines: usize,
+    },
+
+    /// Bump or set the manifest version.
+    #[command(after_help = VERSION_EXAMPLES)]
+    Version {
+        /// Component to increment (major, minor, patch, prerelease).
+        bump: Option<String>,
+
+        /// Set an explicit version instead of bumping.
+        #[arg(long, value_name = "VERSION", conflicts_with = "bump")]
+        set: Option<String>,
+
+        /// Directory containing manifest.json.
+        #[arg(short = 'C', long, default_value = ".")]
+        path: String,
+
+        /// Commit the updated manifest and tag it `v<VERSION>` (requires a clean tree).
+        #[arg(long)]
+        git_tag: bool,
     },
 
     /// Publish a tool to the registry.
+    ///
+    /// Runs the `prepack` and `postpack` scripts, if defined, around bundling.
     #[command(after_help = PUBLISH_EXAMPLES)]
     Publish {
         /// Path to tool directory.
@@ -784,6 +1298,15 @@ pub enum Command {
         #[arg(long)]
         multi_platform: bool,
 
+        /// Platform bundle to build (repeatable): an OS-arch key, "universal", or "all"
+        /// for every declared platform plus universal. Implies --multi-platform.
+        #[arg(
+            long = "platform",
+            value_name = "KEY",
+            conflicts_with_all = ["darwin_arm64", "darwin_x64", "linux_x64", "linux_arm64", "win32_x64", "win32_arm64", "universal"]
+        )]
+        platforms: Vec<String>,
+
         /// Pre-built bundle for darwin-arm64 (Apple Silicon Mac).
         #[arg(long, value_name = "PATH")]
         darwin_arm64: Option<String>,
@@ -815,6 +1338,26 @@ pub enum Command {
         /// API token (uses stored credentials if not provided).
         #[arg(long)]
         token: Option<String>,
+
+        /// Visibility for a newly created tool (ignored for existing tools).
+        #[arg(long, value_parser = ["public", "private"])]
+        access: Option<String>,
+
+        /// Dist-tag to point at this version; installs without a tag use `latest`.
+        #[arg(long, default_value = "latest")]
+        tag: String,
+
+        /// One-time password, if the registry requires one for publishing.
+        #[arg(long, value_name = "CODE")]
+        otp: Option<String>,
+
+        /// Attach build provenance from CI and git metadata (skipped with a warning if incomplete).
+        #[arg(long)]
+        provenance: bool,
+
+        /// Fail instead of publishing when build provenance is incomplete.
+        #[arg(long)]
+        require_provenance: bool,
     },
 
     /// Login to the registry.
@@ -826,7 +1369,12 @@ pub enum Command {
     },
 
     /// Logout from the registry.
-    Logout,
+    #[command(after_help = LOGOUT_EXAMPLES)]
+    Logout {
+        /// Only remove credentials stored for this registry URL.
+        #[arg(long)]
+        registry: Option<String>,
+    },
 
     /// Show authentication status.
     #[command(after_help = WHOAMI_EXAMPLES)]
@@ -848,7 +1396,34 @@ pub enum Command {
     #[command(subcommand)]
     Host(HostCommand),
 
+    /// Read or edit manifest.json fields.
+    #[command(subcommand)]
+    Manifest(ManifestCommand),
+
+    /// Inspect installed tools for security risks.
+    #[command(subcommand)]
+    Security(SecurityCommand),
+
+    /// Print shell completion candidates (used by completion scripts).
+    ///
+    /// `tools` lists installed tool references; `scripts` lists scripts from the
+    /// `manifest.json` in the current directory. Reads local state only.
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// What to complete.
+        #[arg(value_parser = ["tools", "scripts"])]
+        kind: String,
+
+        /// Only print candidates starting with this prefix.
+        #[arg(default_value = "")]
+        prefix: String,
+    },
+
     /// Catch-all for dynamic script names (e.g., `tool build`, `tool test`).
+    ///
+    /// `pre<name>` and `post<name>` scripts (e.g., `prebuild`, `postbuild`) run before and
+    /// after the script when defined. Pass `--capture` (e.g., `tool build --capture`) to
+    /// annotate output for CI logs.
     #[command(external_subcommand)]
     External(Vec<OsString>),
 }
@@ -863,11 +1438,31 @@ pub enum SelfCommand {
         #[arg(long)]
         check: bool,
 
-        /// Install a specific version.
+        /// Install a specific version (semver, e.g. 0.2.0).
         #[arg(long)]
         version: Option<String>,
+
+        /// Release channel to follow (saved as the default for later updates).
+        #[arg(long, value_parser = ["stable", "beta"])]
+        channel: Option<String>,
+
+        /// Allow downgrading, e.g. from a beta build to the latest stable release.
+        #[arg(long)]
+        force: bool,
+
+        /// Skip checksum and signature verification (trusted local testing only).
+        #[arg(long)]
+        no_verify: bool,
+
+        /// Show the resolved version and download without installing anything.
+        #[arg(long)]
+        dry_run: bool,
     },
 
+    /// Restore the version replaced by the last self-update.
+    #[command(after_help = SELF_ROLLBACK_EXAMPLES)]
+    Rollback,
+
     /// Uninstall tool-cli from this system.
     #[command(after_help = SELF_UNINSTALL_EXAMPLES)]
     Uninstall {
@@ -899,8 +1494,8 @@ pub enum ConfigCommand {
         config: Vec<String>,
     },
 
-    /// Show configuration for a tool.
-    #[command(alias = "g", after_help = CONFIG_GET_EXAMPLES)]
+    /// Show configuration for a tool (sensitive values are masked unless --reveal).
+    #[command(alias = "g", visible_alias = "show", after_help = CONFIG_GET_EXAMPLES)]
     Get {
         /// Tool reference.
         tool: String,
@@ -908,9 +1503,9 @@ pub enum ConfigCommand {
         /// Specific key to show (shows all if omitted).
         key: Option<String>,
 
-        /// Output as JSON.
+        /// Print sensitive values unmasked.
         #[arg(long)]
-        json: bool,
+        reveal: bool,
     },
 
     /// List configured tools, or show config schema for a specific tool.
@@ -918,14 +1513,10 @@ pub enum ConfigCommand {
     List {
         /// Tool reference or path (shows config schema for that tool).
         tool: Option<String>,
-
-        /// Output as JSON.
-        #[arg(long)]
-        json: bool,
     },
 
     /// Remove tool configuration and credentials.
-    #[command(alias = "u", after_help = CONFIG_UNSET_EXAMPLES)]
+    #[command(alias = "u", visible_alias = "clear", after_help = CONFIG_UNSET_EXAMPLES)]
     Unset {
         /// Tool reference.
         tool: Option<String>,
@@ -940,6 +1531,54 @@ pub enum ConfigCommand {
     },
 }
 
+/// Manifest subcommands for scripted field edits.
+#[derive(Debug, Subcommand)]
+pub enum ManifestCommand {
+    /// Print a manifest field.
+    #[command(after_help = MANIFEST_GET_EXAMPLES)]
+    Get {
+        /// Field path (e.g., `version`, `server.mcp_config.args[0]`, `_meta["store.tool.mcpb"]`).
+        field: String,
+
+        /// Directory containing manifest.json.
+        #[arg(short = 'C', long, default_value = ".")]
+        path: String,
+    },
+
+    /// Set a manifest field after validating the result.
+    #[command(after_help = MANIFEST_SET_EXAMPLES)]
+    Set {
+        /// Field path (e.g., `version`, `server.mcp_config.args[0]`, `_meta["store.tool.mcpb"]`).
+        field: String,
+
+        /// New value (parsed as JSON, otherwise used as a string).
+        value: String,
+
+        /// Directory containing manifest.json.
+        #[arg(short = 'C', long, default_value = ".")]
+        path: String,
+    },
+}
+
+/// Security subcommands for auditing installed tools.
+#[derive(Debug, Subcommand)]
+pub enum SecurityCommand {
+    /// Scan installed tool manifests for risky configurations.
+    ///
+    /// Reports plaintext secrets, broad filesystem access, executables outside the bundle
+    /// and network access. Exits with status 1 if any high-severity finding is reported.
+    #[command(after_help = SECURITY_SCAN_EXAMPLES)]
+    Scan {
+        /// Installed tool reference.
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        tool: Option<String>,
+
+        /// Scan all installed tools.
+        #[arg(long)]
+        all: bool,
+    },
+}
+
 /// Host subcommands for managing MCP host configurations.
 #[derive(Debug, Subcommand)]
 pub enum HostCommand {
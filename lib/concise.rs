@@ -39,6 +39,7 @@ pub struct ConciseSearchResult<'a> {
     pub version: Option<&'a str>,
     pub description: Option<&'a str>,
     pub downloads: u64,
+    pub categories: &'a [String],
 }
 
 /// A tool schema entry for grep output.
@@ -156,17 +157,18 @@ pub fn quote_always(s: &str) -> String {
 }
 
 /// Format a list of tools for concise output (Header + TSV).
-/// Columns: name, type, path
+/// Columns: ref, type, description, location
 pub fn format_tool_list(entries: &[ConciseToolEntry], no_header: bool) -> String {
     let mut lines = Vec::new();
     if !no_header {
-        lines.push("#name\ttype\tpath".to_string());
+        lines.push("#ref\ttype\tdescription\tlocation".to_string());
     }
     for e in entries {
         lines.push(format!(
-            "{}\t{}\t{}",
+            "{}\t{}\t{}\t{}",
             e.name,
             e.tool_type,
+            quote(e.description.unwrap_or("")),
             quote(&e.path.display().to_string())
         ));
     }
@@ -174,11 +176,11 @@ pub fn format_tool_list(entries: &[ConciseToolEntry], no_header: bool) -> String
 }
 
 /// Format search results for concise output (Header + TSV).
-/// Columns: ref, description, downloads
+/// Columns: ref, description, downloads, categories
 pub fn format_search_results(results: &[ConciseSearchResult], no_header: bool) -> String {
     let mut lines = Vec::new();
     if !no_header {
-        lines.push("#ref\tdescription\tdownloads".to_string());
+        lines.push("#ref\tdescription\tdownloads\tcategories".to_string());
     }
     for r in results {
         let ref_str = match r.version {
@@ -186,7 +188,13 @@ pub fn format_search_results(results: &[ConciseSearchResult], no_header: bool) -
             None => format!("{}/{}", r.namespace, r.name),
         };
         let desc = r.description.unwrap_or("");
-        lines.push(format!("{}\t{}\t{}", ref_str, quote(desc), r.downloads));
+        lines.push(format!(
+            "{}\t{}\t{}\t{}",
+            ref_str,
+            quote(desc),
+            r.downloads,
+            quote(&r.categories.join(","))
+        ));
     }
     lines.join("\n")
 }
@@ -489,8 +497,12 @@ mod tests {
         }];
 
         let output = format_tool_list(&entries, false);
-        assert!(output.starts_with("#name\ttype\tpath"));
-        assert!(output.contains("appcypher/filesystem"));
+        assert_eq!(
+            output,
+            "#ref\ttype\tdescription\tlocation\n\
+             appcypher/filesystem\tstdio\t\"File operations\"\t\
+             /home/user/.tool/tools/appcypher/filesystem@0.1.2"
+        );
     }
 
     #[test]
@@ -516,12 +528,13 @@ mod tests {
             version: Some("0.1.2"),
             description: Some("File operations"),
             downloads: 1000,
+            categories: &["filesystem".to_string(), "storage".to_string()],
         }];
 
         let output = format_search_results(&results, false);
-        assert!(output.starts_with("#ref\tdescription\tdownloads"));
+        assert!(output.starts_with("#ref\tdescription\tdownloads\tcategories"));
         assert!(output.contains("appcypher/filesystem@0.1.2"));
-        assert!(output.contains("1000"));
+        assert!(output.ends_with("1000\tfilesystem,storage"));
     }
 
     #[test]
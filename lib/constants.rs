@@ -13,21 +13,88 @@ use std::sync::LazyLock;
 /// The manifest file name for MCPB bundles.
 pub const MCPB_MANIFEST_FILE: &str = "manifest.json";
 
+/// Hand-authored manifest allowing comments and trailing commas, used when
+/// `manifest.json` is absent. Bundles always contain a strict `manifest.json`.
+pub const MCPB_MANIFEST_JSON5_FILE: &str = "manifest.json5";
+
+/// Checksum listing added to bundles packed with checksums enabled.
+pub const CHECKSUMS_FILE: &str = "checksums.txt";
+
 /// File extension for standard MCPB bundles.
 pub const MCPB_EXT: &str = "mcpb";
 
 /// File extension for MCPB extended bundles (reference mode, HTTP, system_config, etc.).
 pub const MCPBX_EXT: &str = "mcpbx";
 
+/// Platform keys (`{os}-{arch}`) that can be packed as separate bundles.
+pub const OS_ARCH_PLATFORMS: &[&str] = &[
+    "darwin-arm64",
+    "darwin-x64",
+    "darwin-x86_64",
+    "linux-arm64",
+    "linux-x64",
+    "linux-x86_64",
+    "win32-arm64",
+    "win32-x64",
+    "win32-x86_64",
+];
+
+/// Project file pinning the tool-cli version range a project expects.
+pub const TOOLVERSION_FILE: &str = ".toolversion";
+
 /// Default registry URL.
 pub const DEFAULT_REGISTRY_URL: &str = "https://tool.store";
 
+/// Dist-tag that installs without a version or tag resolve to.
+pub const DEFAULT_DIST_TAG: &str = "latest";
+
 /// Environment variable for custom registry URL.
 pub const TOOL_REGISTRY_ENV: &str = "TOOL_REGISTRY";
 
 /// Environment variable for registry auth token.
 pub const REGISTRY_TOKEN_ENV: &str = "TOOL_REGISTRY_TOKEN";
 
+/// Environment variable that disables all network access (same as `--frozen`).
+pub const TOOL_OFFLINE_ENV: &str = "TOOL_OFFLINE";
+
+/// Environment variable that disables colored output when set to a non-empty value
+/// (same as `--no-color`; see <https://no-color.org>).
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// Prefix of environment variables that supply `user_config` values
+/// (e.g. `TOOL_CONFIG_API_KEY` for `api_key`).
+pub const TOOL_CONFIG_ENV_PREFIX: &str = "TOOL_CONFIG_";
+
+/// Environment variable overriding the maximum uncompressed bundle size, in bytes.
+pub const TOOL_MAX_BUNDLE_SIZE_ENV: &str = "TOOL_MAX_BUNDLE_SIZE";
+
+/// Environment variable overriding the maximum uncompressed size of one bundle entry, in bytes.
+pub const TOOL_MAX_ENTRY_SIZE_ENV: &str = "TOOL_MAX_ENTRY_SIZE";
+
+/// Environment variable holding the minisign public key `tool self update` verifies
+/// release signatures against. Signature verification is skipped when unset.
+pub const TOOL_UPDATE_PUBKEY_ENV: &str = "TOOL_UPDATE_PUBKEY";
+
+/// Environment variable listing extra tool directories to search after the default
+/// tools directory, separated like `PATH` (`:` on Unix, `;` on Windows).
+pub const TOOL_PATH_ENV: &str = "TOOL_PATH";
+
+/// Environment variable naming a proxy for registry requests (`--proxy` takes precedence).
+pub const TOOL_PROXY_ENV: &str = "TOOL_PROXY";
+
+/// Environment variable naming a PEM file of extra CA certificates to trust for registry
+/// requests, e.g. for TLS-inspecting proxies.
+pub const TOOL_CA_BUNDLE_ENV: &str = "TOOL_CA_BUNDLE";
+
+/// Environment variable that turns hard-linking identical installed files to a shared
+/// object store on (`1`, `true`, `yes`) or off (anything else), overriding the `dedup`
+/// setting.
+pub const TOOL_DEDUP_ENV: &str = "TOOL_DEDUP";
+
+/// Environment variable overriding how long each stdio initialize handshake may take, in
+/// seconds (20 by default).
+pub const TOOL_HANDSHAKE_TIMEOUT_ENV: &str = "TOOL_HANDSHAKE_TIMEOUT";
+
 /// Environment variable for credentials encryption key.
 pub const CREDENTIALS_SECRET_KEY_ENV: &str = "CREDENTIALS_SECRET_KEY";
 
@@ -38,6 +105,10 @@ pub static DEFAULT_HOME_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
         .unwrap_or_else(|| PathBuf::from(".tool"))
 });
 
+/// Default path for global settings.
+pub static DEFAULT_SETTINGS_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| DEFAULT_HOME_PATH.join("settings.json"));
+
 /// Default path for tool installations.
 pub static DEFAULT_TOOLS_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| DEFAULT_HOME_PATH.join("tools"));
@@ -68,10 +139,21 @@ pub static REGISTRY_AUTH_DIR: LazyLock<PathBuf> = LazyLock::new(|| DEFAULT_HOME_
 pub static DEFAULT_CONFIG_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| DEFAULT_HOME_PATH.join("config"));
 
+/// Default path for captured server logs (one file per tool).
+pub static DEFAULT_LOGS_PATH: LazyLock<PathBuf> = LazyLock::new(|| DEFAULT_HOME_PATH.join("logs"));
+
+/// Default path for the content-addressed store of deduplicated installed files.
+pub static DEFAULT_OBJECTS_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| DEFAULT_HOME_PATH.join("objects"));
+
 /// Default path for host config backups.
 pub static DEFAULT_BACKUPS_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| DEFAULT_HOME_PATH.join("backups"));
 
+/// Path to persisted `tool self update` preferences (e.g. the release channel).
+pub static SELF_UPDATE_STATE_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| DEFAULT_HOME_PATH.join("self_update.json"));
+
 /// Default path for host metadata (tracking managed tools).
 pub static DEFAULT_HOSTS_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| DEFAULT_HOME_PATH.join("hosts"));
@@ -84,3 +166,14 @@ pub static DEFAULT_HOSTS_PATH: LazyLock<PathBuf> =
 pub fn get_registry_url() -> String {
     std::env::var(TOOL_REGISTRY_ENV).unwrap_or_else(|_| DEFAULT_REGISTRY_URL.to_string())
 }
+
+/// Extra tool directories from the TOOL_PATH env var, in priority order.
+pub fn get_extra_tool_paths() -> Vec<PathBuf> {
+    std::env::var_os(TOOL_PATH_ENV)
+        .map(|paths| {
+            std::env::split_paths(&paths)
+                .filter(|p| !p.as_os_str().is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -9,7 +9,9 @@ mod rust;
 mod utils;
 
 use crate::error::{ToolError, ToolResult};
-use crate::mcpb::{McpbManifest, McpbServerType, McpbTransport, PackageManager};
+use crate::mcpb::{
+    McpbManifest, McpbServerType, McpbTransport, McpbUserConfigType, PackageManager,
+};
 use std::path::{Path, PathBuf};
 
 pub use node::NodeDetector;
@@ -17,6 +19,7 @@ pub use python::PythonDetector;
 pub use rust::RustDetector;
 pub use utils::{
     FileGrepMatch, GrepOptions, grep_dir, has_any_pattern, has_pattern, parse_env_example,
+    parse_env_file,
 };
 
 /// Callback type for reporting detection signals as they happen.
@@ -145,6 +148,22 @@ pub enum EnvValueType {
     Boolean,
     Port,
     Hostname,
+    Url,
+    Directory,
+    File,
+}
+
+impl EnvValueType {
+    /// Map to the manifest user_config field type.
+    pub fn user_config_type(self) -> McpbUserConfigType {
+        match self {
+            Self::Number | Self::Port => McpbUserConfigType::Number,
+            Self::Boolean => McpbUserConfigType::Boolean,
+            Self::Directory => McpbUserConfigType::Directory,
+            Self::File => McpbUserConfigType::File,
+            Self::String | Self::Hostname | Self::Url => McpbUserConfigType::String,
+        }
+    }
 }
 
 impl EnvVar {
@@ -209,7 +228,7 @@ pub enum DetectError {
     NoEntryPoint,
     /// Project type could not be determined.
     UnknownProjectType,
-    /// Multiple project types detected with similar confidence.
+    /// Multiple project types or binaries detected; the candidates are listed.
     AmbiguousProject(Vec<String>),
     /// IO error during detection.
     IoError(String),
@@ -221,7 +240,7 @@ impl std::fmt::Display for DetectError {
             Self::NoEntryPoint => write!(f, "Could not detect entry point"),
             Self::UnknownProjectType => write!(f, "Could not determine project type"),
             Self::AmbiguousProject(types) => {
-                write!(f, "Multiple project types detected: {}", types.join(", "))
+                write!(f, "Multiple candidates detected: {}", types.join(", "))
             }
             Self::IoError(e) => write!(f, "IO error: {}", e),
         }
@@ -561,4 +580,24 @@ mod tests {
         };
         assert_eq!(var2.config_key(), "database_url");
     }
+
+    #[test]
+    fn test_env_value_type_user_config_type() {
+        assert!(matches!(
+            EnvValueType::Url.user_config_type(),
+            McpbUserConfigType::String
+        ));
+        assert!(matches!(
+            EnvValueType::Directory.user_config_type(),
+            McpbUserConfigType::Directory
+        ));
+        assert!(matches!(
+            EnvValueType::File.user_config_type(),
+            McpbUserConfigType::File
+        ));
+        assert!(matches!(
+            EnvValueType::Port.user_config_type(),
+            McpbUserConfigType::Number
+        ));
+    }
 }
@@ -10,6 +10,7 @@ use crate::mcpb::{
     McpbUserConfigType, NodePackageManager, PackageManager,
 };
 use crate::scaffold::mcpbignore_template;
+use crate::validate::validators::fields::is_valid_package_name;
 use std::collections::BTreeMap;
 use std::path::Path;
 
@@ -20,6 +21,16 @@ use std::path::Path;
 /// Detector for Node.js MCP server projects.
 pub struct NodeDetector;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Default manifest name when none can be derived from package.json.
+const DEFAULT_TOOL_NAME: &str = "my-mcp-server";
+
+/// Public npm registry host.
+const NPM_PUBLIC_REGISTRY: &str = "registry.npmjs.org";
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -64,19 +75,10 @@ impl NodeDetector {
         }
 
         // 2. Check package.json.bin (first, then check existence)
-        let bin_entry = if let Some(bin) = pkg.get("bin") {
-            match bin {
-                serde_json::Value::String(s) => Some(s.clone()),
-                serde_json::Value::Object(obj) => obj
-                    .values()
-                    .next()
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string()),
-                _ => None,
-            }
-        } else {
-            None
-        };
+        let package_name = pkg.get("name").and_then(|v| v.as_str());
+        let bin_entry = pkg
+            .get("bin")
+            .and_then(|bin| select_bin_entry(bin, package_name));
 
         if let Some(ref entry) = bin_entry
             && dir.join(entry).exists()
@@ -257,6 +259,18 @@ impl NodeDetector {
             );
         }
 
+        if let Some(scope) = pkg
+            .get("name")
+            .and_then(|v| v.as_str())
+            .and_then(|n| split_package_name(n).0)
+            && let Some(registry) = private_registry_for_scope(dir, &pkg, scope)
+        {
+            notes.push(format!(
+                "Scope @{} resolves from private registry {}. Dependencies may need registry credentials to build.",
+                scope, registry
+            ));
+        }
+
         let run_args = if let Some(ref ep) = entry_point {
             vec![format!("${{__dirname}}/{}", ep)]
         } else {
@@ -318,6 +332,88 @@ impl Default for NodeDetector {
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Split an npm package name into its scope (without `@`) and bare name.
+fn split_package_name(name: &str) -> (Option<&str>, &str) {
+    match name.strip_prefix('@').and_then(|rest| rest.split_once('/')) {
+        Some((scope, bare)) => (Some(scope), bare),
+        None => (None, name),
+    }
+}
+
+/// Derive a manifest tool name from an npm package name.
+///
+/// Drops the scope and sanitizes the rest into a valid package name, e.g.
+/// `@acme/Weather_Server` becomes `weather-server`.
+fn tool_name_from_package(name: &str) -> Option<String> {
+    let (_, bare) = split_package_name(name);
+
+    let mut sanitized = String::with_capacity(bare.len());
+    for c in bare.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            sanitized.push(c);
+        } else if !sanitized.is_empty() && !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+
+    let sanitized = sanitized
+        .trim_start_matches(|c: char| !c.is_ascii_lowercase())
+        .trim_end_matches('-')
+        .to_string();
+
+    is_valid_package_name(&sanitized).then_some(sanitized)
+}
+
+/// Choose the entry point from a package.json `bin` field.
+///
+/// `bin` is either a single path or a map of command names to paths. For a map, the
+/// command matching the package name is preferred, falling back to the first entry.
+fn select_bin_entry(bin: &serde_json::Value, package_name: Option<&str>) -> Option<String> {
+    match bin {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Object(obj) => {
+            let matching = package_name.and_then(|name| {
+                let (_, bare) = split_package_name(name);
+                obj.get(name).or_else(|| obj.get(bare))
+            });
+            matching
+                .or_else(|| obj.values().next())
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Find a non-public registry configured for a package scope.
+///
+/// Checks `@scope:registry` in the project's `.npmrc`, then `publishConfig.registry`.
+fn private_registry_for_scope(dir: &Path, pkg: &serde_json::Value, scope: &str) -> Option<String> {
+    let key = format!("@{}:registry", scope);
+    let from_npmrc = std::fs::read_to_string(dir.join(".npmrc"))
+        .ok()
+        .and_then(|npmrc| {
+            npmrc.lines().find_map(|line| {
+                let (k, v) = line.split_once('=')?;
+                (k.trim() == key).then(|| v.trim().to_string())
+            })
+        });
+
+    let registry = from_npmrc.or_else(|| {
+        pkg.get("publishConfig")
+            .and_then(|c| c.get("registry"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    })?;
+
+    (!registry.contains(NPM_PUBLIC_REGISTRY)).then_some(registry)
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -371,10 +467,10 @@ impl ProjectDetector for NodeDetector {
         } else if let Some(pkg) = read_json::<serde_json::Value>(&dir.join("package.json")) {
             pkg.get("name")
                 .and_then(|v| v.as_str())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "my-mcp-server".to_string())
+                .and_then(tool_name_from_package)
+                .unwrap_or_else(|| DEFAULT_TOOL_NAME.to_string())
         } else {
-            "my-mcp-server".to_string()
+            DEFAULT_TOOL_NAME.to_string()
         };
 
         // Build mcp_config
@@ -678,4 +774,83 @@ mod tests {
         assert_eq!(pm, NodePackageManager::Npm); // Default
         assert!(!certain);
     }
+
+    #[test]
+    fn test_select_bin_entry_string() {
+        let bin = serde_json::json!("dist/cli.js");
+        assert_eq!(
+            select_bin_entry(&bin, Some("@acme/server")),
+            Some("dist/cli.js".to_string())
+        );
+    }
+
+    #[test]
+    fn test_select_bin_entry_object() {
+        let bin = serde_json::json!({
+            "server-admin": "dist/admin.js",
+            "server": "dist/server.js"
+        });
+
+        // Matches the bare name of a scoped package
+        assert_eq!(
+            select_bin_entry(&bin, Some("@acme/server")),
+            Some("dist/server.js".to_string())
+        );
+
+        // Falls back to the first entry when nothing matches
+        assert_eq!(
+            select_bin_entry(&bin, Some("other")),
+            Some("dist/admin.js".to_string())
+        );
+        assert_eq!(
+            select_bin_entry(&bin, None),
+            Some("dist/admin.js".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tool_name_from_package() {
+        assert_eq!(
+            tool_name_from_package("@acme/weather-server"),
+            Some("weather-server".to_string())
+        );
+        assert_eq!(
+            tool_name_from_package("@acme/Weather_Server.js"),
+            Some("weather-server-js".to_string())
+        );
+        assert_eq!(
+            tool_name_from_package("plain-server"),
+            Some("plain-server".to_string())
+        );
+        assert_eq!(tool_name_from_package("@acme/x"), None);
+    }
+
+    #[test]
+    fn test_detect_scoped_private_registry_note() {
+        let tmp = TempDir::new().unwrap();
+        let pkg = serde_json::json!({
+            "name": "@acme/server",
+            "main": "index.js"
+        });
+        fs::write(
+            tmp.path().join("package.json"),
+            serde_json::to_string_pretty(&pkg).unwrap(),
+        )
+        .unwrap();
+        fs::write(tmp.path().join("index.js"), "// server code").unwrap();
+        fs::write(
+            tmp.path().join(".npmrc"),
+            "@acme:registry=https://npm.acme.internal/\n",
+        )
+        .unwrap();
+
+        let result = NodeDetector::new().detect(tmp.path()).unwrap();
+        assert!(
+            result
+                .details
+                .notes
+                .iter()
+                .any(|n| n.contains("npm.acme.internal"))
+        );
+    }
 }
@@ -139,34 +139,27 @@ impl PythonDetector {
     fn detect_entry_point(&self, dir: &Path) -> (Option<String>, Option<String>, bool, bool) {
         // 1. Check pyproject.toml for scripts (from config)
         if let Some(pyproject) = read_toml::<PyProject>(&dir.join("pyproject.toml")) {
-            // Check [project.scripts]
-            if let Some(scripts) = pyproject.project.as_ref().and_then(|p| p.scripts.as_ref())
-                && let Some((script_name, first)) = scripts.iter().next()
-                && let Some(entry) = first.as_str()
+            let project = pyproject.project.as_ref();
+            let poetry = pyproject.tool.as_ref().and_then(|t| t.poetry.as_ref());
+            let project_name = project
+                .and_then(|p| p.name.as_deref())
+                .or_else(|| poetry.and_then(|p| p.name.as_deref()));
+
+            // Check [project.scripts], then [tool.poetry.scripts]
+            let scripts = project
+                .and_then(|p| p.scripts.as_ref())
+                .or_else(|| poetry.and_then(|p| p.scripts.as_ref()));
+
+            if let Some(scripts) = scripts
+                && let Some((script_name, entry)) = select_script(scripts, project_name)
                 && let Some(module) = entry.split(':').next()
             {
                 // Try to find the source file
-                if let Some(path) = self.find_module_path(dir, module) {
-                    return (Some(path), Some(script_name.clone()), true, true);
+                if let Some(path) = self.find_module_path(dir, module.trim()) {
+                    return (Some(path), Some(script_name.to_string()), true, true);
                 }
                 // Even if file not found, return script name for running
-                return (None, Some(script_name.clone()), false, true);
-            }
-
-            // Check [tool.poetry.scripts]
-            if let Some(scripts) = pyproject
-                .tool
-                .as_ref()
-                .and_then(|t| t.poetry.as_ref())
-                .and_then(|p| p.scripts.as_ref())
-                && let Some((script_name, first)) = scripts.iter().next()
-                && let Some(entry) = first.as_str()
-                && let Some(module) = entry.split(':').next()
-            {
-                if let Some(path) = self.find_module_path(dir, module) {
-                    return (Some(path), Some(script_name.clone()), true, true);
-                }
-                return (None, Some(script_name.clone()), false, true);
+                return (None, Some(script_name.to_string()), false, true);
             }
         }
 
@@ -608,6 +601,36 @@ impl ProjectDetector for PythonDetector {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Pick the console script to run from a `scripts` table.
+///
+/// Prefers a script named after the project, then one that looks like a server,
+/// then the first entry.
+fn select_script<'a>(
+    scripts: &'a toml::Table,
+    project_name: Option<&str>,
+) -> Option<(&'a str, &'a str)> {
+    let entries: Vec<(&str, &str)> = scripts
+        .iter()
+        .filter_map(|(name, value)| value.as_str().map(|entry| (name.as_str(), entry)))
+        .collect();
+
+    let normalize = |s: &str| s.to_lowercase().replace('_', "-");
+
+    project_name
+        .and_then(|project| {
+            let project = normalize(project);
+            entries.iter().find(|(name, _)| normalize(name) == project)
+        })
+        .or_else(|| {
+            entries.iter().find(|(name, _)| {
+                let name = normalize(name);
+                name.contains("mcp") || name.contains("server")
+            })
+        })
+        .or_else(|| entries.first())
+        .copied()
+}
+
 /// Build MCP config for Python based on package manager and transport.
 /// Prefers script_name over entry_point when available.
 fn build_mcp_config(
@@ -806,4 +829,99 @@ mcp.run()
         let detector = PythonDetector::new();
         assert_eq!(detector.detect_transport(tmp.path()), McpbTransport::Stdio);
     }
+
+    #[test]
+    fn test_detect_script_uv() {
+        let tmp = TempDir::new().unwrap();
+        let pyproject = r#"
+[project]
+name = "weather"
+dependencies = ["mcp>=1.0.0"]
+
+[project.scripts]
+weather = "weather.server:main"
+"#;
+        fs::write(tmp.path().join("pyproject.toml"), pyproject).unwrap();
+        fs::write(tmp.path().join("uv.lock"), "").unwrap();
+        fs::create_dir_all(tmp.path().join("src/weather")).unwrap();
+        fs::write(tmp.path().join("src/weather/server.py"), "").unwrap();
+
+        let result = PythonDetector::new().detect(tmp.path()).unwrap();
+        assert!(result.signals.entry_point_from_config);
+        assert!(result.signals.entry_point_exists);
+        assert_eq!(result.details.script_name.as_deref(), Some("weather"));
+        assert_eq!(
+            result.details.entry_point.as_deref(),
+            Some("src/weather/server.py")
+        );
+        assert_eq!(result.details.run_command.as_deref(), Some("uv"));
+        assert_eq!(result.details.run_args, vec!["run", "weather"]);
+    }
+
+    #[test]
+    fn test_detect_script_poetry() {
+        let tmp = TempDir::new().unwrap();
+        let pyproject = r#"
+[tool.poetry]
+name = "notes"
+
+[tool.poetry.dependencies]
+mcp = "^1.0"
+
+[tool.poetry.scripts]
+notes-mcp = "notes.main:run"
+"#;
+        fs::write(tmp.path().join("pyproject.toml"), pyproject).unwrap();
+        fs::write(tmp.path().join("poetry.lock"), "").unwrap();
+
+        let result = PythonDetector::new().detect(tmp.path()).unwrap();
+        assert!(result.signals.entry_point_from_config);
+        assert!(!result.signals.entry_point_exists);
+        assert_eq!(result.details.script_name.as_deref(), Some("notes-mcp"));
+        assert_eq!(result.details.run_command.as_deref(), Some("poetry"));
+        assert_eq!(result.details.run_args, vec!["run", "notes-mcp"]);
+    }
+
+    #[test]
+    fn test_build_mcp_config_script_pip() {
+        let config = build_mcp_config(
+            Some("files.py"),
+            Some("files"),
+            McpbTransport::Stdio,
+            PythonPackageManager::Pip,
+        );
+        assert_eq!(config.command.as_deref(), Some(".venv/bin/files"));
+        assert!(config.args.is_empty());
+
+        let config = build_mcp_config(
+            Some("files.py"),
+            None,
+            McpbTransport::Stdio,
+            PythonPackageManager::Pip,
+        );
+        assert_eq!(config.command.as_deref(), Some(".venv/bin/python"));
+        assert_eq!(config.args, vec!["files.py"]);
+    }
+
+    #[test]
+    fn test_select_script_prefers_project_name() {
+        let scripts: toml::Table = toml::from_str(
+            r#"
+admin = "pkg.admin:main"
+my-server = "pkg.server:main"
+my_tool = "pkg.tool:main"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            select_script(&scripts, Some("my-tool")),
+            Some(("my_tool", "pkg.tool:main"))
+        );
+        assert_eq!(
+            select_script(&scripts, Some("other")),
+            Some(("my-server", "pkg.server:main"))
+        );
+        assert_eq!(select_script(&toml::Table::new(), Some("other")), None);
+    }
 }
@@ -10,8 +10,8 @@ use crate::mcpb::{
     McpbTransport, McpbUserConfigField, McpbUserConfigType, detect_platform,
 };
 use crate::scaffold::rust_mcpbignore_template;
-use std::collections::BTreeMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -27,6 +27,7 @@ struct CargoToml {
     #[serde(default)]
     dependencies: toml::Table,
     bin: Option<Vec<CargoBin>>,
+    workspace: Option<CargoWorkspace>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -39,6 +40,14 @@ struct CargoBin {
     name: Option<String>,
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct CargoWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    dependencies: toml::Table,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -49,18 +58,122 @@ impl RustDetector {
         Self
     }
 
-    /// Get the binary name from Cargo.toml.
-    fn get_binary_name(&self, cargo: &CargoToml) -> Option<String> {
-        // Check [[bin]] section first
-        if let Some(bins) = &cargo.bin
-            && let Some(first) = bins.first()
-            && let Some(name) = &first.name
+    /// Get all binary target names in the project, including workspace members.
+    ///
+    /// Root binaries come first, then each member's in workspace order. A root package
+    /// without any binary targets falls back to its package name.
+    fn get_binary_names(&self, dir: &Path, cargo: &CargoToml) -> Vec<String> {
+        let mut names = self.package_binaries(dir, cargo);
+
+        for member_dir in self.workspace_members(dir, cargo) {
+            if let Some(member) = read_toml::<CargoToml>(&member_dir.join("Cargo.toml")) {
+                names.extend(self.package_binaries(&member_dir, &member));
+            }
+        }
+
+        if names.is_empty()
+            && let Some(name) = cargo.package.as_ref().and_then(|p| p.name.clone())
         {
-            return Some(name.clone());
+            names.push(name);
         }
 
-        // Fall back to package name
-        cargo.package.as_ref().and_then(|p| p.name.clone())
+        let mut seen = HashSet::new();
+        names.retain(|name| seen.insert(name.clone()));
+        names
+    }
+
+    /// Binary targets of a single package: `[[bin]]` tables, `src/main.rs`, and `src/bin/*`.
+    fn package_binaries(&self, dir: &Path, cargo: &CargoToml) -> Vec<String> {
+        let mut names: Vec<String> = cargo
+            .bin
+            .iter()
+            .flatten()
+            .filter_map(|bin| bin.name.clone())
+            .collect();
+
+        if names.is_empty()
+            && dir.join("src/main.rs").exists()
+            && let Some(name) = cargo.package.as_ref().and_then(|p| p.name.clone())
+        {
+            names.push(name);
+        }
+
+        if let Ok(entries) = std::fs::read_dir(dir.join("src/bin")) {
+            let mut auto_bins: Vec<String> = entries
+                .flatten()
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.is_dir() {
+                        path.join("main.rs")
+                            .exists()
+                            .then(|| entry.file_name().to_string_lossy().to_string())
+                    } else if path.extension().is_some_and(|ext| ext == "rs") {
+                        path.file_stem().map(|s| s.to_string_lossy().to_string())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            auto_bins.sort();
+            names.extend(auto_bins);
+        }
+
+        names
+    }
+
+    /// Directories of workspace members, expanding trailing `/*` globs.
+    fn workspace_members(&self, dir: &Path, cargo: &CargoToml) -> Vec<PathBuf> {
+        let Some(workspace) = &cargo.workspace else {
+            return Vec::new();
+        };
+
+        let mut members = Vec::new();
+        for pattern in &workspace.members {
+            if let Some(parent) = pattern.strip_suffix("/*") {
+                if let Ok(entries) = std::fs::read_dir(dir.join(parent)) {
+                    let mut found: Vec<PathBuf> = entries
+                        .flatten()
+                        .map(|entry| entry.path())
+                        .filter(|path| path.join("Cargo.toml").exists())
+                        .collect();
+                    found.sort();
+                    members.extend(found);
+                }
+            } else {
+                let member_dir = dir.join(pattern);
+                if member_dir != dir && member_dir.join("Cargo.toml").exists() {
+                    members.push(member_dir);
+                }
+            }
+        }
+        members
+    }
+
+    /// Pick the binary to package.
+    ///
+    /// With several binaries, `entry_point` (a binary name or a path ending in one) must
+    /// choose between them.
+    fn select_binary(
+        &self,
+        binaries: &[String],
+        entry_point: Option<&str>,
+    ) -> Result<String, DetectError> {
+        if let Some(entry) = entry_point {
+            let requested = Path::new(entry)
+                .file_name()
+                .map(|n| n.to_string_lossy())
+                .unwrap_or_default();
+            let requested = requested.strip_suffix(".exe").unwrap_or(&requested);
+            if let Some(name) = binaries.iter().find(|name| *name == requested) {
+                return Ok(name.clone());
+            }
+        }
+
+        match binaries {
+            [] => Err(DetectError::NoEntryPoint),
+            [only] => Ok(only.clone()),
+            _ => Err(DetectError::AmbiguousProject(binaries.to_vec())),
+        }
     }
 
     /// Get the entry point path (binary location).
@@ -79,9 +192,22 @@ impl RustDetector {
         }
     }
 
-    /// Check if project has rmcp dependency.
-    fn has_rmcp(&self, cargo: &CargoToml) -> bool {
-        cargo.dependencies.contains_key("rmcp")
+    /// Check if the project or any workspace member depends on rmcp.
+    fn has_rmcp(&self, dir: &Path, cargo: &CargoToml) -> bool {
+        let in_package = |cargo: &CargoToml| {
+            cargo.dependencies.contains_key("rmcp")
+                || cargo
+                    .workspace
+                    .as_ref()
+                    .is_some_and(|w| w.dependencies.contains_key("rmcp"))
+        };
+
+        in_package(cargo)
+            || self
+                .workspace_members(dir, cargo)
+                .iter()
+                .filter_map(|member| read_toml::<CargoToml>(&member.join("Cargo.toml")))
+                .any(|member| in_package(&member))
     }
 
     /// Detect transport by grepping source files.
@@ -114,12 +240,14 @@ impl RustDetector {
         let cargo: CargoToml = read_toml(&cargo_path)?;
 
         // Gather detection signals, reporting each as it's evaluated
-        let entry_from_config = cargo.bin.is_some() || cargo.package.as_ref().is_some();
+        let binaries = self.get_binary_names(dir, &cargo);
+        let entry_from_config = !binaries.is_empty();
         if let Some(cb) = &on_signal {
             cb("Entry point in config", entry_from_config, "30%");
         }
 
-        let binary_name = self.get_binary_name(&cargo)?;
+        // With several binaries, detection reports the first; generate() asks to choose
+        let binary_name = binaries.first()?.clone();
         let platform = detect_platform();
         let entry_point = self.get_entry_point(&binary_name, &platform);
         let is_built = self.is_built(dir, &binary_name);
@@ -127,7 +255,7 @@ impl RustDetector {
             cb("Entry point exists", is_built, "20%");
         }
 
-        let has_mcp_sdk = self.has_rmcp(&cargo);
+        let has_mcp_sdk = self.has_rmcp(dir, &cargo);
         if let Some(cb) = &on_signal {
             cb("MCP SDK detected (rmcp)", has_mcp_sdk, "10%");
         }
@@ -158,7 +286,13 @@ impl RustDetector {
         };
 
         let confidence = signals.confidence();
-        let notes = signals.warnings();
+        let mut notes = signals.warnings();
+        if binaries.len() > 1 {
+            notes.push(format!(
+                "Multiple binaries found ({}); pass --entry <name> to choose",
+                binaries.join(", ")
+            ));
+        }
         let command = self.get_command_path(&binary_name, &platform);
 
         Some(DetectionResult {
@@ -169,7 +303,7 @@ impl RustDetector {
                 script_name: None,
                 package_manager: None,
                 transport: Some(transport),
-                build_command: Some("cargo build --release".to_string()),
+                build_command: Some(format!("cargo build --release --bin {}", binary_name)),
                 run_command: Some(command),
                 run_args: vec![],
                 notes,
@@ -228,18 +362,23 @@ impl ProjectDetector for RustDetector {
         let cargo: CargoToml = read_toml(&dir.join("Cargo.toml"))
             .ok_or_else(|| DetectError::IoError("Failed to read Cargo.toml".into()))?;
 
-        let binary_name = self
-            .get_binary_name(&cargo)
-            .ok_or_else(|| DetectError::IoError("Could not determine binary name".into()))?;
+        let binaries = self.get_binary_names(dir, &cargo);
+        let binary_name = self.select_binary(&binaries, options.entry_point.as_deref())?;
 
         let platform = detect_platform();
 
-        // Use options to override detected values
-        let entry_point = options
-            .entry_point
-            .clone()
-            .or(detection.details.entry_point.clone())
-            .unwrap_or_else(|| self.get_entry_point(&binary_name, &platform));
+        // The entry stays under dist/ (target/ is excluded from bundles); a bare binary
+        // name passed as the entry point only selects which binary that is
+        let entry_point = match options.entry_point.as_deref() {
+            Some(entry) if entry != binary_name => entry.to_string(),
+            Some(_) => self.get_entry_point(&binary_name, &platform),
+            None if binaries.len() > 1 => self.get_entry_point(&binary_name, &platform),
+            None => detection
+                .details
+                .entry_point
+                .clone()
+                .unwrap_or_else(|| self.get_entry_point(&binary_name, &platform)),
+        };
 
         let transport = options
             .transport
@@ -341,11 +480,11 @@ impl ProjectDetector for RustDetector {
                     "scripts": {
                         "build": match platform {
                             McpbPlatform::Win32 => format!(
-                                "cargo build --release && if not exist dist mkdir dist && copy target\\release\\{}.exe dist\\",
+                                "cargo build --release --bin {0} && if not exist dist mkdir dist && copy target\\release\\{0}.exe dist\\",
                                 binary_name
                             ),
                             _ => format!(
-                                "cargo build --release && mkdir -p dist && cp target/release/{} dist/",
+                                "cargo build --release --bin {0} && mkdir -p dist && cp target/release/{0} dist/",
                                 binary_name
                             ),
                         }
@@ -545,4 +684,109 @@ let service = Server::new().serve(stdio()).await?;
 
         assert!(detector.is_built(tmp.path(), "test-mcp-server"));
     }
+
+    fn create_workspace(tmp: &TempDir) {
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["crates/*", "tools/cli"]
+
+[workspace.dependencies]
+rmcp = "0.12"
+"#,
+        )
+        .unwrap();
+
+        for (member, name) in [
+            ("crates/core", "core"),
+            ("crates/server", "weather-server"),
+            ("tools/cli", "weather-cli"),
+        ] {
+            let member_dir = tmp.path().join(member);
+            fs::create_dir_all(member_dir.join("src")).unwrap();
+            fs::write(
+                member_dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", name),
+            )
+            .unwrap();
+            let source = if name == "core" { "lib.rs" } else { "main.rs" };
+            fs::write(member_dir.join("src").join(source), "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_detect_workspace_binaries() {
+        let tmp = TempDir::new().unwrap();
+        create_workspace(&tmp);
+
+        let detector = RustDetector::new();
+        let cargo: CargoToml = read_toml(&tmp.path().join("Cargo.toml")).unwrap();
+        assert_eq!(
+            detector.get_binary_names(tmp.path(), &cargo),
+            vec!["weather-server", "weather-cli"]
+        );
+
+        let result = detector.detect(tmp.path()).unwrap();
+        assert!(result.signals.has_mcp_sdk);
+        assert_eq!(
+            result.details.build_command.as_deref(),
+            Some("cargo build --release --bin weather-server")
+        );
+        assert!(
+            result
+                .details
+                .notes
+                .iter()
+                .any(|n| n.contains("weather-server, weather-cli"))
+        );
+    }
+
+    #[test]
+    fn test_generate_multiple_binaries_requires_choice() {
+        let tmp = TempDir::new().unwrap();
+        create_workspace(&tmp);
+
+        let detector = RustDetector::new();
+        let detection = detector.detect(tmp.path()).unwrap();
+
+        let err = detector
+            .generate(tmp.path(), &detection, &DetectOptions::default())
+            .unwrap_err();
+        assert!(
+            matches!(err, DetectError::AmbiguousProject(ref names) if names == &["weather-server", "weather-cli"])
+        );
+
+        let options = DetectOptions {
+            entry_point: Some("target/release/weather-cli".into()),
+            ..Default::default()
+        };
+        let scaffold = detector.generate(tmp.path(), &detection, &options).unwrap();
+        assert_eq!(scaffold.manifest.name.as_deref(), Some("weather-cli"));
+
+        let options = DetectOptions {
+            entry_point: Some("weather-cli".into()),
+            ..Default::default()
+        };
+        let scaffold = detector.generate(tmp.path(), &detection, &options).unwrap();
+        let entry = scaffold.manifest.server.entry_point.unwrap();
+        assert!(entry.starts_with("dist/weather-cli"));
+    }
+
+    #[test]
+    fn test_detect_src_bin_targets() {
+        let tmp = TempDir::new().unwrap();
+        create_rust_project(&tmp, true);
+        fs::create_dir_all(tmp.path().join("src/bin/admin")).unwrap();
+        fs::write(tmp.path().join("src/main.rs"), "").unwrap();
+        fs::write(tmp.path().join("src/bin/migrate.rs"), "").unwrap();
+        fs::write(tmp.path().join("src/bin/admin/main.rs"), "").unwrap();
+
+        let detector = RustDetector::new();
+        let cargo: CargoToml = read_toml(&tmp.path().join("Cargo.toml")).unwrap();
+        assert_eq!(
+            detector.get_binary_names(tmp.path(), &cargo),
+            vec!["test-mcp-server", "admin", "migrate"]
+        );
+    }
 }
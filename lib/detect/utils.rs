@@ -1,9 +1,12 @@
 //! Shared utilities for project detection.
 
+use crate::error::{ToolError, ToolResult};
+use crate::security::is_sensitive_env;
 use grep_regex::RegexMatcher;
 use grep_searcher::Searcher;
 use grep_searcher::sinks::UTF8;
 use ignore::WalkBuilder;
+use std::cell::Cell;
 use std::path::{Path, PathBuf};
 
 //--------------------------------------------------------------------------------------------------
@@ -178,9 +181,42 @@ pub fn parse_env_example(dir: &Path) -> Vec<super::EnvVar> {
         Err(_) => return vec![],
     };
 
+    parse_env_content(&content)
+}
+
+/// Parse a `.env` file into environment variables, rejecting malformed lines.
+///
+/// Every entry must be a `KEY=VALUE` assignment with a valid variable name; the
+/// error names the file and the offending line.
+pub fn parse_env_file(path: &Path) -> ToolResult<Vec<super::EnvVar>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ToolError::Generic(format!("Failed to read env file {}: {}", path.display(), e))
+    })?;
+
+    parse_env_lines(&content, true)
+        .map_err(|e| ToolError::Generic(format!("Invalid env file {}: {}", path.display(), e)))
+}
+
+/// Parse the contents of a dotenv-style file into environment variables.
+///
+/// Handles `export` prefixes, single/double-quoted values (which may span
+/// multiple lines), and inline `#` comments outside of quotes.
+fn parse_env_content(content: &str) -> Vec<super::EnvVar> {
+    parse_env_lines(content, false).unwrap_or_default()
+}
+
+/// Parse dotenv-style lines.
+///
+/// Malformed lines (no `=`, invalid names) are skipped, or rejected when `strict`.
+fn parse_env_lines(content: &str, strict: bool) -> Result<Vec<super::EnvVar>, String> {
+    use super::EnvVar;
+
     let mut vars = Vec::new();
+    let line_no = Cell::new(0);
+    let mut lines = content.lines().inspect(|_| line_no.set(line_no.get() + 1));
 
-    for line in content.lines() {
+    while let Some(line) = lines.next() {
+        let start_line = line_no.get();
         let line = line.trim();
 
         // Skip empty lines and comments
@@ -188,23 +224,37 @@ pub fn parse_env_example(dir: &Path) -> Vec<super::EnvVar> {
             continue;
         }
 
+        // Strip shell-style `export` prefix
+        let line = line
+            .strip_prefix("export ")
+            .or_else(|| line.strip_prefix("export\t"))
+            .map(str::trim_start)
+            .unwrap_or(line);
+
         // Parse KEY=VALUE or KEY=
         let (name, default) = if let Some(eq_pos) = line.find('=') {
             let key = line[..eq_pos].trim().to_string();
-            let val = line[eq_pos + 1..].trim();
-            let default = if val.is_empty() {
-                None
-            } else {
-                Some(val.to_string())
-            };
+            let val = parse_env_value(line[eq_pos + 1..].trim(), &mut lines);
+            let default = if val.is_empty() { None } else { Some(val) };
             (key, default)
+        } else if strict {
+            return Err(format!("line {}: expected KEY=VALUE", start_line));
         } else {
-            // Line without '=' - just the key
-            (line.to_string(), None)
+            // Line without '=' - just the key (drop any trailing comment)
+            (strip_inline_comment(line).trim().to_string(), None)
         };
 
         // Skip invalid names
-        if name.is_empty() || !name.chars().next().unwrap().is_ascii_alphabetic() {
+        if name.is_empty()
+            || !name.chars().next().unwrap().is_ascii_alphabetic()
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            if strict {
+                return Err(format!(
+                    "line {}: invalid variable name '{}'",
+                    start_line, name
+                ));
+            }
             continue;
         }
 
@@ -223,7 +273,61 @@ pub fn parse_env_example(dir: &Path) -> Vec<super::EnvVar> {
         });
     }
 
-    vars
+    Ok(vars)
+}
+
+/// Parse the value part of a `KEY=VALUE` line.
+///
+/// Quoted values may continue onto following lines, which are pulled from `lines`
+/// until the closing quote is found.
+fn parse_env_value<'a>(raw: &str, lines: &mut impl Iterator<Item = &'a str>) -> String {
+    let quote = match raw.chars().next() {
+        Some(q @ ('"' | '\'')) => q,
+        _ => return strip_inline_comment(raw).trim_end().to_string(),
+    };
+
+    let mut value = String::new();
+    let mut rest = raw[1..].to_string();
+
+    loop {
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                c if c == quote => return value,
+                '\\' if quote == '"' => match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(other) => value.push(other),
+                    None => value.push('\\'),
+                },
+                c => value.push(c),
+            }
+        }
+
+        // No closing quote on this line: continue with the next one
+        match lines.next() {
+            Some(next) => {
+                value.push('\n');
+                rest = next.to_string();
+            }
+            None => return value,
+        }
+    }
+}
+
+/// Strip an unquoted inline `# comment` from a value.
+///
+/// A `#` only starts a comment at the beginning or after whitespace, so values
+/// like `color=#fff` or URLs with fragments are kept intact.
+fn strip_inline_comment(value: &str) -> &str {
+    let mut prev_is_space = true;
+    for (i, c) in value.char_indices() {
+        if c == '#' && prev_is_space {
+            return &value[..i];
+        }
+        prev_is_space = c.is_whitespace();
+    }
+    value
 }
 
 /// Classify an env var as system_config or user_config and infer value type.
@@ -248,9 +352,26 @@ fn classify_env_var(
         return (EnvConfigType::System, EnvValueType::Hostname);
     }
 
-    // User config: infer type from default value
-    let value_type = if let Some(val) = default {
-        if val == "true" || val == "false" {
+    // User config: infer type from name suffix, then from default value
+    let value_type = if ["_URL", "_URI", "_ENDPOINT"]
+        .iter()
+        .any(|suffix| name_upper.ends_with(suffix))
+    {
+        EnvValueType::Url
+    } else if ["_DIR", "_DIRECTORY", "_FOLDER"]
+        .iter()
+        .any(|suffix| name_upper.ends_with(suffix))
+    {
+        EnvValueType::Directory
+    } else if name_upper.ends_with("_PATH") || name_upper.ends_with("_FILE") {
+        // A trailing slash in the default means the path points at a directory
+        if default.is_some_and(|d| d.ends_with('/') || d.ends_with('\\')) {
+            EnvValueType::Directory
+        } else {
+            EnvValueType::File
+        }
+    } else if let Some(val) = default {
+        if val.eq_ignore_ascii_case("true") || val.eq_ignore_ascii_case("false") {
             EnvValueType::Boolean
         } else if val.parse::<i64>().is_ok() || val.parse::<f64>().is_ok() {
             EnvValueType::Number
@@ -264,17 +385,6 @@ fn classify_env_var(
     (EnvConfigType::User, value_type)
 }
 
-/// Check if env var name suggests a sensitive value.
-fn is_sensitive_env(name: &str) -> bool {
-    let name_upper = name.to_uppercase();
-    name_upper.contains("KEY")
-        || name_upper.contains("SECRET")
-        || name_upper.contains("TOKEN")
-        || name_upper.contains("PASSWORD")
-        || name_upper.contains("CREDENTIAL")
-        || name_upper.contains("URL")
-}
-
 /// Build a directory walker with options.
 fn build_walker(dir: &Path, options: &GrepOptions) -> ignore::Walk {
     let mut builder = WalkBuilder::new(dir);
@@ -568,4 +678,175 @@ mod tests {
         let db_host = vars.iter().find(|v| v.name == "DB_HOST").unwrap();
         assert_eq!(db_host.config_type, EnvConfigType::System);
     }
+
+    #[test]
+    fn test_parse_env_file_strict() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join(".env");
+
+        fs::write(&path, "export API_KEY=\"abc\ndef\"\nDEBUG=true\n").unwrap();
+        let vars = parse_env_file(&path).unwrap();
+        assert_eq!(vars[0].default.as_deref(), Some("abc\ndef"));
+        assert!(vars[0].sensitive);
+
+        fs::write(&path, "A=\"multi\nline\"\n\nJUST_A_KEY\n").unwrap();
+        let err = parse_env_file(&path).unwrap_err().to_string();
+        assert!(err.contains("line 4: expected KEY=VALUE"), "{}", err);
+
+        fs::write(&path, "1BAD=value\n").unwrap();
+        let err = parse_env_file(&path).unwrap_err().to_string();
+        assert!(err.contains("invalid variable name '1BAD'"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_env_content_export_prefix() {
+        let vars = parse_env_content("export API_KEY=abc\nexport  DEBUG=true\n");
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0].name, "API_KEY");
+        assert_eq!(vars[0].default.as_deref(), Some("abc"));
+        assert!(vars[0].sensitive);
+        assert_eq!(vars[1].name, "DEBUG");
+        assert_eq!(vars[1].default.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn test_parse_env_content_quoted_values() {
+        use super::super::EnvValueType;
+
+        let vars = parse_env_content(
+            "GREETING=\"hello # not a comment\"\nRAW='a\\nb'\nESCAPED=\"say \\\"hi\\\"\"\nTIMEOUT=\"30\"\nEMPTY=\"\"\n",
+        );
+
+        let greeting = vars.iter().find(|v| v.name == "GREETING").unwrap();
+        assert_eq!(greeting.default.as_deref(), Some("hello # not a comment"));
+
+        // Single quotes are literal
+        let raw = vars.iter().find(|v| v.name == "RAW").unwrap();
+        assert_eq!(raw.default.as_deref(), Some("a\\nb"));
+
+        let escaped = vars.iter().find(|v| v.name == "ESCAPED").unwrap();
+        assert_eq!(escaped.default.as_deref(), Some("say \"hi\""));
+
+        // Quotes don't affect type inference
+        let timeout = vars.iter().find(|v| v.name == "TIMEOUT").unwrap();
+        assert_eq!(timeout.value_type, EnvValueType::Number);
+
+        let empty = vars.iter().find(|v| v.name == "EMPTY").unwrap();
+        assert_eq!(empty.default, None);
+    }
+
+    #[test]
+    fn test_parse_env_content_inline_comments() {
+        use super::super::EnvValueType;
+
+        let vars = parse_env_content(
+            "PORT=8080 # server port\nENABLED=false  # toggle\nCOLOR=#fff\nTOKEN= # paste here\nNAME_ONLY # no value\n",
+        );
+
+        let port = vars.iter().find(|v| v.name == "PORT").unwrap();
+        assert_eq!(port.default.as_deref(), Some("8080"));
+
+        let enabled = vars.iter().find(|v| v.name == "ENABLED").unwrap();
+        assert_eq!(enabled.default.as_deref(), Some("false"));
+        assert_eq!(enabled.value_type, EnvValueType::Boolean);
+
+        let color = vars.iter().find(|v| v.name == "COLOR").unwrap();
+        assert_eq!(color.default.as_deref(), Some("#fff"));
+
+        let token = vars.iter().find(|v| v.name == "TOKEN").unwrap();
+        assert_eq!(token.default, None);
+        assert!(token.sensitive);
+
+        let name_only = vars.iter().find(|v| v.name == "NAME_ONLY").unwrap();
+        assert_eq!(name_only.default, None);
+    }
+
+    #[test]
+    fn test_parse_env_content_multiline_values() {
+        let vars = parse_env_content(
+            "PRIVATE_KEY=\"-----BEGIN KEY-----\nabc\n-----END KEY-----\"\nAFTER=1\n",
+        );
+
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0].name, "PRIVATE_KEY");
+        assert_eq!(
+            vars[0].default.as_deref(),
+            Some("-----BEGIN KEY-----\nabc\n-----END KEY-----")
+        );
+        assert!(vars[0].sensitive);
+        assert_eq!(vars[1].name, "AFTER");
+    }
+
+    #[test]
+    fn test_parse_env_content_skips_blank_and_comment_lines() {
+        let vars = parse_env_content("\n# Header\n   \n  # indented comment\nKEY=value\n");
+        assert_eq!(vars.len(), 1);
+        assert_eq!(vars[0].name, "KEY");
+    }
+
+    #[test]
+    fn test_classify_env_var_url() {
+        use super::super::{EnvConfigType, EnvValueType};
+
+        for name in ["DATABASE_URL", "REDIS_URI", "API_ENDPOINT"] {
+            let (config_type, value_type) = classify_env_var(name, None);
+            assert_eq!(config_type, EnvConfigType::User);
+            assert_eq!(value_type, EnvValueType::Url, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_classify_env_var_paths() {
+        use super::super::EnvValueType;
+
+        assert_eq!(
+            classify_env_var("DATA_DIR", Some("./data")).1,
+            EnvValueType::Directory
+        );
+        assert_eq!(
+            classify_env_var("OUTPUT_FOLDER", None).1,
+            EnvValueType::Directory
+        );
+        assert_eq!(
+            classify_env_var("CONFIG_PATH", Some("config.json")).1,
+            EnvValueType::File
+        );
+        assert_eq!(
+            classify_env_var("CREDENTIALS_FILE", None).1,
+            EnvValueType::File
+        );
+        assert_eq!(
+            classify_env_var("CACHE_PATH", Some("/tmp/cache/")).1,
+            EnvValueType::Directory
+        );
+    }
+
+    #[test]
+    fn test_classify_env_var_default_values() {
+        use super::super::EnvValueType;
+
+        assert_eq!(
+            classify_env_var("VERBOSE", Some("True")).1,
+            EnvValueType::Boolean
+        );
+        assert_eq!(
+            classify_env_var("MAX_RETRIES", Some("3")).1,
+            EnvValueType::Number
+        );
+        assert_eq!(
+            classify_env_var("RATE", Some("0.5")).1,
+            EnvValueType::Number
+        );
+        assert_eq!(
+            classify_env_var("MODE", Some("fast")).1,
+            EnvValueType::String
+        );
+
+        // Port/Hostname detection takes precedence
+        assert_eq!(
+            classify_env_var("SERVER_PORT", Some("8080")).1,
+            EnvValueType::Port
+        );
+        assert_eq!(classify_env_var("DB_HOST", None).1, EnvValueType::Hostname);
+    }
 }
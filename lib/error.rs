@@ -2,6 +2,36 @@
 
 use thiserror::Error;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Exit code for errors without a more specific code.
+pub const EXIT_FAILURE: i32 = 1;
+
+/// Exit code when a tool, version, manifest, or entry point doesn't exist.
+pub const EXIT_NOT_FOUND: i32 = 3;
+
+/// Exit code when authentication is missing or was rejected.
+pub const EXIT_AUTH: i32 = 4;
+
+/// Exit code when a remote service couldn't be reached.
+pub const EXIT_NETWORK: i32 = 5;
+
+/// Exit code when the registry rejected a request.
+pub const EXIT_REGISTRY: i32 = 6;
+
+/// Exit code for filesystem errors.
+pub const EXIT_IO: i32 = 7;
+
+/// Exit code when a manifest fails validation or arguments don't match a schema.
+///
+/// Not 2, which clap uses for invalid command-line usage.
+pub const EXIT_VALIDATION: i32 = 8;
+
+/// Exit code when the user cancelled (matches the shell's code for SIGINT).
+pub const EXIT_CANCELLED: i32 = 130;
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -123,6 +153,14 @@ pub enum ToolError {
     #[error("Operation cancelled")]
     Cancelled,
 
+    /// Network access attempted while disabled by `--frozen`.
+    #[error("{operation} refused: network disabled by --frozen")]
+    NetworkDisabled { operation: String },
+
+    /// A remote service couldn't be reached (connection, timeout, or transfer failure).
+    #[error("{0}")]
+    Network(String),
+
     /// Registry API error with structured response.
     #[error("{operation} failed")]
     RegistryApi {
@@ -145,6 +183,41 @@ pub enum ToolError {
     /// Failed to parse host config.
     #[error("Failed to parse {host} config: {message}")]
     HostConfigParseError { host: String, message: String },
+
+    /// The command already reported its failure; exit with `code` without printing more.
+    #[error("Command failed with exit code {code}")]
+    Reported { code: i32 },
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ToolError {
+    /// Process exit code for this error, so scripts can tell failure kinds apart.
+    ///
+    /// Registry responses are classified by HTTP status: 401/403 as auth, 404 as
+    /// not found, anything else as a registry error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ToolError::NotFound { .. }
+            | ToolError::ManifestNotFound(_)
+            | ToolError::EntryPointNotFound { .. }
+            | ToolError::RegistryApi { status: 404, .. } => EXIT_NOT_FOUND,
+            ToolError::AuthRequired { .. }
+            | ToolError::OAuthNotConfigured
+            | ToolError::RegistryApi {
+                status: 401 | 403, ..
+            } => EXIT_AUTH,
+            ToolError::Network(_) | ToolError::NetworkDisabled { .. } => EXIT_NETWORK,
+            ToolError::RegistryApi { .. } => EXIT_REGISTRY,
+            ToolError::Io(_) => EXIT_IO,
+            ToolError::ValidationFailed(_) => EXIT_VALIDATION,
+            ToolError::Cancelled => EXIT_CANCELLED,
+            ToolError::Reported { code } => *code,
+            _ => EXIT_FAILURE,
+        }
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -156,3 +229,39 @@ impl From<anyhow::Error> for ToolError {
         ToolError::Generic(err.to_string())
     }
 }
+
+impl From<reqwest::Error> for ToolError {
+    fn from(err: reqwest::Error) -> Self {
+        ToolError::Network(format!("Network error: {}", err))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code() {
+        let registry = |status| ToolError::RegistryApi {
+            operation: "Publish".into(),
+            code: "ERROR".into(),
+            message: String::new(),
+            status,
+        };
+
+        assert_eq!(ToolError::Generic("x".into()).exit_code(), EXIT_FAILURE);
+        assert_eq!(ToolError::Network("x".into()).exit_code(), EXIT_NETWORK);
+        assert_eq!(registry(403).exit_code(), EXIT_AUTH);
+        assert_eq!(registry(404).exit_code(), EXIT_NOT_FOUND);
+        assert_eq!(registry(409).exit_code(), EXIT_REGISTRY);
+        assert_eq!(ToolError::Reported { code: 42 }.exit_code(), 42);
+        assert_eq!(
+            ToolError::Io(std::io::Error::other("x")).exit_code(),
+            EXIT_IO
+        );
+    }
+}
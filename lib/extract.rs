@@ -0,0 +1,694 @@
+//! Safe extraction of MCPB bundles.
+//!
+//! Every entry is checked before it is written: its path must stay inside the target
+//! directory (including through links already on disk), and symlink entries may only
+//! point at other paths inside the bundle. Entries are streamed to disk and counted as
+//! they are written, so a bundle cannot expand past [`ExtractLimits`] whatever sizes its
+//! headers declare.
+
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use zip::ZipArchive;
+
+use crate::constants::{TOOL_MAX_BUNDLE_SIZE_ENV, TOOL_MAX_ENTRY_SIZE_ENV};
+use crate::mcpb::{McpbManifest, McpbServerType};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Default maximum total uncompressed size (4 GiB).
+pub const DEFAULT_MAX_BUNDLE_SIZE: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Default maximum uncompressed size of a single entry (1 GiB).
+pub const DEFAULT_MAX_ENTRY_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Default maximum number of entries.
+pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+/// Longest symlink target read from an entry.
+const MAX_LINK_TARGET_LEN: u64 = 4096;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Limits applied while extracting a bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtractLimits {
+    /// Maximum total uncompressed bytes.
+    pub max_total_size: u64,
+
+    /// Maximum uncompressed bytes for one entry.
+    pub max_entry_size: u64,
+
+    /// Maximum number of entries.
+    pub max_entries: usize,
+}
+
+/// Error types for bundle extraction.
+#[derive(Debug, Error)]
+pub enum ExtractError {
+    /// An entry would be written outside the target directory.
+    #[error("refusing unsafe bundle entry `{entry}`: {reason}")]
+    UnsafeEntry { entry: String, reason: String },
+
+    /// The archive has more entries than allowed.
+    #[error("bundle has {count} entries, more than the limit of {limit}")]
+    TooManyEntries { count: usize, limit: usize },
+
+    /// One entry expands past the per-entry limit.
+    #[error("bundle entry `{entry}` is larger than the {limit}-byte entry limit")]
+    EntryTooLarge { entry: String, limit: u64 },
+
+    /// The bundle expands past the total size limit.
+    #[error("bundle expands past the {limit}-byte size limit at entry `{entry}`")]
+    BundleTooLarge { entry: String, limit: u64 },
+
+    /// IO error during extraction.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Zip error.
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ExtractLimits {
+    /// Default limits, with sizes overridable through `TOOL_MAX_BUNDLE_SIZE` and
+    /// `TOOL_MAX_ENTRY_SIZE` (in bytes).
+    pub fn from_env() -> Self {
+        let read = |name: &str| std::env::var(name).ok().and_then(|v| v.trim().parse().ok());
+        let defaults = Self::default();
+        Self {
+            max_total_size: read(TOOL_MAX_BUNDLE_SIZE_ENV).unwrap_or(defaults.max_total_size),
+            max_entry_size: read(TOOL_MAX_ENTRY_SIZE_ENV).unwrap_or(defaults.max_entry_size),
+            ..defaults
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Extract every entry of a bundle archive into `target_dir`.
+///
+/// Aborts as soon as a limit is exceeded; entries already written are left for the
+/// caller to clean up. `on_entry` is called after each entry is written, for progress
+/// reporting.
+pub fn extract_archive<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    target_dir: &Path,
+    limits: &ExtractLimits,
+    mut on_entry: impl FnMut(),
+) -> Result<(), ExtractError> {
+    if archive.len() > limits.max_entries {
+        return Err(ExtractError::TooManyEntries {
+            count: archive.len(),
+            limit: limits.max_entries,
+        });
+    }
+
+    std::fs::create_dir_all(target_dir)?;
+    let root = target_dir.canonicalize()?;
+    let mut total_size = 0u64;
+    let mut links = Vec::new();
+    tracing::debug!(
+        "Extracting {} entries into {}",
+        archive.len(),
+        root.display()
+    );
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+
+        let relative = entry
+            .enclosed_name()
+            .and(enclosed_path(&name))
+            .ok_or_else(|| unsafe_entry(&name, "path escapes the target directory"))?;
+        let dest_path = root.join(&relative);
+
+        if let Some(parent) = dest_path.parent() {
+            // Check what already exists before creating anything below it
+            ensure_ancestor_within(&root, parent, &name)?;
+            std::fs::create_dir_all(parent)?;
+            ensure_within(&root, parent, &name)?;
+        }
+
+        // Never write through a link placed by an earlier entry
+        if dest_path
+            .symlink_metadata()
+            .is_ok_and(|m| m.file_type().is_symlink())
+        {
+            return Err(unsafe_entry(&name, "path is already a symlink"));
+        }
+
+        tracing::trace!("Extracting {}", name);
+
+        #[cfg(unix)]
+        let unix_mode = entry.unix_mode();
+
+        if entry.is_dir() {
+            std::fs::create_dir_all(&dest_path)?;
+        } else if entry.is_symlink() {
+            let mut target = String::new();
+            (&mut entry)
+                .take(MAX_LINK_TARGET_LEN)
+                .read_to_string(&mut target)?;
+
+            if !link_stays_inside(&relative, &target) {
+                return Err(unsafe_entry(
+                    &name,
+                    format!("symlink points outside the bundle ({})", target),
+                ));
+            }
+
+            // Links are created last so no other entry can be written through one
+            links.push((name, dest_path, target));
+            continue;
+        } else {
+            // Declared sizes are only a hint; the copy below enforces the limits
+            if entry.size() > limits.max_entry_size {
+                return Err(ExtractError::EntryTooLarge {
+                    entry: name,
+                    limit: limits.max_entry_size,
+                });
+            }
+
+            let remaining = limits.max_total_size.saturating_sub(total_size);
+            let allowed = limits.max_entry_size.min(remaining);
+            let mut file = File::create(&dest_path)?;
+            let written = std::io::copy(&mut (&mut entry).take(allowed + 1), &mut file)?;
+            drop(file);
+
+            if written > allowed {
+                std::fs::remove_file(&dest_path)?;
+                return Err(if allowed == limits.max_entry_size {
+                    ExtractError::EntryTooLarge {
+                        entry: name,
+                        limit: limits.max_entry_size,
+                    }
+                } else {
+                    ExtractError::BundleTooLarge {
+                        entry: name,
+                        limit: limits.max_total_size,
+                    }
+                });
+            }
+            total_size += written;
+
+            #[cfg(unix)]
+            if let Some(mode) = unix_mode {
+                use std::os::unix::fs::PermissionsExt;
+                // Permission bits only, never setuid/setgid/sticky from a bundle
+                std::fs::set_permissions(
+                    &dest_path,
+                    std::fs::Permissions::from_mode(mode & 0o777),
+                )?;
+            }
+        }
+
+        on_entry();
+    }
+
+    for (name, dest_path, target) in &links {
+        if dest_path.symlink_metadata().is_ok() {
+            return Err(unsafe_entry(
+                name,
+                "symlink would replace an extracted entry",
+            ));
+        }
+        write_symlink(target, dest_path)?;
+        on_entry();
+    }
+
+    // Links through other links can escape even when each target looks relative, so
+    // every link must resolve inside the root once they all exist
+    for (name, dest_path, target) in &links {
+        if !dest_path
+            .canonicalize()
+            .is_ok_and(|real| real.starts_with(&root))
+        {
+            for (_, path, _) in &links {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(unsafe_entry(
+                name,
+                format!("symlink does not resolve inside the bundle ({})", target),
+            ));
+        }
+    }
+
+    tracing::debug!("Extracted {} bytes", total_size);
+    Ok(())
+}
+
+/// Make an extracted binary server's entry point executable.
+///
+/// Bundles packed on Windows carry no Unix modes, so the entry point can come out
+/// without execute bits. Each read bit is mirrored to the matching execute bit. Does
+/// nothing without a readable manifest or on non-Unix platforms.
+pub fn restore_entry_point_mode(target_dir: &Path) -> std::io::Result<()> {
+    let Ok(manifest) = McpbManifest::load(target_dir) else {
+        return Ok(());
+    };
+    if manifest.server.server_type != Some(McpbServerType::Binary) {
+        return Ok(());
+    }
+    let Some(relative) = manifest
+        .server
+        .entry_point
+        .as_deref()
+        .and_then(enclosed_path)
+    else {
+        return Ok(());
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = target_dir.join(relative);
+        if let Ok(metadata) = std::fs::metadata(&path)
+            && metadata.is_file()
+        {
+            let mode = metadata.permissions().mode();
+            let exec = (mode & 0o444) >> 2;
+            if mode & exec != exec {
+                std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode | exec))?;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = relative;
+
+    Ok(())
+}
+
+/// Check that an extracted bundle contains the server its manifest points at.
+///
+/// Bundled servers (`.mcpb`, and `.mcpbx` with an `entry_point`) must ship the entry
+/// point inside the bundle. Reference-mode `.mcpbx` bundles embed no server and always
+/// pass. Does nothing without a readable manifest.
+pub fn verify_entry_point(target_dir: &Path) -> std::io::Result<()> {
+    let Ok(manifest) = McpbManifest::load(target_dir) else {
+        return Ok(());
+    };
+    let Some(entry_point) = manifest.server.entry_point.as_deref() else {
+        return Ok(());
+    };
+
+    let present = enclosed_path(entry_point).is_some_and(|path| target_dir.join(path).is_file());
+    if !present {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("bundle is missing its entry point `{}`", entry_point),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Relative path for an entry name, or `None` if it is absolute, has a drive or
+/// `..` component, or is empty.
+///
+/// Splits on both separators so Windows-style names are checked on every platform.
+fn enclosed_path(name: &str) -> Option<PathBuf> {
+    if name.starts_with(['/', '\\']) || name.contains('\0') {
+        return None;
+    }
+
+    let mut path = PathBuf::new();
+    for part in name.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." => return None,
+            _ if part.contains(':') => return None,
+            _ => path.push(part),
+        }
+    }
+
+    (!path.as_os_str().is_empty()).then_some(path)
+}
+
+/// Whether a symlink at `link` (relative to the bundle root) with `target` resolves
+/// inside the bundle.
+fn link_stays_inside(link: &Path, target: &str) -> bool {
+    if target.is_empty() || target.starts_with(['/', '\\']) {
+        return false;
+    }
+
+    let mut depth = link.parent().map_or(0, |p| p.components().count());
+    for part in target.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." if depth == 0 => return false,
+            ".." => depth -= 1,
+            _ if part.contains(':') => return false,
+            _ => depth += 1,
+        }
+    }
+    true
+}
+
+/// Fail unless `path` resolves (through any links) inside `root`.
+///
+/// Dangling links don't resolve at all and are rejected too.
+fn ensure_within(root: &Path, path: &Path, entry: &str) -> Result<(), ExtractError> {
+    if path.canonicalize().is_ok_and(|real| real.starts_with(root)) {
+        Ok(())
+    } else {
+        Err(unsafe_entry(
+            entry,
+            "path resolves outside the target directory",
+        ))
+    }
+}
+
+/// Fail unless the deepest part of `path` that already exists resolves inside `root`.
+///
+/// Anything below it doesn't exist yet, so creating it stays inside as well.
+fn ensure_ancestor_within(root: &Path, path: &Path, entry: &str) -> Result<(), ExtractError> {
+    let mut existing = path;
+    while existing.symlink_metadata().is_err() {
+        match existing.parent() {
+            Some(parent) => existing = parent,
+            None => break,
+        }
+    }
+    ensure_within(root, existing, entry)
+}
+
+/// Create a symlink entry on disk.
+#[cfg(unix)]
+fn write_symlink(target: &str, dest_path: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, dest_path)
+}
+
+/// Create a symlink entry on disk (stored as a file holding the link target).
+#[cfg(not(unix))]
+fn write_symlink(target: &str, dest_path: &Path) -> std::io::Result<()> {
+    std::fs::write(dest_path, target)
+}
+
+/// Build an [`ExtractError::UnsafeEntry`].
+fn unsafe_entry(entry: &str, reason: impl Into<String>) -> ExtractError {
+    ExtractError::UnsafeEntry {
+        entry: entry.to_string(),
+        reason: reason.into(),
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for ExtractLimits {
+    fn default() -> Self {
+        Self {
+            max_total_size: DEFAULT_MAX_BUNDLE_SIZE,
+            max_entry_size: DEFAULT_MAX_ENTRY_SIZE,
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MCPB_MANIFEST_FILE;
+    use std::io::{Cursor, Write};
+    use tempfile::TempDir;
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    /// Archive entry used to craft test bundles.
+    enum Entry {
+        File(&'static str, &'static str),
+        Link(&'static str, &'static str),
+    }
+
+    /// Extract a crafted archive into a fresh `tool` directory.
+    fn extract(entries: &[Entry]) -> (TempDir, Result<(), ExtractError>) {
+        extract_with_limits(entries, &ExtractLimits::default())
+    }
+
+    /// Extract a crafted archive with custom limits.
+    fn extract_with_limits(
+        entries: &[Entry],
+        limits: &ExtractLimits,
+    ) -> (TempDir, Result<(), ExtractError>) {
+        let mut archive = archive(entries);
+        let dir = TempDir::new().unwrap();
+        let result = extract_archive(&mut archive, &dir.path().join("tool"), limits, || {});
+        (dir, result)
+    }
+
+    /// Build an in-memory archive from crafted entries.
+    fn archive(entries: &[Entry]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        for entry in entries {
+            match entry {
+                Entry::File(name, contents) => {
+                    zip.start_file(*name, options).unwrap();
+                    zip.write_all(contents.as_bytes()).unwrap();
+                }
+                Entry::Link(name, target) => zip.add_symlink(*name, *target, options).unwrap(),
+            }
+        }
+        ZipArchive::new(zip.finish().unwrap()).unwrap()
+    }
+
+    fn assert_unsafe(result: Result<(), ExtractError>, expected: &str) {
+        match result {
+            Err(ExtractError::UnsafeEntry { entry, .. }) => assert_eq!(entry, expected),
+            other => panic!("expected unsafe entry `{}`, got {:?}", expected, other),
+        }
+    }
+
+    #[test]
+    fn test_extracts_regular_bundle() {
+        let (dir, result) = extract(&[
+            Entry::File("manifest.json", "{}"),
+            Entry::File("server/index.js", "//"),
+        ]);
+        result.unwrap();
+
+        let target = dir.path().join("tool");
+        assert_eq!(
+            std::fs::read_to_string(target.join("manifest.json")).unwrap(),
+            "{}"
+        );
+        assert!(target.join("server/index.js").is_file());
+    }
+
+    #[test]
+    fn test_rejects_traversal_and_absolute_paths() {
+        for name in [
+            "../evil.txt",
+            "server/../../evil.txt",
+            "/tmp/evil.txt",
+            "C:\\evil.txt",
+        ] {
+            let (dir, result) = extract(&[
+                Entry::File("manifest.json", "{}"),
+                Entry::File(name, "pwned"),
+            ]);
+            assert_unsafe(result, name);
+            assert!(!dir.path().join("evil.txt").exists());
+        }
+    }
+
+    #[test]
+    fn test_rejects_escaping_symlinks() {
+        let (_dir, result) = extract(&[Entry::Link("passwd", "/etc/passwd")]);
+        assert_unsafe(result, "passwd");
+
+        let (_dir, result) = extract(&[Entry::Link("server/up", "../../outside")]);
+        assert_unsafe(result, "server/up");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_keeps_in_bundle_symlinks() {
+        let (dir, result) = extract(&[
+            Entry::File("server/index.js", "//"),
+            Entry::Link("main.js", "server/index.js"),
+        ]);
+        result.unwrap();
+
+        let link = dir.path().join("tool/main.js");
+        assert!(link.symlink_metadata().unwrap().file_type().is_symlink());
+        assert_eq!(std::fs::read_to_string(link).unwrap(), "//");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_chained_symlink_escape() {
+        // Lexically inside, but `y` -> `.` makes `y/y/../..` climb above the root
+        let (dir, result) = extract(&[
+            Entry::Link("y", "."),
+            Entry::Link("x", "y/y/../.."),
+            Entry::File("x/evil.txt", "pwned"),
+        ]);
+        assert_unsafe(result, "x");
+        assert!(!dir.path().join("evil.txt").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_dangling_symlink_chain() {
+        // `x` dangles until `w` -> `.` exists, then `w/..` is the parent of the root
+        let (dir, result) = extract(&[
+            Entry::Link("x", "w/.."),
+            Entry::Link("w", "."),
+            Entry::File("x/newdir/evil", "pwned"),
+        ]);
+        assert_unsafe(result, "x");
+        assert!(!dir.path().join("newdir").exists());
+
+        let (dir, result) = extract(&[Entry::Link("x", "w/.."), Entry::Link("w", ".")]);
+        assert_unsafe(result, "x");
+        assert!(!dir.path().join("tool/x").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_rejects_parent_through_symlink() {
+        let dir = TempDir::new().unwrap();
+        let outside = dir.path().join("outside");
+        let target = dir.path().join("tool");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::create_dir_all(&target).unwrap();
+        std::os::unix::fs::symlink(&outside, target.join("escape")).unwrap();
+
+        let mut archive = archive(&[Entry::File("escape/newdir/evil.txt", "pwned")]);
+        let result = extract_archive(&mut archive, &target, &ExtractLimits::default(), || {});
+        assert_unsafe(result, "escape/newdir/evil.txt");
+        assert!(!outside.join("newdir").exists());
+    }
+
+    #[test]
+    fn test_enforces_size_limits() {
+        let limits = ExtractLimits {
+            max_total_size: 10,
+            max_entry_size: 6,
+            max_entries: 3,
+        };
+
+        let (dir, result) = extract_with_limits(&[Entry::File("big.bin", "1234567")], &limits);
+        assert!(matches!(result, Err(ExtractError::EntryTooLarge { .. })));
+        assert!(!dir.path().join("tool/big.bin").exists());
+
+        let (_dir, result) = extract_with_limits(
+            &[
+                Entry::File("a.bin", "123456"),
+                Entry::File("b.bin", "123456"),
+            ],
+            &limits,
+        );
+        assert!(matches!(result, Err(ExtractError::BundleTooLarge { .. })));
+
+        let (_dir, result) = extract_with_limits(
+            &[
+                Entry::File("a", ""),
+                Entry::File("b", ""),
+                Entry::File("c", ""),
+                Entry::File("d", ""),
+            ],
+            &limits,
+        );
+        assert!(matches!(
+            result,
+            Err(ExtractError::TooManyEntries { count: 4, .. })
+        ));
+
+        let (_dir, result) = extract_with_limits(
+            &[Entry::File("a.bin", "12345"), Entry::File("b.bin", "12345")],
+            &limits,
+        );
+        result.unwrap();
+    }
+
+    #[test]
+    fn test_verify_entry_point() {
+        let dir = TempDir::new().unwrap();
+        let manifest = dir.path().join("manifest.json");
+
+        std::fs::write(
+            &manifest,
+            r#"{"manifest_version":"0.3","server":{"type":"node","entry_point":"server/index.js"}}"#,
+        )
+        .unwrap();
+        assert!(verify_entry_point(dir.path()).is_err());
+
+        std::fs::create_dir_all(dir.path().join("server")).unwrap();
+        std::fs::write(dir.path().join("server/index.js"), "").unwrap();
+        verify_entry_point(dir.path()).unwrap();
+
+        // Reference-mode bundles embed nothing
+        std::fs::write(
+            &manifest,
+            r#"{"manifest_version":"0.3","server":{"mcp_config":{"command":"npx"}}}"#,
+        )
+        .unwrap();
+        std::fs::remove_dir_all(dir.path().join("server")).unwrap();
+        verify_entry_point(dir.path()).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_strips_special_mode_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file(
+            "bin/tool",
+            SimpleFileOptions::default().unix_permissions(0o4755),
+        )
+        .unwrap();
+        let mut archive = ZipArchive::new(zip.finish().unwrap()).unwrap();
+
+        let dir = TempDir::new().unwrap();
+        extract_archive(&mut archive, dir.path(), &ExtractLimits::default(), || {}).unwrap();
+        let mode = std::fs::metadata(dir.path().join("bin/tool"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o7777, 0o755);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_restore_entry_point_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(MCPB_MANIFEST_FILE),
+            r#"{"manifest_version":"0.3","server":{"type":"binary","entry_point":"bin/tool"}}"#,
+        )
+        .unwrap();
+        let binary = dir.path().join("bin/tool");
+        std::fs::create_dir_all(binary.parent().unwrap()).unwrap();
+        std::fs::write(&binary, "").unwrap();
+        std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o640)).unwrap();
+
+        restore_entry_point_mode(dir.path()).unwrap();
+        let mode = std::fs::metadata(&binary).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o750);
+    }
+}
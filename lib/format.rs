@@ -2,6 +2,7 @@
 
 use colored::Colorize;
 use serde_json::Value;
+use std::io::{self, Write};
 
 //--------------------------------------------------------------------------------------------------
 // Constants
@@ -26,6 +27,17 @@ pub fn truncate_param_desc(desc: &str, verbose: bool) -> String {
     }
 }
 
+/// Format byte size.
+pub fn format_size(bytes: u64) -> String {
+    if bytes < 1_000 {
+        format!("{} B", bytes)
+    } else if bytes < 1_000_000 {
+        format!("{:.1} KB", bytes as f64 / 1_000.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / 1_000_000.0)
+    }
+}
+
 /// Format a description for display.
 ///
 /// - Default: returns first non-empty line only (truncated if needed)
@@ -129,6 +141,87 @@ fn highlight_json_inner(value: &Value, indent: usize) -> String {
     }
 }
 
+/// Check whether text is valid JSON without building a `Value` tree.
+pub fn is_json(text: &str) -> bool {
+    serde_json::from_str::<serde::de::IgnoredAny>(text).is_ok()
+}
+
+/// Pretty-print JSON text directly into a writer.
+///
+/// Reformats token by token without parsing into a `Value`, so memory use stays
+/// constant regardless of payload size. Input must be valid JSON (see [`is_json`]).
+pub fn write_json_pretty<W: Write>(json: &str, out: &mut W) -> io::Result<()> {
+    let bytes = json.as_bytes();
+    let mut depth = 0usize;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        i += 1;
+        match b {
+            b'"' => {
+                // Copy the whole string literal in one write
+                let start = i - 1;
+                let mut escaped = false;
+                while i < bytes.len() {
+                    let c = bytes[i];
+                    i += 1;
+                    if escaped {
+                        escaped = false;
+                    } else if c == b'\\' {
+                        escaped = true;
+                    } else if c == b'"' {
+                        break;
+                    }
+                }
+                out.write_all(&bytes[start..i])?;
+            }
+            b'{' | b'[' => {
+                let close = if b == b'{' { b'}' } else { b']' };
+                // Collapse empty containers to `{}` / `[]`
+                let next = bytes[i..]
+                    .iter()
+                    .position(|c| !c.is_ascii_whitespace())
+                    .map(|p| i + p);
+                match next {
+                    Some(n) if bytes[n] == close => {
+                        out.write_all(&[b, close])?;
+                        i = n + 1;
+                    }
+                    _ => {
+                        depth += 1;
+                        out.write_all(&[b])?;
+                        write_newline_indent(out, depth)?;
+                    }
+                }
+            }
+            b'}' | b']' => {
+                depth = depth.saturating_sub(1);
+                write_newline_indent(out, depth)?;
+                out.write_all(&[b])?;
+            }
+            b',' => {
+                out.write_all(b",")?;
+                write_newline_indent(out, depth)?;
+            }
+            b':' => out.write_all(b": ")?,
+            c if c.is_ascii_whitespace() => {}
+            c => out.write_all(&[c])?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a newline followed by two-space indentation for the given depth.
+fn write_newline_indent<W: Write>(out: &mut W, depth: usize) -> io::Result<()> {
+    out.write_all(b"\n")?;
+    for _ in 0..depth {
+        out.write_all(b"  ")?;
+    }
+    Ok(())
+}
+
 /// Escape special characters in a JSON string.
 fn escape_json_string(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -254,6 +347,33 @@ mod tests {
         assert!(result.contains('3'));
     }
 
+    #[test]
+    fn test_write_json_pretty() {
+        let mut out = Vec::new();
+        write_json_pretty(r#"{"a":[1, 2],"b":{},"c":"x,\"{y}\":z","d":[ ]}"#, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {},\n  \"c\": \"x,\\\"{y}\\\":z\",\n  \"d\": []\n}"
+        );
+
+        // Matches serde_json's pretty printer for valid input
+        let value = serde_json::json!({"nested": {"list": [true, null, 1.5, "é"]}});
+        let mut out = Vec::new();
+        write_json_pretty(&value.to_string(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_json() {
+        assert!(is_json(r#"{"a": 1}"#));
+        assert!(is_json("[1, 2, 3]"));
+        assert!(!is_json("plain text"));
+        assert!(!is_json("{ invalid }"));
+    }
+
     #[test]
     fn test_escape_json_string() {
         assert_eq!(escape_json_string("hello"), "hello");
@@ -1,7 +1,9 @@
 //! Registry authentication command handlers.
 
+use crate::concise::format_whoami;
 use crate::constants::{REGISTRY_AUTH_DIR, REGISTRY_TOKEN_ENV, get_registry_url};
-use crate::error::ToolResult;
+use crate::error::{EXIT_AUTH, ToolError, ToolResult};
+use crate::output::print_json;
 use crate::registry::RegistryClient;
 use crate::styles::Spinner;
 use colored::Colorize;
@@ -28,6 +30,39 @@ pub struct RegistryCredentials {
     pub registry_url: String,
 }
 
+/// Where the token checked by `tool whoami` came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum TokenSource {
+    /// Passed with `--token`.
+    Flag,
+
+    /// Read from the registry token environment variable.
+    Env,
+
+    /// Loaded from stored credentials.
+    Stored,
+}
+
+/// JSON output for `tool whoami`.
+#[derive(Debug, Serialize)]
+struct WhoamiOutput {
+    /// `authenticated`, `invalid`, `expired`, or `unauthenticated`.
+    status: &'static str,
+
+    /// Username, if known.
+    username: Option<String>,
+
+    /// Registry the token was checked against.
+    registry: String,
+
+    /// Where the token came from.
+    source: Option<TokenSource>,
+
+    /// Token expiry, if the registry reports one.
+    expires_at: Option<String>,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -101,6 +136,8 @@ pub async fn get_registry_token() -> ToolResult<Option<String>> {
 ///
 /// If `token` is provided, uses it directly. Otherwise prompts for interactive input.
 pub async fn auth_login(token: Option<&str>) -> ToolResult<()> {
+    crate::registry::ensure_network("Login")?;
+
     let registry_url = get_registry_url();
 
     // Get token
@@ -180,196 +217,206 @@ pub async fn auth_login(token: Option<&str>) -> ToolResult<()> {
 }
 
 /// Logout from the registry.
-pub async fn auth_logout() -> ToolResult<()> {
-    if let Some(creds) = load_credentials().await? {
-        delete_credentials().await?;
+///
+/// With `registry`, stored credentials are only removed if they belong to that registry.
+pub async fn auth_logout(registry: Option<&str>) -> ToolResult<()> {
+    let Some(creds) = load_credentials().await? else {
+        println!("  {} Not logged in", "✗".bright_yellow());
+        return Ok(());
+    };
+
+    if let Some(registry) = registry
+        && !same_registry(registry, &creds.registry_url)
+    {
         println!(
-            "  {} Logged out from {} (was @{})",
-            "✓".bright_green(),
-            creds.registry_url.bright_blue(),
-            creds.username.bright_cyan()
+            "  {} No credentials stored for {}",
+            "✗".bright_yellow(),
+            registry.bright_blue()
+        );
+        println!(
+            "  · Stored credentials are for {}",
+            creds.registry_url.bright_blue()
+        );
+        return Ok(());
+    }
+
+    delete_credentials().await?;
+    println!(
+        "  {} Logged out from {} (was @{})",
+        "✓".bright_green(),
+        creds.registry_url.bright_blue(),
+        creds.username.bright_cyan()
+    );
+    println!(
+        "  · Removed {}",
+        get_credentials_path().display().to_string().dimmed()
+    );
+    if std::env::var(REGISTRY_TOKEN_ENV).is_ok() {
+        println!(
+            "  {} {} is still set and will be used for authentication",
+            "!".bright_yellow(),
+            REGISTRY_TOKEN_ENV.bright_white()
         );
-    } else {
-        println!("  {} Not logged in", "✗".bright_yellow());
     }
 
     Ok(())
 }
 
+/// Compare registry URLs, ignoring trailing slashes and case.
+fn same_registry(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/')
+        .eq_ignore_ascii_case(b.trim_end_matches('/'))
+}
+
 /// Show current authentication status.
 ///
-/// If `token` is provided, validates that specific token instead of using stored credentials.
-pub async fn auth_status(concise: bool, no_header: bool, token: Option<&str>) -> ToolResult<()> {
-    let registry_url = get_registry_url();
-
-    // Helper to print concise output with optional header
-    let print_concise = |user: &str, registry: &str, status: &str| {
-        if !no_header {
-            println!("#user\tregistry\tstatus");
-        }
-        println!("{}\t{}\t{}", user, registry, status);
+/// If `token` is provided, validates that specific token instead of the environment
+/// variable or stored credentials. Exits non-zero when not authenticated.
+pub async fn auth_status(
+    json: bool,
+    concise: bool,
+    no_header: bool,
+    token: Option<&str>,
+) -> ToolResult<()> {
+    let default_registry = get_registry_url();
+
+    // Priority: --token > env var > stored credential
+    let resolved = if let Some(token) = token {
+        Some((
+            TokenSource::Flag,
+            token.to_string(),
+            default_registry.clone(),
+            None,
+        ))
+    } else if let Ok(token) = std::env::var(REGISTRY_TOKEN_ENV) {
+        Some((TokenSource::Env, token, default_registry.clone(), None))
+    } else {
+        load_credentials().await?.map(|creds| {
+            (
+                TokenSource::Stored,
+                creds.token,
+                creds.registry_url,
+                Some(creds.username),
+            )
+        })
     };
 
-    // If explicit token provided, validate it
-    if let Some(token) = token {
-        let client = RegistryClient::new()
-            .with_url(&registry_url)
-            .with_auth_token(token);
-
-        match client.validate_token().await {
-            Ok(user_info) => {
-                if concise {
-                    print_concise(
-                        &format!("@{}", user_info.username),
-                        &registry_url,
-                        "authenticated",
-                    );
-                    return Ok(());
-                }
-                println!("  {} Authenticated via --token", "✓".bright_green());
-                println!(
-                    "  · {}: @{}",
-                    "User".dimmed(),
-                    user_info.username.bright_cyan()
-                );
-                println!(
-                    "  · {}: {}",
-                    "Registry".dimmed(),
-                    registry_url.bright_blue()
-                );
-                println!(
-                    "  · {}: {}...{}",
-                    "Token".dimmed(),
-                    &token[..15.min(token.len())],
-                    &token[token.len().saturating_sub(4)..]
-                );
-            }
-            Err(_) => {
-                if concise {
-                    print_concise("-", &registry_url, "invalid");
-                    return Ok(());
-                }
-                println!("  {} Provided token is invalid", "✗".bright_red());
+    let output = match &resolved {
+        None => WhoamiOutput {
+            status: "unauthenticated",
+            username: None,
+            registry: default_registry,
+            source: None,
+            expires_at: None,
+        },
+        Some((source, token, registry, stored_username)) => {
+            let client = RegistryClient::new()
+                .with_url(registry)
+                .with_auth_token(token);
+
+            match client.validate_token().await {
+                Ok(user_info) => WhoamiOutput {
+                    status: "authenticated",
+                    username: Some(user_info.username),
+                    registry: registry.clone(),
+                    source: Some(*source),
+                    expires_at: user_info.expires_at,
+                },
+                Err(_) => WhoamiOutput {
+                    status: if *source == TokenSource::Stored {
+                        "expired"
+                    } else {
+                        "invalid"
+                    },
+                    username: stored_username.clone(),
+                    registry: registry.clone(),
+                    source: Some(*source),
+                    expires_at: None,
+                },
             }
         }
-        return Ok(());
+    };
+
+    if json {
+        print_json(&output, concise)?;
+    } else if concise {
+        let username = output.username.as_ref().map(|u| format!("@{}", u));
+        println!(
+            "{}",
+            format_whoami(
+                username.as_deref(),
+                &output.registry,
+                output.status,
+                no_header
+            )
+        );
+    } else {
+        let token = resolved.as_ref().map(|(_, token, _, _)| token.as_str());
+        print_whoami(&output, token);
     }
 
-    // Check environment variable first
-    if let Ok(token) = std::env::var(REGISTRY_TOKEN_ENV) {
-        // Try to validate the token
-        let client = RegistryClient::new()
-            .with_url(&registry_url)
-            .with_auth_token(&token);
-
-        match client.validate_token().await {
-            Ok(user_info) => {
-                if concise {
-                    print_concise(
-                        &format!("@{}", user_info.username),
-                        &registry_url,
-                        "authenticated",
-                    );
-                    return Ok(());
-                }
-                println!(
-                    "  {} Authenticated via environment variable",
-                    "✓".bright_green()
-                );
-                println!(
-                    "  · {}: @{}",
-                    "User".dimmed(),
-                    user_info.username.bright_cyan()
-                );
-                println!(
-                    "  · {}: {}",
-                    "Registry".dimmed(),
-                    registry_url.bright_blue()
-                );
-                println!(
-                    "  · {}: {}...{}",
-                    "Token".dimmed(),
-                    &token[..15.min(token.len())],
-                    &token[token.len().saturating_sub(4)..]
-                );
-            }
-            Err(_) => {
-                if concise {
-                    print_concise("-", &registry_url, "invalid");
-                    return Ok(());
-                }
-                println!("  {} Environment token is invalid", "✗".bright_red());
-                println!("  · {}: {}", "Variable".dimmed(), REGISTRY_TOKEN_ENV);
-            }
-        }
-        return Ok(());
+    if output.status != "authenticated" {
+        return Err(ToolError::Reported { code: EXIT_AUTH });
     }
+    Ok(())
+}
 
-    // Check stored credentials
-    if let Some(creds) = load_credentials().await? {
-        // Validate stored token
-        let client = RegistryClient::new()
-            .with_url(&creds.registry_url)
-            .with_auth_token(&creds.token);
-
-        match client.validate_token().await {
-            Ok(_) => {
-                if concise {
-                    print_concise(
-                        &format!("@{}", creds.username),
-                        &creds.registry_url,
-                        "authenticated",
-                    );
-                    return Ok(());
-                }
-                println!("  {} Authenticated", "✓".bright_green());
-                println!("  · {}: @{}", "User".dimmed(), creds.username.bright_cyan());
-                println!(
-                    "  · {}: {}",
-                    "Registry".dimmed(),
-                    creds.registry_url.bright_blue()
-                );
-                let token = &creds.token;
-                println!(
-                    "  · {}: {}...{}",
-                    "Token".dimmed(),
-                    &token[..15.min(token.len())],
-                    &token[token.len().saturating_sub(4)..]
-                );
-            }
-            Err(_) => {
-                if concise {
-                    print_concise(
-                        &format!("@{}", creds.username),
-                        &creds.registry_url,
-                        "expired",
-                    );
-                    return Ok(());
-                }
-                println!(
-                    "  {} Stored token is invalid or expired",
-                    "✗".bright_yellow()
-                );
-                println!("  · Run {} to re-authenticate", "tool login".bright_cyan());
-            }
-        }
-    } else {
-        if concise {
-            print_concise("-", &registry_url, "unauthenticated");
-            return Ok(());
-        }
+/// Print `tool whoami` results for humans.
+fn print_whoami(output: &WhoamiOutput, token: Option<&str>) {
+    let (Some(source), Some(token)) = (output.source, token) else {
         println!("  {} Not authenticated", "✗".bright_yellow());
         println!();
         println!(
             "  · Run {} to authenticate with {}",
             "tool login".bright_cyan(),
-            registry_url.bright_blue()
+            output.registry.bright_blue()
         );
         println!(
             "  · Or set {} environment variable",
             REGISTRY_TOKEN_ENV.bright_white()
         );
+        return;
+    };
+
+    if output.status != "authenticated" {
+        match source {
+            TokenSource::Flag => println!("  {} Provided token is invalid", "✗".bright_red()),
+            TokenSource::Env => {
+                println!("  {} Environment token is invalid", "✗".bright_red());
+                println!("  · {}: {}", "Variable".dimmed(), REGISTRY_TOKEN_ENV);
+            }
+            TokenSource::Stored => {
+                println!(
+                    "  {} Stored token is invalid or expired",
+                    "✗".bright_yellow()
+                );
+                println!("  · Run {} to re-authenticate", "tool login".bright_cyan());
+            }
+        }
+        return;
     }
 
-    Ok(())
+    let via = match source {
+        TokenSource::Flag => " via --token",
+        TokenSource::Env => " via environment variable",
+        TokenSource::Stored => "",
+    };
+    println!("  {} Authenticated{}", "✓".bright_green(), via);
+    if let Some(username) = &output.username {
+        println!("  · {}: @{}", "User".dimmed(), username.bright_cyan());
+    }
+    println!(
+        "  · {}: {}",
+        "Registry".dimmed(),
+        output.registry.bright_blue()
+    );
+    if let Some(expires_at) = &output.expires_at {
+        println!("  · {}: {}", "Expires".dimmed(), expires_at);
+    }
+    println!(
+        "  · {}: {}...{}",
+        "Token".dimmed(),
+        &token[..15.min(token.len())],
+        &token[token.len().saturating_sub(4)..]
+    );
 }
@@ -1,20 +1,50 @@
 //! Tool call command handlers.
 
-use crate::error::{ToolError, ToolResult};
-use crate::format::highlight_json;
-use crate::mcp::call_tool;
+use crate::constants::TOOL_CONFIG_ENV_PREFIX;
+use crate::error::{
+    EXIT_AUTH, EXIT_FAILURE, EXIT_NOT_FOUND, EXIT_VALIDATION, ToolError, ToolResult,
+};
+use crate::format::{highlight_json, is_json, write_json_pretty};
+use crate::mcp::{ArgumentCheck, CallOutcome, call_tool};
 use crate::mcpb::McpbUserConfigField;
 use crate::styles::Spinner;
 use crate::suggest::{
     McpErrorKind, analyze_mcp_error, extract_params_from_schema, find_similar_tools,
     format_suggestions, is_missing_param_error, is_unknown_tool_error,
 };
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use colored::Colorize;
-use std::collections::BTreeMap;
+use rmcp::model::{CallToolResult, RawContent, ResourceContents};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 use super::common::{PrepareToolOptions, PreparedTool, prepare_tool};
 use super::config_cmd::{load_tool_config, tool_config_exists};
 
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A content part written out by `--output-dir`.
+enum SavedContent {
+    /// Content written to a file.
+    File(PathBuf),
+
+    /// Resource link recorded by URI only.
+    Link(String),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Buffer size for direct (`--stream`) call output.
+const STREAM_BUFFER_SIZE: usize = 64 * 1024;
+
+/// File name prefix for saved content parts.
+const SAVED_CONTENT_PREFIX: &str = "result";
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -70,47 +100,52 @@ fn print_missing_param_error_typed(missing_param: &str, method: &str, prepared:
 fn print_tool_params(method: &str, prepared: &PreparedTool) {
     // Get the tool's input schema and show all parameters
     if let Some(schema) = get_tool_input_schema(prepared, method) {
-        let params = extract_params_from_schema(&schema);
+        print_schema_params(&schema);
+    }
+}
 
-        if !params.is_empty() {
-            let required_params: Vec<_> = params.iter().filter(|p| p.required).collect();
-            let optional_params: Vec<_> = params.iter().filter(|p| !p.required).collect();
+/// Print the required and optional parameters declared by an input schema.
+fn print_schema_params(schema: &serde_json::Value) {
+    let params = extract_params_from_schema(schema);
+
+    if !params.is_empty() {
+        let required_params: Vec<_> = params.iter().filter(|p| p.required).collect();
+        let optional_params: Vec<_> = params.iter().filter(|p| !p.required).collect();
+
+        if !required_params.is_empty() {
+            println!("  {}:", "Required".dimmed());
+            for param in &required_params {
+                let desc = param
+                    .description
+                    .as_ref()
+                    .map(|d| format!(" - {}", d.dimmed()))
+                    .unwrap_or_default();
+                println!(
+                    "  · {} ({}){}",
+                    param.name.bright_cyan(),
+                    param.param_type.dimmed(),
+                    desc
+                );
+            }
+        }
 
+        if !optional_params.is_empty() {
             if !required_params.is_empty() {
-                println!("  {}:", "Required".dimmed());
-                for param in &required_params {
-                    let desc = param
-                        .description
-                        .as_ref()
-                        .map(|d| format!(" - {}", d.dimmed()))
-                        .unwrap_or_default();
-                    println!(
-                        "  · {} ({}){}",
-                        param.name.bright_cyan(),
-                        param.param_type.dimmed(),
-                        desc
-                    );
-                }
+                println!();
             }
-
-            if !optional_params.is_empty() {
-                if !required_params.is_empty() {
-                    println!();
-                }
-                println!("  {}:", "Optional".dimmed());
-                for param in &optional_params {
-                    let desc = param
-                        .description
-                        .as_ref()
-                        .map(|d| format!(" - {}", d.dimmed()))
-                        .unwrap_or_default();
-                    println!(
-                        "  · {} ({}){}",
-                        param.name.dimmed(),
-                        param.param_type.dimmed(),
-                        desc
-                    );
-                }
+            println!("  {}:", "Optional".dimmed());
+            for param in &optional_params {
+                let desc = param
+                    .description
+                    .as_ref()
+                    .map(|d| format!(" - {}", d.dimmed()))
+                    .unwrap_or_default();
+                println!(
+                    "  · {} ({}){}",
+                    param.name.dimmed(),
+                    param.param_type.dimmed(),
+                    desc
+                );
             }
         }
     }
@@ -201,11 +236,19 @@ pub async fn tool_call(
     args: Vec<String>,
     config: Vec<String>,
     config_file: Option<String>,
+    env_file: Option<String>,
+    cwd: Option<String>,
     no_save: bool,
     yes: bool,
     _verbose: bool,
     json_output: bool,
     concise: bool,
+    stream: bool,
+    output_dir: Option<String>,
+    raw: bool,
+    params_file: Option<String>,
+    params_stdin: bool,
+    no_validate: bool,
 ) -> ToolResult<()> {
     // Merge -p flags and trailing args
     let params: Vec<String> = param.into_iter().chain(args).collect();
@@ -214,8 +257,9 @@ pub async fn tool_call(
     let tool_name_for_expansion = extract_tool_name_for_expansion(&tool);
     let method = expand_method_shorthand(&method, tool_name_for_expansion);
 
-    // Parse method parameters
-    let arguments = parse_method_params(&params)?;
+    // Parse method parameters: JSON from file/stdin first, key=value overrides win
+    let mut arguments = load_params_json(params_file.as_deref(), params_stdin)?;
+    arguments.extend(parse_method_params(&params)?);
 
     // Prepare the tool (resolve, load config, prompt, save)
     let prepared = prepare_tool(
@@ -223,6 +267,8 @@ pub async fn tool_call(
         PrepareToolOptions {
             config: &config,
             config_file: config_file.as_deref(),
+            env_file: env_file.as_deref(),
+            cwd: cwd.as_deref(),
             no_save,
             yes,
         },
@@ -230,10 +276,19 @@ pub async fn tool_call(
     .await?;
 
     // Show spinner while connecting (human-readable mode only)
-    let show_spinner = !json_output && !concise;
+    let show_spinner = !json_output && !concise && !raw;
     let spinner =
         show_spinner.then(|| Spinner::new(format!("Connecting to {}", prepared.tool_name)));
 
+    // Arguments are checked against the server's own schema once connected
+    let check = if no_validate {
+        ArgumentCheck::Skip
+    } else {
+        ArgumentCheck::Schema {
+            fallback: get_tool_input_schema(&prepared, &method),
+        }
+    };
+
     // Call the tool - handle EntryPointNotFound specially
     // Never pass verbose to connection - verbose only affects output formatting
     let result = match call_tool(
@@ -241,16 +296,41 @@ pub async fn tool_call(
         &prepared.tool_name,
         &method,
         arguments,
+        check,
         false,
     )
     .await
     {
-        Ok(result) => {
+        Ok(CallOutcome::Called(result)) => {
             if let Some(s) = spinner {
                 s.done();
             }
             result
         }
+        Ok(CallOutcome::Rejected { problems, schema }) => {
+            if let Some(s) = spinner {
+                s.fail(None);
+            }
+            println!(
+                "  {} Invalid arguments for {} on {}\n",
+                "✗".bright_red(),
+                method.bold(),
+                prepared.tool_name.bold()
+            );
+            for problem in &problems {
+                println!("  · {}", problem);
+            }
+            println!();
+            print_schema_params(&schema);
+            println!(
+                "\n  · {}: Pass {} to send the arguments unchecked",
+                "hint".bright_blue(),
+                "--no-validate".bright_white()
+            );
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
+        }
         Err(ToolError::EntryPointNotFound {
             entry_point,
             full_path: _,
@@ -279,7 +359,9 @@ pub async fn tool_call(
                 println!("  ·   {}", "}".dimmed());
                 println!("  · {}", "}".dimmed());
             }
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_NOT_FOUND,
+            });
         }
         Err(ToolError::OAuthNotConfigured) | Err(ToolError::AuthRequired { tool_ref: _ }) => {
             if let Some(s) = spinner {
@@ -290,7 +372,7 @@ pub async fn tool_call(
                 "  · Could not initialize credential storage. Check that {} is writable.",
                 "~/.tool/secrets/".bright_cyan()
             );
-            std::process::exit(1);
+            return Err(ToolError::Reported { code: EXIT_AUTH });
         }
         Err(e) => {
             if let Some(s) = spinner {
@@ -304,17 +386,21 @@ pub async fn tool_call(
                 match kind {
                     McpErrorKind::MissingParam(param) => {
                         print_missing_param_error_typed(&param, &method, &prepared);
-                        std::process::exit(1);
+                        return Err(ToolError::Reported {
+                            code: EXIT_VALIDATION,
+                        });
                     }
                     McpErrorKind::UnknownTool(tool) => {
                         print_unknown_tool_error(&tool, &prepared).await;
-                        std::process::exit(1);
+                        return Err(ToolError::Reported {
+                            code: EXIT_NOT_FOUND,
+                        });
                     }
                     McpErrorKind::Other { code, message } => {
                         // Fall through to default error display
                         // but we could add more specific handling here
                         println!("  {} MCP error ({}): {}\n", "✗".bright_red(), code, message);
-                        std::process::exit(1);
+                        return Err(ToolError::Reported { code: EXIT_FAILURE });
                     }
                 }
             }
@@ -325,6 +411,27 @@ pub async fn tool_call(
 
     let is_error = result.result.is_error.unwrap_or(false);
 
+    // Write binary and resource content parts to files
+    let saved = match &output_dir {
+        Some(dir) => save_content_parts(&result.result, Path::new(dir))?,
+        None => Vec::new(),
+    };
+    if raw || concise || json_output || stream {
+        // Keep stdout machine-readable
+        for line in format_saved_content(&saved) {
+            eprintln!("{}", line);
+        }
+    }
+
+    // Raw output: the complete result as a single JSON object (takes precedence over all)
+    if raw {
+        println!("{}", serde_json::to_string(&result.result)?);
+        if is_error {
+            return Err(ToolError::Reported { code: EXIT_FAILURE });
+        }
+        return Ok(());
+    }
+
     // Concise output: minified JSON (takes precedence over --json)
     if concise {
         // Prefer structuredContent if available
@@ -363,7 +470,7 @@ pub async fn tool_call(
             }
         }
         if is_error {
-            std::process::exit(1);
+            return Err(ToolError::Reported { code: EXIT_FAILURE });
         }
         return Ok(());
     }
@@ -379,7 +486,7 @@ pub async fn tool_call(
             println!("null");
         }
         if is_error {
-            std::process::exit(1);
+            return Err(ToolError::Reported { code: EXIT_FAILURE });
         }
         return Ok(());
     }
@@ -416,16 +523,29 @@ pub async fn tool_call(
                 );
                 print_tool_params(&method, &prepared);
             }
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
         }
 
         // Handle unknown tool errors with suggestions from manifest
         if is_unknown_tool_error(&error_text) {
             print_unknown_tool_error(&method, &prepared).await;
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_NOT_FOUND,
+            });
         }
     }
 
+    // Direct output: write content straight to stdout with --stream
+    if stream {
+        stream_call_result(&result.result)?;
+        if is_error {
+            return Err(ToolError::Reported { code: EXIT_FAILURE });
+        }
+        return Ok(());
+    }
+
     // Print header matching rad tool format
     if is_error {
         println!(
@@ -507,44 +627,194 @@ pub async fn tool_call(
         }
     }
 
+    if !saved.is_empty() {
+        println!();
+        for line in format_saved_content(&saved) {
+            println!("{}", line);
+        }
+    }
+
     if is_error {
-        std::process::exit(1);
+        return Err(ToolError::Reported { code: EXIT_FAILURE });
     }
 
     Ok(())
 }
 
+/// Write a call result directly to stdout without building formatted copies.
+///
+/// The result has already been received in full; this only avoids the extra copies the
+/// formatted view makes. Text blocks are written as they are visited through a buffered
+/// writer, and JSON (text or structured content) is pretty-printed straight into it.
+fn stream_call_result(result: &CallToolResult) -> ToolResult<()> {
+    let stdout = std::io::stdout();
+    let mut out = std::io::BufWriter::with_capacity(STREAM_BUFFER_SIZE, stdout.lock());
+
+    for content in &result.content {
+        match &**content {
+            RawContent::Text(text) => {
+                let text_is_json = is_json(&text.text);
+
+                // Skip if text content is the same JSON as structured content
+                if text_is_json
+                    && let Some(structured) = &result.structured_content
+                    && serde_json::from_str::<serde_json::Value>(&text.text)
+                        .ok()
+                        .as_ref()
+                        == Some(structured)
+                {
+                    continue;
+                }
+
+                if text_is_json {
+                    write_json_pretty(&text.text, &mut out)?;
+                } else {
+                    out.write_all(text.text.as_bytes())?;
+                }
+                if !text.text.ends_with('\n') {
+                    writeln!(out)?;
+                }
+            }
+            RawContent::Image(img) => writeln!(out, "[Image: {} bytes]", img.data.len())?,
+            RawContent::Audio(audio) => writeln!(out, "[Audio: {} bytes]", audio.data.len())?,
+            RawContent::Resource(res) => writeln!(out, "[Resource: {:?}]", res.resource)?,
+            RawContent::ResourceLink(link) => writeln!(out, "[ResourceLink: {}]", link.uri)?,
+        }
+    }
+
+    if let Some(structured) = &result.structured_content {
+        serde_json::to_writer_pretty(&mut out, structured)?;
+        writeln!(out)?;
+    }
+
+    out.flush()?;
+    Ok(())
+}
+
+/// Write image, audio and embedded resource parts of a call result into `dir`.
+///
+/// Files are named `result-<index>.<ext>` after the content part's position, with the
+/// extension chosen from its MIME type. Resource links are recorded by URI only.
+fn save_content_parts(result: &CallToolResult, dir: &Path) -> ToolResult<Vec<SavedContent>> {
+    let mut saved = Vec::new();
+
+    std::fs::create_dir_all(dir)
+        .map_err(|e| ToolError::Generic(format!("Failed to create {}: {}", dir.display(), e)))?;
+
+    for (index, content) in result.content.iter().enumerate() {
+        let (bytes, mime_type) = match &**content {
+            RawContent::Text(_) => continue,
+            RawContent::Image(img) => (decode_base64(&img.data)?, Some(img.mime_type.as_str())),
+            RawContent::Audio(audio) => {
+                (decode_base64(&audio.data)?, Some(audio.mime_type.as_str()))
+            }
+            RawContent::Resource(res) => match &res.resource {
+                ResourceContents::TextResourceContents {
+                    text, mime_type, ..
+                } => (text.as_bytes().to_vec(), mime_type.as_deref()),
+                ResourceContents::BlobResourceContents {
+                    blob, mime_type, ..
+                } => (decode_base64(blob)?, mime_type.as_deref()),
+            },
+            RawContent::ResourceLink(link) => {
+                saved.push(SavedContent::Link(link.uri.clone()));
+                continue;
+            }
+        };
+
+        let extension = mime_type.map(extension_for_mime).unwrap_or("bin");
+        let path = dir.join(format!("{}-{}.{}", SAVED_CONTENT_PREFIX, index, extension));
+        std::fs::write(&path, bytes).map_err(|e| {
+            ToolError::Generic(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+        saved.push(SavedContent::File(path));
+    }
+
+    Ok(saved)
+}
+
+/// Decode base64 content data.
+fn decode_base64(data: &str) -> ToolResult<Vec<u8>> {
+    BASE64
+        .decode(data.trim())
+        .map_err(|e| ToolError::Generic(format!("Failed to decode content data: {}", e)))
+}
+
+/// Pick a file extension for a MIME type.
+fn extension_for_mime(mime_type: &str) -> &str {
+    let essence = mime_type.split(';').next().unwrap_or_default().trim();
+    match essence {
+        "image/png" => "png",
+        "image/jpeg" | "image/jpg" => "jpg",
+        "image/gif" => "gif",
+        "image/webp" => "webp",
+        "image/svg+xml" => "svg",
+        "audio/wav" | "audio/x-wav" | "audio/wave" => "wav",
+        "audio/mpeg" | "audio/mp3" => "mp3",
+        "audio/ogg" => "ogg",
+        "audio/flac" => "flac",
+        "audio/webm" => "webm",
+        "application/json" => "json",
+        "application/pdf" => "pdf",
+        "text/plain" => "txt",
+        "text/markdown" => "md",
+        "text/html" => "html",
+        "text/csv" => "csv",
+        _ => essence
+            .split_once('/')
+            .map(|(_, subtype)| subtype)
+            .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or("bin"),
+    }
+}
+
+/// Format saved content parts for display.
+fn format_saved_content(saved: &[SavedContent]) -> Vec<String> {
+    saved
+        .iter()
+        .map(|s| match s {
+            SavedContent::File(path) => format!(
+                "  {} Saved {}",
+                "✓".bright_green(),
+                path.display().to_string().bright_cyan()
+            ),
+            SavedContent::Link(uri) => format!("  · {}: {}", "Resource link".dimmed(), uri),
+        })
+        .collect()
+}
+
 /// Parse user config from -c flags and config file.
 ///
 /// Resolution order (later overrides earlier):
-/// 1. Saved config from `~/.tool/config/...` (lowest priority)
-/// 2. Config file (`--config-file`)
-/// 3. CLI flags (`-k`) (highest priority)
+/// 1. `TOOL_CONFIG_<KEY>` environment variables (lowest priority)
+/// 2. Saved config from `~/.tool/config/...`
+/// 3. Config file (`--config-file`)
+/// 4. CLI flags (`-k`) (highest priority)
 ///
-/// Returns the merged config and whether a saved config file was found.
+/// Only keys declared in the manifest's `user_config` schema are read from the
+/// environment. Returns the merged config, whether a saved config file was found,
+/// and the keys whose value came from the environment (so callers can avoid
+/// persisting them).
 pub(super) fn parse_user_config(
     config_flags: &[String],
     config_file: Option<&str>,
     plugin_ref: &crate::references::PluginRef,
-) -> ToolResult<(BTreeMap<String, String>, bool)> {
+    schema: Option<&BTreeMap<String, McpbUserConfigField>>,
+) -> ToolResult<(BTreeMap<String, String>, bool, BTreeSet<String>)> {
     let mut config = BTreeMap::new();
 
-    // 1. Load saved config (lowest priority)
+    // 2. Load saved config
     let has_saved_config = tool_config_exists(plugin_ref);
     if let Ok(saved) = load_tool_config(plugin_ref) {
         config.extend(saved);
     }
 
-    // 2. Load from config file
+    // 3. Load from config file
     if let Some(file_path) = config_file {
-        let content = std::fs::read_to_string(file_path)?;
-        let file_config: BTreeMap<String, String> = serde_json::from_str(&content)
-            .or_else(|_| toml::from_str(&content))
-            .map_err(|e| ToolError::Generic(format!("Failed to parse config file: {}", e)))?;
-        config.extend(file_config);
+        config.extend(read_config_file(Path::new(file_path))?);
     }
 
-    // 3. Parse -C flags (highest priority)
+    // 4. Parse -k flags (highest priority)
     for flag in config_flags {
         if let Some((key, value)) = flag.split_once('=') {
             config.insert(key.to_string(), value.to_string());
@@ -556,7 +826,129 @@ pub(super) fn parse_user_config(
         }
     }
 
-    Ok((config, has_saved_config))
+    // 1. Fill the remaining keys from environment variables (lowest priority)
+    let mut from_env = BTreeSet::new();
+    for (key, value) in env_user_config(schema, |key| std::env::var(key).ok()) {
+        if !config.contains_key(&key) {
+            from_env.insert(key.clone());
+            config.insert(key, value);
+        }
+    }
+
+    Ok((config, has_saved_config, from_env))
+}
+
+/// Read a `--config-file` as a flat `key -> string` map.
+///
+/// The format is chosen by extension (`.json`, `.toml`, `.yaml`/`.yml`); files with any
+/// other extension are tried as JSON, then TOML, then YAML. Number and boolean values
+/// are converted to strings.
+fn read_config_file(path: &Path) -> ToolResult<BTreeMap<String, String>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ToolError::Generic(format!(
+            "Failed to read config file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+    let (format, value) = match extension.as_deref() {
+        Some("json") => ("JSON", parse_config_json(&content)),
+        Some("toml") => ("TOML", parse_config_toml(&content)),
+        Some("yaml" | "yml") => ("YAML", parse_config_yaml(&content)),
+        _ => match parse_config_json(&content) {
+            Ok(value) => ("JSON", Ok(value)),
+            Err(_) => match parse_config_toml(&content) {
+                Ok(value) => ("TOML", Ok(value)),
+                Err(_) => ("YAML", parse_config_yaml(&content)),
+            },
+        },
+    };
+
+    value
+        .and_then(|value| flatten_config_value(&value))
+        .map_err(|e| {
+            ToolError::Generic(format!(
+                "Failed to parse config file {} as {}: {}",
+                path.display(),
+                format,
+                e
+            ))
+        })
+}
+
+fn parse_config_json(content: &str) -> Result<serde_json::Value, String> {
+    serde_json::from_str(content).map_err(|e| e.to_string())
+}
+
+fn parse_config_toml(content: &str) -> Result<serde_json::Value, String> {
+    toml::from_str(content).map_err(|e| e.to_string())
+}
+
+fn parse_config_yaml(content: &str) -> Result<serde_json::Value, String> {
+    serde_norway::from_str(content).map_err(|e| e.to_string())
+}
+
+/// Flatten a parsed config file into `key -> string` pairs.
+fn flatten_config_value(value: &serde_json::Value) -> Result<BTreeMap<String, String>, String> {
+    let Some(obj) = value.as_object() else {
+        return Err("expected a map of config keys to values".to_string());
+    };
+
+    obj.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => {
+                    return Err(format!(
+                        "value for '{}' must be a string, number, or boolean",
+                        key
+                    ));
+                }
+            };
+            Ok((key.clone(), value))
+        })
+        .collect()
+}
+
+/// Read `TOOL_CONFIG_<KEY>` environment variables for the keys declared in `schema`.
+///
+/// Keys are uppercased with non-alphanumeric characters replaced by `_`
+/// (`api_key` → `TOOL_CONFIG_API_KEY`). Empty values are ignored.
+fn env_user_config(
+    schema: Option<&BTreeMap<String, McpbUserConfigField>>,
+    get: impl Fn(&str) -> Option<String>,
+) -> BTreeMap<String, String> {
+    let Some(schema) = schema else {
+        return BTreeMap::new();
+    };
+
+    schema
+        .keys()
+        .filter_map(|key| {
+            get(&user_config_env_var(key))
+                .filter(|v| !v.is_empty())
+                .map(|v| (key.clone(), v))
+        })
+        .collect()
+}
+
+/// Environment variable name for a `user_config` key.
+fn user_config_env_var(key: &str) -> String {
+    let suffix: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}{}", TOOL_CONFIG_ENV_PREFIX, suffix)
 }
 
 /// Apply default values from user_config schema.
@@ -605,8 +997,6 @@ pub(super) fn prompt_missing_user_config(
     skip_interactive: bool,
     has_saved_config: bool,
 ) -> ToolResult<()> {
-    use std::io::IsTerminal;
-
     let Some(schema) = schema else {
         return Ok(());
     };
@@ -727,6 +1117,67 @@ pub(super) fn prompt_missing_user_config(
     Ok(())
 }
 
+/// Load method parameters as a JSON object from `--params-file` or `--params-stdin`.
+fn load_params_json(
+    params_file: Option<&str>,
+    params_stdin: bool,
+) -> ToolResult<BTreeMap<String, serde_json::Value>> {
+    let (source, contents) = match (params_file, params_stdin) {
+        (Some(_), true) => {
+            return Err(ToolError::Generic(
+                "Cannot use --params-file and --params-stdin together".into(),
+            ));
+        }
+        (Some(path), false) => {
+            let contents = std::fs::read_to_string(path).map_err(|e| {
+                ToolError::Generic(format!("Failed to read params file {}: {}", path, e))
+            })?;
+            (path.to_string(), contents)
+        }
+        (None, true) => {
+            let mut contents = String::new();
+            std::io::Read::read_to_string(&mut std::io::stdin(), &mut contents).map_err(|e| {
+                ToolError::Generic(format!("Failed to read params from stdin: {}", e))
+            })?;
+            ("stdin".to_string(), contents)
+        }
+        (None, false) => return Ok(BTreeMap::new()),
+    };
+
+    parse_params_json(&source, &contents)
+}
+
+/// Parse a JSON document of method parameters, which must be an object.
+fn parse_params_json(
+    source: &str,
+    contents: &str,
+) -> ToolResult<BTreeMap<String, serde_json::Value>> {
+    let value: serde_json::Value = serde_json::from_str(contents).map_err(|e| {
+        ToolError::Generic(format!("Invalid JSON in params from {}: {}", source, e))
+    })?;
+
+    match value {
+        serde_json::Value::Object(map) => Ok(map.into_iter().collect()),
+        other => Err(ToolError::Generic(format!(
+            "Params from {} must be a JSON object, got {}",
+            source,
+            json_type_name(&other)
+        ))),
+    }
+}
+
+/// Name of a JSON value's type for error messages.
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "a boolean",
+        serde_json::Value::Number(_) => "a number",
+        serde_json::Value::String(_) => "a string",
+        serde_json::Value::Array(_) => "an array",
+        serde_json::Value::Object(_) => "an object",
+    }
+}
+
 /// Parse method parameters from command line.
 fn parse_method_params(params: &[String]) -> ToolResult<BTreeMap<String, serde_json::Value>> {
     let mut result = BTreeMap::new();
@@ -756,6 +1207,53 @@ fn parse_method_params(params: &[String]) -> ToolResult<BTreeMap<String, serde_j
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let yaml = dir.path().join("config.yml");
+        std::fs::write(&yaml, "api_key: secret\nport: 8080\nverbose: true\n").unwrap();
+        let config = read_config_file(&yaml).unwrap();
+        assert_eq!(config["api_key"], "secret");
+        assert_eq!(config["port"], "8080");
+        assert_eq!(config["verbose"], "true");
+
+        // Unknown extensions fall back to content sniffing
+        let sniffed = dir.path().join("config.conf");
+        std::fs::write(&sniffed, "api_key = \"secret\"\n").unwrap();
+        assert_eq!(read_config_file(&sniffed).unwrap()["api_key"], "secret");
+
+        let invalid = dir.path().join("config.json");
+        std::fs::write(&invalid, "api_key: secret").unwrap();
+        let err = read_config_file(&invalid).unwrap_err().to_string();
+        assert!(err.contains("config.json") && err.contains("as JSON"));
+    }
+
+    #[test]
+    fn test_env_user_config() {
+        let schema: BTreeMap<String, McpbUserConfigField> =
+            serde_json::from_value(serde_json::json!({
+                "api_key": {"type": "string", "title": "API Key"},
+                "base-url": {"type": "string", "title": "Base URL"},
+                "region": {"type": "string", "title": "Region"}
+            }))
+            .unwrap();
+
+        let env = |key: &str| match key {
+            "TOOL_CONFIG_API_KEY" => Some("secret".to_string()),
+            "TOOL_CONFIG_BASE_URL" => Some("https://example.com".to_string()),
+            "TOOL_CONFIG_REGION" => Some(String::new()),
+            "TOOL_CONFIG_UNDECLARED" => Some("ignored".to_string()),
+            _ => None,
+        };
+
+        let config = env_user_config(Some(&schema), env);
+        assert_eq!(config.len(), 2);
+        assert_eq!(config["api_key"], "secret");
+        assert_eq!(config["base-url"], "https://example.com");
+        assert!(env_user_config(None, env).is_empty());
+    }
+
     #[test]
     fn test_expand_method_shorthand() {
         // Basic shorthand expansion
@@ -816,4 +1314,44 @@ mod tests {
             "my-tool"
         );
     }
+
+    #[test]
+    fn test_parse_params_json() {
+        let params = parse_params_json(
+            "params.json",
+            r#"{"query": {"filter": {"tags": ["a", "b"]}}, "limit": 5}"#,
+        )
+        .unwrap();
+        assert_eq!(params["limit"], serde_json::json!(5));
+        assert_eq!(
+            params["query"]["filter"]["tags"],
+            serde_json::json!(["a", "b"])
+        );
+
+        // Non-object documents are rejected
+        let err = parse_params_json("params.json", "[1, 2]").unwrap_err();
+        assert!(err.to_string().contains("must be a JSON object"));
+        assert!(parse_params_json("stdin", "{not json").is_err());
+    }
+
+    #[test]
+    fn test_cli_params_override_json_params() {
+        let mut params = parse_params_json("stdin", r#"{"a": 1, "b": 2}"#).unwrap();
+        params.extend(parse_method_params(&["b=3".to_string()]).unwrap());
+        assert_eq!(params["a"], serde_json::json!(1));
+        assert_eq!(params["b"], serde_json::json!(3));
+    }
+
+    #[test]
+    fn test_extension_for_mime() {
+        assert_eq!(extension_for_mime("image/png"), "png");
+        assert_eq!(extension_for_mime("image/jpeg"), "jpg");
+        assert_eq!(extension_for_mime("audio/mpeg"), "mp3");
+        assert_eq!(extension_for_mime("text/plain; charset=utf-8"), "txt");
+
+        // Unknown types fall back to a clean subtype, then to bin
+        assert_eq!(extension_for_mime("image/avif"), "avif");
+        assert_eq!(extension_for_mime("application/vnd.foo+json"), "bin");
+        assert_eq!(extension_for_mime("garbage"), "bin");
+    }
 }
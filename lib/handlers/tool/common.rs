@@ -1,10 +1,13 @@
 //! Common utilities for tool command handlers.
 
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
 use colored::Colorize;
 
+use crate::detect::parse_env_file;
 use crate::error::{ToolError, ToolResult};
 use crate::mcpb::{McpbManifest, McpbTransport, ResolvedMcpbManifest};
 use crate::references::PluginRef;
@@ -52,6 +55,10 @@ pub struct PrepareToolOptions<'a> {
     pub config: &'a [String],
     /// Path to config file.
     pub config_file: Option<&'a str>,
+    /// Path to a `.env` file whose variables are passed to the server.
+    pub env_file: Option<&'a str>,
+    /// Working directory for the server process (defaults to the tool directory).
+    pub cwd: Option<&'a str>,
     /// Skip auto-saving config.
     pub no_save: bool,
     /// Skip interactive prompts.
@@ -103,9 +110,13 @@ pub async fn prepare_tool(tool: &str, options: PrepareToolOptions<'_>) -> ToolRe
 
     let manifest_schema = resolved_plugin.template.user_config.as_ref();
 
-    // Parse user config from saved config, config file, and -k flags
-    let (mut user_config, has_saved_config) =
-        parse_user_config(options.config, options.config_file, &plugin_ref)?;
+    // Parse user config from env vars, saved config, config file, and -k flags
+    let (mut user_config, has_saved_config, from_env) = parse_user_config(
+        options.config,
+        options.config_file,
+        &plugin_ref,
+        manifest_schema,
+    )?;
 
     // Prompt for missing required config values, then apply defaults
     prompt_missing_user_config(
@@ -117,16 +128,46 @@ pub async fn prepare_tool(tool: &str, options: PrepareToolOptions<'_>) -> ToolRe
     apply_user_config_defaults(manifest_schema, &mut user_config);
 
     // Auto-save config for future use (unless --no-save)
-    if !options.no_save && !user_config.is_empty() {
-        let _ = save_tool_config_with_schema(&plugin_ref, &user_config, manifest_schema);
+    // Values taken from the environment aren't persisted
+    let to_save: BTreeMap<String, String> = user_config
+        .iter()
+        .filter(|(key, _)| !from_env.contains(*key))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if !options.no_save && !to_save.is_empty() {
+        let _ = save_tool_config_with_schema(&plugin_ref, &to_save, manifest_schema);
     }
 
     // Allocate system config and resolve manifest
     let system_config = allocate_system_config(resolved_plugin.template.system_config.as_ref())?;
-    let resolved = resolved_plugin
+    let mut resolved = resolved_plugin
         .template
         .resolve(&user_config, &system_config)?;
 
+    if let Some(cwd) = options.cwd {
+        let dir = Path::new(cwd)
+            .canonicalize()
+            .map_err(|e| ToolError::Generic(format!("Invalid working directory {}: {}", cwd, e)))?;
+        if !dir.is_dir() {
+            return Err(ToolError::Generic(format!("{} is not a directory", cwd)));
+        }
+        resolved.working_dir = Some(dir);
+    }
+
+    // Layer .env file variables over the inherited environment; variables the
+    // manifest sets explicitly take precedence
+    if let Some(env_file) = options.env_file {
+        let vars = parse_env_file(Path::new(env_file))?;
+        resolved.redactor.add_env_vars(&vars);
+        for var in vars {
+            resolved
+                .mcp_config
+                .env
+                .entry(var.name)
+                .or_insert_with(|| var.default.unwrap_or_default());
+        }
+    }
+
     // Get transport type
     let transport = resolved.transport;
 
@@ -225,3 +266,13 @@ pub fn auto_install_local_tool(
         }
     }
 }
+
+/// Order versions newest first; unparseable versions sort last.
+pub(super) fn compare_versions_desc(a: &str, b: &str) -> Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => b.cmp(&a),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
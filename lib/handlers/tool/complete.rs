@@ -0,0 +1,52 @@
+//! Candidates for dynamic shell completion.
+
+use super::scripts::script_names;
+use crate::error::ToolResult;
+use crate::resolver::FilePluginResolver;
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Completion kind listing installed tool references (`tool call <TAB>`).
+const COMPLETE_TOOLS: &str = "tools";
+
+/// Completion kind listing scripts from the local manifest (`tool run <TAB>`).
+const COMPLETE_SCRIPTS: &str = "scripts";
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Print completion candidates of `kind` starting with `prefix`, one per line.
+///
+/// Backs the hidden `tool __complete` command called by shell completion scripts. Only
+/// local state is read, never the network, so completion stays responsive. Failures
+/// produce no candidates rather than an error, since shells show stderr mid-prompt.
+pub async fn complete(kind: &str, prefix: &str) -> ToolResult<()> {
+    let candidates = match kind {
+        COMPLETE_TOOLS => tool_candidates().await,
+        COMPLETE_SCRIPTS => script_names(Path::new(".")).unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    for candidate in candidates.iter().filter(|c| c.starts_with(prefix)) {
+        println!("{}", candidate);
+    }
+    Ok(())
+}
+
+/// Installed tool references, sorted.
+async fn tool_candidates() -> Vec<String> {
+    let mut refs: Vec<String> = FilePluginResolver::default()
+        .list_tools()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(|r| r.to_string())
+        .collect();
+    refs.sort();
+    refs.dedup();
+    refs
+}
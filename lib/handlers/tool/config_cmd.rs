@@ -7,11 +7,11 @@ use crate::mcp::connect_with_oauth;
 use crate::mcpb::{McpbTransport, McpbUserConfigField, McpbUserConfigType};
 use crate::output::{
     ConfigGetEntry, ConfigGetOutput, ConfigListEntry, ConfigListOutput, ConfigOAuthOutput,
-    ConfigPropertyOutput, ConfigSchemaOutput,
+    ConfigPropertyOutput, ConfigSchemaOutput, print_json,
 };
 use crate::prompt::init_theme;
 use crate::references::PluginRef;
-use crate::security::get_credential_crypto;
+use crate::security::{get_credential_crypto, mask_sensitive};
 use crate::system_config::allocate_system_config;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use colored::Colorize;
@@ -47,7 +47,12 @@ struct EncryptedConfigEnvelope {
 //--------------------------------------------------------------------------------------------------
 
 /// Main entry point for config command.
-pub async fn config_tool(cmd: ConfigCommand, concise: bool, no_header: bool) -> ToolResult<()> {
+pub async fn config_tool(
+    cmd: ConfigCommand,
+    json: bool,
+    concise: bool,
+    no_header: bool,
+) -> ToolResult<()> {
     match cmd {
         ConfigCommand::Set {
             tool,
@@ -55,10 +60,10 @@ pub async fn config_tool(cmd: ConfigCommand, concise: bool, no_header: bool) ->
             yes,
             config,
         } => config_set(tool, values, yes, config, concise).await,
-        ConfigCommand::Get { tool, key, json } => {
-            config_get(tool, key, json, concise, no_header).await
+        ConfigCommand::Get { tool, key, reveal } => {
+            config_get(tool, key, reveal, json, concise, no_header).await
         }
-        ConfigCommand::List { tool, json } => config_list(tool, json, concise, no_header).await,
+        ConfigCommand::List { tool } => config_list(tool, json, concise, no_header).await,
         ConfigCommand::Unset { tool, all, yes } => config_unset(tool, all, yes, concise).await,
     }
 }
@@ -288,9 +293,12 @@ async fn try_oauth_for_http_tool(
 }
 
 /// Handle `config get` subcommand.
+///
+/// Sensitive values are masked unless `reveal` is set.
 async fn config_get(
     tool: String,
     key: Option<String>,
+    reveal: bool,
     json_output: bool,
     concise: bool,
     no_header: bool,
@@ -307,11 +315,7 @@ async fn config_get(
                 tool: plugin_ref.to_string(),
                 config: BTreeMap::new(),
             };
-            if concise {
-                println!("{}", serde_json::to_string(&output)?);
-            } else {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            }
+            print_json(&output, concise)?;
             return Ok(());
         }
         if concise {
@@ -347,16 +351,18 @@ async fn config_get(
             .map(|f| f.sensitive.unwrap_or(false))
             .unwrap_or(false);
 
+        let display_value = if sensitive && !reveal {
+            mask_sensitive(value)
+        } else {
+            value.clone()
+        };
+
         if json_output {
             let mut entries = BTreeMap::new();
             entries.insert(
                 key.clone(),
                 ConfigGetEntry {
-                    value: if sensitive {
-                        mask_sensitive(value)
-                    } else {
-                        value.clone()
-                    },
+                    value: display_value,
                     sensitive,
                 },
             );
@@ -364,15 +370,11 @@ async fn config_get(
                 tool: plugin_ref.to_string(),
                 config: entries,
             };
-            if concise {
-                println!("{}", serde_json::to_string(&output)?);
-            } else {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            }
+            print_json(&output, concise)?;
         } else if concise {
-            println!("{}", value);
+            println!("{}", display_value);
         } else {
-            println!("\n  {}.{} = {}\n", plugin_ref, key, value);
+            println!("\n  {}.{} = {}\n", plugin_ref, key, display_value);
         }
         return Ok(());
     }
@@ -389,7 +391,7 @@ async fn config_get(
             entries.insert(
                 key.clone(),
                 ConfigGetEntry {
-                    value: if sensitive {
+                    value: if sensitive && !reveal {
                         mask_sensitive(value)
                     } else {
                         value.clone()
@@ -402,11 +404,7 @@ async fn config_get(
             tool: plugin_ref.to_string(),
             config: entries,
         };
-        if concise {
-            println!("{}", serde_json::to_string(&output)?);
-        } else {
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        }
+        print_json(&output, concise)?;
     } else if concise {
         if !no_header {
             println!("#key\tvalue\tsensitive");
@@ -417,7 +415,7 @@ async fn config_get(
                 .and_then(|s| s.get(key))
                 .map(|f| f.sensitive.unwrap_or(false))
                 .unwrap_or(false);
-            let display_value = if sensitive {
+            let display_value = if sensitive && !reveal {
                 mask_sensitive(value)
             } else {
                 value.clone()
@@ -435,7 +433,12 @@ async fn config_get(
                 .map(|f| f.sensitive.unwrap_or(false))
                 .unwrap_or(false);
             let display_value = if sensitive {
-                format!("{}  {}", mask_sensitive(value), "(sensitive)".dimmed())
+                let shown = if reveal {
+                    value.clone()
+                } else {
+                    mask_sensitive(value)
+                };
+                format!("{}  {}", shown, "(sensitive)".dimmed())
             } else {
                 value.clone()
             };
@@ -471,11 +474,7 @@ async fn config_list(
                 let output = ConfigListOutput {
                     tools: BTreeMap::new(),
                 };
-                if concise {
-                    println!("{}", serde_json::to_string(&output)?);
-                } else {
-                    println!("{}", serde_json::to_string_pretty(&output)?);
-                }
+                print_json(&output, concise)?;
             }
             return Ok(());
         }
@@ -502,11 +501,7 @@ async fn config_list(
             );
         }
         let output = ConfigListOutput { tools: entries };
-        if concise {
-            println!("{}", serde_json::to_string(&output)?);
-        } else {
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        }
+        print_json(&output, concise)?;
     } else if concise {
         if !no_header {
             println!("#tool\tkeys\tpath");
@@ -565,11 +560,7 @@ async fn config_list_tool(
                 user_config: BTreeMap::new(),
                 oauth: None,
             };
-            if concise {
-                println!("{}", serde_json::to_string(&output)?);
-            } else {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            }
+            print_json(&output, concise)?;
         } else if !concise {
             println!("\n  Config: {}\n", plugin_ref.to_string().bold());
             println!("    {}", "No configurable options.".dimmed());
@@ -605,11 +596,7 @@ async fn config_list_tool(
             user_config,
             oauth,
         };
-        if concise {
-            println!("{}", serde_json::to_string(&output)?);
-        } else {
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        }
+        print_json(&output, concise)?;
     } else if concise {
         if !no_header {
             println!("#key\ttype\trequired\tsensitive\tdefault\ttitle");
@@ -1542,15 +1529,6 @@ fn resolve_variables(s: &str) -> String {
     result
 }
 
-/// Mask a sensitive value for display.
-fn mask_sensitive(value: &str) -> String {
-    if value.len() <= 8 {
-        "***".to_string()
-    } else {
-        format!("{}...{}", &value[..3], &value[value.len() - 3..])
-    }
-}
-
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
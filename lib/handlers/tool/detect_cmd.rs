@@ -4,9 +4,12 @@ use crate::constants::MCPB_MANIFEST_FILE;
 use crate::detect::{DetectOptions, DetectionMatch, DetectorRegistry};
 use crate::error::{ToolError, ToolResult};
 use crate::mcpb::McpbTransport;
+use crate::output::print_json;
 use colored::Colorize;
 use std::path::{Path, PathBuf};
 
+use super::scripts::{default_shell, shell_command};
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -20,11 +23,18 @@ pub async fn detect_mcpb(
     transport: Option<String>,
     name: Option<String>,
     force: bool,
+    json_output: bool,
     concise: bool,
     no_header: bool,
     verify: bool,
     yes: bool,
 ) -> ToolResult<()> {
+    if json_output && verify {
+        return Err(ToolError::Generic(
+            "--json only reports detection results; it cannot be combined with --verify".into(),
+        ));
+    }
+
     // Resolve path
     let dir = PathBuf::from(&path);
     let dir = if dir.is_absolute() {
@@ -51,8 +61,8 @@ pub async fn detect_mcpb(
     // Run detection
     let registry = DetectorRegistry::new();
 
-    // For non-concise mode, use verbose detection to print signals as they happen
-    let is_verbose = !concise;
+    // For human output, use verbose detection to print signals as they happen
+    let is_verbose = !concise && !json_output;
     if is_verbose {
         println!("\n  {}", "Signals".dimmed());
     }
@@ -119,6 +129,21 @@ pub async fn detect_mcpb(
         .or(detection.result.details.transport)
         .unwrap_or(McpbTransport::Stdio);
 
+    if json_output {
+        let details = &detection.result.details;
+        let output = serde_json::json!({
+            "type": detection.display_name,
+            "transport": transport_display.to_string().to_lowercase(),
+            "entry": entry_display,
+            "package_manager": details.package_manager.as_ref().map(|pm| pm.to_string()),
+            "confidence": detection.result.confidence,
+            "build": details.build_command,
+            "notes": details.notes,
+        });
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
     // Concise output: Header + TSV format
     if concise {
         use crate::concise::quote;
@@ -304,6 +329,7 @@ pub(super) async fn verify_server(
     yes: bool,
 ) -> bool {
     use crate::mcpb::{McpbManifest, McpbServer, ResolvedMcpConfig, ResolvedMcpbManifest};
+    use crate::security::Redactor;
     use std::collections::BTreeMap;
     use std::io::IsTerminal;
 
@@ -341,19 +367,7 @@ pub(super) async fn verify_server(
             match build {
                 Ok(true) => {
                     let _ = cliclack::outro("Building...");
-                    let status = if cfg!(windows) {
-                        std::process::Command::new("cmd")
-                            .arg("/C")
-                            .arg(build_cmd)
-                            .current_dir(dir)
-                            .status()
-                    } else {
-                        std::process::Command::new("sh")
-                            .arg("-c")
-                            .arg(build_cmd)
-                            .current_dir(dir)
-                            .status()
-                    };
+                    let status = shell_command(default_shell(), build_cmd, dir).status();
 
                     match status {
                         Ok(s) if s.success() => {
@@ -507,6 +521,8 @@ pub(super) async fn verify_server(
         },
         transport,
         is_reference: false,
+        working_dir: None,
+        redactor: Redactor::default(),
     };
 
     // Attempt connection with timeout
@@ -0,0 +1,232 @@
+//! Dev command handler: rebuild and restart a tool when its sources change.
+
+use colored::Colorize;
+use ignore::gitignore::Gitignore;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use crate::error::{ToolError, ToolResult};
+use crate::mcp::{McpConnection, connect_with_oauth};
+use crate::mcpb::McpbManifest;
+use crate::pack::{build_ignore_matcher, is_builtin_ignored};
+use crate::styles::Spinner;
+
+use super::common::{PrepareToolOptions, prepare_tool};
+use super::scripts::run_script;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Quiet period after the last change before reloading.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Directories that are never watched (dependencies and build caches).
+const WATCH_SKIP_DIRS: &[&str] = &["node_modules", "target", "__pycache__", ".venv"];
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Run a tool in dev mode, rebuilding and restarting it on source changes.
+pub async fn tool_dev(
+    path: String,
+    config: Vec<String>,
+    config_file: Option<String>,
+    no_save: bool,
+    yes: bool,
+    no_build: bool,
+    verbose: bool,
+) -> ToolResult<()> {
+    let dir = PathBuf::from(&path)
+        .canonicalize()
+        .map_err(|_| ToolError::Generic(format!("Directory not found: {}", path)))?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<PathBuf>();
+    let watch_root = dir.clone();
+    let mut watcher = RecommendedWatcher::new(
+        move |event: notify::Result<Event>| {
+            if let Ok(event) = event
+                && !event.kind.is_access()
+            {
+                for path in event.paths {
+                    if !is_skipped(&path, &watch_root) {
+                        let _ = tx.send(path);
+                    }
+                }
+            }
+        },
+        notify::Config::default(),
+    )
+    .map_err(|e| ToolError::Generic(format!("Failed to start file watcher: {}", e)))?;
+    watcher
+        .watch(&dir, RecursiveMode::Recursive)
+        .map_err(|e| ToolError::Generic(format!("Failed to watch {}: {}", dir.display(), e)))?;
+
+    eprintln!(
+        "  {} Watching {} {}\n",
+        "→".bright_blue(),
+        dir.display().to_string().bright_white(),
+        "(Ctrl+C to stop)".dimmed()
+    );
+
+    let mut reason: Option<PathBuf> = None;
+    loop {
+        if let Some(changed) = reason.take() {
+            let relative = changed.strip_prefix(&dir).unwrap_or(&changed);
+            eprintln!(
+                "\n  {} Reloading {} {}\n",
+                "↻".bright_cyan(),
+                "·".dimmed(),
+                relative.display().to_string().dimmed()
+            );
+        }
+
+        // The server is stopped when the connection is dropped at the end of this iteration
+        let options = PrepareToolOptions {
+            config: &config,
+            config_file: config_file.as_deref(),
+            env_file: None,
+            cwd: None,
+            no_save,
+            yes,
+        };
+        let _connection = match start_dev_server(&dir, options, no_build, verbose).await {
+            Ok(conn) => Some(conn),
+            Err(e) => {
+                // Keep watching so the next edit can fix the failure
+                eprintln!("  {} {}", "✗".bright_red(), e);
+                None
+            }
+        };
+
+        // Discard events produced by our own build before waiting for edits
+        tokio::time::sleep(DEBOUNCE).await;
+        while rx.try_recv().is_ok() {}
+
+        let matcher = build_ignore_matcher(&dir).ok();
+        tokio::select! {
+            changed = wait_for_change(&mut rx, &dir, matcher.as_ref()) => match changed {
+                Some(changed) => reason = Some(changed),
+                None => return Ok(()),
+            },
+            _ = tokio::signal::ctrl_c() => {
+                eprintln!();
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Build (if configured), start the server and print its capabilities.
+async fn start_dev_server(
+    dir: &Path,
+    options: PrepareToolOptions<'_>,
+    no_build: bool,
+    verbose: bool,
+) -> ToolResult<McpConnection> {
+    let dir_str = dir.to_string_lossy().to_string();
+
+    if !no_build
+        && McpbManifest::load(dir)?
+            .scripts()
+            .is_some_and(|s| s.for_current_os("build").is_some())
+    {
+        run_script("build", Some(dir_str.clone()), Vec::new(), false).await?;
+    }
+
+    let prepared = prepare_tool(&dir_str, options).await?;
+
+    let started = Instant::now();
+    let spinner = Spinner::new(format!("Starting {}", prepared.tool_name));
+    let connection =
+        match connect_with_oauth(&prepared.resolved, &prepared.tool_name, verbose).await {
+            Ok(conn) => {
+                spinner.done();
+                conn
+            }
+            Err(e) => {
+                spinner.fail(None);
+                return Err(e);
+            }
+        };
+
+    let server_info = connection
+        .peer_info()
+        .map(|info| format!("{} v{}", info.server_info.name, info.server_info.version))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let tools = connection
+        .peer()
+        .list_tools(None)
+        .await
+        .map(|r| r.tools)
+        .unwrap_or_default();
+
+    eprintln!(
+        "  {} {} ready in {}ms",
+        "✓".bright_green(),
+        server_info.bright_white(),
+        started.elapsed().as_millis()
+    );
+    if tools.is_empty() {
+        eprintln!("  · {}", "no tools".dimmed());
+    } else {
+        let names: Vec<_> = tools.iter().map(|t| t.name.to_string()).collect();
+        eprintln!(
+            "  · {} {}",
+            format!("{} tool(s):", names.len()).dimmed(),
+            names.join(", ").bright_cyan()
+        );
+    }
+
+    Ok(connection)
+}
+
+/// Wait for a relevant change, then for the debounce period to pass without new ones.
+///
+/// Returns the first changed path, or `None` if the watcher stopped.
+async fn wait_for_change(
+    rx: &mut mpsc::UnboundedReceiver<PathBuf>,
+    dir: &Path,
+    matcher: Option<&Gitignore>,
+) -> Option<PathBuf> {
+    let is_relevant = |path: &Path| {
+        let Ok(relative) = path.strip_prefix(dir) else {
+            return false;
+        };
+        !matcher.is_some_and(|m| {
+            m.matched_path_or_any_parents(relative, path.is_dir())
+                .is_ignore()
+        })
+    };
+
+    let first = loop {
+        let path = rx.recv().await?;
+        if is_relevant(&path) {
+            break path;
+        }
+    };
+
+    // Debounce: keep draining until changes go quiet
+    while let Ok(Some(_)) = tokio::time::timeout(DEBOUNCE, rx.recv()).await {}
+
+    Some(first)
+}
+
+/// Whether a path is never watched (VCS metadata, bundles, dependencies).
+fn is_skipped(path: &Path, dir: &Path) -> bool {
+    if is_builtin_ignored(path, dir) {
+        return true;
+    }
+
+    path.strip_prefix(dir).is_ok_and(|relative| {
+        relative.components().any(|c| {
+            WATCH_SKIP_DIRS
+                .iter()
+                .any(|skip| c.as_os_str() == std::ffi::OsStr::new(skip))
+        })
+    })
+}
@@ -0,0 +1,455 @@
+//! Diff command handler: compare two manifests, bundles or published versions.
+
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+use crate::constants::MCPB_MANIFEST_FILE;
+use crate::error::{ToolError, ToolResult};
+use crate::mcpb::TOOL_STORE_NAMESPACE;
+use crate::output::print_json;
+use crate::pack::{is_bundle_file, read_manifest_from_bundle};
+use crate::references::PluginRef;
+use crate::registry::RegistryClient;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Kind of change between two manifests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    /// Present only in the second manifest.
+    Added,
+
+    /// Present only in the first manifest.
+    Removed,
+
+    /// Present in both with different values.
+    Changed,
+}
+
+/// A single difference between two manifests.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestChange {
+    /// Area of the manifest (`tools`, `user_config`, `server`, ...).
+    pub section: &'static str,
+
+    /// Tool name, config key or field path within the section.
+    pub key: String,
+
+    /// Kind of change.
+    pub kind: ChangeKind,
+
+    /// Value in the first manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+
+    /// Value in the second manifest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Top-level fields diffed as their own sections rather than as plain fields.
+const SECTION_FIELDS: &[&str] = &["tools", "prompts", "user_config", "system_config", "server"];
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Compare the manifests of two tools.
+///
+/// Each side can be a tool directory, a `.mcpb`/`.mcpbx` bundle or a
+/// `namespace/name[@version]` registry reference.
+pub async fn diff_manifests(a: &str, b: &str, json_output: bool) -> ToolResult<()> {
+    let (before, after) = (
+        load_manifest_source(a).await?,
+        load_manifest_source(b).await?,
+    );
+    let changes = compute_manifest_diff(&before, &after);
+
+    if json_output {
+        let output = serde_json::json!({
+            "a": a,
+            "b": b,
+            "changes": changes,
+        });
+        print_json(&output, false)?;
+        return Ok(());
+    }
+
+    println!("  Comparing {} → {}\n", a.bright_cyan(), b.bright_cyan());
+
+    if changes.is_empty() {
+        println!("  {} No differences", "✓".bright_green());
+        return Ok(());
+    }
+
+    let mut section = "";
+    for change in &changes {
+        if change.section != section {
+            section = change.section;
+            println!("  {}", section.bold());
+        }
+        print_change(change);
+    }
+
+    let count = |kind| changes.iter().filter(|c| c.kind == kind).count();
+    println!(
+        "\n  {} added, {} removed, {} changed",
+        count(ChangeKind::Added).to_string().bright_green(),
+        count(ChangeKind::Removed).to_string().bright_red(),
+        count(ChangeKind::Changed).to_string().bright_yellow()
+    );
+
+    Ok(())
+}
+
+/// Print one change line.
+fn print_change(change: &ManifestChange) {
+    match change.kind {
+        ChangeKind::Added => println!("    {} {}", "+".bright_green(), change.key.bright_green()),
+        ChangeKind::Removed => println!("    {} {}", "-".bright_red(), change.key.bright_red()),
+        ChangeKind::Changed => {
+            let (Some(before), Some(after)) = (&change.before, &change.after) else {
+                return;
+            };
+
+            // Entries (tools, config keys) list what changed; plain fields show both values
+            let detail = match (before, after) {
+                (Value::Object(b), Value::Object(a)) if change.section != "manifest" => {
+                    changed_keys(b, a).join(", ").dimmed().to_string()
+                }
+                _ => format!(
+                    "{} → {}",
+                    display_value(before).dimmed(),
+                    display_value(after).bright_cyan()
+                ),
+            };
+            println!("    {} {}  {}", "~".bright_yellow(), change.key, detail);
+        }
+    }
+}
+
+/// Render a value compactly, without quotes around strings.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Load a manifest as raw JSON from a directory, bundle or registry reference.
+async fn load_manifest_source(source: &str) -> ToolResult<Value> {
+    let path = Path::new(source);
+    if is_bundle_file(source) {
+        let bytes = std::fs::read(path)
+            .map_err(|e| ToolError::Generic(format!("Failed to read {}: {}", source, e)))?;
+        let (_, raw) = read_manifest_from_bundle(&bytes)
+            .map_err(|e| ToolError::Generic(format!("Invalid bundle {}: {}", source, e)))?;
+        return Ok(serde_json::from_slice(&raw)?);
+    }
+
+    if path.is_dir() {
+        let manifest_path = path.join(MCPB_MANIFEST_FILE);
+        if !manifest_path.exists() {
+            return Err(ToolError::ManifestNotFound(path.to_path_buf()));
+        }
+        return Ok(serde_json::from_str(&std::fs::read_to_string(
+            manifest_path,
+        )?)?);
+    }
+
+    let plugin_ref: PluginRef = source.parse().map_err(|_| {
+        ToolError::Generic(format!(
+            "`{}` is not a directory, bundle or registry reference",
+            source
+        ))
+    })?;
+    let Some(namespace) = plugin_ref.namespace() else {
+        return Err(ToolError::Generic(format!(
+            "`{}` is not a directory, bundle or namespace/name reference",
+            source
+        )));
+    };
+
+    let client = RegistryClient::new();
+    let version = match (plugin_ref.tag(), plugin_ref.version_str()) {
+        (Some(tag), _) => {
+            client
+                .resolve_tag(namespace, plugin_ref.name(), tag)
+                .await?
+        }
+        (None, Some(v)) => v.to_string(),
+        (None, None) => {
+            client
+                .get_artifact(namespace, plugin_ref.name())
+                .await?
+                .latest_version
+                .ok_or_else(|| ToolError::Generic(format!("No published version for {}", source)))?
+                .version
+        }
+    };
+
+    client
+        .get_version(namespace, plugin_ref.name(), &version)
+        .await?
+        .manifest
+        .ok_or_else(|| {
+            ToolError::Generic(format!(
+                "Registry has no manifest for {}/{}@{}",
+                namespace,
+                plugin_ref.name(),
+                version
+            ))
+        })
+}
+
+/// Compute the differences between two raw manifests.
+pub fn compute_manifest_diff(before: &Value, after: &Value) -> Vec<ManifestChange> {
+    let mut changes = Vec::new();
+
+    diff_entries(
+        "tools",
+        &declared_entries(before, "tools", "tools/list", "name"),
+        &declared_entries(after, "tools", "tools/list", "name"),
+        &mut changes,
+    );
+    diff_entries(
+        "prompts",
+        &declared_entries(before, "prompts", "prompts/list", "name"),
+        &declared_entries(after, "prompts", "prompts/list", "name"),
+        &mut changes,
+    );
+    diff_entries(
+        "resources",
+        &declared_entries(before, "resources", "resources/list", "uri"),
+        &declared_entries(after, "resources", "resources/list", "uri"),
+        &mut changes,
+    );
+
+    for section in ["user_config", "system_config"] {
+        let entries = |manifest: &Value| -> Vec<(String, Value)> {
+            manifest
+                .get(section)
+                .and_then(Value::as_object)
+                .map(|o| o.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default()
+        };
+        diff_entries(section, &entries(before), &entries(after), &mut changes);
+    }
+
+    let mut before_server = Vec::new();
+    let mut after_server = Vec::new();
+    flatten("", before.get("server"), &mut before_server);
+    flatten("", after.get("server"), &mut after_server);
+    diff_entries("server", &before_server, &after_server, &mut changes);
+
+    let mut before_fields = Vec::new();
+    let mut after_fields = Vec::new();
+    flatten("", Some(&plain_fields(before)), &mut before_fields);
+    flatten("", Some(&plain_fields(after)), &mut after_fields);
+    diff_entries("manifest", &before_fields, &after_fields, &mut changes);
+
+    changes
+}
+
+/// Declared tools, prompts or resources keyed by `id_key`.
+///
+/// Static responses in `_meta` take precedence over the top-level field.
+fn declared_entries(
+    manifest: &Value,
+    field: &str,
+    method: &str,
+    id_key: &str,
+) -> Vec<(String, Value)> {
+    let list = manifest
+        .get("_meta")
+        .and_then(|m| m.get(TOOL_STORE_NAMESPACE))
+        .and_then(|s| s.get("static_responses"))
+        .and_then(|r| r.get(method))
+        .and_then(|r| r.get(field))
+        .or_else(|| manifest.get(field));
+
+    list.and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    let id = item.get(id_key)?.as_str()?;
+                    Some((id.to_string(), item.clone()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Top-level fields not covered by a dedicated section, without static responses.
+fn plain_fields(manifest: &Value) -> Value {
+    let mut fields: Map<String, Value> = manifest
+        .as_object()
+        .map(|o| {
+            o.iter()
+                .filter(|(k, _)| !SECTION_FIELDS.contains(&k.as_str()))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(store) = fields
+        .get_mut("_meta")
+        .and_then(|m| m.get_mut(TOOL_STORE_NAMESPACE))
+        .and_then(Value::as_object_mut)
+    {
+        store.remove("static_responses");
+    }
+
+    Value::Object(fields)
+}
+
+/// Flatten nested objects into dotted paths; arrays and scalars are leaves.
+fn flatten(prefix: &str, value: Option<&Value>, out: &mut Vec<(String, Value)>) {
+    match value {
+        Some(Value::Object(map)) if !map.is_empty() => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&path, Some(value), out);
+            }
+        }
+        Some(value) if !prefix.is_empty() => out.push((prefix.to_string(), value.clone())),
+        _ => {}
+    }
+}
+
+/// Diff two keyed lists, preserving the order of the first then new keys of the second.
+fn diff_entries(
+    section: &'static str,
+    before: &[(String, Value)],
+    after: &[(String, Value)],
+    changes: &mut Vec<ManifestChange>,
+) {
+    for (key, old) in before {
+        match after.iter().find(|(k, _)| k == key) {
+            None => changes.push(ManifestChange {
+                section,
+                key: key.clone(),
+                kind: ChangeKind::Removed,
+                before: Some(old.clone()),
+                after: None,
+            }),
+            Some((_, new)) if new != old => changes.push(ManifestChange {
+                section,
+                key: key.clone(),
+                kind: ChangeKind::Changed,
+                before: Some(old.clone()),
+                after: Some(new.clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for (key, new) in after {
+        if !before.iter().any(|(k, _)| k == key) {
+            changes.push(ManifestChange {
+                section,
+                key: key.clone(),
+                kind: ChangeKind::Added,
+                before: None,
+                after: Some(new.clone()),
+            });
+        }
+    }
+}
+
+/// Keys whose values differ between two objects (including added and removed keys).
+fn changed_keys(before: &Map<String, Value>, after: &Map<String, Value>) -> Vec<String> {
+    let mut keys: Vec<String> = before
+        .iter()
+        .filter(|(k, v)| after.get(*k) != Some(*v))
+        .map(|(k, _)| k.clone())
+        .collect();
+    keys.extend(after.keys().filter(|k| !before.contains_key(*k)).cloned());
+    keys
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn summary(changes: &[ManifestChange]) -> Vec<(&str, &str, ChangeKind)> {
+        changes
+            .iter()
+            .map(|c| (c.section, c.key.as_str(), c.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_manifest_diff() {
+        let before = json!({
+            "name": "tool",
+            "version": "1.0.0",
+            "server": { "type": "node", "mcp_config": { "command": "node" } },
+            "tools": [
+                { "name": "read", "description": "Read" },
+                { "name": "old", "description": "Old" }
+            ],
+            "user_config": { "token": { "type": "string" } }
+        });
+        let after = json!({
+            "name": "tool",
+            "version": "1.1.0",
+            "server": { "type": "node", "transport": "http", "mcp_config": { "url": "http://x" } },
+            "tools": [
+                { "name": "read", "description": "Read a file" },
+                { "name": "new", "description": "New" }
+            ],
+            "user_config": { "token": { "type": "string" }, "region": { "type": "string" } }
+        });
+
+        let changes = compute_manifest_diff(&before, &after);
+        assert_eq!(
+            summary(&changes),
+            [
+                ("tools", "read", ChangeKind::Changed),
+                ("tools", "old", ChangeKind::Removed),
+                ("tools", "new", ChangeKind::Added),
+                ("user_config", "region", ChangeKind::Added),
+                ("server", "mcp_config.command", ChangeKind::Removed),
+                ("server", "transport", ChangeKind::Added),
+                ("server", "mcp_config.url", ChangeKind::Added),
+                ("manifest", "version", ChangeKind::Changed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifest_diff_prefers_static_responses() {
+        let with_static = |description: &str| {
+            json!({
+                "tools": [{ "name": "read", "description": "top-level" }],
+                "_meta": { "store.tool.mcpb": { "static_responses": { "tools/list": {
+                    "tools": [{ "name": "read", "description": description }]
+                } } } }
+            })
+        };
+
+        let changes = compute_manifest_diff(&with_static("a"), &with_static("b"));
+        assert_eq!(summary(&changes), [("tools", "read", ChangeKind::Changed)]);
+        assert!(compute_manifest_diff(&with_static("a"), &with_static("a")).is_empty());
+    }
+}
@@ -0,0 +1,383 @@
+//! Environment diagnostic command handler.
+
+use crate::constants::{
+    CREDENTIALS_SECRET_KEY_ENV, DEFAULT_TOOLS_PATH, ENCRYPTION_KEY_PATH, MCPB_MANIFEST_FILE,
+};
+use crate::detect::DetectorRegistry;
+use crate::error::{EXIT_FAILURE, ToolError, ToolResult};
+use crate::handlers::auth::get_registry_token;
+use crate::mcpb::{McpbManifest, PackageManager};
+use crate::output::print_json;
+use crate::registry::{RegistryClient, is_network_frozen};
+use crate::resolver::FilePluginResolver;
+use colored::Colorize;
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Outcome of a single diagnostic check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    /// Everything is in order.
+    Pass,
+    /// Worth fixing, but nothing is broken.
+    Warn,
+    /// Commands will fail until this is fixed.
+    Fail,
+}
+
+/// A diagnostic check result.
+#[derive(Debug, Serialize)]
+struct Check {
+    /// Short name of what was checked.
+    name: String,
+    /// Outcome.
+    status: CheckStatus,
+    /// What was found.
+    detail: String,
+    /// How to fix it, for failed and warned checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<String>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Check {
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Diagnose common setup problems and print how to fix them.
+///
+/// Exits non-zero if any critical check fails; warnings alone don't.
+pub async fn doctor(json_output: bool) -> ToolResult<()> {
+    let mut checks = vec![check_tools_dir()];
+    checks.push(check_registry().await);
+    checks.push(check_credentials().await);
+    checks.push(check_encryption_key());
+    checks.extend(check_project(Path::new(".")));
+    checks.push(check_orphans());
+
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+
+    if json_output {
+        let output = serde_json::json!({
+            "healthy": failed == 0,
+            "checks": checks,
+        });
+        print_json(&output, false)?;
+    } else {
+        let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+        for check in &checks {
+            let icon = match check.status {
+                CheckStatus::Pass => "✓".bright_green(),
+                CheckStatus::Warn => "!".bright_yellow(),
+                CheckStatus::Fail => "✗".bright_red(),
+            };
+            println!(
+                "  {} {:<width$}  {}",
+                icon,
+                check.name,
+                check.detail.dimmed(),
+                width = width
+            );
+            if let Some(fix) = &check.fix {
+                println!("    {} {}", "→".bright_blue(), fix);
+            }
+        }
+
+        println!();
+        if failed == 0 {
+            println!("  {} No critical problems found", "✓".bright_green());
+        } else {
+            println!(
+                "  {} {} critical {} failed",
+                "✗".bright_red(),
+                failed,
+                if failed == 1 { "check" } else { "checks" }
+            );
+        }
+    }
+
+    if failed > 0 {
+        return Err(ToolError::Reported { code: EXIT_FAILURE });
+    }
+
+    Ok(())
+}
+
+/// The tools directory exists (or can be created) and is writable.
+fn check_tools_dir() -> Check {
+    const NAME: &str = "Tools directory";
+    let dir = DEFAULT_TOOLS_PATH.as_path();
+    let display = dir.display().to_string();
+
+    if !dir.exists() {
+        return Check::pass(NAME, format!("{} (created on first install)", display));
+    }
+    if !dir.is_dir() {
+        return Check::fail(
+            NAME,
+            format!("{} is not a directory", display),
+            format!("Move or remove {}", display),
+        );
+    }
+
+    let probe = dir.join(format!(".doctor-{}", std::process::id()));
+    match std::fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = std::fs::remove_file(&probe);
+            Check::pass(NAME, format!("{} (writable)", display))
+        }
+        Err(e) => Check::fail(
+            NAME,
+            format!("{} is not writable: {}", display, e),
+            format!("Fix the permissions on {}", display),
+        ),
+    }
+}
+
+/// The registry answers requests.
+async fn check_registry() -> Check {
+    const NAME: &str = "Registry";
+    let client = RegistryClient::new();
+    let url = client.registry_url().to_string();
+
+    if is_network_frozen() {
+        return Check::warn(
+            NAME,
+            format!("{} (not checked, network disabled)", url),
+            "Unset TOOL_OFFLINE or drop --frozen to reach the registry",
+        );
+    }
+
+    match client.ping().await {
+        Ok(()) => Check::pass(NAME, format!("{} (reachable)", url)),
+        Err(e) => Check::fail(
+            NAME,
+            format!("{} unreachable: {}", url, e),
+            "Check your network connection, or point TOOL_REGISTRY at a reachable registry",
+        ),
+    }
+}
+
+/// Registry credentials are present (only needed for publishing).
+async fn check_credentials() -> Check {
+    const NAME: &str = "Registry login";
+    match get_registry_token().await {
+        Ok(Some(_)) => Check::pass(NAME, "credentials found"),
+        Ok(None) => Check::warn(
+            NAME,
+            "not logged in (only needed to publish)",
+            "Run `tool login`",
+        ),
+        Err(e) => Check::warn(
+            NAME,
+            format!("could not read credentials: {}", e),
+            "Run `tool login` again",
+        ),
+    }
+}
+
+/// A key for encrypting saved tool credentials is configured or can be generated.
+fn check_encryption_key() -> Check {
+    const NAME: &str = "Credential encryption";
+    if std::env::var_os(CREDENTIALS_SECRET_KEY_ENV).is_some() {
+        return Check::pass(NAME, format!("key from {}", CREDENTIALS_SECRET_KEY_ENV));
+    }
+
+    let key_path = ENCRYPTION_KEY_PATH.as_path();
+    if key_path.exists() {
+        return Check::pass(NAME, format!("key file {}", key_path.display()));
+    }
+
+    Check::pass(
+        NAME,
+        format!(
+            "no key yet; one is generated at {} on first use",
+            key_path.display()
+        ),
+    )
+}
+
+/// Runtimes needed by the project in `dir`, and whether its entry point has been built.
+fn check_project(dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    if let Some(detection) = DetectorRegistry::new().detect(dir) {
+        let mut programs: Vec<String> = match detection.detector_name {
+            "node" => vec!["node".into()],
+            "python" => vec![python_program().into()],
+            "rust" => vec!["cargo".into()],
+            _ => Vec::new(),
+        };
+        match detection.result.details.package_manager {
+            Some(PackageManager::Node(pm)) => programs.push(pm.to_string()),
+            Some(PackageManager::Python(pm)) => programs.push(pm.to_string()),
+            None => {}
+        }
+        programs.dedup();
+
+        for program in programs {
+            let name = format!("{} ({} project)", program, detection.display_name);
+            checks.push(match command_version(&program) {
+                Some(version) => Check::pass(name, version),
+                None => Check::fail(
+                    name,
+                    format!("{} not found on PATH", program),
+                    format!("Install {} or add it to your PATH", program),
+                ),
+            });
+        }
+    }
+
+    if dir.join(MCPB_MANIFEST_FILE).exists() {
+        checks.push(check_entry_point(dir));
+    }
+
+    checks
+}
+
+/// The manifest's entry point exists on disk.
+fn check_entry_point(dir: &Path) -> Check {
+    const NAME: &str = "Entry point";
+    let manifest = match McpbManifest::load(dir) {
+        Ok(m) => m,
+        Err(e) => {
+            return Check::fail(
+                NAME,
+                format!("could not load {}: {}", MCPB_MANIFEST_FILE, e),
+                "Run `tool validate` for details",
+            );
+        }
+    };
+
+    match manifest.server.entry_point.as_deref() {
+        None => Check::pass(NAME, "none (reference mode)"),
+        Some(entry) if dir.join(entry).exists() => Check::pass(NAME, entry.to_string()),
+        Some(entry) => Check::warn(
+            NAME,
+            format!("{} does not exist", entry),
+            "Build the project first (e.g. `tool run build`)",
+        ),
+    }
+}
+
+/// No broken symlinks or stale directories in the tool search paths.
+fn check_orphans() -> Check {
+    const NAME: &str = "Installed tools";
+    match FilePluginResolver::default().list_orphaned_entries() {
+        Ok(orphans) if orphans.is_empty() => Check::pass(NAME, "no orphaned entries"),
+        Ok(orphans) => Check::warn(
+            NAME,
+            format!(
+                "{} orphaned {}",
+                orphans.len(),
+                if orphans.len() == 1 {
+                    "entry"
+                } else {
+                    "entries"
+                }
+            ),
+            "Run `tool prune`",
+        ),
+        Err(e) => Check::warn(
+            NAME,
+            format!("could not scan: {}", e),
+            "Check the tools directory permissions",
+        ),
+    }
+}
+
+/// Python interpreter name for this platform.
+fn python_program() -> &'static str {
+    if cfg!(windows) { "python" } else { "python3" }
+}
+
+/// First line of `<program> --version`, if the program runs.
+fn command_version(program: &str) -> Option<String> {
+    let output = Command::new(program).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stdout.lines().chain(stderr.lines()).next()?.trim();
+    Some(line.to_string())
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_entry_point_reports_unbuilt_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = serde_json::json!({
+            "manifest_version": "0.3",
+            "name": "demo",
+            "version": "1.0.0",
+            "server": {
+                "type": "node",
+                "entry_point": "dist/index.js",
+                "mcp_config": { "command": "node", "args": ["dist/index.js"] }
+            }
+        });
+        std::fs::write(dir.path().join(MCPB_MANIFEST_FILE), manifest.to_string()).unwrap();
+
+        let check = check_entry_point(dir.path());
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.fix.is_some());
+
+        std::fs::create_dir_all(dir.path().join("dist")).unwrap();
+        std::fs::write(dir.path().join("dist/index.js"), "").unwrap();
+        assert_eq!(check_entry_point(dir.path()).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_command_version_missing_program() {
+        assert!(command_version("definitely-not-a-real-program-xyz").is_none());
+    }
+}
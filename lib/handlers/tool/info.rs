@@ -1,15 +1,47 @@
 //! Tool info command handlers.
 
-use crate::error::{ToolError, ToolResult};
+use crate::error::{EXIT_AUTH, EXIT_NOT_FOUND, ToolError, ToolResult};
 use crate::format::{format_description, truncate_param_desc};
 use crate::mcp::{ToolCapabilities, ToolType, get_tool_info, get_tool_type};
-use crate::output::ToolInfoOutput;
+use crate::mcpb::{McpbManifest, McpbSystemConfigField, McpbUserConfigField};
+use crate::output::{ToolInfoOutput, print_json};
+use crate::references::PluginRef;
+use crate::registry::RegistryClient;
+use crate::resolver::{FilePluginResolver, load_tool_from_path};
 use crate::styles::Spinner;
 use colored::Colorize;
 use rmcp::model::Tool;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use super::common::{PrepareToolOptions, prepare_tool};
+use super::list::resolve_tool_path;
+use super::preview::preview_registry_tool;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// JSON output for `tool info --config-schema`: the manifest's raw config schema.
+#[derive(Debug, Serialize)]
+struct ConfigSchemaOutput {
+    /// Values the user supplies (`-k KEY=VALUE`).
+    user_config: BTreeMap<String, McpbUserConfigField>,
+
+    /// Values the host allocates (ports, directories).
+    system_config: BTreeMap<String, McpbSystemConfigField>,
+}
+
+/// One row of the human-readable config schema table.
+struct ConfigSchemaRow {
+    key: String,
+    field_type: String,
+    required: bool,
+    default: Option<serde_json::Value>,
+    sensitive: bool,
+    description: Option<String>,
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -27,9 +59,11 @@ pub async fn tool_info(
     show_prompts: bool,
     show_resources: bool,
     show_all: bool,
+    config_schema: bool,
     json_output: bool,
     config: Vec<String>,
     config_file: Option<String>,
+    cwd: Option<String>,
     no_save: bool,
     yes: bool,
     verbose: bool,
@@ -37,12 +71,38 @@ pub async fn tool_info(
     no_header: bool,
     level: usize,
 ) -> ToolResult<()> {
+    if config_schema {
+        return tool_config_schema(&tool, json_output, concise, no_header).await;
+    }
+
+    // Registry tools that aren't installed are described from their published manifest
+    if is_uninstalled_registry_ref(&tool).await {
+        return preview_registry_tool(
+            tool,
+            methods,
+            input_only,
+            output_only,
+            description_only,
+            show_tools,
+            show_prompts,
+            show_all,
+            json_output,
+            concise,
+            no_header,
+            level,
+            true,
+        )
+        .await;
+    }
+
     // Prepare the tool (resolve, load config, prompt, save)
     let prepared = prepare_tool(
         &tool,
         PrepareToolOptions {
             config: &config,
             config_file: config_file.as_deref(),
+            env_file: None,
+            cwd: cwd.as_deref(),
             no_save,
             yes,
         },
@@ -94,7 +154,9 @@ pub async fn tool_info(
                 println!("  ·   {}", "}".dimmed());
                 println!("  · {}", "}".dimmed());
             }
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_NOT_FOUND,
+            });
         }
         Err(ToolError::OAuthNotConfigured) | Err(ToolError::AuthRequired { tool_ref: _ }) => {
             if let Some(s) = spinner {
@@ -105,7 +167,7 @@ pub async fn tool_info(
                 "  · Could not initialize credential storage. Check that {} is writable.",
                 "~/.tool/secrets/".bright_cyan()
             );
-            std::process::exit(1);
+            return Err(ToolError::Reported { code: EXIT_AUTH });
         }
         Err(e) => {
             if let Some(s) = spinner {
@@ -137,7 +199,9 @@ pub async fn tool_info(
                         method_name.bright_white()
                     );
                 }
-                std::process::exit(1);
+                return Err(ToolError::Reported {
+                    code: EXIT_NOT_FOUND,
+                });
             }
         }
 
@@ -798,11 +862,7 @@ fn output_methods_json(tools: &[&Tool], concise: bool) -> ToolResult<()> {
         map.insert(tool.name.to_string(), value);
     }
     let json = serde_json::Value::Object(map);
-    if concise {
-        println!("{}", serde_json::to_string(&json)?);
-    } else {
-        println!("{}", serde_json::to_string_pretty(&json)?);
-    }
+    print_json(&json, concise)?;
     Ok(())
 }
 
@@ -1087,3 +1147,218 @@ fn output_methods_normal(
     }
     println!();
 }
+
+/// Whether `tool` is a `namespace/name` reference that is neither installed nor a local path.
+async fn is_uninstalled_registry_ref(tool: &str) -> bool {
+    if Path::new(tool).exists() {
+        return false;
+    }
+
+    let Ok(plugin_ref) = PluginRef::parse(tool) else {
+        return false;
+    };
+    if plugin_ref.namespace().is_none() {
+        return false;
+    }
+
+    matches!(
+        FilePluginResolver::default().resolve_tool(tool).await,
+        Ok(None)
+    )
+}
+
+/// Print a tool's user_config and system_config schema without starting it.
+async fn tool_config_schema(
+    tool: &str,
+    json_output: bool,
+    concise: bool,
+    no_header: bool,
+) -> ToolResult<()> {
+    let manifest = load_schema_manifest(tool).await?;
+    let output = ConfigSchemaOutput {
+        user_config: manifest.user_config.unwrap_or_default(),
+        system_config: manifest.system_config.unwrap_or_default(),
+    };
+
+    if json_output {
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
+    let user_rows: Vec<ConfigSchemaRow> = output
+        .user_config
+        .iter()
+        .map(|(key, field)| ConfigSchemaRow {
+            key: key.clone(),
+            field_type: serde_name(&field.field_type),
+            required: field.required.unwrap_or(false),
+            default: field.default.clone(),
+            sensitive: field.sensitive.unwrap_or(false),
+            description: field.description.clone(),
+        })
+        .collect();
+    let system_rows: Vec<ConfigSchemaRow> = output
+        .system_config
+        .iter()
+        .map(|(key, field)| ConfigSchemaRow {
+            key: key.clone(),
+            field_type: serde_name(&field.field_type),
+            required: field.required.unwrap_or(false),
+            default: field.default.clone(),
+            sensitive: false,
+            description: field.description.clone(),
+        })
+        .collect();
+
+    if concise {
+        if !no_header {
+            println!("#scope\tkey\ttype\trequired\tdefault\tsensitive");
+        }
+        for (scope, rows) in [("user", &user_rows), ("system", &system_rows)] {
+            for row in rows {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    scope,
+                    row.key,
+                    row.field_type,
+                    row.required,
+                    row.default
+                        .as_ref()
+                        .map(|d| d.to_string())
+                        .unwrap_or_default(),
+                    row.sensitive
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    if user_rows.is_empty() && system_rows.is_empty() {
+        println!("  {} {} takes no configuration", "·".dimmed(), tool);
+        return Ok(());
+    }
+
+    if !user_rows.is_empty() {
+        println!();
+        println!(
+            "  {} {}",
+            "User config".bold(),
+            "(set with -k KEY=VALUE)".dimmed()
+        );
+        print_config_rows(&user_rows, true);
+    }
+    if !system_rows.is_empty() {
+        println!();
+        println!(
+            "  {} {}",
+            "System config".bold(),
+            "(allocated by the host)".dimmed()
+        );
+        print_config_rows(&system_rows, false);
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Load the manifest for `tool --config-schema`, from the registry if it isn't installed.
+async fn load_schema_manifest(tool: &str) -> ToolResult<McpbManifest> {
+    if !is_uninstalled_registry_ref(tool).await {
+        let resolved = resolve_tool_path(tool).await?;
+        return Ok(load_tool_from_path(&resolved.path)?.template);
+    }
+
+    let plugin_ref = PluginRef::parse(tool)?;
+    let namespace = plugin_ref.namespace().unwrap_or_default();
+    let name = plugin_ref.name();
+
+    let client = RegistryClient::new();
+    let version_info = match (plugin_ref.tag(), plugin_ref.version()) {
+        (Some(tag), _) => {
+            let version = client.resolve_tag(namespace, name, tag).await?;
+            client.get_version(namespace, name, &version).await?
+        }
+        (None, Some(version)) => {
+            client
+                .get_version(namespace, name, &version.to_string())
+                .await?
+        }
+        (None, None) => client
+            .get_artifact(namespace, name)
+            .await?
+            .latest_version
+            .ok_or_else(|| ToolError::NotFound {
+                kind: "version".to_string(),
+                reference: format!("{}/{}", namespace, name),
+            })?,
+    };
+
+    let manifest = version_info.manifest.ok_or_else(|| {
+        ToolError::Generic("Registry did not return manifest data for this version".into())
+    })?;
+    Ok(serde_json::from_value(manifest)?)
+}
+
+/// Print config schema rows as an aligned table.
+fn print_config_rows(rows: &[ConfigSchemaRow], show_sensitive: bool) {
+    let key_width = rows.iter().map(|r| r.key.len()).max().unwrap_or(0).max(3);
+    let type_width = rows
+        .iter()
+        .map(|r| r.field_type.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let default_width = rows
+        .iter()
+        .map(|r| r.default.as_ref().map_or(1, |d| d.to_string().len()))
+        .max()
+        .unwrap_or(0)
+        .max(7);
+
+    println!(
+        "  {}",
+        format!(
+            "{:<key_width$}  {:<type_width$}  {:<8}  {:<default_width$}  {}",
+            "KEY",
+            "TYPE",
+            "REQUIRED",
+            "DEFAULT",
+            if show_sensitive {
+                "SENSITIVE  DESCRIPTION"
+            } else {
+                "DESCRIPTION"
+            }
+        )
+        .dimmed()
+    );
+    for row in rows {
+        let default = row
+            .default
+            .as_ref()
+            .map_or("-".to_string(), |d| d.to_string());
+        let sensitive = if !show_sensitive {
+            String::new()
+        } else if row.sensitive {
+            format!("{:<9}  ", "yes")
+        } else {
+            format!("{:<9}  ", "no")
+        };
+        println!(
+            "  {:<key_width$}  {:<type_width$}  {:<8}  {:<default_width$}  {}{}",
+            row.key.bright_cyan(),
+            row.field_type,
+            if row.required { "yes" } else { "no" },
+            default,
+            sensitive,
+            row.description.as_deref().unwrap_or("").dimmed()
+        );
+    }
+}
+
+/// Serialized name of a config field type (e.g. `string`, `data_directory`).
+fn serde_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
@@ -4,22 +4,105 @@ use crate::constants::MCPB_MANIFEST_FILE;
 use crate::error::{ToolError, ToolResult};
 use crate::mcpb::{
     InitMode, McpbAuthor, McpbManifest, McpbMcpConfig, McpbServer, McpbServerType, McpbTransport,
-    NodePackageManager, OAuthConfig, PackageManager, PythonPackageManager,
+    McpbUserConfigField, McpbUserConfigType, NodePackageManager, OAuthConfig, PackageManager,
+    PythonPackageManager,
 };
+use crate::output::print_json;
 use crate::scaffold::{
-    mcpbignore_template, node_gitignore_template, node_scaffold, python_gitignore_template,
-    python_scaffold, rust_gitignore_template, rust_mcpbignore_template, rust_scaffold,
+    docker_scaffold, mcpbignore_template, node_gitignore_template, node_scaffold,
+    python_gitignore_template, python_scaffold, rust_gitignore_template, rust_mcpbignore_template,
+    rust_scaffold, typescript_mcpbignore_template, typescript_scaffold,
 };
+use crate::security::Redactor;
 use crate::validate::validators::fields::is_valid_package_name;
 use colored::Colorize;
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Values accepted by `tool init -t`, the server type each scaffolds, and what it creates.
+const SCAFFOLD_TYPES: [(&str, McpbServerType, &str); 4] = [
+    (
+        "node",
+        McpbServerType::Node,
+        "Node.js server using the MCP TypeScript SDK",
+    ),
+    (
+        "python",
+        McpbServerType::Python,
+        "Python server using the MCP Python SDK",
+    ),
+    (
+        "rust",
+        McpbServerType::Binary,
+        "Rust server built with rmcp, packed as a binary",
+    ),
+    (
+        "binary",
+        McpbServerType::Binary,
+        "Manifest for a pre-built executable (no source scaffold)",
+    ),
+];
+
+/// User config key holding the token for `--auth bearer`.
+const BEARER_TOKEN_KEY: &str = "api_key";
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// One row of `tool init --list-types`.
+#[derive(Debug, Serialize)]
+struct InitTypeOutput {
+    /// Value for `-t`.
+    #[serde(rename = "type")]
+    server_type: &'static str,
+
+    /// Server transport.
+    transport: String,
+
+    /// Package manager for `--pm` (`None` for binary servers).
+    package_manager: Option<String>,
+
+    /// Whether `tool init -t <type>` picks this package manager when `--pm` is omitted.
+    default: bool,
+
+    /// What gets scaffolded.
+    description: &'static str,
+
+    /// Command that scaffolds this combination.
+    command: String,
+}
+
+/// How an HTTP reference server authenticates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceAuth {
+    /// No authentication.
+    None,
+    /// A bearer token from user config, sent in the `Authorization` header.
+    Bearer,
+    /// OAuth, discovered from the server unless endpoints are given.
+    OAuth,
+}
+
+/// A reference-mode value that `tool init` asks for interactively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReferencePrompt {
+    /// HTTP server URL.
+    Url,
+    /// How the HTTP server authenticates.
+    Auth,
+    /// Stdio command.
+    Command,
+    /// Stdio command arguments.
+    Args,
+}
+
 /// Options for mcp_config passed via CLI arguments.
 #[derive(Debug, Clone, Default)]
 pub struct McpConfigOptions {
@@ -33,6 +116,8 @@ pub struct McpConfigOptions {
     pub url: Option<String>,
     /// HTTP headers as KEY=VALUE pairs.
     pub headers: Vec<String>,
+    /// Authentication type (inferred from the OAuth options when unset).
+    pub auth: Option<ReferenceAuth>,
     /// OAuth client ID.
     pub oauth_client_id: Option<String>,
     /// OAuth authorization URL.
@@ -43,6 +128,13 @@ pub struct McpConfigOptions {
     pub oauth_scopes: Option<String>,
 }
 
+/// Writes scaffold files into a directory, remembering which already existed.
+struct ScaffoldWriter {
+    dir: PathBuf,
+    written: Vec<PathBuf>,
+    replaced: Vec<PathBuf>,
+}
+
 impl McpConfigOptions {
     /// Check if any mcp_config options are specified.
     pub fn has_any(&self) -> bool {
@@ -51,10 +143,8 @@ impl McpConfigOptions {
             || !self.env.is_empty()
             || self.url.is_some()
             || !self.headers.is_empty()
-            || self.oauth_client_id.is_some()
-            || self.oauth_authorization_url.is_some()
-            || self.oauth_token_url.is_some()
-            || self.oauth_scopes.is_some()
+            || self.auth.is_some()
+            || self.has_oauth()
     }
 
     /// Check if this implies reference mode.
@@ -64,14 +154,66 @@ impl McpConfigOptions {
 
     /// Check if this implies HTTP transport.
     pub fn implies_http(&self) -> bool {
-        self.url.is_some()
-            || !self.headers.is_empty()
-            || self.oauth_client_id.is_some()
+        self.url.is_some() || !self.headers.is_empty() || self.auth.is_some() || self.has_oauth()
+    }
+
+    /// Check if any OAuth options are specified.
+    fn has_oauth(&self) -> bool {
+        self.oauth_client_id.is_some()
             || self.oauth_authorization_url.is_some()
             || self.oauth_token_url.is_some()
             || self.oauth_scopes.is_some()
     }
 
+    /// The authentication type, inferring OAuth from its options.
+    pub fn resolved_auth(&self) -> ReferenceAuth {
+        match self.auth {
+            Some(auth) => auth,
+            None if self.has_oauth() => ReferenceAuth::OAuth,
+            None => ReferenceAuth::None,
+        }
+    }
+
+    /// Reject malformed URLs and OAuth options that don't match the auth type.
+    pub fn validate(&self) -> ToolResult<()> {
+        if let Some(url) = &self.url {
+            check_http_url("server URL", url).map_err(ToolError::Generic)?;
+        }
+        if self.has_oauth() && self.resolved_auth() != ReferenceAuth::OAuth {
+            return Err(ToolError::Generic(
+                "--oauth-* options require --auth oauth".into(),
+            ));
+        }
+        if let Some(url) = &self.oauth_authorization_url {
+            check_http_url("OAuth authorization URL", url).map_err(ToolError::Generic)?;
+        }
+        if let Some(url) = &self.oauth_token_url {
+            check_http_url("OAuth token URL", url).map_err(ToolError::Generic)?;
+        }
+        Ok(())
+    }
+
+    /// User config fields the auth type needs, if any.
+    pub fn to_user_config(&self) -> Option<BTreeMap<String, McpbUserConfigField>> {
+        if self.resolved_auth() != ReferenceAuth::Bearer {
+            return None;
+        }
+
+        let field = McpbUserConfigField {
+            field_type: McpbUserConfigType::String,
+            title: "API key".into(),
+            description: Some("Bearer token sent in the Authorization header".into()),
+            required: Some(true),
+            default: None,
+            multiple: None,
+            sensitive: Some(true),
+            enum_values: None,
+            min: None,
+            max: None,
+        };
+        Some(BTreeMap::from([(BEARER_TOKEN_KEY.to_string(), field)]))
+    }
+
     /// Parse env/headers from KEY=VALUE format into a BTreeMap.
     fn parse_key_values(pairs: &[String]) -> BTreeMap<String, String> {
         pairs
@@ -96,13 +238,15 @@ impl McpConfigOptions {
         }
 
         let env = Self::parse_key_values(&self.env);
-        let headers = Self::parse_key_values(&self.headers);
+        let mut headers = Self::parse_key_values(&self.headers);
+        if self.resolved_auth() == ReferenceAuth::Bearer {
+            headers
+                .entry("Authorization".into())
+                .or_insert_with(|| format!("Bearer ${{user_config.{}}}", BEARER_TOKEN_KEY));
+        }
 
-        let oauth_config = if self.oauth_client_id.is_some()
-            || self.oauth_authorization_url.is_some()
-            || self.oauth_token_url.is_some()
-            || self.oauth_scopes.is_some()
-        {
+        // `--auth oauth` alone still marks the server as OAuth, with discovered endpoints
+        let oauth_config = if self.resolved_auth() == ReferenceAuth::OAuth {
             Some(OAuthConfig {
                 client_id: self.oauth_client_id.clone(),
                 authorization_url: self.oauth_authorization_url.clone(),
@@ -128,6 +272,31 @@ impl McpConfigOptions {
     }
 }
 
+impl ScaffoldWriter {
+    fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            written: Vec::new(),
+            replaced: Vec::new(),
+        }
+    }
+
+    /// Write a file relative to the scaffold directory, creating parent directories.
+    fn write(&mut self, relative: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> ToolResult<()> {
+        let relative = relative.as_ref();
+        let path = self.dir.join(relative);
+        if path.exists() {
+            self.replaced.push(relative.to_path_buf());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, contents)?;
+        self.written.push(relative.to_path_buf());
+        Ok(())
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -149,12 +318,18 @@ pub async fn init_mcpb(
     transport: Option<String>,
     force: bool,
     verify: bool,
+    interactive: bool,
+    docker: bool,
+    typescript: bool,
+    template: Option<String>,
+    git: bool,
     // mcp_config options
     command: Option<String>,
     args: Option<String>,
     env: Vec<String>,
     url: Option<String>,
     headers: Vec<String>,
+    auth: Option<String>,
     oauth_client_id: Option<String>,
     oauth_authorization_url: Option<String>,
     oauth_token_url: Option<String>,
@@ -165,6 +340,18 @@ pub async fn init_mcpb(
         .map(|s| s.split_whitespace().map(|x| x.to_string()).collect())
         .unwrap_or_default();
 
+    let auth = auth
+        .map(|a| match a.to_lowercase().as_str() {
+            "none" => Ok(ReferenceAuth::None),
+            "bearer" => Ok(ReferenceAuth::Bearer),
+            "oauth" => Ok(ReferenceAuth::OAuth),
+            _ => Err(ToolError::Generic(format!(
+                "Unknown auth type '{}'. Use none, bearer, or oauth.",
+                a
+            ))),
+        })
+        .transpose()?;
+
     // Build mcp_config options struct
     let mcp_opts = McpConfigOptions {
         command,
@@ -172,6 +359,7 @@ pub async fn init_mcpb(
         env,
         url,
         headers,
+        auth,
         oauth_client_id,
         oauth_authorization_url,
         oauth_token_url,
@@ -188,6 +376,7 @@ pub async fn init_mcpb(
             license,
             http,
             yes,
+            false,
             force,
             mcp_opts,
         )
@@ -225,9 +414,37 @@ pub async fn init_mcpb(
         ));
     }
 
+    // User-supplied template replaces the built-in scaffolds
+    if let Some(template) = template {
+        return init_template(
+            &template,
+            &target_dir,
+            name,
+            path.as_deref(),
+            description,
+            author,
+            license,
+            force,
+            git,
+        );
+    }
+
     // Non-empty directory -> migration flow (detection-based)
     // Handles both: new migration and re-migration with --force
     if !is_empty {
+        if docker || typescript || git {
+            let flag = if docker {
+                "--docker"
+            } else if typescript {
+                "--typescript"
+            } else {
+                "--git"
+            };
+            return Err(ToolError::Generic(format!(
+                "{} is only supported when scaffolding a new project.",
+                flag
+            )));
+        }
         return init_migrate(
             target_dir,
             name,
@@ -237,6 +454,7 @@ pub async fn init_mcpb(
             force,
             path.as_deref(),
             verify,
+            interactive,
         )
         .await;
     }
@@ -258,25 +476,28 @@ pub async fn init_mcpb(
         .map(|s| s.to_string());
 
     // Parse CLI flags into individual components
-    let parsed_server_type = server_type
-        .as_ref()
-        .and_then(|t| match t.to_lowercase().as_str() {
-            "node" => Some(McpbServerType::Node),
-            "python" => Some(McpbServerType::Python),
-            "rust" | "binary" => Some(McpbServerType::Binary),
-            _ => None,
-        });
+    let parsed_server_type = server_type.as_ref().and_then(|t| {
+        let t = t.to_lowercase();
+        SCAFFOLD_TYPES
+            .iter()
+            .find(|(name, _, _)| *name == t)
+            .map(|(_, server_type, _)| *server_type)
+    });
 
-    let parsed_transport = if http {
+    // Containers are reached over HTTP
+    let parsed_transport = if http || docker {
         Some(McpbTransport::Http)
     } else {
         None
     };
 
     let parsed_pm = package_manager.as_deref().and_then(parse_package_manager);
+    if let (Some(server_type), Some(pm)) = (&server_type, parsed_pm) {
+        check_package_manager(server_type, pm)?;
+    }
 
     // Get final values based on -y flag
-    let (pkg_name, mode, is_rust, description, license, author) = if yes {
+    let (pkg_name, mode, is_rust, typescript, description, license, author) = if yes {
         // Non-interactive: use CLI args or defaults
         let pkg_name = resolved_name.or(default_name.clone()).ok_or_else(|| {
             ToolError::Generic("Could not determine package name. Use --name.".into())
@@ -286,7 +507,15 @@ pub async fn init_mcpb(
         let is_rust = server_type
             .as_ref()
             .is_some_and(|t| t.to_lowercase() == "rust");
-        (pkg_name, mode, is_rust, description, license, author)
+        (
+            pkg_name,
+            mode,
+            is_rust,
+            typescript,
+            description,
+            license,
+            author,
+        )
     } else {
         // Interactive: prompt for values, prefill with CLI args
         let default_author = get_git_author_name();
@@ -296,6 +525,7 @@ pub async fn init_mcpb(
             server_type: parsed_server_type,
             transport: parsed_transport,
             package_manager: parsed_pm,
+            typescript,
             description,
             license,
             author,
@@ -311,11 +541,12 @@ pub async fn init_mcpb(
         if result.mode.is_reference()
             && (result.command.is_some() || result.url.is_some() || !result.args.is_empty())
         {
+            // Keep the flags given on the command line, e.g. `--auth oauth`
             let mcp_opts = McpConfigOptions {
                 command: result.command,
                 args: result.args,
                 url: result.url,
-                ..Default::default()
+                ..mcp_opts
             };
             return init_reference(
                 path,
@@ -324,7 +555,8 @@ pub async fn init_mcpb(
                 result.author,
                 result.license,
                 result.mode.is_http(),
-                true, // yes - we already have all the values from prompts
+                false,
+                true, // arguments were already asked for, an empty answer included
                 force,
                 mcp_opts,
             )
@@ -335,6 +567,7 @@ pub async fn init_mcpb(
             result.name,
             result.mode,
             is_rust,
+            result.typescript,
             result.description,
             result.license,
             result.author,
@@ -356,6 +589,27 @@ pub async fn init_mcpb(
         McpbManifest::from_mode(&mode).with_name(&pkg_name)
     };
 
+    // TypeScript only applies to Node.js bundles
+    let typescript = typescript && !is_rust && mode.server_type() == Some(McpbServerType::Node);
+    if typescript {
+        let pm = match mode.package_manager() {
+            Some(PackageManager::Node(pm)) => pm,
+            _ => NodePackageManager::default(),
+        };
+        manifest = manifest.with_typescript(pm);
+    }
+
+    // Container scaffolding needs a language scaffold to build from
+    let docker_type = if is_rust {
+        Some(McpbServerType::Binary)
+    } else {
+        mode.server_type().filter(|t| *t != McpbServerType::Binary)
+    };
+    let docker = docker && docker_type.is_some();
+    if docker {
+        manifest = manifest.with_container(&pkg_name);
+    }
+
     if let Some(desc) = description {
         manifest = manifest.with_description(desc);
     }
@@ -365,32 +619,36 @@ pub async fn init_mcpb(
     }
 
     // Try to get author from --author flag or git config
+    let git_author = get_git_author();
     if let Some(author_name) = author {
         manifest = manifest.with_author(McpbAuthor::new(author_name));
-    } else if let Some(git_author) = get_git_author() {
+    } else if let Some(git_author) = git_author.clone() {
         manifest = manifest.with_author(git_author);
     }
 
+    if manifest_exists {
+        backup_manifest(&manifest_path)?;
+    }
+    let mut writer = ScaffoldWriter::new(&target_dir);
+
     // Write manifest.json
     let manifest_json = serde_json::to_string_pretty(&manifest)?;
-    std::fs::write(&manifest_path, &manifest_json)?;
+    writer.write(MCPB_MANIFEST_FILE, &manifest_json)?;
 
     // Write .mcpbignore
-    let mcpbignore_path = target_dir.join(".mcpbignore");
     let mcpbignore_content: String = if is_rust {
         rust_mcpbignore_template(&pkg_name)
+    } else if typescript {
+        typescript_mcpbignore_template()
     } else {
         mcpbignore_template().to_string()
     };
-    std::fs::write(&mcpbignore_path, &mcpbignore_content)?;
+    writer.write(".mcpbignore", &mcpbignore_content)?;
 
     // Write README.md
-    let readme_path = target_dir.join("README.md");
-    let readme_content = format!("# {}\n", pkg_name);
-    std::fs::write(&readme_path, readme_content)?;
+    writer.write("README.md", format!("# {}\n", pkg_name))?;
 
     // Write .gitignore (type-specific) - only for bundle modes
-    let gitignore_path = target_dir.join(".gitignore");
     let gitignore_content = if mode.is_reference() {
         ""
     } else if is_rust {
@@ -403,7 +661,7 @@ pub async fn init_mcpb(
         }
     };
     if !gitignore_content.is_empty() {
-        std::fs::write(&gitignore_path, gitignore_content)?;
+        writer.write(".gitignore", gitignore_content)?;
     }
 
     // Write scaffold files for bundle mode only
@@ -412,44 +670,98 @@ pub async fn init_mcpb(
 
         if is_rust {
             let scaffold = rust_scaffold(&pkg_name, transport);
-            let src_dir = target_dir.join("src");
-            std::fs::create_dir_all(&src_dir)?;
-            std::fs::write(src_dir.join("main.rs"), &scaffold.main_rs)?;
-            std::fs::write(src_dir.join("lib.rs"), &scaffold.lib_rs)?;
-            std::fs::write(target_dir.join("Cargo.toml"), &scaffold.cargo_toml)?;
+            writer.write("src/main.rs", &scaffold.main_rs)?;
+            writer.write("src/lib.rs", &scaffold.lib_rs)?;
+            writer.write("Cargo.toml", &scaffold.cargo_toml)?;
         } else if let Some(server_type) = mode.server_type() {
             match server_type {
+                McpbServerType::Node if typescript => {
+                    let scaffold = typescript_scaffold(&pkg_name, transport);
+                    writer.write("src/index.ts", &scaffold.index_ts)?;
+                    writer.write("tsconfig.json", &scaffold.tsconfig_json)?;
+                    writer.write("package.json", &scaffold.package_json)?;
+                }
                 McpbServerType::Node => {
                     let scaffold = node_scaffold(&pkg_name, transport);
-                    let server_dir = target_dir.join("server");
-                    std::fs::create_dir_all(&server_dir)?;
-                    std::fs::write(server_dir.join("index.js"), &scaffold.index_js)?;
-                    std::fs::write(target_dir.join("package.json"), &scaffold.package_json)?;
+                    writer.write("server/index.js", &scaffold.index_js)?;
+                    writer.write("package.json", &scaffold.package_json)?;
                 }
                 McpbServerType::Python => {
                     let pkg_manager = mode
                         .python_package_manager()
                         .unwrap_or(PythonPackageManager::default());
                     let scaffold = python_scaffold(&pkg_name, transport, pkg_manager);
-                    let server_dir = target_dir.join("server");
-                    std::fs::create_dir_all(&server_dir)?;
-                    std::fs::write(server_dir.join("main.py"), &scaffold.main_py)?;
-                    std::fs::write(
-                        target_dir.join(scaffold.project_file_name),
-                        &scaffold.project_file,
-                    )?;
+                    writer.write("server/main.py", &scaffold.main_py)?;
+                    writer.write(scaffold.project_file_name, &scaffold.project_file)?;
                 }
                 McpbServerType::Binary => {}
             }
         }
+
+        if docker && let Some(server_type) = docker_type {
+            let scaffold = docker_scaffold(&pkg_name, server_type, transport);
+            writer.write("Dockerfile", &scaffold.dockerfile)?;
+            writer.write(".dockerignore", &scaffold.dockerignore)?;
+        }
     }
 
     // Print success message
-    print_init_success(&pkg_name, &mode, is_rust, path.as_deref());
+    print_init_success(
+        &pkg_name,
+        &mode,
+        is_rust,
+        typescript,
+        docker,
+        path.as_deref(),
+    );
+    print_replaced(&writer.replaced);
+
+    if git {
+        init_git_repo(&target_dir, &writer.written, git_author.as_ref());
+    }
 
     Ok(())
 }
 
+/// Convert an existing MCP server project with the interactive wizard (`tool migrate -i`).
+pub async fn migrate_project_interactive(paths: Vec<String>) -> ToolResult<()> {
+    let path = match paths.as_slice() {
+        [] => None,
+        [path] => Some(path.clone()),
+        _ => {
+            return Err(ToolError::Generic(
+                "--interactive migrates one project at a time".into(),
+            ));
+        }
+    };
+
+    let target_dir = std::env::current_dir()?.join(path.as_deref().unwrap_or("."));
+    if !target_dir.is_dir() || is_dir_empty(&target_dir)? {
+        return Err(ToolError::Generic(format!(
+            "No project to migrate in {}. Use `tool init` to scaffold a new one.",
+            target_dir.display()
+        )));
+    }
+    if target_dir.join(MCPB_MANIFEST_FILE).exists() {
+        return Err(ToolError::Generic(
+            "manifest.json already exists. Use `tool init -i --force` to re-migrate.".into(),
+        ));
+    }
+
+    init_migrate(
+        target_dir,
+        None,
+        None,
+        None,
+        false,
+        false,
+        path.as_deref(),
+        false,
+        true,
+    )
+    .await
+}
+
 /// Build InitMode for non-interactive mode.
 fn build_init_mode(
     reference: bool,
@@ -479,6 +791,145 @@ fn build_init_mode(
     }
 }
 
+/// List the server type, transport, and package manager combinations `tool init` scaffolds.
+pub fn list_init_types(json: bool, concise: bool, no_header: bool) -> ToolResult<()> {
+    let rows = init_types();
+
+    if json {
+        print_json(&rows, concise)?;
+        return Ok(());
+    }
+
+    if concise {
+        if !no_header {
+            println!("#type\ttransport\tpackage_manager\tdefault\tcommand");
+        }
+        for row in &rows {
+            println!(
+                "{}\t{}\t{}\t{}\t{}",
+                row.server_type,
+                row.transport,
+                row.package_manager.as_deref().unwrap_or("-"),
+                row.default,
+                row.command
+            );
+        }
+        return Ok(());
+    }
+
+    for (name, _, description) in SCAFFOLD_TYPES {
+        println!("\n  {} {}", name.bold(), description.dimmed());
+        for row in rows.iter().filter(|r| r.server_type == name) {
+            let pm = match &row.package_manager {
+                Some(pm) if row.default => format!("{} (default)", pm),
+                Some(pm) => pm.clone(),
+                None => "-".into(),
+            };
+            println!(
+                "    · {:<6} {:<15} {}",
+                row.transport,
+                pm,
+                row.command.bright_cyan()
+            );
+        }
+    }
+    println!(
+        "\n  Add {} to write a manifest for an existing server without scaffolding.",
+        "--reference".bold()
+    );
+
+    Ok(())
+}
+
+/// Every combination `tool init` can scaffold, with defaults resolved by [`build_init_mode`].
+fn init_types() -> Vec<InitTypeOutput> {
+    let mut rows = Vec::new();
+    for (name, server_type, description) in SCAFFOLD_TYPES {
+        let package_managers = package_managers_for(server_type);
+
+        for transport in McpbTransport::ALL {
+            let default_pm =
+                build_init_mode(false, Some(server_type), Some(transport), None).package_manager();
+            let http = if transport == McpbTransport::Http {
+                " --http"
+            } else {
+                ""
+            };
+
+            if package_managers.is_empty() {
+                rows.push(InitTypeOutput {
+                    server_type: name,
+                    transport: transport.to_string(),
+                    package_manager: None,
+                    default: true,
+                    description,
+                    command: format!("tool init my-tool -t {}{}", name, http),
+                });
+            }
+            for pm in &package_managers {
+                let default = default_pm == Some(*pm);
+                let pm_flag = if default {
+                    String::new()
+                } else {
+                    format!(" --pm {}", pm)
+                };
+                rows.push(InitTypeOutput {
+                    server_type: name,
+                    transport: transport.to_string(),
+                    package_manager: Some(pm.to_string()),
+                    default,
+                    description,
+                    command: format!("tool init my-tool -t {}{}{}", name, http, pm_flag),
+                });
+            }
+        }
+    }
+    rows
+}
+
+/// Package managers that can build a server type.
+fn package_managers_for(server_type: McpbServerType) -> Vec<PackageManager> {
+    PackageManager::all()
+        .filter(|pm| pm.server_type() == server_type)
+        .collect()
+}
+
+/// Reject a `--pm` that can't build the `-t` server type, listing the valid pairings.
+fn check_package_manager(server_type: &str, package_manager: PackageManager) -> ToolResult<()> {
+    let server_type = server_type.to_lowercase();
+    let Some((_, parsed, _)) = SCAFFOLD_TYPES
+        .iter()
+        .find(|(name, _, _)| *name == server_type)
+    else {
+        return Ok(());
+    };
+    if package_manager.server_type() == *parsed {
+        return Ok(());
+    }
+
+    let pairings: Vec<String> = SCAFFOLD_TYPES
+        .iter()
+        .map(|(name, server_type, _)| {
+            let pms: Vec<String> = package_managers_for(*server_type)
+                .iter()
+                .map(|pm| pm.to_string())
+                .collect();
+            if pms.is_empty() {
+                format!("{} (no --pm)", name)
+            } else {
+                format!("{} ({})", name, pms.join(", "))
+            }
+        })
+        .collect();
+
+    Err(ToolError::Generic(format!(
+        "Package manager '{}' can't be used with server type '{}'. Valid pairings: {}",
+        package_manager,
+        server_type,
+        pairings.join(", ")
+    )))
+}
+
 /// Parse a package manager string.
 pub(super) fn parse_package_manager(pm: &str) -> Option<PackageManager> {
     match pm.to_lowercase().as_str() {
@@ -493,6 +944,104 @@ pub(super) fn parse_package_manager(pm: &str) -> Option<PackageManager> {
     }
 }
 
+/// Copy an existing manifest to `manifest.json.bak` before it is overwritten.
+fn backup_manifest(manifest_path: &Path) -> ToolResult<PathBuf> {
+    let backup = manifest_path.with_file_name(format!("{}.bak", MCPB_MANIFEST_FILE));
+    std::fs::copy(manifest_path, &backup)?;
+    println!(
+        "  {} Backed up {} to {}\n",
+        "!".bright_yellow(),
+        MCPB_MANIFEST_FILE,
+        backup.file_name().unwrap_or_default().to_string_lossy()
+    );
+    Ok(backup)
+}
+
+/// List existing files that were overwritten by `--force`.
+fn print_replaced(replaced: &[PathBuf]) {
+    if replaced.is_empty() {
+        return;
+    }
+
+    println!("\n  {} Replaced existing files:", "!".bright_yellow());
+    for path in replaced {
+        println!("  · {}", path.display());
+    }
+}
+
+/// Initialize a git repository in `dir` and commit the scaffolded files.
+///
+/// Skipped when git is unavailable or `dir` is already inside a work tree.
+fn init_git_repo(dir: &Path, files: &[PathBuf], author: Option<&McpbAuthor>) {
+    let git = |args: &[&str]| {
+        let mut cmd = Command::new("git");
+        cmd.current_dir(dir);
+        // Commit as the resolved author even when git config is incomplete
+        if let Some(author) = author {
+            cmd.arg("-c").arg(format!("user.name={}", author.name));
+            if let Some(email) = &author.email {
+                cmd.arg("-c").arg(format!("user.email={}", email));
+            }
+        }
+        cmd.args(args).output()
+    };
+
+    let inside_repo = match git(&["rev-parse", "--is-inside-work-tree"]) {
+        Ok(output) => output.status.success(),
+        Err(_) => {
+            println!(
+                "\n  {} Skipped git init: git is not installed",
+                "!".bright_yellow()
+            );
+            return;
+        }
+    };
+    if inside_repo {
+        println!(
+            "\n  {} Skipped git init: already inside a git repository",
+            "!".bright_yellow()
+        );
+        return;
+    }
+
+    let mut add_args = vec!["add", "--"];
+    let file_args: Vec<String> = files
+        .iter()
+        .map(|f| f.to_string_lossy().into_owned())
+        .collect();
+    add_args.extend(file_args.iter().map(String::as_str));
+
+    let steps: [(&str, &[&str]); 3] = [
+        ("init", &["init", "--quiet"]),
+        ("add", &add_args),
+        ("commit", &["commit", "--quiet", "-m", "Initial commit"]),
+    ];
+    for (step, args) in steps {
+        match git(args) {
+            Ok(output) if output.status.success() => {}
+            Ok(output) => {
+                println!(
+                    "\n  {} git {} failed: {}",
+                    "!".bright_yellow(),
+                    step,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                return;
+            }
+            Err(e) => {
+                println!("\n  {} git {} failed: {}", "!".bright_yellow(), step, e);
+                return;
+            }
+        }
+    }
+
+    println!(
+        "\n  {} Initialized git repository with {} files in the initial commit",
+        "✓".bright_green(),
+        files.len()
+    );
+}
+
 /// Check if a directory is empty (ignoring hidden files like .git).
 fn is_dir_empty(dir: &Path) -> ToolResult<bool> {
     for entry in std::fs::read_dir(dir)? {
@@ -515,9 +1064,10 @@ async fn init_migrate(
     entry: Option<String>,
     transport: Option<String>,
     yes: bool,
-    _force: bool,
+    force: bool,
     display_path: Option<&str>,
     verify: bool,
+    interactive: bool,
 ) -> ToolResult<()> {
     use crate::detect::{
         DetectOptions, DetectorRegistry, EnvConfigType, EnvVar, parse_env_example,
@@ -581,7 +1131,7 @@ async fn init_migrate(
         .transpose()?;
 
     // Build options
-    let options = DetectOptions {
+    let mut options = DetectOptions {
         entry_point: entry.clone(),
         transport: transport_override,
         package_manager: None,
@@ -594,7 +1144,7 @@ async fn init_migrate(
         .details
         .entry_point
         .as_ref());
-    let transport_display = options
+    let mut transport_display = options
         .transport
         .or(detection.result.details.transport)
         .unwrap_or(McpbTransport::Stdio);
@@ -647,6 +1197,53 @@ async fn init_migrate(
         println!("  · {:<12} {}", "Build".dimmed(), build_cmd.dimmed());
     }
 
+    // Interactive wizard: let the user correct detected values before generating
+    if interactive {
+        if std::io::stdin().is_terminal() {
+            use crate::prompt::{MigratePrefill, prompt_migrate};
+
+            // Preview generation to pick up the detector's default package name
+            let detected_name = options.name.clone().or_else(|| {
+                registry
+                    .generate(
+                        detection.detector_name,
+                        &target_dir,
+                        &detection.result,
+                        &options,
+                    )
+                    .ok()
+                    .and_then(|scaffold| scaffold.manifest.name)
+            });
+
+            println!();
+            let answers = prompt_migrate(MigratePrefill {
+                name: detected_name,
+                server_type: detection.server_type,
+                entry_point: entry_display.cloned(),
+                transport: transport_display,
+                package_manager: detection.result.details.package_manager,
+            })?;
+
+            transport_display = answers.transport;
+            options = DetectOptions {
+                entry_point: answers.entry_point,
+                transport: Some(answers.transport),
+                package_manager: answers.package_manager,
+                name: Some(answers.name),
+            };
+        } else {
+            println!(
+                "\n  {} Not a terminal, using detected values",
+                "!".bright_yellow()
+            );
+        }
+    }
+    let entry_display = options.entry_point.as_ref().or(detection
+        .result
+        .details
+        .entry_point
+        .as_ref());
+
     // Verify: start server and send MCP initialize
     if verify {
         let verified =
@@ -679,6 +1276,10 @@ async fn init_migrate(
             println!();
             crate::prompt::init_theme();
 
+            // Don't echo example secrets in the selection hints
+            let mut redactor = Redactor::default();
+            redactor.add_env_vars(&env_vars);
+
             let options: Vec<(EnvVar, String, String)> = env_vars
                 .into_iter()
                 .map(|var| {
@@ -695,7 +1296,7 @@ async fn init_migrate(
                     let hint = var
                         .default
                         .as_ref()
-                        .map(|d| format!("default: {}", d))
+                        .map(|d| format!("default: {}", redactor.redact(d)))
                         .unwrap_or_default();
                     (var, label, hint)
                 })
@@ -803,22 +1404,17 @@ async fn init_migrate(
                     }
                 }
                 EnvConfigType::User => {
-                    let field_type = match var.value_type {
-                        crate::detect::EnvValueType::String => McpbUserConfigType::String,
-                        crate::detect::EnvValueType::Number => McpbUserConfigType::Number,
-                        crate::detect::EnvValueType::Boolean => McpbUserConfigType::Boolean,
-                        crate::detect::EnvValueType::Port => McpbUserConfigType::Number,
-                        crate::detect::EnvValueType::Hostname => McpbUserConfigType::String,
-                    };
+                    let field_type = var.value_type.user_config_type();
                     let default: Option<serde_json::Value> =
                         var.default.as_ref().map(|d| match var.value_type {
                             crate::detect::EnvValueType::Number
                             | crate::detect::EnvValueType::Port => d
                                 .parse::<i64>()
-                                .map(|n| serde_json::Value::Number(serde_json::Number::from(n)))
+                                .map(serde_json::Value::from)
+                                .or_else(|_| d.parse::<f64>().map(serde_json::Value::from))
                                 .unwrap_or_else(|_| serde_json::Value::String(d.clone())),
                             crate::detect::EnvValueType::Boolean => {
-                                serde_json::Value::Bool(d == "true")
+                                serde_json::Value::Bool(d.eq_ignore_ascii_case("true"))
                             }
                             _ => serde_json::Value::String(d.clone()),
                         });
@@ -850,12 +1446,15 @@ async fn init_migrate(
     }
 
     // Write manifest.json
+    if manifest_path.exists() {
+        backup_manifest(&manifest_path)?;
+    }
+    let mut writer = ScaffoldWriter::new(&target_dir);
     let manifest_json = serde_json::to_string_pretty(&scaffold.manifest)?;
-    std::fs::write(&manifest_path, &manifest_json)?;
+    writer.write(MCPB_MANIFEST_FILE, &manifest_json)?;
 
     // Write .mcpbignore
-    let mcpbignore_path = target_dir.join(".mcpbignore");
-    std::fs::write(&mcpbignore_path, &scaffold.mcpbignore)?;
+    writer.write(".mcpbignore", &scaffold.mcpbignore)?;
 
     let is_mcpbx = scaffold.manifest.requires_mcpbx();
     let format_display = if is_mcpbx {
@@ -869,6 +1468,9 @@ async fn init_migrate(
         format_display
     );
     println!("  {} Created .mcpbignore", "✓".bright_green());
+    if force {
+        print_replaced(&writer.replaced);
+    }
 
     // Print next steps
     print_migrate_next_steps(
@@ -964,7 +1566,14 @@ fn get_git_author() -> Option<McpbAuthor> {
 }
 
 /// Print the scaffolding success output.
-fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Option<&str>) {
+fn print_init_success(
+    name: &str,
+    mode: &InitMode,
+    is_rust: bool,
+    typescript: bool,
+    docker: bool,
+    dir_path: Option<&str>,
+) {
     let action = if mode.is_reference() {
         "Created"
     } else {
@@ -976,6 +1585,8 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
         "reference".to_string()
     } else if is_rust {
         "rust".to_string()
+    } else if typescript {
+        "node (typescript)".to_string()
     } else {
         mode.server_type()
             .map(|t| t.to_string())
@@ -998,6 +1609,9 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
             println!("  · {}      dist/{}", "Entry".dimmed(), name);
         } else {
             match mode.server_type() {
+                Some(McpbServerType::Node) if typescript => {
+                    println!("  · {}      dist/index.js", "Entry".dimmed());
+                }
                 Some(McpbServerType::Node) => {
                     println!("  · {}      server/index.js", "Entry".dimmed());
                 }
@@ -1025,6 +1639,7 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
     } else if is_rust {
         println!("  ├── manifest.json");
         println!("  ├── README.md");
+        print_docker_tree(docker);
         println!("  ├── Cargo.toml");
         println!("  ├── .gitignore");
         println!("  ├── .mcpbignore");
@@ -1033,9 +1648,21 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
         println!("      └── lib.rs");
     } else {
         match mode.server_type() {
+            Some(McpbServerType::Node) if typescript => {
+                println!("  ├── manifest.json");
+                println!("  ├── README.md");
+                print_docker_tree(docker);
+                println!("  ├── package.json");
+                println!("  ├── tsconfig.json");
+                println!("  ├── .gitignore");
+                println!("  ├── .mcpbignore");
+                println!("  └── src/");
+                println!("      └── index.ts");
+            }
             Some(McpbServerType::Node) => {
                 println!("  ├── manifest.json");
                 println!("  ├── README.md");
+                print_docker_tree(docker);
                 println!("  ├── package.json");
                 println!("  ├── .gitignore");
                 println!("  ├── .mcpbignore");
@@ -1049,6 +1676,7 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
                 };
                 println!("  ├── manifest.json");
                 println!("  ├── README.md");
+                print_docker_tree(docker);
                 println!("  ├── {}", project_file);
                 println!("  ├── .gitignore");
                 println!("  ├── .mcpbignore");
@@ -1124,10 +1752,15 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
             pack_hint.dimmed()
         );
     } else {
+        let build_hint = if typescript {
+            "# install dependencies and compile"
+        } else {
+            "# install dependencies"
+        };
         println!(
             "  {}. tool build              {}",
             step,
-            "# install dependencies".dimmed()
+            build_hint.dimmed()
         );
         println!(
             "  {}. tool info               {}",
@@ -1155,6 +1788,142 @@ fn print_init_success(name: &str, mode: &InitMode, is_rust: bool, dir_path: Opti
             pack_hint.dimmed()
         );
     }
+
+    if docker {
+        print_docker_steps(name);
+    }
+}
+
+/// Scaffold a package by copying a user-supplied template.
+#[allow(clippy::too_many_arguments)]
+fn init_template(
+    template: &str,
+    target_dir: &Path,
+    name: Option<String>,
+    display_path: Option<&str>,
+    description: Option<String>,
+    author: Option<String>,
+    license: Option<String>,
+    force: bool,
+    git: bool,
+) -> ToolResult<()> {
+    use chrono::Datelike;
+
+    let pkg_name = name
+        .or_else(|| {
+            target_dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|s| s.to_string())
+        })
+        .ok_or_else(|| {
+            ToolError::Generic("Could not determine package name. Use --name.".into())
+        })?;
+
+    if !is_valid_package_name(&pkg_name) {
+        return Err(ToolError::Generic(format!(
+            "Invalid package name \"{}\"\nName must be 3-64 characters, start with a lowercase letter, and contain only lowercase letters, numbers, and hyphens.",
+            pkg_name
+        )));
+    }
+
+    let git_author = get_git_author();
+    let author = author
+        .or_else(|| git_author.as_ref().map(|a| a.name.clone()))
+        .unwrap_or_default();
+
+    let mut values = BTreeMap::new();
+    values.insert("name", pkg_name.clone());
+    values.insert("name_snake", pkg_name.replace('-', "_"));
+    values.insert("author", author);
+    values.insert(
+        "description",
+        description.unwrap_or_else(|| "An MCP server".to_string()),
+    );
+    values.insert("license", license.unwrap_or_default());
+    values.insert("year", chrono::Utc::now().year().to_string());
+
+    let manifest_path = target_dir.join(MCPB_MANIFEST_FILE);
+    if force && manifest_path.exists() {
+        backup_manifest(&manifest_path)?;
+    }
+
+    let applied = super::template::apply_template(template, target_dir, &values, force)?;
+    let mut written = applied.files;
+    written.sort();
+
+    println!(
+        "  {} Scaffolded {} from template\n",
+        "✓".bright_green(),
+        pkg_name.bold()
+    );
+    println!("  · {}   {}", "Template".dimmed(), template);
+    println!("  · {}      {}\n", "Files".dimmed(), written.len());
+
+    let prefix = match display_path {
+        Some(p) => format!("{}/", p),
+        None => "./".to_string(),
+    };
+    println!("  {}", prefix.bold());
+    for (i, path) in written.iter().enumerate() {
+        let branch = if i + 1 == written.len() {
+            "└──"
+        } else {
+            "├──"
+        };
+        println!("  {} {}", branch, path.display());
+    }
+    print_replaced(&applied.replaced);
+
+    if git {
+        init_git_repo(target_dir, &written, git_author.as_ref());
+    }
+
+    println!("\n  {}:", "Next Steps".bold());
+    let mut step = 1;
+    if let Some(p) = display_path {
+        println!("  {}. cd {}", step, p);
+        step += 1;
+    }
+    println!(
+        "  {}. tool build              {}",
+        step,
+        "# run the template's build script".dimmed()
+    );
+    println!(
+        "  {}. tool info               {}",
+        step + 1,
+        "# list tools".dimmed()
+    );
+
+    Ok(())
+}
+
+/// Print Dockerfile entries in the scaffold tree.
+fn print_docker_tree(docker: bool) {
+    if docker {
+        println!("  ├── Dockerfile");
+        println!("  ├── .dockerignore");
+    }
+}
+
+/// Print the steps to build and run the scaffolded container.
+fn print_docker_steps(name: &str) {
+    println!("\n  {}:", "Container".bold());
+    println!(
+        "  · docker build -t {} .      {}",
+        name,
+        "# build image".dimmed()
+    );
+    println!(
+        "  · docker run --rm -p 3000:3000 {}  {}",
+        name,
+        "# serve on http://127.0.0.1:3000/mcp".dimmed()
+    );
+    println!(
+        "  · tool run                  {}",
+        "# run via manifest (docker run)".dimmed()
+    );
 }
 
 /// Initialize a reference manifest with explicit mcp_config options.
@@ -1170,6 +1939,7 @@ async fn init_reference(
     license: Option<String>,
     http: bool,
     yes: bool,
+    args_prompted: bool,
     force: bool,
     mut mcp_opts: McpConfigOptions,
 ) -> ToolResult<()> {
@@ -1237,30 +2007,29 @@ async fn init_reference(
         };
 
         // Prompt for command or url depending on transport
-        if is_http {
-            // HTTP mode: prompt for url if not provided
-            if mcp_opts.url.is_none() {
-                let url: String = cliclack::input("Server URL")
-                    .placeholder("https://api.example.com/mcp/")
-                    .interact()?;
-                mcp_opts.url = Some(url);
-            }
-        } else {
-            // Stdio mode: prompt for command and args if not provided
-            if mcp_opts.command.is_none() {
-                let cmd: String = cliclack::input("Command").placeholder("npx").interact()?;
-                mcp_opts.command = Some(cmd);
-            }
-
-            if mcp_opts.args.is_empty() {
-                let args_str: String = cliclack::input("Arguments (space-separated)")
-                    .placeholder("@anthropic/mcp-server --verbose")
-                    .required(false)
-                    .interact()?;
-
-                if !args_str.trim().is_empty() {
-                    mcp_opts.args = args_str.split_whitespace().map(|s| s.to_string()).collect();
-                }
+        let pending = pending_reference_prompts(&mcp_opts, is_http, args_prompted);
+        if pending.contains(&ReferencePrompt::Url) {
+            let url: String = cliclack::input("Server URL")
+                .placeholder("https://api.example.com/mcp/")
+                .validate(|input: &String| check_http_url("server URL", input))
+                .interact()?;
+            mcp_opts.url = Some(url);
+        }
+        if pending.contains(&ReferencePrompt::Auth) {
+            prompt_reference_auth(&mut mcp_opts)?;
+        }
+        if pending.contains(&ReferencePrompt::Command) {
+            let cmd: String = cliclack::input("Command").placeholder("npx").interact()?;
+            mcp_opts.command = Some(cmd);
+        }
+        if pending.contains(&ReferencePrompt::Args) {
+            let args_str: String = cliclack::input("Arguments (space-separated)")
+                .placeholder("@anthropic/mcp-server --verbose")
+                .required(false)
+                .interact()?;
+
+            if !args_str.trim().is_empty() {
+                mcp_opts.args = args_str.split_whitespace().map(|s| s.to_string()).collect();
             }
         }
 
@@ -1279,6 +2048,7 @@ async fn init_reference(
             pkg_name
         )));
     }
+    mcp_opts.validate()?;
 
     // Determine transport
     let transport = if is_http {
@@ -1317,7 +2087,7 @@ async fn init_reference(
         prompts: None,
         tools_generated: None,
         prompts_generated: None,
-        user_config: None,
+        user_config: mcp_opts.to_user_config(),
         system_config: None,
         compatibility: None,
         privacy_policies: None,
@@ -1339,24 +2109,139 @@ async fn init_reference(
     }
 
     // Write manifest.json
+    if manifest_path.exists() {
+        backup_manifest(&manifest_path)?;
+    }
+    let mut writer = ScaffoldWriter::new(&target_dir);
     let manifest_json = serde_json::to_string_pretty(&manifest)?;
-    std::fs::write(&manifest_path, &manifest_json)?;
+    writer.write(MCPB_MANIFEST_FILE, &manifest_json)?;
 
     // Write .mcpbignore
-    let mcpbignore_path = target_dir.join(".mcpbignore");
-    std::fs::write(&mcpbignore_path, mcpbignore_template())?;
+    writer.write(".mcpbignore", mcpbignore_template())?;
 
     // Write README.md
-    let readme_path = target_dir.join("README.md");
-    let readme_content = format!("# {}\n", pkg_name);
-    std::fs::write(&readme_path, readme_content)?;
+    writer.write("README.md", format!("# {}\n", pkg_name))?;
 
     // Print success message
     print_reference_success(&pkg_name, transport, &mcp_opts, path.as_deref());
+    print_replaced(&writer.replaced);
 
     Ok(())
 }
 
+/// Reference-mode values still missing after the command-line flags, in prompt order.
+///
+/// `args_prompted` is set when the caller already asked for arguments, since an empty
+/// answer is valid and must not be asked again.
+fn pending_reference_prompts(
+    mcp_opts: &McpConfigOptions,
+    is_http: bool,
+    args_prompted: bool,
+) -> Vec<ReferencePrompt> {
+    let mut pending = Vec::new();
+    if is_http {
+        if mcp_opts.url.is_none() {
+            pending.push(ReferencePrompt::Url);
+        }
+
+        // Ask how to authenticate unless flags already said
+        if mcp_opts.auth.is_none()
+            && !mcp_opts.has_oauth()
+            && !mcp_opts
+                .headers
+                .iter()
+                .any(|h| h.to_lowercase().starts_with("authorization="))
+        {
+            pending.push(ReferencePrompt::Auth);
+        }
+    } else {
+        if mcp_opts.command.is_none() {
+            pending.push(ReferencePrompt::Command);
+        }
+        if mcp_opts.args.is_empty() && !args_prompted {
+            pending.push(ReferencePrompt::Args);
+        }
+    }
+    pending
+}
+
+/// Ask how an HTTP reference server authenticates and collect the OAuth endpoints.
+fn prompt_reference_auth(mcp_opts: &mut McpConfigOptions) -> ToolResult<()> {
+    let auth: &str = cliclack::select("Authentication")
+        .item("none", "None", "Server is public")
+        .item(
+            "bearer",
+            "Bearer token",
+            "Send an API key from `tool config set` in the Authorization header",
+        )
+        .item("oauth", "OAuth", "Sign in through the server's OAuth flow")
+        .interact()?;
+
+    mcp_opts.auth = Some(match auth {
+        "bearer" => ReferenceAuth::Bearer,
+        "oauth" => ReferenceAuth::OAuth,
+        _ => ReferenceAuth::None,
+    });
+    if auth != "oauth" {
+        return Ok(());
+    }
+
+    // Endpoints are discovered from the server when left blank
+    let optional = |value: String| {
+        let value = value.trim().to_string();
+        (!value.is_empty()).then_some(value)
+    };
+    let optional_url = |label: &'static str| {
+        move |input: &String| {
+            if input.trim().is_empty() {
+                Ok(())
+            } else {
+                check_http_url(label, input.trim())
+            }
+        }
+    };
+
+    mcp_opts.oauth_client_id = optional(
+        cliclack::input("OAuth client ID (optional)")
+            .required(false)
+            .interact()?,
+    );
+    mcp_opts.oauth_authorization_url = optional(
+        cliclack::input("Authorization URL (optional)")
+            .placeholder("https://auth.example.com/authorize")
+            .required(false)
+            .validate(optional_url("authorization URL"))
+            .interact()?,
+    );
+    mcp_opts.oauth_token_url = optional(
+        cliclack::input("Token URL (optional)")
+            .placeholder("https://auth.example.com/token")
+            .required(false)
+            .validate(optional_url("token URL"))
+            .interact()?,
+    );
+    mcp_opts.oauth_scopes = optional(
+        cliclack::input("Scopes, comma-separated (optional)")
+            .required(false)
+            .interact()?,
+    );
+
+    Ok(())
+}
+
+/// Check that a URL is an absolute http(s) URL with a host.
+fn check_http_url(label: &str, url: &str) -> Result<(), String> {
+    let parsed =
+        reqwest::Url::parse(url).map_err(|e| format!("Invalid {} '{}': {}", label, url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(format!(
+            "Invalid {} '{}': expected an http:// or https:// URL",
+            label, url
+        ));
+    }
+    Ok(())
+}
+
 /// Print success output for reference mode initialization.
 fn print_reference_success(
     name: &str,
@@ -1385,8 +2270,12 @@ fn print_reference_success(
     if let Some(ref url) = mcp_opts.url {
         println!("  · {}        {}", "URL".dimmed(), url);
     }
-    if mcp_opts.oauth_client_id.is_some() {
-        println!("  · {}      configured", "OAuth".dimmed());
+    match mcp_opts.resolved_auth() {
+        ReferenceAuth::None => {}
+        ReferenceAuth::Bearer => {
+            println!("  · {}       bearer token", "Auth".dimmed());
+        }
+        ReferenceAuth::OAuth => println!("  · {}       oauth", "Auth".dimmed()),
     }
 
     println!("  · {}    0.1.0\n", "Version".dimmed());
@@ -1412,6 +2301,16 @@ fn print_reference_success(
         step += 1;
     }
 
+    if mcp_opts.resolved_auth() == ReferenceAuth::Bearer {
+        println!(
+            "  {}. tool config set . {}=<token> {}",
+            step,
+            BEARER_TOKEN_KEY,
+            "# store the bearer token".dimmed()
+        );
+        step += 1;
+    }
+
     println!(
         "  {}. tool info               {}",
         step,
@@ -1430,3 +2329,170 @@ fn print_reference_success(
         pack_hint.dimmed()
     );
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_types_cover_matrix() {
+        let rows = init_types();
+
+        // node: 4 pms, python: 3 pms, rust and binary: none; each over both transports
+        assert_eq!(rows.len(), (4 + 3 + 1 + 1) * 2);
+
+        let defaults: Vec<_> = rows
+            .iter()
+            .filter(|r| r.default && r.transport == "stdio")
+            .map(|r| (r.server_type, r.package_manager.as_deref()))
+            .collect();
+        assert_eq!(
+            defaults,
+            [
+                ("node", Some("npm")),
+                ("python", Some("uv")),
+                ("rust", None),
+                ("binary", None)
+            ]
+        );
+
+        let pnpm_http = rows
+            .iter()
+            .find(|r| r.package_manager.as_deref() == Some("pnpm") && r.transport == "http")
+            .unwrap();
+        assert_eq!(
+            pnpm_http.command,
+            "tool init my-tool -t node --http --pm pnpm"
+        );
+    }
+
+    #[test]
+    fn test_check_package_manager_mismatches() {
+        let pm = |s| parse_package_manager(s).unwrap();
+
+        for server_type in ["rust", "binary"] {
+            for name in ["npm", "pnpm", "bun", "yarn", "uv", "pip", "poetry"] {
+                assert!(check_package_manager(server_type, pm(name)).is_err());
+            }
+        }
+        for name in ["uv", "pip", "poetry"] {
+            assert!(check_package_manager("node", pm(name)).is_err());
+            assert!(check_package_manager("python", pm(name)).is_ok());
+        }
+        for name in ["npm", "pnpm", "bun", "yarn"] {
+            assert!(check_package_manager("python", pm(name)).is_err());
+            assert!(check_package_manager("Node", pm(name)).is_ok());
+        }
+
+        let err = check_package_manager("rust", pm("npm")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Package manager 'npm' can't be used with server type 'rust'. Valid pairings: \
+             node (npm, pnpm, bun, yarn), python (uv, pip, poetry), rust (no --pm), binary (no --pm)"
+        );
+    }
+
+    #[test]
+    fn test_bearer_auth_config() {
+        let opts = McpConfigOptions {
+            url: Some("https://api.example.com/mcp".into()),
+            auth: Some(ReferenceAuth::Bearer),
+            ..Default::default()
+        };
+        opts.validate().unwrap();
+
+        let config = opts.to_mcp_config().unwrap();
+        assert_eq!(
+            config.headers.get("Authorization").map(String::as_str),
+            Some("Bearer ${user_config.api_key}")
+        );
+        assert!(config.oauth_config.is_none());
+
+        let user_config = opts.to_user_config().unwrap();
+        assert_eq!(user_config[BEARER_TOKEN_KEY].sensitive, Some(true));
+    }
+
+    #[test]
+    fn test_oauth_auth_validation() {
+        let mut opts = McpConfigOptions {
+            url: Some("https://api.example.com/mcp".into()),
+            oauth_client_id: Some("abc".into()),
+            oauth_token_url: Some("https://auth.example.com/token".into()),
+            ..Default::default()
+        };
+        assert_eq!(opts.resolved_auth(), ReferenceAuth::OAuth);
+        opts.validate().unwrap();
+        assert!(opts.to_user_config().is_none());
+
+        opts.oauth_token_url = Some("auth.example.com/token".into());
+        assert!(opts.validate().is_err());
+
+        opts.oauth_token_url = None;
+        opts.auth = Some(ReferenceAuth::Bearer);
+        assert!(opts.validate().is_err());
+    }
+
+    #[test]
+    fn test_oauth_auth_without_options() {
+        let opts = McpConfigOptions {
+            url: Some("https://api.example.com/mcp".into()),
+            auth: Some(ReferenceAuth::OAuth),
+            ..Default::default()
+        };
+        opts.validate().unwrap();
+
+        let oauth = opts.to_mcp_config().unwrap().oauth_config.unwrap();
+        assert!(oauth.client_id.is_none());
+        assert!(oauth.authorization_url.is_none());
+        assert!(oauth.token_url.is_none());
+        assert!(oauth.scopes.is_none());
+    }
+
+    #[test]
+    fn test_pending_reference_prompts() {
+        // Interactive `tool init --auth oauth`: the prompted URL is all that was missing
+        let opts = McpConfigOptions {
+            url: Some("https://api.example.com/mcp".into()),
+            auth: Some(ReferenceAuth::OAuth),
+            ..Default::default()
+        };
+        assert!(pending_reference_prompts(&opts, true, true).is_empty());
+
+        let opts = McpConfigOptions {
+            auth: Some(ReferenceAuth::OAuth),
+            ..Default::default()
+        };
+        assert_eq!(
+            pending_reference_prompts(&opts, true, false),
+            vec![ReferencePrompt::Url]
+        );
+        assert_eq!(
+            pending_reference_prompts(&McpConfigOptions::default(), true, false),
+            vec![ReferencePrompt::Url, ReferencePrompt::Auth]
+        );
+
+        // Arguments left empty at the first prompt aren't asked for again
+        let opts = McpConfigOptions {
+            command: Some("npx".into()),
+            ..Default::default()
+        };
+        assert!(pending_reference_prompts(&opts, false, true).is_empty());
+        assert_eq!(
+            pending_reference_prompts(&opts, false, false),
+            vec![ReferencePrompt::Args]
+        );
+    }
+
+    #[test]
+    fn test_check_http_url() {
+        assert!(check_http_url("server URL", "https://api.example.com/mcp/").is_ok());
+        assert!(check_http_url("server URL", "http://localhost:8080/mcp").is_ok());
+        assert!(check_http_url("server URL", "api.example.com/mcp").is_err());
+        assert!(check_http_url("server URL", "ftp://example.com").is_err());
+        assert!(check_http_url("server URL", "https://").is_err());
+    }
+}
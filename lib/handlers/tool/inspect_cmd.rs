@@ -0,0 +1,307 @@
+//! Bundle inspect command handler.
+
+use crate::constants::OS_ARCH_PLATFORMS;
+use crate::error::{ToolError, ToolResult};
+use crate::format::format_size;
+use crate::mcpb::McpbManifest;
+use crate::output::print_json;
+use crate::pack::{compute_sha256, read_manifest_from_bundle};
+use crate::tree::FileTree;
+use colored::Colorize;
+use serde::Serialize;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// JSON output for `tool inspect`.
+#[derive(Debug, Serialize)]
+struct InspectOutput {
+    /// Bundle file path.
+    path: String,
+
+    /// Tool name from the manifest.
+    name: Option<String>,
+
+    /// Tool version from the manifest.
+    version: Option<String>,
+
+    /// Server transport.
+    transport: String,
+
+    /// Bundle format the manifest calls for (`mcpb` or `mcpbx`).
+    format: &'static str,
+
+    /// Set when the file's extension doesn't match `format`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extension_mismatch: Option<String>,
+
+    /// Platform the bundle was packed for (`None` for universal bundles).
+    platform: Option<String>,
+
+    /// SHA-256 of the bundle file.
+    sha256: String,
+
+    /// Bundle file size in bytes.
+    size: u64,
+
+    /// Declared tool names.
+    tools: Vec<String>,
+
+    /// Declared prompt names.
+    prompts: Vec<String>,
+
+    /// Declared resource URIs.
+    resources: Vec<String>,
+
+    /// Archive entries (files only).
+    files: Vec<InspectFileOutput>,
+
+    /// Total uncompressed size of all files in bytes.
+    total_size: u64,
+}
+
+/// A single archive entry in [`InspectOutput`].
+#[derive(Debug, Serialize)]
+struct InspectFileOutput {
+    path: String,
+    size: u64,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Show a bundle's manifest summary, contents, and checksum without installing it.
+pub fn inspect_bundle(path: String, json: bool, concise: bool, no_header: bool) -> ToolResult<()> {
+    let bytes = std::fs::read(&path)
+        .map_err(|e| ToolError::Generic(format!("Failed to read bundle {}: {}", path, e)))?;
+    let (manifest, _) = read_manifest_from_bundle(&bytes)
+        .map_err(|e| ToolError::Generic(format!("Invalid bundle {}: {}", path, e)))?;
+
+    let mut archive = ZipArchive::new(Cursor::new(&bytes))?;
+    let mut files = Vec::new();
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i)?;
+        if !entry.is_dir() {
+            files.push(InspectFileOutput {
+                path: entry.name().to_string(),
+                size: entry.size(),
+            });
+        }
+    }
+
+    let (tools, prompts, resources) = declared_capabilities(&manifest);
+    let output = InspectOutput {
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        transport: manifest.transport().to_string(),
+        format: manifest.bundle_extension(),
+        extension_mismatch: manifest.bundle_extension_mismatch(std::path::Path::new(&path)),
+        platform: bundle_platform(&manifest).or_else(|| platform_from_file_name(&path)),
+        sha256: compute_sha256(&bytes),
+        size: bytes.len() as u64,
+        tools,
+        prompts,
+        resources,
+        total_size: files.iter().map(|f| f.size).sum(),
+        files,
+        path,
+    };
+
+    if json {
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
+    if concise {
+        if !no_header {
+            println!("#name\tversion\ttransport\tplatform\tsha256\tsize");
+        }
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            output.name.as_deref().unwrap_or("-"),
+            output.version.as_deref().unwrap_or("-"),
+            output.transport,
+            output.platform.as_deref().unwrap_or("universal"),
+            output.sha256,
+            output.size
+        );
+        return Ok(());
+    }
+
+    print_inspect(&output);
+    Ok(())
+}
+
+/// Tool names, prompt names, and resource URIs declared by a manifest.
+///
+/// Manifest `tools`/`prompts` are preferred; static responses fill in the rest.
+fn declared_capabilities(manifest: &McpbManifest) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let static_responses = manifest.static_responses();
+
+    let tools = match &manifest.tools {
+        Some(tools) => tools.iter().map(|t| t.name.clone()).collect(),
+        None => static_responses
+            .as_ref()
+            .and_then(|s| s.tools_list.as_ref())
+            .map(|l| l.tools.iter().map(|t| t.name.clone()).collect())
+            .unwrap_or_default(),
+    };
+    let prompts = match &manifest.prompts {
+        Some(prompts) => prompts.iter().map(|p| p.name.clone()).collect(),
+        None => static_responses
+            .as_ref()
+            .and_then(|s| s.prompts_list.as_ref())
+            .map(|l| l.prompts.iter().map(|p| p.name.clone()).collect())
+            .unwrap_or_default(),
+    };
+    let resources = static_responses
+        .as_ref()
+        .and_then(|s| s.resources_list.as_ref())
+        .map(|l| l.resources.iter().map(|r| r.uri.clone()).collect())
+        .unwrap_or_default();
+
+    (tools, prompts, resources)
+}
+
+/// Platform a bundle was packed for, if it's platform-specific.
+///
+/// Platform bundles narrow `_meta["store.tool.mcpb"].compatibility.platforms` to their
+/// single OS-arch key when packed.
+fn bundle_platform(manifest: &McpbManifest) -> Option<String> {
+    let platforms = manifest
+        .meta
+        .as_ref()?
+        .get("store.tool.mcpb")?
+        .get("compatibility")?
+        .get("platforms")?
+        .as_array()?;
+
+    match platforms.as_slice() {
+        [platform] => platform
+            .as_str()
+            .filter(|p| OS_ARCH_PLATFORMS.contains(p))
+            .map(String::from),
+        _ => None,
+    }
+}
+
+/// Platform suffix of a bundle file name (e.g. `my-tool-1.0.0-linux-x64.mcpb`).
+fn platform_from_file_name(path: &str) -> Option<String> {
+    let stem = std::path::Path::new(path).file_stem()?.to_string_lossy();
+    OS_ARCH_PLATFORMS
+        .iter()
+        .find(|p| stem.ends_with(&format!("-{}", p)))
+        .map(|p| p.to_string())
+}
+
+/// Print the human-readable inspect view.
+fn print_inspect(output: &InspectOutput) {
+    let name = output.name.as_deref().unwrap_or("(unnamed)");
+    let version = output.version.as_deref().unwrap_or("0.0.0");
+
+    println!();
+    let platform = output.platform.as_deref().unwrap_or("universal");
+    println!(
+        "  {}@{} {}",
+        name.bold(),
+        version,
+        format!("[{}]", platform).bright_cyan()
+    );
+    println!("  · {}: {}", "Format".dimmed(), output.format);
+    println!("  · {}: {}", "Transport".dimmed(), output.transport);
+    println!("  · {}: {}", "SHA-256".dimmed(), output.sha256);
+    println!("  · {}: {}", "Size".dimmed(), format_size(output.size));
+
+    for (label, names) in [
+        ("Tools", &output.tools),
+        ("Prompts", &output.prompts),
+        ("Resources", &output.resources),
+    ] {
+        if !names.is_empty() {
+            println!(
+                "  · {}: {} ({})",
+                label.dimmed(),
+                names.join(", "),
+                names.len()
+            );
+        }
+    }
+
+    if let Some(mismatch) = &output.extension_mismatch {
+        println!("  {} {}", "!".bright_yellow(), mismatch);
+    }
+
+    let mut tree = FileTree::new();
+    for file in &output.files {
+        tree.insert(&file.path, file.size);
+    }
+
+    println!();
+    for line in tree.render(&format!("{}/", name)).lines() {
+        println!("  {}", line);
+    }
+    println!(
+        "\n  · {}  {} files, {}",
+        "Total".dimmed(),
+        output.files.len(),
+        format_size(output.total_size)
+    );
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn manifest(meta: serde_json::Value) -> McpbManifest {
+        serde_json::from_value(json!({
+            "manifest_version": "0.3",
+            "name": "my-tool",
+            "server": {"type": "binary", "entry_point": "bin/my-tool"},
+            "tools": [{"name": "search", "description": "Search"}],
+            "_meta": meta
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bundle_platform() {
+        let platform = manifest(json!({
+            "store.tool.mcpb": {"compatibility": {"platforms": ["linux-x64"]}}
+        }));
+        assert_eq!(bundle_platform(&platform).as_deref(), Some("linux-x64"));
+
+        let universal = manifest(json!({
+            "store.tool.mcpb": {"compatibility": {"platforms": ["darwin-arm64", "linux-x64"]}}
+        }));
+        assert_eq!(bundle_platform(&universal), None);
+
+        assert_eq!(
+            platform_from_file_name("dist/my-tool@1.0.0-darwin-arm64.mcpb").as_deref(),
+            Some("darwin-arm64")
+        );
+        assert_eq!(platform_from_file_name("my-tool-1.0.0.mcpb"), None);
+    }
+
+    #[test]
+    fn test_declared_capabilities() {
+        let manifest = manifest(json!({
+            "store.tool.mcpb": {"static_responses": {
+                "resources/list": {"resources": [{"uri": "file:///readme", "name": "readme"}]}
+            }}
+        }));
+
+        let (tools, prompts, resources) = declared_capabilities(&manifest);
+        assert_eq!(tools, ["search"]);
+        assert!(prompts.is_empty());
+        assert_eq!(resources, ["file:///readme"]);
+    }
+}
@@ -1,13 +1,22 @@
 //! Tool installation command handlers.
 
-use super::pack_cmd::format_size;
-use crate::constants::MCPB_MANIFEST_FILE;
-use crate::error::{ToolError, ToolResult};
+use super::common::compare_versions_desc;
+use crate::constants::{DEFAULT_OBJECTS_PATH, MCPB_MANIFEST_FILE};
+use crate::error::{EXIT_FAILURE, ToolError, ToolResult};
+use crate::extract::{
+    ExtractLimits, extract_archive, restore_entry_point_mode, verify_entry_point,
+};
+use crate::format::format_size;
+use crate::objects::{dedup_dir, dedup_enabled, remove_install};
+use crate::pack::is_bundle_file;
+use crate::progress::{
+    ProgressEvent, Transfer, emit, multi_progress, progress_bar, track_transfer,
+};
 use crate::references::PluginRef;
 use crate::registry::RegistryClient;
 use crate::resolver::FilePluginResolver;
 use colored::Colorize;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use indicatif::{ProgressBar, ProgressStyle};
 use std::path::{Path, PathBuf};
 
 //--------------------------------------------------------------------------------------------------
@@ -28,6 +37,22 @@ pub enum InstallResult {
     Failed(String),
 }
 
+/// Outcome of `tool install` across all requested tools.
+#[derive(Debug, Clone, Default)]
+pub struct InstallSummary {
+    /// Number of tools installed from the registry or linked from local paths.
+    pub installed: usize,
+
+    /// Number of tools installed by extracting a bundle file.
+    pub extracted: usize,
+
+    /// Tools that were already installed.
+    pub already_installed: Vec<String>,
+
+    /// Tools that couldn't be installed, with the error message.
+    pub failed: Vec<(String, String)>,
+}
+
 /// Result of ensuring tools are installed.
 #[derive(Debug, Clone, Default)]
 #[allow(dead_code)]
@@ -85,6 +110,8 @@ pub struct RegistryPreflight {
     pub target_dir: PathBuf,
     /// Temporary file path for download.
     pub temp_file: PathBuf,
+    /// Yank or deprecation notice for the resolved version.
+    pub warning: Option<String>,
 }
 
 /// Pre-flight information for a bundle file extraction.
@@ -124,6 +151,7 @@ struct DownloadPreflight {
     output_path: PathBuf,
     #[allow(dead_code)]
     platform: Option<String>,
+    warning: Option<String>,
 }
 
 /// Result of platform bundle selection.
@@ -137,6 +165,14 @@ struct BundleSelection {
     selected_platform: Option<String>,
     /// File extension (mcpb or mcpbx)
     extension: String,
+    /// Why this bundle was chosen (shown by `install --explain`)
+    reason: String,
+}
+
+/// Decisions recorded while resolving an install, printed by `install --explain`.
+#[derive(Debug, Default)]
+struct InstallTrace {
+    steps: Vec<(&'static str, String)>,
 }
 
 /// Result of download_and_install with size info.
@@ -147,6 +183,31 @@ struct InstallSuccess {
     size: u64,
 }
 
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl InstallSummary {
+    /// Whether any requested tool failed to install.
+    pub fn has_failures(&self) -> bool {
+        !self.failed.is_empty()
+    }
+}
+
+impl InstallTrace {
+    /// Record a resolution step.
+    fn record(&mut self, label: &'static str, detail: String) {
+        self.steps.push((label, detail));
+    }
+
+    /// Print the recorded steps.
+    fn print(&self) {
+        for (label, detail) in &self.steps {
+            println!("  · {:<12} {}", label.dimmed(), detail);
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -196,17 +257,17 @@ async fn preflight_download(
     let client = RegistryClient::new();
 
     // Determine the version
-    let version = if let Some(v) = plugin_ref.version_str() {
-        v.to_string()
-    } else {
-        let artifact = client
-            .get_artifact(&namespace, &tool_name)
+    let version = match (plugin_ref.tag(), plugin_ref.version_str()) {
+        (Some(tag), _) => client
+            .resolve_tag(&namespace, &tool_name, tag)
             .await
-            .map_err(|_| format!("Tool {}/{} not found in registry", namespace, tool_name))?;
-        artifact
-            .latest_version
-            .ok_or_else(|| format!("No versions published for {}/{}", namespace, tool_name))?
-            .version
+            .map_err(|e| e.to_string())?,
+        (None, Some(v)) => v.to_string(),
+        (None, None) => {
+            resolve_unpinned_version(&client, &namespace, &tool_name)
+                .await?
+                .0
+        }
     };
 
     // Get full version info
@@ -214,6 +275,7 @@ async fn preflight_download(
         .get_version(&namespace, &tool_name, &version)
         .await
         .map_err(|e| format!("Failed to fetch version info: {}", e))?;
+    let warning = version_status_warning(&version_info, &namespace, &tool_name);
 
     // Determine which bundle to download based on platform preference
     let bundle = select_platform_bundle(&version_info, platform, &tool_name, &version)?;
@@ -245,9 +307,86 @@ async fn preflight_download(
         download_url,
         output_path,
         platform: bundle.selected_platform,
+        warning,
     })
 }
 
+/// Resolve the version to install when the reference doesn't pin one.
+///
+/// Uses the registry's latest unless it was yanked, in which case the newest version that
+/// isn't yanked is chosen (stable releases first). Returns the version and, if one was
+/// skipped, the yanked latest.
+async fn resolve_unpinned_version(
+    client: &RegistryClient,
+    namespace: &str,
+    tool_name: &str,
+) -> Result<(String, Option<String>), String> {
+    let artifact = client
+        .get_artifact(namespace, tool_name)
+        .await
+        .map_err(|_| format!("Tool {}/{} not found in registry", namespace, tool_name))?;
+    let latest = artifact
+        .latest_version
+        .ok_or_else(|| format!("No published version for {}/{}", namespace, tool_name))?;
+
+    if !latest.yanked {
+        return Ok((latest.version, None));
+    }
+
+    let mut candidates: Vec<_> = client
+        .list_versions(namespace, tool_name)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|v| !v.yanked)
+        .map(|v| v.version)
+        .collect();
+    candidates.sort_by(|a, b| compare_versions_desc(a, b));
+
+    let version = candidates
+        .iter()
+        .find(|v| semver::Version::parse(v).is_ok_and(|s| s.pre.is_empty()))
+        .or_else(|| candidates.first())
+        .cloned()
+        .ok_or_else(|| {
+            format!(
+                "Every published version of {}/{} has been yanked",
+                namespace, tool_name
+            )
+        })?;
+    Ok((version, Some(latest.version)))
+}
+
+/// Notice for a yanked or deprecated version, including the publisher's message.
+fn version_status_warning(
+    version_info: &crate::registry::VersionInfo,
+    namespace: &str,
+    tool_name: &str,
+) -> Option<String> {
+    let reference = format!("{}/{}@{}", namespace, tool_name, version_info.version);
+    let message = version_info
+        .deprecation_message
+        .as_deref()
+        .map(|m| format!(": {}", m))
+        .unwrap_or_default();
+
+    if version_info.yanked {
+        Some(format!(
+            "{} has been yanked by its publisher{}",
+            reference, message
+        ))
+    } else if version_info.deprecated {
+        Some(format!("{} is deprecated{}", reference, message))
+    } else {
+        None
+    }
+}
+
+/// Print a yank or deprecation notice.
+fn print_version_warning(warning: &str) {
+    println!("  {} {}", "⚠".bright_yellow(), warning.bright_yellow());
+}
+
 /// Select the appropriate bundle based on platform preference.
 /// Returns info needed to construct the backend download URL.
 fn select_platform_bundle(
@@ -290,6 +429,7 @@ fn select_platform_bundle(
                 size: info.size,
                 selected_platform: None,
                 extension: ext.to_string(),
+                reason: "universal bundle requested".into(),
             });
         }
         // Fall back to main_download_url only if it's actually a bundle
@@ -307,6 +447,7 @@ fn select_platform_bundle(
                 size,
                 selected_platform: None,
                 extension: ext.to_string(),
+                reason: "universal bundle requested; using main download".into(),
             });
         }
         return Err(format!("No universal bundle for {}@{}", tool_name, version));
@@ -344,6 +485,7 @@ fn select_platform_bundle(
                         size: info.size,
                         selected_platform: Some(variant.to_string()),
                         extension: ext.to_string(),
+                        reason: format!("matches platform {}", variant),
                     });
                 }
             }
@@ -369,6 +511,10 @@ fn select_platform_bundle(
                 size: info.size,
                 selected_platform: None,
                 extension: ext.to_string(),
+                reason: format!(
+                    "no bundle for {}; falling back to universal",
+                    target_platform
+                ),
             });
         }
     }
@@ -388,6 +534,7 @@ fn select_platform_bundle(
             size,
             selected_platform: None,
             extension: ext.to_string(),
+            reason: "no platform bundles; using main download".into(),
         });
     }
 
@@ -440,6 +587,9 @@ pub async fn download_tools(
         );
     }
 
+    for name in names {
+        emit(ProgressEvent::Resolving { name: name.clone() });
+    }
     let preflight_futures: Vec<_> = names
         .iter()
         .map(|name| preflight_download(name, output_dir.as_deref(), platform))
@@ -460,6 +610,14 @@ pub async fn download_tools(
     // Print preflight failures
     for (name, msg) in &failed {
         println!("  {} {}: {}", "✗".bright_red(), name, msg);
+        emit(ProgressEvent::Failed {
+            name: name.clone(),
+            error: msg.clone(),
+        });
+    }
+
+    for warning in preflights.iter().filter_map(|pf| pf.warning.as_deref()) {
+        print_version_warning(warning);
     }
 
     // Phase 2: Download
@@ -477,7 +635,7 @@ pub async fn download_tools(
                 pf.version.bright_cyan()
             );
 
-            let pb = ProgressBar::new(pf.download_size);
+            let pb = progress_bar(pf.download_size);
             pb.set_style(
                 ProgressStyle::default_bar()
                     .template("  [{bar:40.cyan/dim}] {bytes}/{total_bytes} {bytes_per_sec}")
@@ -485,11 +643,14 @@ pub async fn download_tools(
                     .progress_chars("█░░"),
             );
             pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            let name = format!("{}/{}", pf.namespace, pf.tool_name);
+            let tracker = track_transfer(&pb, name.clone(), Transfer::Download);
 
-            match client
+            let result = client
                 .download_from_url_with_progress_pb(&pf.download_url, &pf.output_path, &pb)
-                .await
-            {
+                .await;
+            tracker.finish();
+            match result {
                 Ok(size) => {
                     pb.finish_and_clear();
                     let path_str = pf.output_path.display().to_string();
@@ -504,10 +665,18 @@ pub async fn download_tools(
                         colored_path,
                         format_size(size)
                     );
+                    emit(ProgressEvent::Downloaded {
+                        name,
+                        path: path_str,
+                    });
                 }
                 Err(e) => {
                     pb.finish_and_clear();
                     println!("  {} Download failed: {}", "✗".bright_red(), e);
+                    emit(ProgressEvent::Failed {
+                        name,
+                        error: e.to_string(),
+                    });
                 }
             }
         } else {
@@ -519,7 +688,7 @@ pub async fn download_tools(
                 count.to_string().bright_cyan()
             );
 
-            let mp = MultiProgress::new();
+            let mp = multi_progress();
             let style = ProgressStyle::default_bar()
                 .template("  {msg:<30} [{bar:25.cyan/dim}] {bytes:>10}/{total_bytes:<10}")
                 .unwrap()
@@ -528,10 +697,15 @@ pub async fn download_tools(
             let handles: Vec<_> = preflights
                 .into_iter()
                 .map(|pf| {
-                    let pb = mp.add(ProgressBar::new(pf.download_size));
+                    let pb = mp.add(progress_bar(pf.download_size));
                     pb.set_style(style.clone());
                     pb.set_message(format!("{}/{}", pf.namespace, pf.tool_name));
                     pb.enable_steady_tick(std::time::Duration::from_millis(100));
+                    let tracker = track_transfer(
+                        &pb,
+                        format!("{}/{}", pf.namespace, pf.tool_name),
+                        Transfer::Download,
+                    );
 
                     let client = RegistryClient::new();
                     tokio::spawn(async move {
@@ -542,6 +716,7 @@ pub async fn download_tools(
                                 &pb,
                             )
                             .await;
+                        tracker.finish();
                         pb.finish_and_clear();
                         (pf, result)
                     })
@@ -569,6 +744,10 @@ pub async fn download_tools(
                             colored_path,
                             format_size(size)
                         );
+                        emit(ProgressEvent::Downloaded {
+                            name: format!("{}/{}", pf.namespace, pf.tool_name),
+                            path: path_str,
+                        });
                         downloaded_count += 1;
                     }
                     Ok((pf, Err(e))) => {
@@ -579,6 +758,10 @@ pub async fn download_tools(
                             pf.tool_name,
                             e
                         );
+                        emit(ProgressEvent::Failed {
+                            name: format!("{}/{}", pf.namespace, pf.tool_name),
+                            error: e.to_string(),
+                        });
                         failed_count += 1;
                     }
                     Err(_) => {
@@ -615,11 +798,24 @@ pub async fn download_tools(
 }
 
 /// Run pre-flight checks for a tool (validation, metadata fetch, already-installed check).
-async fn preflight_tool(name: &str, platform: Option<&str>) -> PreflightResult {
+///
+/// When `trace` is given, each resolution decision is recorded for `install --explain`.
+async fn preflight_tool(
+    name: &str,
+    platform: Option<&str>,
+    mut trace: Option<&mut InstallTrace>,
+) -> PreflightResult {
     use crate::constants::DEFAULT_TOOLS_PATH;
 
+    let mut record = |label: &'static str, detail: String| {
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.record(label, detail);
+        }
+    };
+
     // Check if this is a bundle file (.mcpb or .mcpbx)
     if is_bundle_file(name) {
+        record("source", "bundle file".into());
         return preflight_bundle_file(name);
     }
 
@@ -648,22 +844,44 @@ async fn preflight_tool(name: &str, platform: Option<&str>) -> PreflightResult {
     };
 
     let tool_name = plugin_ref.name().to_string();
+    record(
+        "reference",
+        format!(
+            "namespace={} name={} version={}",
+            namespace,
+            tool_name,
+            plugin_ref.version_str().unwrap_or("(unspecified)")
+        ),
+    );
 
     // Get artifact details from registry
     let client = RegistryClient::new();
-    let artifact = match client.get_artifact(&namespace, &tool_name).await {
-        Ok(a) => a,
-        Err(_) => {
-            return PreflightResult::Failed(format!("Tool {} not found in registry", name));
-        }
-    };
-
-    // Get latest version string
-    let version = match &artifact.latest_version {
-        Some(v) => v.version.clone(),
-        None => {
-            return PreflightResult::Failed(format!("No published version for {}", name));
+    let version = match (plugin_ref.tag(), plugin_ref.version_str()) {
+        (Some(tag), _) => match client.resolve_tag(&namespace, &tool_name, tag).await {
+            Ok(version) => {
+                record("version", format!("{} (tag {})", version, tag));
+                version
+            }
+            Err(e) => return PreflightResult::Failed(e.to_string()),
+        },
+        (None, Some(v)) => {
+            record("version", format!("{} (pinned in reference)", v));
+            v.to_string()
         }
+        (None, None) => match resolve_unpinned_version(&client, &namespace, &tool_name).await {
+            Ok((version, None)) => {
+                record("version", format!("{} (latest published)", version));
+                version
+            }
+            Ok((version, Some(yanked))) => {
+                record(
+                    "version",
+                    format!("{} (latest not yanked; skipped {})", version, yanked),
+                );
+                version
+            }
+            Err(msg) => return PreflightResult::Failed(msg),
+        },
     };
 
     // Fetch full version info (includes download URL)
@@ -674,12 +892,39 @@ async fn preflight_tool(name: &str, platform: Option<&str>) -> PreflightResult {
         }
     };
 
+    let warning = version_status_warning(&version_info, &namespace, &tool_name);
+    if let Some(warning) = &warning {
+        record("status", warning.clone());
+    }
+
+    record(
+        "platform",
+        match platform {
+            Some(p) => format!("{} (--platform)", p),
+            None => format!("{} (detected)", get_current_platform()),
+        },
+    );
+
     // Select the appropriate platform bundle
     let bundle = match select_platform_bundle(&version_info, platform, &tool_name, &version) {
         Ok(result) => result,
         Err(msg) => return PreflightResult::Failed(msg),
     };
 
+    let selected = bundle.filename.as_deref().unwrap_or("main download");
+    record("artifact", format!("{} ({})", selected, bundle.reason));
+    let mut alternatives: Vec<&str> = version_info
+        .files
+        .iter()
+        .flat_map(|files| files.keys())
+        .map(String::as_str)
+        .filter(|f| (f.ends_with(".mcpb") || f.ends_with(".mcpbx")) && *f != selected)
+        .collect();
+    alternatives.sort_unstable();
+    if !alternatives.is_empty() {
+        record("alternatives", alternatives.join(", "));
+    }
+
     // Construct backend download URL for tracking
     let download_url = match &bundle.filename {
         Some(filename) => client.get_file_download_url(&namespace, &tool_name, &version, filename),
@@ -691,6 +936,7 @@ async fn preflight_tool(name: &str, platform: Option<&str>) -> PreflightResult {
     let target_dir = DEFAULT_TOOLS_PATH
         .join(&namespace)
         .join(format!("{}@{}", tool_name, version));
+    record("target", target_dir.display().to_string());
 
     if target_dir.join(MCPB_MANIFEST_FILE).exists() {
         return PreflightResult::AlreadyInstalled;
@@ -709,9 +955,125 @@ async fn preflight_tool(name: &str, platform: Option<&str>) -> PreflightResult {
         download_url,
         target_dir,
         temp_file,
+        warning,
     })
 }
 
+/// Resolve tools without downloading or linking anything.
+///
+/// With `explain`, prints each decision made while resolving: the parsed reference,
+/// chosen version, platform, selected artifact and its alternatives, and target directory.
+/// Exits non-zero when any tool fails to resolve.
+pub async fn dry_run_install(
+    names: &[String],
+    platform: Option<&str>,
+    explain: bool,
+) -> ToolResult<()> {
+    let mut failed = false;
+
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 && explain {
+            println!();
+        }
+        println!("  {} Resolving {}", "→".bright_blue(), name.bright_cyan());
+
+        let mut trace = InstallTrace::default();
+
+        // Local installs link immediately during preflight, so resolve them here
+        let result = if !is_bundle_file(name) && is_local_path(name) {
+            trace.record("source", "local directory".into());
+            preflight_local_link(name, &mut trace)
+        } else {
+            preflight_tool(name, platform, Some(&mut trace)).await
+        };
+
+        if let PreflightResult::Bundle(preflight) = &result {
+            trace.record("target", preflight.target_dir.display().to_string());
+        }
+
+        if explain {
+            trace.print();
+        }
+
+        match result {
+            PreflightResult::Registry(pf) => {
+                if let Some(warning) = &pf.warning {
+                    print_version_warning(warning);
+                }
+                println!(
+                    "  {} Would install {}/{}@{} ({})",
+                    "✓".bright_green(),
+                    pf.namespace,
+                    pf.tool_name,
+                    pf.version.bright_cyan(),
+                    format_size(pf.download_size)
+                )
+            }
+            PreflightResult::Bundle(pf) => println!(
+                "  {} Would extract {} from {}",
+                "✓".bright_green(),
+                pf.display_name.bright_cyan(),
+                pf.source_path.display().to_string().dimmed()
+            ),
+            PreflightResult::Local(InstallResult::Failed(msg)) | PreflightResult::Failed(msg) => {
+                println!("  {} {}", "✗".bright_red(), msg);
+                failed = true;
+            }
+            PreflightResult::Local(_) => {
+                println!("  {} Would link {}", "✓".bright_green(), name.bright_cyan())
+            }
+            PreflightResult::AlreadyInstalled => {
+                println!("  {} Already installed", "·".dimmed())
+            }
+        }
+    }
+
+    if failed {
+        return Err(ToolError::Reported { code: EXIT_FAILURE });
+    }
+
+    Ok(())
+}
+
+/// Resolve a local tool link target without creating it.
+fn preflight_local_link(path: &str, trace: &mut InstallTrace) -> PreflightResult {
+    use crate::constants::DEFAULT_TOOLS_PATH;
+    use crate::mcpb::McpbManifest;
+
+    let source_path = match path.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None => PathBuf::from(path),
+    };
+    let Ok(source_path) = source_path.canonicalize() else {
+        return PreflightResult::Failed(format!("Path not found: {}", path));
+    };
+    trace.record("path", source_path.display().to_string());
+
+    let manifest = match McpbManifest::load(&source_path) {
+        Ok(m) => m,
+        Err(e) => return PreflightResult::Failed(format!("Failed to load manifest: {}", e)),
+    };
+    let Some(tool_name) = manifest.name else {
+        return PreflightResult::Failed("manifest.json must include a name field".to_string());
+    };
+
+    let target_name = match manifest.version {
+        Some(v) => format!("{}@{}", tool_name, v),
+        None => tool_name,
+    };
+    let target_path = DEFAULT_TOOLS_PATH.join(&target_name);
+    trace.record("target", target_path.display().to_string());
+
+    match std::fs::read_link(&target_path) {
+        Ok(existing) if existing == source_path => return PreflightResult::AlreadyInstalled,
+        Ok(existing) => trace.record("replaces", format!("link to {}", existing.display())),
+        Err(_) if target_path.exists() => trace.record("replaces", "existing directory".into()),
+        Err(_) => {}
+    }
+
+    PreflightResult::Local(InstallResult::InstalledLocal)
+}
+
 /// Download and install a tool with a progress bar.
 /// Returns the install result and size on success.
 async fn download_and_install(
@@ -719,12 +1081,17 @@ async fn download_and_install(
     pb: ProgressBar,
 ) -> Result<InstallSuccess, String> {
     let client = RegistryClient::new();
+    let name = format!("{}/{}", preflight.namespace, preflight.tool_name);
 
     // Download from CDN URL with progress
+    let tracker = track_transfer(&pb, name.clone(), Transfer::Download);
     let size = client
         .download_from_url_with_progress_pb(&preflight.download_url, &preflight.temp_file, &pb)
-        .await
-        .map_err(|e| format!("Failed to download: {}", e))?;
+        .await;
+    tracker.finish();
+    let size = size.map_err(|e| format!("Failed to download: {}", e))?;
+
+    emit(ProgressEvent::Extracting { name });
 
     // Create target directory
     tokio::fs::create_dir_all(&preflight.target_dir)
@@ -734,6 +1101,7 @@ async fn download_and_install(
     // Extract the bundle
     extract_bundle(&preflight.temp_file, &preflight.target_dir)
         .map_err(|e| format!("Failed to extract: {}", e))?;
+    dedup_install(&preflight.target_dir);
 
     // Clean up temp file
     let _ = std::fs::remove_file(&preflight.temp_file);
@@ -746,12 +1114,29 @@ async fn download_and_install(
     })
 }
 
+/// Record a failed install and report it as a progress event.
+fn record_failure(failed: &mut Vec<(String, String)>, name: String, error: String) {
+    emit(ProgressEvent::Failed {
+        name: name.clone(),
+        error: error.clone(),
+    });
+    failed.push((name, error));
+}
+
+/// Report a registry install as a progress event.
+fn emit_installed(success: &InstallSuccess) {
+    emit(ProgressEvent::Installed {
+        name: format!("{}/{}", success.namespace, success.tool_name),
+        version: Some(success.version.clone()),
+    });
+}
+
 /// Install multiple tools from the registry or local paths.
 ///
 /// If `platform` is specified, it will be used to select a platform-specific
 /// artifact when installing multi-artifact versions. Use "universal" to
 /// explicitly select the universal bundle.
-pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<()> {
+pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<InstallSummary> {
     use futures_util::future::join_all;
 
     // Phase 1: Run preflight checks
@@ -771,9 +1156,12 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
         );
     }
 
+    for name in names {
+        emit(ProgressEvent::Resolving { name: name.clone() });
+    }
     let preflight_futures: Vec<_> = names
         .iter()
-        .map(|name| preflight_tool(name, platform))
+        .map(|name| preflight_tool(name, platform, None))
         .collect();
     let preflight_results = join_all(preflight_futures).await;
 
@@ -782,9 +1170,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
     let mut bundle_preflights = Vec::new();
     let mut local_count = 0usize;
     let mut installed_count = 0usize;
-    let mut failed_count = 0usize;
     let mut bundle_installed = 0usize;
-    let mut bundle_failed = 0usize;
     let mut already_installed = Vec::new();
     let mut failed = Vec::new();
 
@@ -797,16 +1183,22 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                 bundle_preflights.push(preflight);
             }
             PreflightResult::Local(install_result) => match install_result {
-                InstallResult::InstalledLocal => local_count += 1,
+                InstallResult::InstalledLocal => {
+                    emit(ProgressEvent::Installed {
+                        name: name.clone(),
+                        version: None,
+                    });
+                    local_count += 1;
+                }
                 InstallResult::AlreadyInstalled => already_installed.push(name.clone()),
-                InstallResult::Failed(msg) => failed.push((name.clone(), msg)),
+                InstallResult::Failed(msg) => record_failure(&mut failed, name.clone(), msg),
                 _ => {}
             },
             PreflightResult::AlreadyInstalled => {
                 already_installed.push(name.clone());
             }
             PreflightResult::Failed(msg) => {
-                failed.push((name.clone(), msg));
+                record_failure(&mut failed, name.clone(), msg);
             }
         }
     }
@@ -825,6 +1217,13 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
         println!("  {} {}: {}", "✗".bright_red(), name, msg);
     }
 
+    for warning in registry_preflights
+        .iter()
+        .filter_map(|pf| pf.warning.as_deref())
+    {
+        print_version_warning(warning);
+    }
+
     // Track counts for determining single-item display
     let registry_count = registry_preflights.len();
     let bundle_count = bundle_preflights.len();
@@ -836,6 +1235,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
         if is_single {
             // Single package: match download format exactly
             let preflight = registry_preflights.remove(0);
+            let name = format!("{}/{}", preflight.namespace, preflight.tool_name);
             println!(
                 "  {} Downloading {}/{}@{}",
                 "→".bright_blue(),
@@ -844,7 +1244,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                 preflight.version.bright_cyan()
             );
 
-            let pb = ProgressBar::new(preflight.download_size);
+            let pb = progress_bar(preflight.download_size);
             pb.set_style(
                 ProgressStyle::default_bar()
                     .template("  [{bar:40.cyan/dim}] {bytes}/{total_bytes} {bytes_per_sec}")
@@ -864,12 +1264,13 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                         success.version.bright_cyan(),
                         format_size(success.size)
                     );
+                    emit_installed(&success);
                     installed_count += 1;
                 }
                 Err(msg) => {
                     pb.finish_and_clear();
                     println!("  {} Install failed: {}", "✗".bright_red(), msg);
-                    failed_count += 1;
+                    record_failure(&mut failed, name, msg);
                 }
             }
         } else {
@@ -880,17 +1281,21 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                 count.to_string().bright_cyan()
             );
 
-            let mp = MultiProgress::new();
+            let mp = multi_progress();
             let style = ProgressStyle::default_bar()
                 .template("  {msg:<30} [{bar:25.cyan/dim}] {bytes:>10}/{total_bytes:<10}")
                 .unwrap()
                 .progress_chars("█░░");
 
             // Create progress bars and spawn download tasks
+            let names: Vec<String> = registry_preflights
+                .iter()
+                .map(|p| format!("{}/{}", p.namespace, p.tool_name))
+                .collect();
             let handles: Vec<_> = registry_preflights
                 .into_iter()
                 .map(|preflight| {
-                    let pb = mp.add(ProgressBar::new(preflight.download_size));
+                    let pb = mp.add(progress_bar(preflight.download_size));
                     pb.set_style(style.clone());
                     pb.set_message(format!("{}/{}", preflight.namespace, preflight.tool_name));
                     pb.enable_steady_tick(std::time::Duration::from_millis(100));
@@ -907,7 +1312,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
             let results = join_all(handles).await;
 
             // Print results
-            for result in results {
+            for (name, result) in names.into_iter().zip(results) {
                 match result {
                     Ok(Ok(success)) => {
                         println!(
@@ -918,15 +1323,16 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                             success.version.bright_cyan(),
                             format_size(success.size)
                         );
+                        emit_installed(&success);
                         installed_count += 1;
                     }
                     Ok(Err(msg)) => {
                         println!("  {} {}", "✗".bright_red(), msg);
-                        failed_count += 1;
+                        record_failure(&mut failed, name, msg);
                     }
                     Err(_) => {
                         println!("  {} Task panicked", "✗".bright_red());
-                        failed_count += 1;
+                        record_failure(&mut failed, name, "task panicked".to_string());
                     }
                 }
             }
@@ -944,7 +1350,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                 preflight.display_name.bright_cyan()
             );
 
-            let pb = ProgressBar::new(preflight.entry_count);
+            let pb = progress_bar(preflight.entry_count);
             pb.set_style(
                 ProgressStyle::default_bar()
                     .template("  [{bar:40.cyan/dim}] {pos}/{len} files")
@@ -952,6 +1358,9 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                     .progress_chars("█░░"),
             );
             pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            emit(ProgressEvent::Extracting {
+                name: preflight.display_name.clone(),
+            });
 
             match extract_bundle_with_preflight(&preflight, pb.clone()) {
                 Ok(()) => {
@@ -962,12 +1371,16 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                         preflight.display_name.bright_cyan(),
                         "(extracted)".dimmed()
                     );
+                    emit(ProgressEvent::Installed {
+                        name: preflight.display_name.clone(),
+                        version: None,
+                    });
                     bundle_installed += 1;
                 }
                 Err(msg) => {
                     pb.finish_and_clear();
                     println!("  {} {}: {}", "✗".bright_red(), preflight.display_name, msg);
-                    bundle_failed += 1;
+                    record_failure(&mut failed, preflight.display_name.clone(), msg);
                 }
             }
         } else {
@@ -983,22 +1396,29 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                 }
             );
 
-            let mp = MultiProgress::new();
+            let mp = multi_progress();
             let style = ProgressStyle::default_bar()
                 .template("  {msg:<30} [{bar:25.cyan/dim}] {pos:>5}/{len:<5}")
                 .unwrap()
                 .progress_chars("█░░");
 
             // Create progress bars and spawn extraction tasks
+            let names: Vec<String> = bundle_preflights
+                .iter()
+                .map(|p| p.display_name.clone())
+                .collect();
             let handles: Vec<_> = bundle_preflights
                 .into_iter()
                 .map(|preflight| {
-                    let pb = mp.add(ProgressBar::new(preflight.entry_count));
+                    let pb = mp.add(progress_bar(preflight.entry_count));
                     pb.set_style(style.clone());
                     pb.set_message(preflight.display_name.clone());
                     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
                     let display_name = preflight.display_name.clone();
+                    emit(ProgressEvent::Extracting {
+                        name: display_name.clone(),
+                    });
                     tokio::task::spawn_blocking(move || {
                         let result = extract_bundle_with_preflight(&preflight, pb.clone());
                         pb.finish_and_clear();
@@ -1011,7 +1431,7 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
             let results = futures_util::future::join_all(handles).await;
 
             // Print results
-            for result in results {
+            for (display_name, result) in names.into_iter().zip(results) {
                 match result {
                     Ok((name, Ok(()))) => {
                         println!(
@@ -1020,29 +1440,32 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
                             name.bright_cyan(),
                             "(extracted)".dimmed()
                         );
+                        emit(ProgressEvent::Installed {
+                            name: name.clone(),
+                            version: None,
+                        });
                         bundle_installed += 1;
                     }
                     Ok((name, Err(msg))) => {
                         println!("  {} {}: {}", "✗".bright_red(), name, msg);
-                        bundle_failed += 1;
+                        record_failure(&mut failed, name, msg);
                     }
                     Err(_) => {
                         println!("  {} Extraction task panicked", "✗".bright_red());
-                        bundle_failed += 1;
+                        record_failure(
+                            &mut failed,
+                            display_name,
+                            "extraction task panicked".to_string(),
+                        );
                     }
                 }
             }
         }
-
-        failed.extend(std::iter::repeat_n(
-            ("bundle".to_string(), "extraction failed".to_string()),
-            bundle_failed,
-        ));
     }
 
-    // Combined summary at the end
+    // Combined summary at the end (preflight failures included)
     let total_installed = installed_count + local_count;
-    let total_failed = failed_count + bundle_failed;
+    let total_failed = failed.len();
     let has_any = total_installed > 0
         || bundle_installed > 0
         || total_failed > 0
@@ -1090,7 +1513,12 @@ pub async fn add_tools(names: &[String], platform: Option<&str>) -> ToolResult<(
         println!("  {}", parts.join(", "));
     }
 
-    Ok(())
+    Ok(InstallSummary {
+        installed: total_installed,
+        extracted: bundle_installed,
+        already_installed,
+        failed,
+    })
 }
 
 /// Check which tools need to be installed (preflight phase, no side effects).
@@ -1110,8 +1538,10 @@ pub async fn preflight_ensure(
     let mut to_check: Vec<String> = Vec::new();
 
     for name in names {
-        // Check if already installed
-        if let Ok(Some(_)) = resolver.resolve_tool(name).await {
+        // Check if already installed. A tag can move, so only the registry knows which
+        // version it names
+        let tagged = name.parse::<PluginRef>().is_ok_and(|p| p.tag().is_some());
+        if !tagged && let Ok(Some(_)) = resolver.resolve_tool(name).await {
             result.already_installed.push(name.clone());
             continue;
         }
@@ -1142,7 +1572,7 @@ pub async fn preflight_ensure(
     // Phase 2: Run preflight for tools to install (no output here - just gather info)
     let preflight_futures: Vec<_> = to_check
         .iter()
-        .map(|name| preflight_tool(name, platform))
+        .map(|name| preflight_tool(name, platform, None))
         .collect();
     let preflight_results = join_all(preflight_futures).await;
 
@@ -1169,6 +1599,62 @@ pub async fn preflight_ensure(
     Ok(result)
 }
 
+/// Verify that every tool is already installed, without installing anything.
+///
+/// Used by `tool install --locked`. References are resolved with [`preflight_ensure`],
+/// so pinned versions and tags must match what is installed. Tools that would need
+/// installing are listed and the command fails, so CI can confirm the environment is
+/// already in place.
+pub async fn locked_install(names: &[String], platform: Option<&str>) -> ToolResult<()> {
+    let preflight = preflight_ensure(names, platform).await?;
+    let mut missing = 0usize;
+
+    for name in names {
+        if preflight.already_installed.contains(name) {
+            println!("  {} Installed {}", "✓".bright_green(), name.bright_cyan());
+            continue;
+        }
+
+        missing += 1;
+        if let Some((_, pf)) = preflight.to_install.iter().find(|(n, _)| n == name) {
+            println!(
+                "  {} Missing {} (would install {})",
+                "✗".bright_red(),
+                name.bright_white().bold(),
+                pf.version
+            );
+        } else if let Some((_, msg)) = preflight.failed.iter().find(|(n, _)| n == name) {
+            println!(
+                "  {} {}: {}",
+                "✗".bright_red(),
+                name.bright_white().bold(),
+                msg
+            );
+        } else {
+            println!(
+                "  {} Missing {}",
+                "✗".bright_red(),
+                name.bright_white().bold()
+            );
+        }
+    }
+
+    if missing > 0 {
+        return Err(ToolError::Generic(format!(
+            "{} of {} {} not installed (--locked doesn't install anything)",
+            missing,
+            names.len(),
+            if names.len() == 1 {
+                "tool is"
+            } else {
+                "tools are"
+            }
+        )));
+    }
+
+    Ok(())
+}
+
 /// Execute the install based on preflight results.
 ///
 /// Downloads and installs tools that were identified in the preflight phase.
@@ -1217,7 +1703,7 @@ pub async fn execute_ensure(preflight: EnsurePreflight) -> ToolResult<EnsureInst
         // Single package: show progress bar
         let (name, preflight) = registry_preflights.remove(0);
 
-        let pb = ProgressBar::new(preflight.download_size);
+        let pb = progress_bar(preflight.download_size);
         pb.set_style(
             ProgressStyle::default_bar()
                 .template("  [{bar:40.cyan/dim}] {bytes}/{total_bytes} {bytes_per_sec}")
@@ -1246,7 +1732,7 @@ pub async fn execute_ensure(preflight: EnsurePreflight) -> ToolResult<EnsureInst
         }
     } else {
         // Multiple packages: parallel download with multi-progress
-        let mp = MultiProgress::new();
+        let mp = multi_progress();
         let style = ProgressStyle::default_bar()
             .template("  {msg:<30} [{bar:25.cyan/dim}] {bytes:>10}/{total_bytes:<10}")
             .unwrap()
@@ -1255,7 +1741,7 @@ pub async fn execute_ensure(preflight: EnsurePreflight) -> ToolResult<EnsureInst
         let handles: Vec<_> = registry_preflights
             .into_iter()
             .map(|(name, preflight)| {
-                let pb = mp.add(ProgressBar::new(preflight.download_size));
+                let pb = mp.add(progress_bar(preflight.download_size));
                 pb.set_style(style.clone());
                 pb.set_message(format!("{}/{}", preflight.namespace, preflight.tool_name));
                 pb.enable_steady_tick(std::time::Duration::from_millis(100));
@@ -1300,7 +1786,6 @@ pub async fn execute_ensure(preflight: EnsurePreflight) -> ToolResult<EnsureInst
 
 /// Extract a ZIP bundle to a directory.
 fn extract_bundle(bundle_path: &std::path::Path, target_dir: &std::path::Path) -> ToolResult<()> {
-    use std::io::Read;
     use zip::ZipArchive;
 
     let file = std::fs::File::open(bundle_path)
@@ -1309,54 +1794,17 @@ fn extract_bundle(bundle_path: &std::path::Path, target_dir: &std::path::Path) -
     let mut archive = ZipArchive::new(file)
         .map_err(|e| ToolError::Generic(format!("Failed to read ZIP archive: {}", e)))?;
 
-    for i in 0..archive.len() {
-        let mut entry = archive
-            .by_index(i)
-            .map_err(|e| ToolError::Generic(format!("Failed to read archive entry: {}", e)))?;
-
-        let entry_path = entry
-            .enclosed_name()
-            .ok_or_else(|| ToolError::Generic("Invalid entry path in archive".into()))?;
-
-        let dest_path = target_dir.join(entry_path);
-
-        if let Some(parent) = dest_path.parent() {
-            std::fs::create_dir_all(parent).map_err(|e| {
-                ToolError::Generic(format!("Failed to create directory {:?}: {}", parent, e))
-            })?;
-        }
-
-        #[cfg(unix)]
-        let unix_mode = entry.unix_mode();
-
-        if entry.is_dir() {
-            std::fs::create_dir_all(&dest_path).map_err(|e| {
-                ToolError::Generic(format!("Failed to create directory {:?}: {}", dest_path, e))
-            })?;
-        } else {
-            let mut content = Vec::new();
-            entry
-                .read_to_end(&mut content)
-                .map_err(|e| ToolError::Generic(format!("Failed to read entry content: {}", e)))?;
-
-            std::fs::write(&dest_path, &content).map_err(|e| {
-                ToolError::Generic(format!("Failed to write file {:?}: {}", dest_path, e))
-            })?;
-
-            #[cfg(unix)]
-            if let Some(mode) = unix_mode {
-                use std::os::unix::fs::PermissionsExt;
-                let permissions = std::fs::Permissions::from_mode(mode);
-                std::fs::set_permissions(&dest_path, permissions).map_err(|e| {
-                    ToolError::Generic(format!(
-                        "Failed to set permissions on {:?}: {}",
-                        dest_path, e
-                    ))
-                })?;
-            }
-        }
-    }
-
+    // Don't leave a partial install behind
+    extract_archive(&mut archive, target_dir, &ExtractLimits::from_env(), || {}).map_err(|e| {
+        let _ = std::fs::remove_dir_all(target_dir);
+        ToolError::Generic(format!("Failed to extract bundle: {}", e))
+    })?;
+
+    verify_entry_point(target_dir).map_err(|e| {
+        let _ = std::fs::remove_dir_all(target_dir);
+        ToolError::Generic(format!("Invalid bundle: {}", e))
+    })?;
+    restore_entry_point_mode(target_dir)?;
     Ok(())
 }
 
@@ -1365,7 +1813,6 @@ fn extract_bundle_with_preflight(
     preflight: &BundlePreflight,
     pb: ProgressBar,
 ) -> Result<(), String> {
-    use std::io::Read;
     use zip::ZipArchive;
 
     // Create target directory
@@ -1379,50 +1826,41 @@ fn extract_bundle_with_preflight(
     let mut archive =
         ZipArchive::new(file).map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
 
-    for i in 0..archive.len() {
-        let mut entry = archive
-            .by_index(i)
-            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
-
-        let entry_path = entry
-            .enclosed_name()
-            .ok_or_else(|| "Invalid entry path in archive".to_string())?;
-
-        let dest_path = preflight.target_dir.join(entry_path);
-
-        if let Some(parent) = dest_path.parent() {
-            std::fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create directory {:?}: {}", parent, e))?;
-        }
-
-        #[cfg(unix)]
-        let unix_mode = entry.unix_mode();
-
-        if entry.is_dir() {
-            std::fs::create_dir_all(&dest_path)
-                .map_err(|e| format!("Failed to create directory {:?}: {}", dest_path, e))?;
-        } else {
-            let mut content = Vec::new();
-            entry
-                .read_to_end(&mut content)
-                .map_err(|e| format!("Failed to read entry content: {}", e))?;
-
-            std::fs::write(&dest_path, &content)
-                .map_err(|e| format!("Failed to write file {:?}: {}", dest_path, e))?;
-
-            #[cfg(unix)]
-            if let Some(mode) = unix_mode {
-                use std::os::unix::fs::PermissionsExt;
-                let permissions = std::fs::Permissions::from_mode(mode);
-                std::fs::set_permissions(&dest_path, permissions)
-                    .map_err(|e| format!("Failed to set permissions on {:?}: {}", dest_path, e))?;
-            }
-        }
+    // Don't leave a partial install behind
+    let limits = ExtractLimits::from_env();
+    extract_archive(&mut archive, &preflight.target_dir, &limits, || pb.inc(1)).map_err(|e| {
+        let _ = std::fs::remove_dir_all(&preflight.target_dir);
+        format!("Failed to extract bundle: {}", e)
+    })?;
+
+    verify_entry_point(&preflight.target_dir).map_err(|e| {
+        let _ = std::fs::remove_dir_all(&preflight.target_dir);
+        format!("Invalid bundle: {}", e)
+    })?;
+    restore_entry_point_mode(&preflight.target_dir)
+        .map_err(|e| format!("Failed to set entry point permissions: {}", e))?;
+    dedup_install(&preflight.target_dir);
+    Ok(())
+}
 
-        pb.inc(1);
+/// Hard-link an install's files to the shared object store when dedup is enabled.
+///
+/// Failures only cost disk space, so they are logged rather than failing the install.
+fn dedup_install(target_dir: &Path) {
+    if !dedup_enabled() {
+        return;
     }
 
-    Ok(())
+    match dedup_dir(target_dir, &DEFAULT_OBJECTS_PATH) {
+        Ok(stats) => tracing::debug!(
+            "Deduplicated {}: {} linked, {} stored, {} saved",
+            target_dir.display(),
+            stats.linked,
+            stats.stored,
+            format_size(stats.saved_bytes)
+        ),
+        Err(e) => tracing::warn!("Failed to deduplicate {}: {}", target_dir.display(), e),
+    }
 }
 
 /// Check if the input looks like a local path rather than a registry reference.
@@ -1437,14 +1875,6 @@ fn is_local_path(input: &str) -> bool {
         || PathBuf::from(input).join(MCPB_MANIFEST_FILE).exists()
 }
 
-/// Check if the input looks like a bundle file (.mcpb or .mcpbx).
-fn is_bundle_file(input: &str) -> bool {
-    use crate::constants::{MCPB_EXT, MCPBX_EXT};
-
-    let lower = input.to_lowercase();
-    lower.ends_with(&format!(".{}", MCPB_EXT)) || lower.ends_with(&format!(".{}", MCPBX_EXT))
-}
-
 /// Pre-flight check for a bundle file. Validates the bundle and returns metadata.
 fn preflight_bundle_file(path: &str) -> PreflightResult {
     use crate::constants::DEFAULT_TOOLS_PATH;
@@ -1518,6 +1948,10 @@ fn preflight_bundle_file(path: &str) -> PreflightResult {
     };
     let version = manifest.version.clone();
 
+    if let Some(mismatch) = manifest.bundle_extension_mismatch(&source_path) {
+        println!("  {} {}", "!".bright_yellow(), mismatch);
+    }
+
     // Build target directory name (unnamespaced)
     let display_name = match &version {
         Some(v) => format!("{}@{}", tool_name, v),
@@ -1629,14 +2063,8 @@ async fn install_local_tool(path: &str) -> InstallResult {
         }
 
         // Remove existing (symlink or directory)
-        if target_path.is_symlink() || target_path.is_file() {
-            if let Err(e) = std::fs::remove_file(&target_path) {
-                let msg = format!("Failed to remove existing link: {}", e);
-                println!("  {} {}", "✗".bright_red(), msg);
-                return InstallResult::Failed(msg);
-            }
-        } else if let Err(e) = std::fs::remove_dir_all(&target_path) {
-            let msg = format!("Failed to remove existing directory: {}", e);
+        if let Err(e) = remove_install(&target_path, &DEFAULT_OBJECTS_PATH) {
+            let msg = format!("Failed to remove existing install: {}", e);
             println!("  {} {}", "✗".bright_red(), msg);
             return InstallResult::Failed(msg);
         }
@@ -1739,15 +2167,8 @@ pub fn link_local_tool_force(
 
     // Remove existing if present
     if target_path.exists() || target_path.is_symlink() {
-        if target_path.is_symlink() || target_path.is_file() {
-            std::fs::remove_file(&target_path).map_err(|e| {
-                ToolError::Generic(format!("Failed to remove existing link: {}", e))
-            })?;
-        } else {
-            std::fs::remove_dir_all(&target_path).map_err(|e| {
-                ToolError::Generic(format!("Failed to remove existing directory: {}", e))
-            })?;
-        }
+        remove_install(&target_path, &DEFAULT_OBJECTS_PATH)
+            .map_err(|e| ToolError::Generic(format!("Failed to remove existing install: {}", e)))?;
     }
 
     // Ensure parent directory exists
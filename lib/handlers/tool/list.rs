@@ -71,14 +71,8 @@ pub async fn list_tools(
             } else {
                 println!("  {} No tools installed", "✗".bright_red());
                 println!("\n  {}", "Searched:".dimmed());
-                if let Ok(cwd) = std::env::current_dir() {
-                    println!("  · {}", cwd.join("tools").display().to_string().dimmed());
-                }
-                if let Some(home) = dirs::home_dir() {
-                    println!(
-                        "  · {}",
-                        home.join(".tool/tools").display().to_string().dimmed()
-                    );
+                for search_path in resolver.search_paths() {
+                    println!("  · {}", search_path.display().to_string().dimmed());
                 }
             }
         }
@@ -283,20 +277,29 @@ pub async fn list_tools(
         return Ok(());
     }
 
-    // Concise output: Header + TSV format
+    // Concise output: Header + TSV format (same conventions as `tool search -c`)
     if concise {
-        use crate::concise::quote;
-        if !no_header {
-            println!("#name\ttype\tpath");
-        }
-        for entry in &tool_entries {
-            println!(
-                "{}\t{}\t{}",
-                entry.name,
-                entry.tool_type,
-                quote(&entry.path.display().to_string())
-            );
-        }
+        use crate::concise::{ConciseToolEntry, format_tool_list};
+        let descriptions: Vec<Option<String>> = tool_entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .description
+                    .as_deref()
+                    .and_then(|d| format_description(d, false, ""))
+            })
+            .collect();
+        let entries: Vec<ConciseToolEntry> = tool_entries
+            .iter()
+            .zip(&descriptions)
+            .map(|(entry, description)| ConciseToolEntry {
+                name: &entry.name,
+                tool_type: &entry.tool_type,
+                description: description.as_deref(),
+                path: &entry.path,
+            })
+            .collect();
+        println!("{}", format_tool_list(&entries, no_header));
         return Ok(());
     }
 
@@ -326,6 +329,7 @@ pub async fn list_tools(
                 "Location".dimmed(),
                 entry.path.display().to_string().dimmed()
             );
+            print_source(&resolver, &entry.path);
 
             // Try to get full tool info
             let resolved_plugin = match load_tool_from_path(&entry.path) {
@@ -443,6 +447,7 @@ pub async fn list_tools(
                 "Location".dimmed(),
                 entry.path.display().to_string().dimmed()
             );
+            print_source(&resolver, &entry.path);
             println!();
         }
     }
@@ -450,6 +455,20 @@ pub async fn list_tools(
     Ok(())
 }
 
+/// Print the search path a tool came from, when more than one is configured.
+fn print_source(resolver: &FilePluginResolver, path: &Path) {
+    if resolver.search_paths().len() < 2 {
+        return;
+    }
+    if let Some(source) = resolver.search_path_for(path) {
+        println!(
+            "  · {}     {}",
+            "Source".dimmed(),
+            source.display().to_string().dimmed()
+        );
+    }
+}
+
 /// Resolve a tool reference to a path.
 ///
 /// Resolution order:
@@ -0,0 +1,91 @@
+//! Server log viewing handler.
+
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::{ToolError, ToolResult};
+use crate::mcp::server_log_path;
+
+use super::common::{ResolvedTool, resolve_tool};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// How often a followed log is checked for new output.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Print the captured stderr of a tool's server, optionally following new output.
+///
+/// Server stderr is captured whenever a stdio server is started by `tool call`,
+/// `tool run`, `tool info`, or `tool dev`.
+pub async fn tool_logs(tool: String, follow: bool, lines: usize) -> ToolResult<()> {
+    let ResolvedTool { plugin, .. } = resolve_tool(&tool, false, true).await?;
+    let name = plugin.template.name.clone().ok_or_else(|| {
+        ToolError::Generic(format!("Tool '{}' has no name in its manifest", tool))
+    })?;
+
+    let path = server_log_path(&name);
+    if !path.exists() && !follow {
+        return Err(ToolError::Generic(format!(
+            "No logs for '{}' yet. Logs are captured when the server is started (e.g. with `tool run`).",
+            name
+        )));
+    }
+
+    let mut offset = print_tail(&path, lines)?;
+    if !follow {
+        return Ok(());
+    }
+
+    loop {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+        offset = print_appended(&path, offset)?;
+    }
+}
+
+/// Print the last `lines` lines of a log. Returns the file length read up to.
+fn print_tail(path: &Path, lines: usize) -> ToolResult<u64> {
+    let Ok(content) = std::fs::read(path) else {
+        return Ok(0);
+    };
+
+    let text = String::from_utf8_lossy(&content);
+    let all: Vec<&str> = text.lines().collect();
+    let mut stdout = std::io::stdout().lock();
+    for line in &all[all.len().saturating_sub(lines)..] {
+        writeln!(stdout, "{}", line)?;
+    }
+
+    Ok(content.len() as u64)
+}
+
+/// Print anything written to a log since `offset`. Returns the new offset.
+///
+/// A log that shrank (rotated or recreated) is read again from the start.
+fn print_appended(path: &Path, offset: u64) -> ToolResult<u64> {
+    let Ok(mut file) = std::fs::File::open(path) else {
+        return Ok(0);
+    };
+
+    let len = file.metadata()?.len();
+    let offset = if len < offset { 0 } else { offset };
+    if len == offset {
+        return Ok(offset);
+    }
+
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(&buf)?;
+    stdout.flush()?;
+
+    Ok(offset + buf.len() as u64)
+}
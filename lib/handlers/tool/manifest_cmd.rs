@@ -0,0 +1,521 @@
+//! Manifest field get/set command handlers.
+
+use crate::commands::ManifestCommand;
+use crate::constants::MCPB_MANIFEST_FILE;
+use crate::error::{ToolError, ToolResult};
+use crate::mcpb::{
+    McpbManifest, TOOL_STORE_NAMESPACE, find_manifest_path, from_str_relaxed, has_comments,
+};
+use crate::output::print_json;
+use crate::validate::validate_manifest_content;
+use colored::Colorize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A single step in a manifest field path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// Object key.
+    Key(String),
+
+    /// Array index.
+    Index(usize),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Handle manifest subcommands.
+pub fn manifest_command(cmd: ManifestCommand, json: bool, concise: bool) -> ToolResult<()> {
+    match cmd {
+        ManifestCommand::Get { field, path } => manifest_get(&field, &path, json, concise),
+        ManifestCommand::Set { field, value, path } => manifest_set(&field, &value, &path, concise),
+    }
+}
+
+/// Print a manifest field.
+///
+/// Strings are printed bare so scripts can capture them; other values as JSON. With
+/// `--json`, the value is wrapped in the standard JSON envelope.
+fn manifest_get(field: &str, path: &str, json: bool, concise: bool) -> ToolResult<()> {
+    let (_, raw) = read_raw_manifest(path)?;
+    let segments = parse_field_path(field)?;
+
+    let value = get_field(&raw, &segments).ok_or_else(|| {
+        ToolError::Generic(format!(
+            "Field `{}` not found in {}",
+            field, MCPB_MANIFEST_FILE
+        ))
+    })?;
+
+    match value {
+        _ if json => print_json(value, concise)?,
+        Value::String(s) => println!("{}", s),
+        other => println!("{}", serde_json::to_string_pretty(other)?),
+    }
+
+    Ok(())
+}
+
+/// Set a manifest field, validating the edited manifest before writing it.
+fn manifest_set(field: &str, value: &str, path: &str, concise: bool) -> ToolResult<()> {
+    // Values are JSON when they parse, otherwise plain strings
+    let new_value =
+        serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    update_manifest_field(path, field, new_value.clone())?;
+
+    if !concise {
+        println!(
+            "  {} Set {} = {}",
+            "✓".bright_green(),
+            field.bold(),
+            serde_json::to_string(&new_value)?.bright_cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Set a field in a directory's manifest, validating the result before writing.
+///
+/// The edited raw JSON is what gets written, so keys the typed manifest doesn't
+/// model are preserved. Returns the path of the manifest that was written.
+pub(super) fn update_manifest_field(
+    path: &str,
+    field: &str,
+    new_value: Value,
+) -> ToolResult<PathBuf> {
+    let (manifest_path, mut raw) = read_raw_manifest(path)?;
+    ensure_rewritable(&manifest_path)?;
+    let segments = parse_field_path(field)?;
+    set_field(&mut raw, &segments, new_value.clone())?;
+
+    // The typed manifest must accept the edit and carry the new value
+    let manifest: McpbManifest = serde_json::from_value(raw.clone()).map_err(|e| {
+        ToolError::Generic(format!(
+            "Setting `{}` produces an invalid manifest: {}",
+            field, e
+        ))
+    })?;
+    let typed = serde_json::to_value(&manifest)?;
+    if get_field(&typed, &segments) != Some(&new_value) {
+        return Err(ToolError::Generic(format!(
+            "`{}` is not a manifest field",
+            field
+        )));
+    }
+
+    let content = format!("{}\n", serde_json::to_string_pretty(&raw)?);
+    let result = validate_manifest_content(Path::new(path), &content);
+    if !result.is_valid() {
+        return Err(ToolError::ValidationFailed(result));
+    }
+
+    std::fs::write(&manifest_path, &content)?;
+
+    Ok(manifest_path)
+}
+
+/// Fail if the manifest at `manifest_path` has comments.
+///
+/// Edited manifests are written back as strict JSON, which would silently drop them, so
+/// commented manifests (usually `manifest.json5`) have to be edited by hand.
+pub(super) fn ensure_rewritable(manifest_path: &Path) -> ToolResult<()> {
+    let content = std::fs::read_to_string(manifest_path)?;
+    if has_comments(&content) {
+        return Err(ToolError::Generic(format!(
+            "{} has comments that rewriting it would remove; edit it by hand instead",
+            manifest_path.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Read a directory's manifest (manifest.json or manifest.json5) as raw JSON.
+///
+/// Returns the manifest path alongside the parsed value.
+pub(super) fn read_raw_manifest(path: &str) -> ToolResult<(PathBuf, Value)> {
+    let manifest_path = find_manifest_path(Path::new(path));
+    if !manifest_path.exists() {
+        return Err(ToolError::ManifestNotFound(PathBuf::from(path)));
+    }
+
+    let content = std::fs::read_to_string(&manifest_path)?;
+    let raw = from_str_relaxed(&content)?;
+    Ok((manifest_path, raw))
+}
+
+/// Parse a field path like `server.mcp_config.args[0]` or `_meta["store.tool.mcpb"].scripts`.
+///
+/// Dotted keys that don't exist are re-joined when resolving, so
+/// `_meta.store.tool.mcpb.scripts` also works for existing namespaces.
+fn parse_field_path(path: &str) -> ToolResult<Vec<Segment>> {
+    let invalid = || ToolError::Generic(format!("Invalid field path `{}`", path));
+
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut after_bracket = false;
+    let mut chars = path.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                // Reject empty segments such as `a..b` or a leading dot
+                if current.is_empty() && !after_bracket {
+                    return Err(invalid());
+                }
+                if !current.is_empty() {
+                    segments.push(Segment::Key(std::mem::take(&mut current)));
+                }
+                after_bracket = false;
+                if chars.peek().is_none() {
+                    return Err(invalid());
+                }
+            }
+            '[' => {
+                if !current.is_empty() {
+                    segments.push(Segment::Key(std::mem::take(&mut current)));
+                }
+                let quoted = chars.peek() == Some(&'"');
+                if quoted {
+                    chars.next();
+                }
+                let close = if quoted { '"' } else { ']' };
+
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(c) if c == close => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(invalid()),
+                    }
+                }
+
+                if quoted {
+                    if chars.next() != Some(']') {
+                        return Err(invalid());
+                    }
+                    segments.push(Segment::Key(inner));
+                } else {
+                    segments.push(Segment::Index(inner.parse().map_err(|_| invalid())?));
+                }
+                after_bracket = true;
+            }
+            _ => {
+                current.push(c);
+                after_bracket = false;
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push(Segment::Key(current));
+    }
+
+    if segments.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(segments)
+}
+
+/// Re-join dotted keys that only exist as a single key, e.g. `store`, `tool`, `mcpb`.
+///
+/// The tool.store namespace is always joined so it can be created by `set`.
+fn normalize_segments(root: &Value, segments: &[Segment]) -> Vec<Segment> {
+    let mut normalized = Vec::with_capacity(segments.len());
+    let mut current = Some(root);
+    let mut i = 0;
+
+    while i < segments.len() {
+        let mut segment = segments[i].clone();
+        let mut consumed = 1;
+
+        // Missing parents (current is None) are created by `set`, so only the namespace can match
+        let map = match current {
+            Some(Value::Object(map)) => Some(map),
+            _ => None,
+        };
+        if (current.is_none() || map.is_some())
+            && let Segment::Key(key) = &segments[i]
+            && !map.is_some_and(|m| m.contains_key(key))
+        {
+            // Find the longest run of following keys that names an existing key
+            let mut joined = key.clone();
+            for (offset, next) in segments[i + 1..].iter().enumerate() {
+                let Segment::Key(next) = next else { break };
+                joined.push('.');
+                joined.push_str(next);
+                if map.is_some_and(|m| m.contains_key(&joined)) || joined == TOOL_STORE_NAMESPACE {
+                    segment = Segment::Key(joined.clone());
+                    consumed = offset + 2;
+                }
+            }
+        }
+
+        current = current.and_then(|v| step(v, &segment));
+        normalized.push(segment);
+        i += consumed;
+    }
+
+    normalized
+}
+
+/// Step into a value by one segment.
+fn step<'a>(value: &'a Value, segment: &Segment) -> Option<&'a Value> {
+    match (value, segment) {
+        (Value::Object(map), Segment::Key(key)) => map.get(key),
+        (Value::Array(items), Segment::Index(index)) => items.get(*index),
+        (Value::Array(items), Segment::Key(key)) => items.get(key.parse::<usize>().ok()?),
+        _ => None,
+    }
+}
+
+/// Get the value at a field path.
+fn get_field<'a>(root: &'a Value, segments: &[Segment]) -> Option<&'a Value> {
+    normalize_segments(root, segments)
+        .iter()
+        .try_fold(root, |value, segment| step(value, segment))
+}
+
+/// Set the value at a field path, creating missing objects along the way.
+fn set_field(root: &mut Value, segments: &[Segment], new_value: Value) -> ToolResult<()> {
+    let segments = normalize_segments(root, segments);
+    let (last, parents) = segments
+        .split_last()
+        .ok_or_else(|| ToolError::Generic("Empty field path".into()))?;
+
+    let mut current = root;
+    for segment in parents {
+        current = child_mut(current, segment, true)?;
+    }
+
+    match (current, last) {
+        (Value::Object(map), Segment::Key(key)) => {
+            map.insert(key.clone(), new_value);
+        }
+        (current, segment) => *child_mut(current, segment, false)? = new_value,
+    }
+
+    Ok(())
+}
+
+/// Get a mutable child, optionally creating a missing object key.
+fn child_mut<'a>(
+    value: &'a mut Value,
+    segment: &Segment,
+    create: bool,
+) -> ToolResult<&'a mut Value> {
+    let describe = |segment: &Segment| match segment {
+        Segment::Key(key) => key.clone(),
+        Segment::Index(index) => format!("[{}]", index),
+    };
+
+    match (value, segment) {
+        (Value::Object(map), Segment::Key(key)) => {
+            if create {
+                Ok(map
+                    .entry(key.clone())
+                    .or_insert_with(|| Value::Object(Default::default())))
+            } else {
+                map.get_mut(key)
+                    .ok_or_else(|| ToolError::Generic(format!("Field `{}` not found", key)))
+            }
+        }
+        (Value::Array(items), segment) => {
+            let index = match segment {
+                Segment::Index(index) => Some(*index),
+                Segment::Key(key) => key.parse::<usize>().ok(),
+            };
+            let len = items.len();
+            index.and_then(|i| items.get_mut(i)).ok_or_else(|| {
+                ToolError::Generic(format!(
+                    "Index `{}` out of bounds for array of length {}",
+                    describe(segment),
+                    len
+                ))
+            })
+        }
+        (_, segment) => Err(ToolError::Generic(format!(
+            "Cannot set `{}` on a non-object value",
+            describe(segment)
+        ))),
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::MCPB_MANIFEST_JSON5_FILE;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_field_path() {
+        assert_eq!(
+            parse_field_path("server.mcp_config.args[0]").unwrap(),
+            vec![
+                Segment::Key("server".into()),
+                Segment::Key("mcp_config".into()),
+                Segment::Key("args".into()),
+                Segment::Index(0),
+            ]
+        );
+        assert_eq!(
+            parse_field_path(r#"_meta["store.tool.mcpb"].scripts"#).unwrap(),
+            vec![
+                Segment::Key("_meta".into()),
+                Segment::Key("store.tool.mcpb".into()),
+                Segment::Key("scripts".into()),
+            ]
+        );
+
+        assert!(parse_field_path("").is_err());
+        assert!(parse_field_path("a..b").is_err());
+        assert!(parse_field_path("a.").is_err());
+        assert!(parse_field_path("a[x]").is_err());
+        assert!(parse_field_path(r#"a["b"#).is_err());
+    }
+
+    #[test]
+    fn test_get_field_joins_dotted_namespace() {
+        let manifest = json!({
+            "version": "1.0.0",
+            "_meta": { "store.tool.mcpb": { "scripts": { "build": "make" } } }
+        });
+
+        let segments = parse_field_path("_meta.store.tool.mcpb.scripts.build").unwrap();
+        assert_eq!(get_field(&manifest, &segments), Some(&json!("make")));
+
+        let segments = parse_field_path("version").unwrap();
+        assert_eq!(get_field(&manifest, &segments), Some(&json!("1.0.0")));
+
+        let segments = parse_field_path("missing.field").unwrap();
+        assert_eq!(get_field(&manifest, &segments), None);
+    }
+
+    #[test]
+    fn test_set_field_creates_tool_store_namespace() {
+        let mut manifest = json!({ "version": "1.0.0" });
+
+        let segments = parse_field_path("_meta.store.tool.mcpb.cli_version").unwrap();
+        set_field(&mut manifest, &segments, json!("^0.5")).unwrap();
+        assert_eq!(
+            manifest["_meta"]["store.tool.mcpb"]["cli_version"],
+            json!("^0.5")
+        );
+    }
+
+    #[test]
+    fn test_set_field() {
+        let mut manifest = json!({
+            "version": "1.0.0",
+            "server": { "mcp_config": { "args": ["a", "b"] } },
+            "_meta": { "store.tool.mcpb": { "scripts": {} } }
+        });
+
+        set_field(
+            &mut manifest,
+            &parse_field_path("version").unwrap(),
+            json!("1.2.3"),
+        )
+        .unwrap();
+        assert_eq!(manifest["version"], json!("1.2.3"));
+
+        set_field(
+            &mut manifest,
+            &parse_field_path("server.mcp_config.args[1]").unwrap(),
+            json!("c"),
+        )
+        .unwrap();
+        assert_eq!(manifest["server"]["mcp_config"]["args"], json!(["a", "c"]));
+
+        set_field(
+            &mut manifest,
+            &parse_field_path("_meta.store.tool.mcpb.scripts.test").unwrap(),
+            json!("cargo test"),
+        )
+        .unwrap();
+        assert_eq!(
+            manifest["_meta"]["store.tool.mcpb"]["scripts"]["test"],
+            json!("cargo test")
+        );
+
+        // Missing objects are created
+        set_field(
+            &mut manifest,
+            &parse_field_path("author.name").unwrap(),
+            json!("Jane"),
+        )
+        .unwrap();
+        assert_eq!(manifest["author"]["name"], json!("Jane"));
+
+        // Out-of-bounds indexes are rejected
+        assert!(
+            set_field(
+                &mut manifest,
+                &parse_field_path("server.mcp_config.args[5]").unwrap(),
+                json!("x"),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_update_manifest_field_preserves_unmodeled_keys() {
+        let dir = TempDir::new().unwrap();
+        let manifest = json!({
+            "manifest_version": "0.3",
+            "name": "my-tool",
+            "version": "1.0.0",
+            "description": "A tool",
+            "author": { "name": "Test" },
+            "x_custom": { "keep": true },
+            "server": {
+                "transport": "http",
+                "mcp_config": { "url": "https://api.example.com/mcp" }
+            }
+        });
+        std::fs::write(
+            dir.path().join(MCPB_MANIFEST_FILE),
+            serde_json::to_string_pretty(&manifest).unwrap(),
+        )
+        .unwrap();
+
+        let path = dir.path().to_str().unwrap();
+        update_manifest_field(path, "version", json!("1.2.3")).unwrap();
+
+        let (_, raw) = read_raw_manifest(path).unwrap();
+        assert_eq!(raw["version"], json!("1.2.3"));
+        assert_eq!(raw["x_custom"], json!({ "keep": true }));
+    }
+
+    #[test]
+    fn test_update_manifest_field_refuses_commented_manifest() {
+        let dir = TempDir::new().unwrap();
+        let content = r#"{
+  // Bumped by hand
+  "manifest_version": "0.3",
+  "name": "my-tool",
+  "version": "1.0.0",
+  "server": { "transport": "http", "mcp_config": { "url": "https://api.example.com/mcp" } },
+}
+"#;
+        let manifest_path = dir.path().join(MCPB_MANIFEST_JSON5_FILE);
+        std::fs::write(&manifest_path, content).unwrap();
+
+        let err = update_manifest_field(dir.path().to_str().unwrap(), "version", json!("1.2.3"))
+            .unwrap_err();
+        assert!(err.to_string().contains("has comments"));
+        assert_eq!(std::fs::read_to_string(&manifest_path).unwrap(), content);
+    }
+}
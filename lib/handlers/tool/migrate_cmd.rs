@@ -0,0 +1,158 @@
+//! Manifest schema migration command handler.
+
+use crate::error::{ToolError, ToolResult};
+use crate::mcpb::{
+    ManifestMigration, McpbManifest, find_manifest_path, from_str_relaxed, migrate_raw,
+};
+use crate::output::print_json;
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+use super::manifest_cmd::ensure_rewritable;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// JSON output for one migrated manifest.
+#[derive(Debug, Serialize)]
+struct MigrateOutput {
+    /// Manifest file path.
+    path: String,
+
+    /// Changes applied (or that would be applied with `--dry-run`).
+    changes: Vec<MigrateChangeOutput>,
+
+    /// Whether the manifest was rewritten.
+    written: bool,
+}
+
+/// A single change in [`MigrateOutput`].
+#[derive(Debug, Serialize)]
+struct MigrateChangeOutput {
+    field: String,
+    description: String,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Upgrade the manifests in `paths` to the current schema.
+///
+/// Migrated manifests are rewritten as strict JSON; with `dry_run`, nothing is written.
+pub fn migrate_manifests(
+    paths: Vec<String>,
+    schema: bool,
+    dry_run: bool,
+    json: bool,
+) -> ToolResult<()> {
+    if !schema {
+        return Err(ToolError::Generic(
+            "Nothing to migrate. Use --schema to upgrade manifests to the current schema.\n\
+             To convert an existing MCP server project, use --interactive or `tool init`."
+                .into(),
+        ));
+    }
+
+    let paths = if paths.is_empty() {
+        vec![".".to_string()]
+    } else {
+        paths
+    };
+
+    let mut outputs = Vec::new();
+    for path in &paths {
+        let manifest_path = find_manifest_path(Path::new(path));
+        let (changes, written) = migrate_manifest_file(&manifest_path, dry_run)?;
+
+        if !json {
+            print_migration(&manifest_path, &changes, dry_run);
+        }
+
+        outputs.push(MigrateOutput {
+            path: manifest_path.display().to_string(),
+            changes: changes
+                .into_iter()
+                .map(|c| MigrateChangeOutput {
+                    field: c.field,
+                    description: c.description,
+                })
+                .collect(),
+            written,
+        });
+    }
+
+    if json {
+        print_json(&outputs, false)?;
+    } else {
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Migrate a single manifest file, returning the changes and whether it was written.
+fn migrate_manifest_file(
+    manifest_path: &Path,
+    dry_run: bool,
+) -> ToolResult<(Vec<ManifestMigration>, bool)> {
+    let content = std::fs::read_to_string(manifest_path).map_err(|e| {
+        ToolError::Generic(format!("Failed to read {}: {}", manifest_path.display(), e))
+    })?;
+    let mut raw: Value = from_str_relaxed(&content).map_err(|e| {
+        ToolError::Generic(format!(
+            "Failed to parse {}: {}",
+            manifest_path.display(),
+            e
+        ))
+    })?;
+
+    // Migrate the raw JSON so fields the typed manifest doesn't model are kept
+    let changes = migrate_raw(&mut raw);
+    serde_json::from_value::<McpbManifest>(raw.clone()).map_err(|e| {
+        ToolError::Generic(format!(
+            "{} is invalid after migration: {}",
+            manifest_path.display(),
+            e
+        ))
+    })?;
+
+    if changes.is_empty() || dry_run {
+        return Ok((changes, false));
+    }
+
+    ensure_rewritable(manifest_path)?;
+    let content = format!("{}\n", serde_json::to_string_pretty(&raw)?);
+    std::fs::write(manifest_path, content)?;
+    Ok((changes, true))
+}
+
+/// Print the changes applied to one manifest.
+fn print_migration(manifest_path: &Path, changes: &[ManifestMigration], dry_run: bool) {
+    if changes.is_empty() {
+        println!(
+            "  {} {} is up to date",
+            "·".dimmed(),
+            manifest_path.display()
+        );
+        return;
+    }
+
+    let (marker, verb) = if dry_run {
+        ("~".bright_yellow(), "Would migrate")
+    } else {
+        ("✓".bright_green(), "Migrated")
+    };
+    println!(
+        "  {} {} {}",
+        marker,
+        verb,
+        manifest_path.display().to_string().bold()
+    );
+    for change in changes {
+        println!("    · {}: {}", change.field.bold(), change.description);
+    }
+}
@@ -2,22 +2,37 @@
 
 mod call;
 mod common;
+mod complete;
 mod config_cmd;
 mod detect_cmd;
+mod dev;
+mod diff;
+mod doctor;
 mod grep;
 mod host_cmd;
 mod info;
 mod init;
+mod inspect_cmd;
 mod install;
 mod list;
+mod logs_cmd;
+mod manifest_cmd;
+mod migrate_cmd;
 mod pack_cmd;
 mod preview;
 mod publish;
 mod run;
 mod scripts;
 mod search;
+mod security_cmd;
+mod template;
 mod uninstall;
+mod unpack_cmd;
 mod validate_cmd;
+mod vars_cmd;
+mod version_cmd;
+mod versions;
+mod yank;
 
 //--------------------------------------------------------------------------------------------------
 // Re-Exports
@@ -25,19 +40,36 @@ mod validate_cmd;
 
 pub use call::tool_call;
 pub use common::{PrepareToolOptions, PreparedTool, prepare_tool};
+pub use complete::complete;
 pub use config_cmd::{config_tool, load_tool_config};
 pub use detect_cmd::detect_mcpb;
+pub use dev::tool_dev;
+pub use diff::{ChangeKind, ManifestChange, compute_manifest_diff, diff_manifests};
+pub use doctor::doctor;
 pub use grep::grep_tool;
 pub use host_cmd::handle_host_command;
 pub use info::tool_info;
-pub use init::init_mcpb;
-pub use install::{LinkResult, add_tools, download_tools, link_local_tool, link_local_tool_force};
+pub use init::{init_mcpb, list_init_types, migrate_project_interactive};
+pub use inspect_cmd::inspect_bundle;
+pub use install::{
+    InstallSummary, LinkResult, add_tools, download_tools, dry_run_install, link_local_tool,
+    link_local_tool_force, locked_install,
+};
 pub use list::{ResolvedToolPath, list_tools, resolve_tool_path};
+pub use logs_cmd::tool_logs;
+pub use manifest_cmd::manifest_command;
+pub use migrate_cmd::migrate_manifests;
 pub use pack_cmd::pack_mcpb;
-pub use preview::tool_preview;
+pub use preview::{preview_bundle_files, tool_preview};
 pub use publish::publish_mcpb;
 pub use run::tool_run;
 pub use scripts::{list_scripts, run_external_script, run_script};
 pub use search::search_tools;
-pub use uninstall::remove_tools;
+pub use security_cmd::security_command;
+pub use uninstall::{UninstallSummary, prune_tools, remove_tools};
+pub use unpack_cmd::unpack_bundle;
 pub use validate_cmd::validate_mcpb;
+pub use vars_cmd::tool_vars;
+pub use version_cmd::{VersionBump, bump_version};
+pub use versions::tool_versions;
+pub use yank::{deprecate_version, yank_version};
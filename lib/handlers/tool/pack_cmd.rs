@@ -1,17 +1,23 @@
 //! Tool pack command handlers.
 
-use crate::error::{ToolError, ToolResult};
-use crate::mcpb::McpbManifest;
+use crate::cli_version::check_cli_version;
+use crate::constants::OS_ARCH_PLATFORMS;
+use crate::error::{EXIT_NOT_FOUND, EXIT_VALIDATION, ToolError, ToolResult};
+use crate::format::format_size;
+use crate::mcpb::{McpbManifest, from_str_relaxed};
 use crate::pack::{
-    PackError, PackOptions, PackProgress, PackResult, pack_bundle, pack_bundle_for_platform,
+    PackError, PackOptions, PackProgress, PackResult, PackStage, pack_bundle,
+    pack_bundle_for_platform, resolve_manifest_path,
 };
 use crate::styles::Spinner;
-use crate::validate::validate_manifest;
+use crate::validate::{validate_manifest, validate_manifest_content};
 use colored::Colorize;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use super::scripts::{POSTPACK_SCRIPT, PREPACK_SCRIPT, run_lifecycle_script};
+
 //--------------------------------------------------------------------------------------------------
 // Constants
 //--------------------------------------------------------------------------------------------------
@@ -24,22 +30,62 @@ const SCROLLING_FILE_COUNT: usize = 3;
 //--------------------------------------------------------------------------------------------------
 
 /// Pack a tool into an .mcpb bundle.
+///
+/// Runs the `prepack` and `postpack` scripts, if defined, around bundling. `exclude` and
+/// `include` patterns apply after `.mcpbignore`, with `include` taking precedence. A
+/// `manifest` path (relative to the current directory) is bundled as the root manifest.
+/// With `platform`, the bundle is packed with that platform's overrides applied.
+#[allow(clippy::too_many_arguments)]
 pub async fn pack_mcpb(
     path: Option<String>,
     output: Option<String>,
+    manifest: Option<String>,
     no_validate: bool,
     strict: bool,
     verbose: bool,
     multi_platform: bool,
+    platform: Option<String>,
+    checksums: bool,
+    exclude: Vec<String>,
+    include: Vec<String>,
+    follow_symlinks: bool,
 ) -> ToolResult<()> {
     let dir = path
         .map(PathBuf::from)
         .unwrap_or_else(|| std::env::current_dir().unwrap());
+    let manifest = manifest.map(|m| std::env::current_dir().unwrap().join(m));
+
+    // Reject a bad manifest override before running any scripts
+    if let Some(manifest) = &manifest {
+        resolve_manifest_path(&dir, Some(manifest))
+            .map_err(|e| ToolError::Generic(format!("Pack failed: {}", e)))?;
+    }
+
+    if let Some(platform) = &platform
+        && !is_valid_os_arch_platform(platform)
+    {
+        return Err(ToolError::Generic(format!(
+            "Invalid platform '{}'. Expected one of: {}",
+            platform,
+            OS_ARCH_PLATFORMS.join(", ")
+        )));
+    }
+
+    // Check the running CLI against the project's pinned version (fails with --strict)
+    check_cli_version(&dir, strict)?;
+
+    run_lifecycle_script(&dir, PREPACK_SCRIPT)?;
 
     // Strict validation: treat warnings as errors
     if strict && !no_validate {
         let spinner = Spinner::new("Validating manifest (strict)");
-        let validation = validate_manifest(&dir);
+        let validation = match &manifest {
+            Some(manifest) => {
+                let content = std::fs::read_to_string(manifest)?;
+                validate_manifest_content(&dir, &content)
+            }
+            None => validate_manifest(&dir),
+        };
         if !validation.is_strict_valid() {
             spinner.fail(Some("Validation failed"));
             println!();
@@ -70,27 +116,64 @@ pub async fn pack_mcpb(
                 }
             );
             println!("\n  Cannot pack with --strict. Fix errors and warnings, then retry.");
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
         }
         spinner.succeed(Some("Validation passed (strict)"));
     }
 
-    // Handle multi-platform packing
+    let output = match (&platform, output) {
+        (Some(platform), None) => Some(platform_bundle_path(&dir, manifest.as_deref(), platform)?),
+        (_, output) => output.map(PathBuf::from),
+    };
+
+    let options = PackOptions {
+        output,
+        manifest,
+        validate: !no_validate,
+        verbose,
+        emit_checksums: checksums,
+        exclude,
+        include,
+        follow_symlinks,
+        ..Default::default()
+    };
+
     if multi_platform {
-        return pack_multi_platform(&dir, no_validate, verbose).await;
+        pack_multi_platform(&dir, options).await?;
+    } else {
+        // Single bundle packing with progress bar
+        pack_single_bundle(&dir, options, platform.as_deref())?;
     }
 
-    // Single bundle packing with progress bar
-    pack_single_bundle(&dir, output, no_validate, verbose)
+    run_lifecycle_script(&dir, POSTPACK_SCRIPT)
 }
 
-/// Pack a single bundle with progress bar and scrolling file names.
-fn pack_single_bundle(
+/// Default output path for a platform bundle: `<name>@<version>-<platform>.<ext>`.
+fn platform_bundle_path(
     dir: &Path,
-    output: Option<String>,
-    no_validate: bool,
-    verbose: bool,
-) -> ToolResult<()> {
+    manifest: Option<&Path>,
+    platform: &str,
+) -> ToolResult<PathBuf> {
+    let manifest_path = resolve_manifest_path(dir, manifest)
+        .map_err(|e| ToolError::Generic(format!("Pack failed: {}", e)))?;
+    let manifest: McpbManifest = from_str_relaxed(&std::fs::read_to_string(manifest_path)?)
+        .map_err(|e| ToolError::Generic(format!("Failed to load manifest: {}", e)))?;
+
+    Ok(dir.join(format!(
+        "{}@{}-{}.{}",
+        manifest.name.as_deref().unwrap_or("bundle"),
+        manifest.version.as_deref().unwrap_or("0.0.0"),
+        platform,
+        manifest.bundle_extension()
+    )))
+}
+
+/// Pack a single bundle with progress bar and scrolling file names.
+///
+/// With `platform`, that platform's overrides are applied to the bundled manifest.
+fn pack_single_bundle(dir: &Path, base: PackOptions, platform: Option<&str>) -> ToolResult<()> {
     // Create multi-progress for progress bar + file lines
     let mp = MultiProgress::new();
 
@@ -124,17 +207,11 @@ fn pack_single_bundle(
     let pb_clone = pb.clone();
 
     let options = PackOptions {
-        output: output.map(PathBuf::from),
-        validate: !no_validate,
-        verbose,
-        extract_icon: false,
-        on_progress: Some(Arc::new(move |progress| match progress {
-            PackProgress::Started { total_files } => {
-                pb_clone.set_length(total_files as u64);
-            }
-            PackProgress::FileAdded { path, current } => {
-                pb_clone.set_position(current as u64);
+        on_progress: Some(Arc::new(move |progress: PackProgress| {
+            pb_clone.set_length(progress.total_files as u64);
+            pb_clone.set_position(progress.files_done as u64);
 
+            if let PackStage::FileAdded(path) = progress.stage {
                 // Update scrolling file display
                 let mut files = recent_files_clone.lock().unwrap();
                 files.push(path);
@@ -157,11 +234,14 @@ fn pack_single_bundle(
                     }
                 }
             }
-            PackProgress::Finished => {}
         })),
+        ..base
     };
 
-    let result = pack_bundle(dir, &options);
+    let result = match platform {
+        Some(platform) => pack_bundle_for_platform(dir, &options, Some(platform)),
+        None => pack_bundle(dir, &options),
+    };
 
     // Clear file lines and finish progress bar
     for line in &file_lines {
@@ -171,12 +251,20 @@ fn pack_single_bundle(
     match result {
         Ok(result) => {
             pb.finish_and_clear();
-            println!(
-                "  {} Bundle created [{} files]",
-                "✓".bright_green(),
-                result.file_count
-            );
-            print_pack_success(&result, !no_validate, verbose);
+            match platform {
+                Some(platform) => println!(
+                    "  {} Bundle created for {} [{} files]",
+                    "✓".bright_green(),
+                    platform.bright_cyan(),
+                    result.file_count
+                ),
+                None => println!(
+                    "  {} Bundle created [{} files]",
+                    "✓".bright_green(),
+                    result.file_count
+                ),
+            }
+            print_pack_success(&result, &options);
             Ok(())
         }
         Err(e) => {
@@ -188,9 +276,11 @@ fn pack_single_bundle(
 }
 
 /// Pack bundles for each platform override + universal bundle.
-async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> ToolResult<()> {
+async fn pack_multi_platform(dir: &Path, base: PackOptions) -> ToolResult<()> {
     // Load manifest to get platform overrides
-    let manifest = McpbManifest::load(dir)
+    let manifest_path = resolve_manifest_path(dir, base.manifest.as_deref())
+        .map_err(|e| ToolError::Generic(format!("Failed to load manifest: {}", e)))?;
+    let manifest: McpbManifest = from_str_relaxed(&std::fs::read_to_string(manifest_path)?)
         .map_err(|e| ToolError::Generic(format!("Failed to load manifest: {}", e)))?;
 
     // Get platform overrides from _meta["store.tool.mcpb"] or server.mcp_config
@@ -204,7 +294,14 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
         println!("  Creating single universal bundle instead.");
         println!();
 
-        return pack_single_bundle(dir, None, no_validate, verbose);
+        return pack_single_bundle(
+            dir,
+            PackOptions {
+                output: None,
+                ..base
+            },
+            None,
+        );
     }
 
     // Create multi-progress for all bundles
@@ -241,18 +338,12 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
 
         let options = PackOptions {
             output: None,
-            validate: !no_validate,
             verbose: false,
-            extract_icon: false,
-            on_progress: Some(Arc::new(move |progress| match progress {
-                PackProgress::Started { total_files } => {
-                    pb_clone.set_length(total_files as u64);
-                }
-                PackProgress::FileAdded { current, .. } => {
-                    pb_clone.set_position(current as u64);
-                }
-                PackProgress::Finished => {}
+            on_progress: Some(Arc::new(move |progress: PackProgress| {
+                pb_clone.set_length(progress.total_files as u64);
+                pb_clone.set_position(progress.files_done as u64);
             })),
+            ..base.clone()
         };
 
         let handle = tokio::task::spawn_blocking(move || {
@@ -269,18 +360,12 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
     let universal_pb_clone = universal_pb.clone();
     let universal_options = PackOptions {
         output: None,
-        validate: !no_validate,
         verbose: false,
-        extract_icon: false,
-        on_progress: Some(Arc::new(move |progress| match progress {
-            PackProgress::Started { total_files } => {
-                universal_pb_clone.set_length(total_files as u64);
-            }
-            PackProgress::FileAdded { current, .. } => {
-                universal_pb_clone.set_position(current as u64);
-            }
-            PackProgress::Finished => {}
+        on_progress: Some(Arc::new(move |progress: PackProgress| {
+            universal_pb_clone.set_length(progress.total_files as u64);
+            universal_pb_clone.set_position(progress.files_done as u64);
         })),
+        ..base.clone()
     };
     let universal_handle =
         tokio::task::spawn_blocking(move || pack_bundle(&dir_clone, &universal_options));
@@ -307,7 +392,7 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
     // Print results
     println!("  {} Bundles packed", "✓".bright_green());
 
-    if !no_validate {
+    if base.validate {
         println!("  {} Validation passed", "✓".bright_green());
     }
 
@@ -360,6 +445,7 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
                 format_size(pack_result.compressed_size),
                 "universal".bright_cyan()
             );
+            print_skipped_symlinks(&pack_result.skipped_symlinks);
             success_count += 1;
             total_size += pack_result.compressed_size;
         }
@@ -386,19 +472,7 @@ async fn pack_multi_platform(dir: &Path, no_validate: bool, verbose: bool) -> To
 /// Check if a platform key is a valid OS-arch format (e.g., "darwin-arm64", "linux-x64").
 /// OS-only keys like "darwin", "linux", "win32" are invalid for multi-platform packing.
 fn is_valid_os_arch_platform(platform: &str) -> bool {
-    // Valid formats: {os}-{arch} where os is darwin/linux/win32 and arch is arm64/x64/x86_64
-    let valid_patterns = [
-        "darwin-arm64",
-        "darwin-x64",
-        "darwin-x86_64",
-        "linux-arm64",
-        "linux-x64",
-        "linux-x86_64",
-        "win32-arm64",
-        "win32-x64",
-        "win32-x86_64",
-    ];
-    valid_patterns.contains(&platform)
+    OS_ARCH_PLATFORMS.contains(&platform)
 }
 
 /// Get platform overrides from manifest.
@@ -434,12 +508,21 @@ fn get_platform_overrides(manifest: &McpbManifest) -> Vec<String> {
 }
 
 /// Print success message for a pack result.
-fn print_pack_success(result: &PackResult, validated: bool, verbose: bool) {
-    if validated {
+fn print_pack_success(result: &PackResult, options: &PackOptions) {
+    if options.validate {
         println!("  {} Validation passed", "✓".bright_green());
     }
 
-    if verbose {
+    if options.verbose {
+        // CLI rules apply after .mcpbignore, in this order
+        for (flag, patterns) in [
+            ("--exclude", &options.exclude),
+            ("--include", &options.include),
+        ] {
+            for pattern in patterns {
+                println!("  · {} {}", flag.dimmed(), pattern);
+            }
+        }
         for ignored in &result.ignored_files {
             println!(
                 "  {} {} {}",
@@ -468,6 +551,19 @@ fn print_pack_success(result: &PackResult, validated: bool, verbose: bool) {
         format_size(result.compressed_size),
         format_size(result.total_size)
     );
+    print_skipped_symlinks(&result.skipped_symlinks);
+}
+
+/// Warn about symlinks left out because they point outside the bundle.
+fn print_skipped_symlinks(skipped: &[String]) {
+    for path in skipped {
+        println!(
+            "  {} Skipped symlink {} {}",
+            "⚠".bright_yellow(),
+            path.bold(),
+            "(points outside the bundle; use --follow-symlinks to include)".dimmed()
+        );
+    }
 }
 
 /// Handle pack errors with appropriate output.
@@ -502,7 +598,9 @@ fn handle_pack_error(e: PackError) -> ToolResult<()> {
                 }
             );
             println!("\n  Cannot pack invalid manifest. Fix errors and retry.");
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
         }
         PackError::ManifestNotFound(path) => {
             println!(
@@ -511,19 +609,10 @@ fn handle_pack_error(e: PackError) -> ToolResult<()> {
                 path.display()
             );
             println!("  Run `tool init` to create one.");
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_NOT_FOUND,
+            });
         }
         e => Err(ToolError::Generic(format!("Pack failed: {}", e))),
     }
 }
-
-/// Format byte size.
-pub(super) fn format_size(bytes: u64) -> String {
-    if bytes < 1_000 {
-        format!("{} B", bytes)
-    } else if bytes < 1_000_000 {
-        format!("{:.1} KB", bytes as f64 / 1_000.0)
-    } else {
-        format!("{:.1} MB", bytes as f64 / 1_000_000.0)
-    }
-}
@@ -1,12 +1,63 @@
 //! Tool preview command handler - preview tools from the registry without installing.
 
-use crate::error::{ToolError, ToolResult};
-use crate::format::format_description;
-use crate::mcpb::{McpbPrompt, McpbTool, McpbToolFull, StaticResponses};
+use crate::error::{EXIT_NOT_FOUND, ToolError, ToolResult};
+use crate::format::{format_description, format_size};
+use crate::mcpb::{McpbPrompt, McpbTool, McpbToolFull, StaticResponses, find_manifest_path};
+use crate::output::print_json;
+use crate::pack::{CollectOptions, collect_bundle_files, estimate_bundle_size};
 use crate::references::PluginRef;
 use crate::registry::RegistryClient;
 use crate::styles::Spinner;
+use crate::tree::FileTree;
 use colored::Colorize;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use super::common::compare_versions_desc;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Published versions listed in the human-readable registry info header.
+const MAX_VERSIONS_SHOWN: usize = 5;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Registry metadata shown by `tool info` for a tool that isn't installed.
+struct RegistryMetadata {
+    /// Total downloads across all versions.
+    downloads: i64,
+
+    /// Published versions, newest first.
+    versions: Vec<String>,
+}
+
+/// JSON output for `tool preview --files`.
+#[derive(Debug, Serialize)]
+struct BundleFilesOutput {
+    /// Files that would be bundled, in walk order.
+    files: Vec<BundleFileOutput>,
+
+    /// Number of files.
+    file_count: usize,
+
+    /// Total uncompressed size in bytes.
+    total_size: u64,
+
+    /// Estimated bundle size in bytes (with `--estimate`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    estimated_compressed_size: Option<u64>,
+}
+
+/// A single file in [`BundleFilesOutput`].
+#[derive(Debug, Serialize)]
+struct BundleFileOutput {
+    path: String,
+    size: u64,
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -27,6 +78,140 @@ pub async fn tool_preview(
     concise: bool,
     no_header: bool,
     level: usize,
+) -> ToolResult<()> {
+    preview_registry_tool(
+        tool,
+        methods,
+        input_only,
+        output_only,
+        description_only,
+        show_tools,
+        show_prompts,
+        show_all,
+        json_output,
+        concise,
+        no_header,
+        level,
+        false,
+    )
+    .await
+}
+
+/// Show the files `tool pack` would include from a local tool directory, without packing.
+///
+/// Applies `.mcpbignore` and the same `exclude`/`include` overrides as `tool pack`. With
+/// `estimate`, files are deflated in memory to estimate the packed bundle size.
+pub fn preview_bundle_files(
+    path: String,
+    exclude: Vec<String>,
+    include: Vec<String>,
+    estimate: bool,
+    json_output: bool,
+    concise: bool,
+    no_header: bool,
+) -> ToolResult<()> {
+    let dir = PathBuf::from(&path);
+    if !find_manifest_path(&dir).exists() {
+        return Err(ToolError::Generic(format!(
+            "No manifest.json found in {}",
+            dir.display()
+        )));
+    }
+
+    let options = CollectOptions {
+        exclude,
+        include,
+        ..Default::default()
+    };
+    let collected =
+        collect_bundle_files(&dir, &options).map_err(|e| ToolError::PackError(e.to_string()))?;
+
+    let files: Vec<BundleFileOutput> = collected
+        .entries
+        .iter()
+        .filter(|entry| !entry.is_dir)
+        .map(|entry| BundleFileOutput {
+            path: entry.relative_path.replace('\\', "/"),
+            size: entry.contents.len() as u64,
+        })
+        .collect();
+
+    let estimated = estimate.then(|| estimate_bundle_size(&collected.entries));
+
+    if json_output {
+        let output = BundleFilesOutput {
+            file_count: files.len(),
+            total_size: collected.total_size,
+            estimated_compressed_size: estimated,
+            files,
+        };
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
+    if concise {
+        if !no_header {
+            println!("#path\tsize");
+        }
+        for file in &files {
+            println!("{}\t{}", file.path, file.size);
+        }
+        return Ok(());
+    }
+
+    let mut tree = FileTree::new();
+    for file in &files {
+        tree.insert(&file.path, file.size);
+    }
+
+    let root = dir
+        .canonicalize()
+        .ok()
+        .and_then(|d| d.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or(path);
+
+    println!();
+    for line in tree.render(&format!("{}/", root)).lines() {
+        println!("  {}", line);
+    }
+    println!(
+        "\n  · {}  {} files, {}",
+        "Total".dimmed(),
+        files.len(),
+        format_size(tree.total_size())
+    );
+    if let Some(estimated) = estimated {
+        println!(
+            "  · {}  ~{} compressed {}",
+            "Bundle".dimmed(),
+            format_size(estimated),
+            "(estimate; the packed bundle may differ slightly)".dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Show a registry tool's declared capabilities from its published manifest.
+///
+/// With `from_info` (a `tool info` on a tool that isn't installed), the output also
+/// carries download stats and version history, and is marked as coming from the
+/// registry rather than a live connection.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn preview_registry_tool(
+    tool: String,
+    methods: Vec<String>,
+    input_only: bool,
+    output_only: bool,
+    description_only: bool,
+    show_tools: bool,
+    show_prompts: bool,
+    show_all: bool,
+    json_output: bool,
+    concise: bool,
+    no_header: bool,
+    level: usize,
+    from_info: bool,
 ) -> ToolResult<()> {
     // Parse tool reference
     let plugin_ref = PluginRef::parse(&tool)?;
@@ -38,8 +223,11 @@ pub async fn tool_preview(
     })?;
     let name = plugin_ref.name();
 
-    // Determine version to fetch
-    let version_str = plugin_ref.version().map(|v| v.to_string());
+    // Determine version (or tag) to fetch
+    let version_str = match plugin_ref.tag() {
+        Some(tag) => Some(tag.to_string()),
+        None => plugin_ref.version().map(|v| v.to_string()),
+    };
 
     // Show spinner while fetching (human-readable mode only)
     let show_spinner = !json_output && !concise;
@@ -57,7 +245,10 @@ pub async fn tool_preview(
 
     // Fetch version info with manifest from registry
     let client = RegistryClient::new();
-    let version_info = if let Some(ref v) = version_str {
+    let version_info = if let Some(tag) = plugin_ref.tag() {
+        let version = client.resolve_tag(namespace, name, tag).await?;
+        client.get_version(namespace, name, &version).await?
+    } else if let Some(ref v) = version_str {
         client.get_version(namespace, name, v).await?
     } else {
         // Get latest version
@@ -68,6 +259,12 @@ pub async fn tool_preview(
         })?
     };
 
+    let metadata = if from_info {
+        Some(fetch_registry_metadata(&client, namespace, name).await?)
+    } else {
+        None
+    };
+
     if let Some(s) = spinner {
         s.done();
     }
@@ -102,7 +299,9 @@ pub async fn tool_preview(
                         method_name.bright_white()
                     );
                 }
-                std::process::exit(1);
+                return Err(ToolError::Reported {
+                    code: EXIT_NOT_FOUND,
+                });
             }
         }
 
@@ -131,7 +330,8 @@ pub async fn tool_preview(
     }
 
     if json_output {
-        output_preview_json(&manifest, &tools, &prompts, concise)?;
+        let registry = metadata.as_ref().map(|m| (version.as_str(), m));
+        output_preview_json(&manifest, &tools, &prompts, registry, concise)?;
         return Ok(());
     }
 
@@ -154,13 +354,26 @@ pub async fn tool_preview(
     let show_all = show_all || (!show_tools && !show_prompts);
 
     // Header
-    println!(
-        "  {} {}/{} v{}\n",
-        "✓".bright_green(),
-        namespace.bold(),
-        name.bold(),
-        version
-    );
+    match &metadata {
+        Some(metadata) => {
+            println!(
+                "  {} {}/{} v{}  {}\n",
+                "✓".bright_green(),
+                namespace.bold(),
+                name.bold(),
+                version,
+                "from registry (not connected)".bright_yellow()
+            );
+            output_registry_metadata(metadata);
+        }
+        None => println!(
+            "  {} {}/{} v{}\n",
+            "✓".bright_green(),
+            namespace.bold(),
+            name.bold(),
+            version
+        ),
+    }
 
     // Show metadata
     if let Some(desc) = manifest.get("description").and_then(|d| d.as_str()) {
@@ -189,6 +402,52 @@ pub async fn tool_preview(
     Ok(())
 }
 
+/// Fetch download stats and published versions (newest first) for a registry tool.
+async fn fetch_registry_metadata(
+    client: &RegistryClient,
+    namespace: &str,
+    name: &str,
+) -> ToolResult<RegistryMetadata> {
+    let artifact = client.get_artifact(namespace, name).await?;
+    let mut versions: Vec<String> = client
+        .list_versions(namespace, name)
+        .await?
+        .into_iter()
+        .map(|v| v.version)
+        .collect();
+    versions.sort_by(|a, b| compare_versions_desc(a, b));
+
+    Ok(RegistryMetadata {
+        downloads: artifact.total_downloads,
+        versions,
+    })
+}
+
+/// Print download stats and recent versions.
+fn output_registry_metadata(metadata: &RegistryMetadata) {
+    println!("  · {:<10} {}", "Downloads".dimmed(), metadata.downloads);
+
+    let shown = metadata
+        .versions
+        .iter()
+        .take(MAX_VERSIONS_SHOWN)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let hidden = metadata.versions.len().saturating_sub(MAX_VERSIONS_SHOWN);
+    if hidden > 0 {
+        println!(
+            "  · {:<10} {} {}",
+            "Versions".dimmed(),
+            shown,
+            format!("(+{} more)", hidden).dimmed()
+        );
+    } else {
+        println!("  · {:<10} {}", "Versions".dimmed(), shown);
+    }
+    println!();
+}
+
 /// Extract tools from manifest, preferring static_responses over top-level tools.
 fn extract_tools_from_manifest(manifest: &serde_json::Value) -> Vec<McpbToolFull> {
     // Try _meta.store.tool.mcpb.static_responses.tools/list.tools first
@@ -640,10 +899,14 @@ fn output_preview_concise(
 }
 
 /// Output preview as JSON (matching tool info --json format).
+///
+/// `registry` (version and metadata) marks output from `tool info` on a tool that isn't
+/// installed.
 fn output_preview_json(
     manifest: &serde_json::Value,
     tools: &[McpbToolFull],
     prompts: &[McpbPrompt],
+    registry: Option<(&str, &RegistryMetadata)>,
     concise: bool,
 ) -> ToolResult<()> {
     // Build tools map
@@ -679,7 +942,7 @@ fn output_preview_json(
         .and_then(|t| t.as_str())
         .unwrap_or("stdio");
 
-    let output = serde_json::json!({
+    let mut output = serde_json::json!({
         "server": {
             "type": server_type,
         },
@@ -688,12 +951,15 @@ fn output_preview_json(
         "prompts": prompts_map,
         "resources": {},
     });
-
-    if concise {
-        println!("{}", serde_json::to_string(&output)?);
-    } else {
-        println!("{}", serde_json::to_string_pretty(&output)?);
+    if let Some((version, metadata)) = registry {
+        output["source"] = "registry".into();
+        output["connected"] = false.into();
+        output["version"] = version.into();
+        output["downloads"] = metadata.downloads.into();
+        output["versions"] = metadata.versions.clone().into();
     }
+
+    print_json(&output, concise)?;
     Ok(())
 }
 
@@ -709,11 +975,7 @@ fn output_methods_json(tools: &[&McpbToolFull], concise: bool) -> ToolResult<()>
         map.insert(tool.name.clone(), value);
     }
     let json = serde_json::Value::Object(map);
-    if concise {
-        println!("{}", serde_json::to_string(&json)?);
-    } else {
-        println!("{}", serde_json::to_string_pretty(&json)?);
-    }
+    print_json(&json, concise)?;
     Ok(())
 }
 
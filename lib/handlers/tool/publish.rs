@@ -1,19 +1,33 @@
 //! Registry publish command handlers.
 
-use super::pack_cmd::format_size;
-use crate::constants::MCPB_MANIFEST_FILE;
-use crate::error::{ToolError, ToolResult};
-use crate::mcpb::McpbManifest;
-use crate::pack::{PackError, PackOptions, compute_sha256, pack_bundle};
-use crate::registry::RegistryClient;
+use super::scripts::{POSTPACK_SCRIPT, PREPACK_SCRIPT, run_lifecycle_script};
+use crate::cli_version::check_cli_version;
+use crate::constants::{DEFAULT_DIST_TAG, OS_ARCH_PLATFORMS};
+use crate::error::{EXIT_VALIDATION, ToolError, ToolResult};
+use crate::format::format_size;
+use crate::mcpb::{McpbManifest, canonical_manifest_bytes, find_manifest_path};
+use crate::pack::{
+    PackError, PackOptions, compute_sha256, find_missing_platform_binaries, pack_bundle,
+};
+use crate::progress::{ProgressEvent, Transfer, emit, multi_progress, track_transfer};
+use crate::provenance::BuildProvenance;
+use crate::references::PluginRef;
+use crate::registry::{FileSpec, IconInfo, PublishResult, RegistryClient, is_otp_required};
 use crate::styles::Spinner;
 use colored::Colorize;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use indicatif::{ProgressBar, ProgressStyle};
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// `--platform` value selecting every declared platform plus universal.
+const ALL_PLATFORMS: &str = "all";
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -52,32 +66,108 @@ pub struct ArtifactEntry {
     pub checksum: String,
 }
 
+/// Contents of a file to upload.
+enum UploadSource {
+    /// Small files (icons, `version.json`) held in memory.
+    Bytes(Vec<u8>),
+
+    /// Bundles streamed from disk so memory use stays bounded.
+    File(PathBuf),
+}
+
+/// Temporary bundles that are removed when publishing finishes or fails.
+struct TempBundles(Vec<PathBuf>);
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl UploadSource {
+    /// Size of the contents in bytes.
+    fn size(&self) -> u64 {
+        match self {
+            Self::Bytes(bytes) => bytes.len() as u64,
+            Self::File(path) => std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        }
+    }
+
+    /// Upload the contents to a presigned URL, reporting progress.
+    async fn upload<F>(
+        &self,
+        client: &RegistryClient,
+        upload_url: &str,
+        on_progress: F,
+    ) -> ToolResult<()>
+    where
+        F: Fn(u64) + Send + Sync + 'static,
+    {
+        match self {
+            Self::Bytes(bytes) => {
+                client
+                    .upload_bundle_with_progress(upload_url, bytes, on_progress)
+                    .await
+            }
+            Self::File(path) => {
+                client
+                    .upload_file_with_progress(upload_url, path, on_progress)
+                    .await
+            }
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Drop for TempBundles {
+    fn drop(&mut self) {
+        for path in &self.0 {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
 
 /// Publish a tool to the registry.
 ///
-/// If `token` is provided, uses it directly instead of stored credentials.
+/// If `token` is provided, uses it directly instead of stored credentials. When bundling
+/// from source, the `prepack` and `postpack` scripts run around bundling, if defined.
 pub async fn publish_mcpb(
     path: &str,
     dry_run: bool,
     strict: bool,
     multi_platform: bool,
     prebuilt_artifacts: HashMap<String, PathBuf>,
+    platforms: &[String],
     token: Option<&str>,
+    access: Option<&str>,
+    tag: &str,
+    otp: Option<&str>,
+    provenance: bool,
+    require_provenance: bool,
 ) -> ToolResult<()> {
     use crate::handlers::auth::{get_registry_token, load_credentials};
     use crate::validate::validate_manifest;
-    use sha2::{Digest, Sha256};
+
+    // Publishing always talks to the registry
+    if !dry_run {
+        crate::registry::ensure_network("Publish")?;
+    }
 
     // Resolve the directory
     let dir = PathBuf::from(path)
         .canonicalize()
         .map_err(|_| ToolError::Generic(format!("Directory not found: {}", path)))?;
 
+    // Check the running CLI against the project's pinned version (fails with --strict)
+    check_cli_version(&dir, strict)?;
+
     // Check manifest exists
-    let manifest_path = dir.join(MCPB_MANIFEST_FILE);
+    let manifest_path = find_manifest_path(&dir);
     if !manifest_path.exists() {
         return Err(ToolError::Generic(format!(
             "manifest.json not found in {}. Run `tool init` first.",
@@ -86,7 +176,10 @@ pub async fn publish_mcpb(
     }
 
     // Read manifest
-    let manifest_content = std::fs::read_to_string(&manifest_path)?;
+    // Relaxed manifests (comments, trailing commas) are published as strict JSON
+    let manifest_bytes = canonical_manifest_bytes(&std::fs::read(&manifest_path)?)
+        .map_err(|e| ToolError::Generic(format!("Failed to parse manifest.json: {}", e)))?;
+    let manifest_content = String::from_utf8_lossy(&manifest_bytes).into_owned();
     let manifest: McpbManifest = serde_json::from_str(&manifest_content)
         .map_err(|e| ToolError::Generic(format!("Failed to parse manifest.json: {}", e)))?;
 
@@ -101,12 +194,13 @@ pub async fn publish_mcpb(
         .ok_or_else(|| ToolError::Generic("manifest.json must include a version field".into()))?;
 
     // Validate version is semver
-    if semver::Version::parse(version).is_err() {
+    let Ok(parsed_version) = semver::Version::parse(version) else {
         return Err(ToolError::Generic(format!(
             "Version '{}' is not valid semver (expected format: x.y.z)",
             version
         )));
-    }
+    };
+    PluginRef::validate_tag(tag)?;
 
     // Get authenticated user
     // Priority: explicit token > env var > stored credentials
@@ -156,6 +250,25 @@ pub async fn publish_mcpb(
     if let Some(desc) = description {
         println!("  · {}: {}", "Description".dimmed(), desc.dimmed());
     }
+    if let Some(access) = access {
+        println!("  · {}: {}", "Access".dimmed(), access.bright_white());
+    }
+    println!("  · {}: {}", "Tag".dimmed(), tag.bright_white());
+    if !parsed_version.pre.is_empty() && tag == DEFAULT_DIST_TAG {
+        println!(
+            "  {} Pre-release {} will become the default install; use {} to publish it under another tag",
+            "!".bright_yellow(),
+            version,
+            "--tag next".bright_cyan()
+        );
+    }
+
+    // Gather provenance before packing so --require-provenance fails early
+    let provenance = if provenance || require_provenance {
+        resolve_provenance(&dir, require_provenance)?
+    } else {
+        None
+    };
 
     // Strict validation: treat warnings as errors
     if strict {
@@ -185,12 +298,15 @@ pub async fn publish_mcpb(
                 }
             );
             println!("\n  Cannot publish with --strict. Fix errors and warnings, then retry.");
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
         }
     }
 
     // Check if we should use multi-platform mode
-    let use_multi_platform = multi_platform || !prebuilt_artifacts.is_empty();
+    let use_multi_platform =
+        multi_platform || !prebuilt_artifacts.is_empty() || !platforms.is_empty();
 
     if use_multi_platform {
         // Build multi-artifact options
@@ -201,6 +317,9 @@ pub async fn publish_mcpb(
                 include_universal: prebuilt_artifacts.contains_key("universal"),
                 explicit_artifacts: prebuilt_artifacts,
             }
+        } else if !platforms.is_empty() {
+            // Explicitly selected platforms
+            select_platforms(&manifest, platforms)?
         } else {
             // Auto-detect platforms from manifest
             let platforms = detect_available_platforms(&manifest);
@@ -234,6 +353,10 @@ pub async fn publish_mcpb(
                 options,
                 dry_run,
                 resolved_token,
+                access,
+                tag,
+                otp,
+                provenance.as_ref(),
             )
             .await;
         }
@@ -242,6 +365,10 @@ pub async fn publish_mcpb(
     // Single-artifact mode (original logic)
     // Bundle the tool
     println!();
+    run_lifecycle_script(&dir, PREPACK_SCRIPT)?;
+    emit(ProgressEvent::Packing {
+        name: tool_name.clone(),
+    });
     let spinner = Spinner::new("Creating bundle");
 
     let pack_options = PackOptions {
@@ -249,7 +376,7 @@ pub async fn publish_mcpb(
         output: None,
         verbose: false,
         extract_icon: true,
-        on_progress: None,
+        ..Default::default()
     };
     let pack_result = match pack_bundle(&dir, &pack_options) {
         Ok(result) => {
@@ -265,10 +392,11 @@ pub async fn publish_mcpb(
         }
     };
 
-    // Read the bundle
-    let bundle = std::fs::read(&pack_result.output_path)
-        .map_err(|e| ToolError::Generic(format!("Failed to read bundle: {}", e)))?;
-    let bundle_size = bundle.len() as u64;
+    // The bundle is streamed from disk on upload and removed once publishing ends
+    let _temp_bundles = TempBundles(vec![pack_result.output_path.clone()]);
+    run_lifecycle_script(&dir, POSTPACK_SCRIPT)?;
+
+    let bundle_size = pack_result.compressed_size;
     println!("  · Size: {}", format_size(bundle_size).bright_white());
 
     // Report icons if present
@@ -282,9 +410,6 @@ pub async fn publish_mcpb(
         );
     }
 
-    // Clean up temp bundle (icons are in memory, no files to clean)
-    let _ = std::fs::remove_file(&pack_result.output_path);
-
     if dry_run {
         println!(
             "\n  {} Dry run complete. Would publish {}/{}@{}",
@@ -298,50 +423,28 @@ pub async fn publish_mcpb(
 
     // Create registry client with auth
     let resolved_token = resolved_token.unwrap();
-    let client = RegistryClient::new().with_auth_token(&resolved_token);
-
-    // Check if artifact exists
-    println!();
-    let spinner = Spinner::new(format!("Checking registry ({})", client.registry_url()));
-    let artifact_exists = match client.artifact_exists(&namespace, tool_name).await {
-        Ok(exists) => {
-            spinner.succeed(Some("Registry checked"));
-            exists
-        }
-        Err(e) => {
-            spinner.fail(None);
-            return Err(e);
-        }
-    };
-
-    if !artifact_exists {
-        let spinner = Spinner::new("Creating artifact entry");
-        let categories = manifest.categories();
-        match client
-            .create_artifact(&namespace, tool_name, description, categories)
-            .await
-        {
-            Ok(()) => {
-                spinner.succeed(Some(&format!("Created {}/{}", namespace, tool_name)));
-            }
-            Err(e) => {
-                spinner.fail(None);
-                return Err(e);
-            }
-        }
+    let mut client = RegistryClient::new().with_auth_token(&resolved_token);
+    if let Some(otp) = otp {
+        client.set_otp(otp);
     }
 
-    // Compute SHA-256 for bundle
-    let mut hasher = Sha256::new();
-    hasher.update(&bundle);
-    let sha256 = format!("{:x}", hasher.finalize());
+    println!();
+    let visibility = ensure_artifact(
+        &client,
+        &manifest,
+        &namespace,
+        tool_name,
+        description,
+        access,
+    )
+    .await?;
 
     // Build file specs for upload
     let file_name = format!("{}.{}", tool_name, manifest.bundle_extension());
     let mut files = vec![crate::registry::FileSpec {
         name: file_name.clone(),
         size: bundle_size as i64,
-        sha256: sha256.clone(),
+        sha256: pack_result.checksum.clone(),
     }];
 
     // Add all icons to upload
@@ -360,18 +463,31 @@ pub async fn publish_mcpb(
         file_count,
         if file_count > 1 { "s" } else { "" }
     );
-    let upload_info = client
-        .init_upload(&namespace, tool_name, version, files)
-        .await?;
+    let provenance = provenance.map(|p| p.statement(&provenance_subjects(&files)));
+    let upload_info = match client
+        .init_upload(&namespace, tool_name, version, files.clone())
+        .await
+    {
+        Err(e) if is_otp_required(&e) => {
+            client.set_otp(prompt_otp(e)?);
+            client
+                .init_upload(&namespace, tool_name, version, files)
+                .await?
+        }
+        result => result?,
+    };
 
     // Build list of files to upload
-    let mut files_to_upload: Vec<(String, Vec<u8>)> = vec![(file_name.clone(), bundle)];
+    let mut files_to_upload: Vec<(String, UploadSource)> = vec![(
+        file_name.clone(),
+        UploadSource::File(pack_result.output_path.clone()),
+    )];
     for icon in &pack_result.icons {
-        files_to_upload.push((icon.name.clone(), icon.bytes.clone()));
+        files_to_upload.push((icon.name.clone(), UploadSource::Bytes(icon.bytes.clone())));
     }
 
     // Upload all files in parallel
-    let mp = MultiProgress::new();
+    let mp = multi_progress();
     let style = ProgressStyle::default_bar()
         .template("  {msg:<25} [{bar:25.cyan/dim}] {bytes:>10}/{total_bytes:<10}")
         .unwrap()
@@ -379,13 +495,14 @@ pub async fn publish_mcpb(
 
     let upload_handles: Vec<_> = files_to_upload
         .into_iter()
-        .map(|(name, bytes)| {
+        .map(|(name, source)| {
             let upload_target = upload_info.uploads.iter().find(|t| t.name == name).cloned();
 
-            let pb = mp.add(ProgressBar::new(bytes.len() as u64));
+            let pb = mp.add(ProgressBar::new(source.size()));
             pb.set_style(style.clone());
             pb.set_message(name.clone());
             pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            let tracker = track_transfer(&pb, name.clone(), Transfer::Upload);
 
             let client = client.clone();
             tokio::spawn(async move {
@@ -396,16 +513,13 @@ pub async fn publish_mcpb(
 
                 let pb_arc = Arc::new(pb);
                 let pb_clone = Arc::clone(&pb_arc);
-                let result = client
-                    .upload_bundle_with_progress(
-                        &upload_target.upload_url,
-                        &bytes,
-                        move |uploaded| {
-                            pb_clone.set_position(uploaded);
-                        },
-                    )
+                let result = source
+                    .upload(&client, &upload_target.upload_url, move |uploaded| {
+                        pb_clone.set_position(uploaded);
+                    })
                     .await;
 
+                tracker.finish();
                 pb_arc.finish_and_clear();
                 result.map_err(|e| format!("Upload failed for {}: {}", name, e))
             })
@@ -417,11 +531,16 @@ pub async fn publish_mcpb(
 
     // Check for failures
     for result in upload_results {
-        match result {
-            Ok(Ok(())) => {}
-            Ok(Err(e)) => return Err(ToolError::Generic(e)),
-            Err(e) => return Err(ToolError::Generic(format!("Upload task failed: {}", e))),
-        }
+        let error = match result {
+            Ok(Ok(())) => continue,
+            Ok(Err(e)) => e,
+            Err(e) => format!("Upload task failed: {}", e),
+        };
+        emit(ProgressEvent::Failed {
+            name: format!("{}/{}", namespace, tool_name),
+            error: error.clone(),
+        });
+        return Err(ToolError::Generic(error));
     }
 
     println!("  {} Upload complete", "✓".bright_green());
@@ -492,34 +611,31 @@ pub async fn publish_mcpb(
         }
     };
 
-    let result = match client
-        .publish_version(
-            &namespace,
-            tool_name,
-            &upload_info.upload_id,
-            version,
-            &file_name,
-            manifest_json,
-            description,
-            icons,
-        )
-        .await
-    {
-        Ok(result) => {
-            spinner.succeed(Some("Version published"));
-            result
-        }
-        Err(e) => {
-            spinner.fail(Some("Publishing failed"));
-            return Err(e);
-        }
-    };
+    let result = publish_uploaded_version(
+        &mut client,
+        spinner,
+        &namespace,
+        tool_name,
+        &upload_info.upload_id,
+        version,
+        &file_name,
+        manifest_json,
+        description,
+        icons,
+        tag,
+        provenance,
+    )
+    .await?;
 
     let format_display = if manifest.requires_mcpbx() {
         "mcpbx".bright_yellow()
     } else {
         "mcpb".bright_green()
     };
+    emit(ProgressEvent::Published {
+        name: format!("{}/{}", namespace, tool_name),
+        version: result.version.clone(),
+    });
     println!(
         "\n  {} Published {}/{}@{} ({})",
         "✓".bright_green(),
@@ -534,10 +650,214 @@ pub async fn publish_mcpb(
         namespace,
         tool_name
     );
+    if let Some(visibility) = &visibility {
+        println!("  · {}: {}", "Visibility".dimmed(), visibility);
+    }
+    if tag != DEFAULT_DIST_TAG {
+        println!(
+            "  · Install with {}",
+            format!("tool install {}/{}@{}", namespace, tool_name, tag).bright_white()
+        );
+    }
 
     Ok(())
 }
 
+/// Publish an uploaded version, prompting for a one-time password and retrying once if
+/// the registry asks for one (e.g. because the code expired during the upload).
+#[allow(clippy::too_many_arguments)]
+async fn publish_uploaded_version(
+    client: &mut RegistryClient,
+    mut spinner: Spinner,
+    namespace: &str,
+    tool_name: &str,
+    upload_id: &str,
+    version: &str,
+    main_file: &str,
+    manifest_json: serde_json::Value,
+    description: Option<&str>,
+    icons: Option<Vec<IconInfo>>,
+    tag: &str,
+    provenance: Option<serde_json::Value>,
+) -> ToolResult<PublishResult> {
+    let result = match client
+        .publish_version(
+            namespace,
+            tool_name,
+            upload_id,
+            version,
+            main_file,
+            manifest_json.clone(),
+            description,
+            icons.clone(),
+            tag,
+            provenance.clone(),
+        )
+        .await
+    {
+        Err(e) if is_otp_required(&e) => {
+            spinner.fail(Some("One-time password required"));
+            client.set_otp(prompt_otp(e)?);
+            spinner = Spinner::new("Publishing version");
+            client
+                .publish_version(
+                    namespace,
+                    tool_name,
+                    upload_id,
+                    version,
+                    main_file,
+                    manifest_json,
+                    description,
+                    icons,
+                    tag,
+                    provenance,
+                )
+                .await
+        }
+        result => result,
+    };
+
+    match result {
+        Ok(result) => {
+            spinner.succeed(Some("Version published"));
+            Ok(result)
+        }
+        Err(e) => {
+            spinner.fail(Some("Publishing failed"));
+            Err(e)
+        }
+    }
+}
+
+/// Gather build provenance, warning (or failing when `required`) if it's incomplete.
+fn resolve_provenance(dir: &Path, required: bool) -> ToolResult<Option<BuildProvenance>> {
+    let provenance = BuildProvenance::gather(dir);
+    let missing = provenance.missing();
+
+    if missing.is_empty() {
+        if let Some(run_url) = &provenance.ci_run_url {
+            println!("  · {}: {}", "Provenance".dimmed(), run_url.dimmed());
+        }
+        return Ok(Some(provenance));
+    }
+
+    if required {
+        return Err(ToolError::Generic(format!(
+            "Build provenance is incomplete (missing {}). Publish from CI or drop --require-provenance.",
+            missing.join(", ")
+        )));
+    }
+
+    println!(
+        "  {} Publishing without provenance: missing {}",
+        "!".bright_yellow(),
+        missing.join(", ")
+    );
+    Ok(None)
+}
+
+/// Provenance subjects (name, sha256) for the files being uploaded.
+fn provenance_subjects(files: &[FileSpec]) -> Vec<(String, String)> {
+    files
+        .iter()
+        .map(|f| (f.name.clone(), f.sha256.clone()))
+        .collect()
+}
+
+/// Prompt for a one-time password after the registry asked for one.
+///
+/// Without a terminal to prompt on, the registry's error is returned with a hint.
+fn prompt_otp(error: ToolError) -> ToolResult<String> {
+    use std::io::{IsTerminal, Write};
+
+    if !std::io::stdin().is_terminal() {
+        return Err(ToolError::Generic(format!(
+            "{}\nPass --otp <code> to publish non-interactively.",
+            error
+        )));
+    }
+
+    println!(
+        "  {} The registry requires a one-time password",
+        "!".bright_yellow()
+    );
+    print!("  Enter code: ");
+    std::io::stdout().flush()?;
+
+    let mut code = String::new();
+    std::io::stdin().read_line(&mut code)?;
+    let code = code.trim();
+    if code.is_empty() {
+        return Err(ToolError::Generic("No one-time password provided".into()));
+    }
+    Ok(code.to_string())
+}
+
+/// Create the artifact entry if it doesn't exist yet and return its visibility, if known.
+///
+/// `access` only applies when the artifact is created. For an existing artifact, a
+/// conflicting `access` is reported as a warning rather than changing its visibility.
+async fn ensure_artifact(
+    client: &RegistryClient,
+    manifest: &McpbManifest,
+    namespace: &str,
+    tool_name: &str,
+    description: Option<&str>,
+    access: Option<&str>,
+) -> ToolResult<Option<String>> {
+    let spinner = Spinner::new(format!("Checking registry ({})", client.registry_url()));
+    let artifact_exists = match client.artifact_exists(namespace, tool_name).await {
+        Ok(exists) => {
+            spinner.succeed(Some("Registry checked"));
+            exists
+        }
+        Err(e) => {
+            spinner.fail(None);
+            return Err(e);
+        }
+    };
+
+    if artifact_exists {
+        let visibility = client
+            .get_artifact(namespace, tool_name)
+            .await
+            .ok()
+            .and_then(|details| details.visibility);
+
+        if let (Some(access), Some(existing)) = (access, &visibility)
+            && access != existing
+        {
+            println!(
+                "  {} Ignoring --access {}: {}/{} is already {}",
+                "!".bright_yellow(),
+                access,
+                namespace,
+                tool_name,
+                existing
+            );
+            println!("  · Visibility can only be set when the tool is first published");
+        }
+        return Ok(visibility);
+    }
+
+    let spinner = Spinner::new("Creating artifact entry");
+    let categories = manifest.categories();
+    match client
+        .create_artifact(namespace, tool_name, description, categories, access)
+        .await
+    {
+        Ok(()) => {
+            spinner.succeed(Some(&format!("Created {}/{}", namespace, tool_name)));
+        }
+        Err(e) => {
+            spinner.fail(None);
+            return Err(e);
+        }
+    }
+
+    Ok(Some(access.unwrap_or("public").to_string()))
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions: Multi-Artifact Publishing
 //--------------------------------------------------------------------------------------------------
@@ -545,19 +865,43 @@ pub async fn publish_mcpb(
 /// Check if a platform key is a valid OS-arch format (e.g., "darwin-arm64", "linux-x64").
 /// OS-only keys like "darwin", "linux", "win32" are invalid for multi-platform packing.
 fn is_valid_os_arch_platform(platform: &str) -> bool {
-    // Valid formats: {os}-{arch} where os is darwin/linux/win32 and arch is arm64/x64/x86_64
-    let valid_patterns = [
-        "darwin-arm64",
-        "darwin-x64",
-        "darwin-x86_64",
-        "linux-arm64",
-        "linux-x64",
-        "linux-x86_64",
-        "win32-arm64",
-        "win32-x64",
-        "win32-x86_64",
-    ];
-    valid_patterns.contains(&platform)
+    OS_ARCH_PLATFORMS.contains(&platform)
+}
+
+/// Resolve `--platform` keys into multi-artifact options.
+///
+/// `all` selects every OS-arch platform declared in the manifest plus universal;
+/// `universal` adds the universal bundle.
+fn select_platforms(manifest: &McpbManifest, keys: &[String]) -> ToolResult<MultiArtifactOptions> {
+    let mut platforms = Vec::new();
+    let mut include_universal = false;
+
+    for key in keys {
+        match key.as_str() {
+            ALL_PLATFORMS => {
+                platforms.extend(detect_available_platforms(manifest));
+                include_universal = true;
+            }
+            "universal" => include_universal = true,
+            key if is_valid_os_arch_platform(key) => platforms.push(key.to_string()),
+            key => {
+                return Err(ToolError::Generic(format!(
+                    "Invalid platform '{}'. Expected one of: {}, universal, {}",
+                    key,
+                    OS_ARCH_PLATFORMS.join(", "),
+                    ALL_PLATFORMS
+                )));
+            }
+        }
+    }
+
+    platforms.sort();
+    platforms.dedup();
+    Ok(MultiArtifactOptions {
+        platforms,
+        include_universal,
+        explicit_artifacts: HashMap::new(),
+    })
 }
 
 /// Detect available platforms from manifest's platform_overrides.
@@ -599,6 +943,10 @@ async fn publish_multi_artifact_impl(
     options: MultiArtifactOptions,
     dry_run: bool,
     token: Option<String>,
+    access: Option<&str>,
+    tag: &str,
+    otp: Option<&str>,
+    provenance: Option<&BuildProvenance>,
 ) -> ToolResult<()> {
     println!();
     println!(
@@ -613,7 +961,8 @@ async fn publish_multi_artifact_impl(
     );
 
     // Collect all files to upload
-    let mut files_to_upload: Vec<(String, Vec<u8>, String)> = Vec::new(); // (name, bytes, checksum)
+    let mut files_to_upload: Vec<(String, UploadSource, String)> = Vec::new(); // (name, source, checksum)
+    let mut temp_bundles = TempBundles(Vec::new());
     let mut version_manifest_artifacts: HashMap<String, ArtifactEntry> = HashMap::new();
 
     // Process explicit artifacts or pack bundles
@@ -700,7 +1049,7 @@ async fn publish_multi_artifact_impl(
                             for icon in icons {
                                 files_to_upload.push((
                                     icon.name.clone(),
-                                    icon.bytes.clone(),
+                                    UploadSource::Bytes(icon.bytes.clone()),
                                     icon.checksum.clone(),
                                 ));
                             }
@@ -726,18 +1075,35 @@ async fn publish_multi_artifact_impl(
                     checksum: format!("sha256:{}", checksum),
                 },
             );
-            files_to_upload.push((filename, bytes, checksum));
+            // Validated bundles are streamed from disk rather than kept in memory
+            files_to_upload.push((filename, UploadSource::File(path.clone()), checksum));
         }
     } else {
         // Pack bundles for each platform in parallel
         use crate::pack::pack_bundle_for_platform;
 
+        run_lifecycle_script(dir, PREPACK_SCRIPT)?;
+
+        // Catch platform_overrides pointing at binaries that don't exist before packing
+        let manifest_json: serde_json::Value = serde_json::from_str(manifest_content)?;
+        let missing = find_missing_platform_binaries(dir, &manifest_json, &options.platforms);
+        if !missing.is_empty() {
+            let details: Vec<String> = missing
+                .iter()
+                .map(|(platform, path)| format!("  {}: {}", platform, path))
+                .collect();
+            return Err(ToolError::Generic(format!(
+                "Platform binaries not found:\n{}\nBuild them first or fix platform_overrides in the manifest.",
+                details.join("\n")
+            )));
+        }
+
         let pack_options = PackOptions {
             validate: true,
             output: None,
             verbose: false,
             extract_icon: true,
-            on_progress: None,
+            ..Default::default()
         };
 
         // Create pack tasks for all platforms
@@ -767,6 +1133,9 @@ async fn publish_multi_artifact_impl(
         };
 
         // Wait for all packs to complete with spinner
+        emit(ProgressEvent::Packing {
+            name: tool_name.to_string(),
+        });
         let spinner = Spinner::new("Packing bundles");
         let pack_results = futures_util::future::join_all(pack_handles).await;
         let universal_result = match universal_handle {
@@ -783,9 +1152,8 @@ async fn publish_multi_artifact_impl(
                 result.map_err(|e| ToolError::Generic(format!("Pack task failed: {}", e)))?;
             match pack_result {
                 Ok(pack_result) => {
-                    let bundle_bytes = std::fs::read(&pack_result.output_path)
-                        .map_err(|e| ToolError::Generic(format!("Failed to read bundle: {}", e)))?;
-                    let bundle_checksum = compute_sha256(&bundle_bytes);
+                    let bundle_checksum = pack_result.checksum.clone();
+                    let bundle_size = pack_result.compressed_size;
                     let bundle_filename = pack_result
                         .output_path
                         .file_name()
@@ -797,25 +1165,29 @@ async fn publish_multi_artifact_impl(
                         "  · {}: {} ({})",
                         platform.bright_cyan(),
                         bundle_filename,
-                        format_size(bundle_bytes.len() as u64)
+                        format_size(bundle_size)
                     );
 
                     version_manifest_artifacts.insert(
                         platform,
                         ArtifactEntry {
                             filename: bundle_filename.clone(),
-                            size: bundle_bytes.len() as u64,
+                            size: bundle_size,
                             checksum: format!("sha256:{}", bundle_checksum),
                         },
                     );
-                    files_to_upload.push((bundle_filename, bundle_bytes, bundle_checksum));
+                    files_to_upload.push((
+                        bundle_filename,
+                        UploadSource::File(pack_result.output_path.clone()),
+                        bundle_checksum,
+                    ));
 
                     // Keep track of icons from first successful pack
                     if icons_info.is_none() && !pack_result.icons.is_empty() {
                         icons_info = Some(pack_result.icons.clone());
                     }
 
-                    let _ = std::fs::remove_file(&pack_result.output_path);
+                    temp_bundles.0.push(pack_result.output_path.clone());
                 }
                 Err(e) => {
                     return Err(match e {
@@ -832,9 +1204,8 @@ async fn publish_multi_artifact_impl(
                 result.map_err(|e| ToolError::Generic(format!("Pack task failed: {}", e)))?;
             match pack_result {
                 Ok(pack_result) => {
-                    let bundle_bytes = std::fs::read(&pack_result.output_path)
-                        .map_err(|e| ToolError::Generic(format!("Failed to read bundle: {}", e)))?;
-                    let bundle_checksum = compute_sha256(&bundle_bytes);
+                    let bundle_checksum = pack_result.checksum.clone();
+                    let bundle_size = pack_result.compressed_size;
                     let bundle_filename = pack_result
                         .output_path
                         .file_name()
@@ -846,25 +1217,29 @@ async fn publish_multi_artifact_impl(
                         "  · {}: {} ({})",
                         "universal".bright_cyan(),
                         bundle_filename,
-                        format_size(bundle_bytes.len() as u64)
+                        format_size(bundle_size)
                     );
 
                     version_manifest_artifacts.insert(
                         "universal".to_string(),
                         ArtifactEntry {
                             filename: bundle_filename.clone(),
-                            size: bundle_bytes.len() as u64,
+                            size: bundle_size,
                             checksum: format!("sha256:{}", bundle_checksum),
                         },
                     );
-                    files_to_upload.push((bundle_filename, bundle_bytes, bundle_checksum));
+                    files_to_upload.push((
+                        bundle_filename,
+                        UploadSource::File(pack_result.output_path.clone()),
+                        bundle_checksum,
+                    ));
 
                     // Use icons from universal bundle if not already set
                     if icons_info.is_none() && !pack_result.icons.is_empty() {
                         icons_info = Some(pack_result.icons.clone());
                     }
 
-                    let _ = std::fs::remove_file(&pack_result.output_path);
+                    temp_bundles.0.push(pack_result.output_path.clone());
                 }
                 Err(e) => {
                     return Err(match e {
@@ -875,6 +1250,8 @@ async fn publish_multi_artifact_impl(
             }
         }
 
+        run_lifecycle_script(dir, POSTPACK_SCRIPT)?;
+
         // Add all icons if found
         if let Some(ref icons) = icons_info {
             let total_icon_size: u64 = icons.iter().map(|i| i.bytes.len() as u64).sum();
@@ -887,7 +1264,7 @@ async fn publish_multi_artifact_impl(
             for icon in icons {
                 files_to_upload.push((
                     icon.name.clone(),
-                    icon.bytes.clone(),
+                    UploadSource::Bytes(icon.bytes.clone()),
                     icon.checksum.clone(),
                 ));
             }
@@ -914,7 +1291,7 @@ async fn publish_multi_artifact_impl(
         0,
         (
             "version.json".to_string(),
-            version_json_bytes,
+            UploadSource::Bytes(version_json_bytes),
             version_json_checksum,
         ),
     );
@@ -933,45 +1310,21 @@ async fn publish_multi_artifact_impl(
 
     // Create registry client with auth
     let token = token.ok_or_else(|| ToolError::Generic("Authentication required".into()))?;
-    let client = RegistryClient::new().with_auth_token(&token);
+    let mut client = RegistryClient::new().with_auth_token(&token);
+    if let Some(otp) = otp {
+        client.set_otp(otp);
+    }
 
-    // Check if artifact exists
     println!();
-    let spinner = Spinner::new(format!("Checking registry ({})", client.registry_url()));
-    let artifact_exists = match client.artifact_exists(namespace, tool_name).await {
-        Ok(exists) => {
-            spinner.succeed(Some("Registry checked"));
-            exists
-        }
-        Err(e) => {
-            spinner.fail(None);
-            return Err(e);
-        }
-    };
-
-    if !artifact_exists {
-        let spinner = Spinner::new("Creating artifact entry");
-        let categories = manifest.categories();
-        match client
-            .create_artifact(namespace, tool_name, description, categories)
-            .await
-        {
-            Ok(()) => {
-                spinner.succeed(Some(&format!("Created {}/{}", namespace, tool_name)));
-            }
-            Err(e) => {
-                spinner.fail(None);
-                return Err(e);
-            }
-        }
-    }
+    let visibility =
+        ensure_artifact(&client, manifest, namespace, tool_name, description, access).await?;
 
     // Build file specs for upload
     let file_specs: Vec<crate::registry::FileSpec> = files_to_upload
         .iter()
-        .map(|(name, bytes, checksum)| crate::registry::FileSpec {
+        .map(|(name, source, checksum)| crate::registry::FileSpec {
             name: name.clone(),
-            size: bytes.len() as i64,
+            size: source.size() as i64,
             sha256: checksum.clone(),
         })
         .collect();
@@ -983,12 +1336,22 @@ async fn publish_multi_artifact_impl(
         files_to_upload.len()
     );
 
-    let upload_info = client
-        .init_upload(namespace, tool_name, version, file_specs)
-        .await?;
+    let provenance = provenance.map(|p| p.statement(&provenance_subjects(&file_specs)));
+    let upload_info = match client
+        .init_upload(namespace, tool_name, version, file_specs.clone())
+        .await
+    {
+        Err(e) if is_otp_required(&e) => {
+            client.set_otp(prompt_otp(e)?);
+            client
+                .init_upload(namespace, tool_name, version, file_specs)
+                .await?
+        }
+        result => result?,
+    };
 
     // Upload all files in parallel
-    let mp = MultiProgress::new();
+    let mp = multi_progress();
     let style = ProgressStyle::default_bar()
         .template("  {msg:<25} [{bar:25.cyan/dim}] {bytes:>10}/{total_bytes:<10}")
         .unwrap()
@@ -996,13 +1359,14 @@ async fn publish_multi_artifact_impl(
 
     let upload_handles: Vec<_> = files_to_upload
         .into_iter()
-        .map(|(name, bytes, _checksum)| {
+        .map(|(name, source, _checksum)| {
             let upload_target = upload_info.uploads.iter().find(|t| t.name == name).cloned();
 
-            let pb = mp.add(ProgressBar::new(bytes.len() as u64));
+            let pb = mp.add(ProgressBar::new(source.size()));
             pb.set_style(style.clone());
             pb.set_message(name.clone());
             pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            let tracker = track_transfer(&pb, name.clone(), Transfer::Upload);
 
             let client = client.clone();
             tokio::spawn(async move {
@@ -1013,16 +1377,13 @@ async fn publish_multi_artifact_impl(
 
                 let pb_arc = Arc::new(pb);
                 let pb_clone = Arc::clone(&pb_arc);
-                let result = client
-                    .upload_bundle_with_progress(
-                        &upload_target.upload_url,
-                        &bytes,
-                        move |uploaded| {
-                            pb_clone.set_position(uploaded);
-                        },
-                    )
+                let result = source
+                    .upload(&client, &upload_target.upload_url, move |uploaded| {
+                        pb_clone.set_position(uploaded);
+                    })
                     .await;
 
+                tracker.finish();
                 pb_arc.finish_and_clear();
                 result.map_err(|e| format!("Upload failed for {}: {}", name, e))
             })
@@ -1034,11 +1395,16 @@ async fn publish_multi_artifact_impl(
 
     // Check for failures
     for result in upload_results {
-        match result {
-            Ok(Ok(())) => {}
-            Ok(Err(e)) => return Err(ToolError::Generic(e)),
-            Err(e) => return Err(ToolError::Generic(format!("Upload task failed: {}", e))),
-        }
+        let error = match result {
+            Ok(Ok(())) => continue,
+            Ok(Err(e)) => e,
+            Err(e) => format!("Upload task failed: {}", e),
+        };
+        emit(ProgressEvent::Failed {
+            name: format!("{}/{}", namespace, tool_name),
+            error: error.clone(),
+        });
+        return Err(ToolError::Generic(error));
     }
 
     println!("  {} Upload complete", "✓".bright_green());
@@ -1104,29 +1470,26 @@ async fn publish_multi_artifact_impl(
         }
     };
 
-    let result = match client
-        .publish_version(
-            namespace,
-            tool_name,
-            &upload_info.upload_id,
-            version,
-            "version.json",
-            manifest_json,
-            description,
-            icons,
-        )
-        .await
-    {
-        Ok(result) => {
-            spinner.succeed(Some("Version published"));
-            result
-        }
-        Err(e) => {
-            spinner.fail(Some("Publishing failed"));
-            return Err(e);
-        }
-    };
-
+    let result = publish_uploaded_version(
+        &mut client,
+        spinner,
+        namespace,
+        tool_name,
+        &upload_info.upload_id,
+        version,
+        "version.json",
+        manifest_json,
+        description,
+        icons,
+        tag,
+        provenance,
+    )
+    .await?;
+
+    emit(ProgressEvent::Published {
+        name: format!("{}/{}", namespace, tool_name),
+        version: result.version.clone(),
+    });
     println!(
         "\n  {} Published {}/{}@{} ({} artifacts)",
         "✓".bright_green(),
@@ -1141,6 +1504,58 @@ async fn publish_multi_artifact_impl(
         namespace,
         tool_name
     );
+    if let Some(visibility) = &visibility {
+        println!("  · {}: {}", "Visibility".dimmed(), visibility);
+    }
+    if tag != DEFAULT_DIST_TAG {
+        println!(
+            "  · Install with {}",
+            format!("tool install {}/{}@{}", namespace, tool_name, tag).bright_white()
+        );
+    }
 
     Ok(())
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_with_overrides(keys: &[&str]) -> McpbManifest {
+        let overrides: serde_json::Map<String, serde_json::Value> = keys
+            .iter()
+            .map(|k| (k.to_string(), serde_json::json!({})))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "manifest_version": "0.3",
+            "name": "my-tool",
+            "version": "1.0.0",
+            "server": {"type": "binary", "entry_point": "bin/my-tool"},
+            "_meta": {
+                "store.tool.mcpb": {"mcp_config": {"platform_overrides": overrides}}
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_select_platforms() {
+        let manifest = manifest_with_overrides(&["linux-x64", "darwin-arm64", "win32"]);
+
+        let all = select_platforms(&manifest, &["all".into()]).unwrap();
+        assert_eq!(all.platforms, ["darwin-arm64", "linux-x64"]);
+        assert!(all.include_universal);
+
+        let curated =
+            select_platforms(&manifest, &["win32-x64".into(), "linux-x64".into()]).unwrap();
+        assert_eq!(curated.platforms, ["linux-x64", "win32-x64"]);
+        assert!(!curated.include_universal);
+
+        let err = select_platforms(&manifest, &["darwin".into()]).unwrap_err();
+        assert!(err.to_string().contains("darwin-arm64"));
+    }
+}
@@ -22,6 +22,8 @@ pub async fn tool_run(
     host: String,
     config: Vec<String>,
     config_file: Option<String>,
+    env_file: Option<String>,
+    cwd: Option<String>,
     no_save: bool,
     yes: bool,
     verbose: bool,
@@ -38,6 +40,8 @@ pub async fn tool_run(
         PrepareToolOptions {
             config: &config,
             config_file: config_file.as_deref(),
+            env_file: env_file.as_deref(),
+            cwd: cwd.as_deref(),
             no_save,
             yes,
         },
@@ -91,8 +95,8 @@ pub async fn tool_run(
                 .mcp_config
                 .url
                 .as_deref()
-                .unwrap_or("http")
-                .to_string(),
+                .map(|url| prepared.resolved.redactor.redact(url))
+                .unwrap_or_else(|| "http".to_string()),
         }
     );
     eprintln!();
@@ -1,129 +1,279 @@
 //! Script execution handlers.
 
-use crate::constants::MCPB_MANIFEST_FILE;
-use crate::error::{ToolError, ToolResult};
+use crate::cli_version::check_cli_version;
+use crate::error::{EXIT_FAILURE, EXIT_NOT_FOUND, ToolError, ToolResult};
+use crate::mcpb::{find_manifest_path, from_str_relaxed, script_keys};
+use crate::output::print_json;
+use crate::vars::{ShellKind, shell_quote, substitute_script_vars};
+use chrono::Local;
 use colored::Colorize;
-use std::path::PathBuf;
-use std::process::Command;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Flag enabling captured, annotated script output (e.g., `tool build --capture`).
+const CAPTURE_FLAG: &str = "--capture";
+
+/// Number of trailing output lines shown when a captured script fails.
+const CAPTURE_TAIL_LINES: usize = 50;
+
+/// Key in `_meta.store.tool.mcpb` naming the shell scripts run under (e.g. `pwsh`, `bash`).
+const SCRIPT_SHELL_KEY: &str = "script_shell";
+
+/// Prefix of the hook run before a script (`prebuild` before `build`).
+const PRE_HOOK_PREFIX: &str = "pre";
+
+/// Prefix of the hook run after a script succeeds (`postbuild` after `build`).
+const POST_HOOK_PREFIX: &str = "post";
+
+/// Exit status of `tool scripts` when the manifest defines no scripts.
+const NO_SCRIPTS_EXIT_CODE: i32 = EXIT_FAILURE;
+
+/// Exit status of `tool scripts` when there is no manifest.
+const MISSING_MANIFEST_EXIT_CODE: i32 = EXIT_NOT_FOUND;
+
+/// Script run before `tool pack` and `tool publish` bundle the tool.
+pub(super) const PREPACK_SCRIPT: &str = "prepack";
+
+/// Script run after `tool pack` and `tool publish` bundle the tool successfully.
+pub(super) const POSTPACK_SCRIPT: &str = "postpack";
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Scripts and shell settings from a tool's manifest.
+struct ManifestScripts {
+    /// Tool directory scripts run in.
+    dir: PathBuf,
+
+    /// `_meta.store.tool.mcpb.scripts`.
+    scripts: serde_json::Map<String, serde_json::Value>,
+
+    /// Shell program scripts run under.
+    shell: String,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ManifestScripts {
+    /// Load scripts from `dir/manifest.json`, or `None` if there is no manifest.
+    fn load(dir: &Path) -> ToolResult<Option<Self>> {
+        let manifest_path = find_manifest_path(dir);
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&manifest_path)?;
+        let manifest: serde_json::Value = from_str_relaxed(&content)
+            .map_err(|e| ToolError::Generic(format!("Invalid JSON: {}", e)))?;
+
+        let store_meta = manifest.get("_meta").and_then(|m| m.get("store.tool.mcpb"));
+        let scripts = store_meta
+            .and_then(|r| r.get("scripts"))
+            .and_then(|s| s.as_object())
+            .cloned()
+            .unwrap_or_default();
+        let shell = store_meta
+            .and_then(|r| r.get(SCRIPT_SHELL_KEY))
+            .and_then(|v| v.as_str())
+            .unwrap_or(default_shell())
+            .to_string();
+
+        Ok(Some(Self {
+            dir: dir.to_path_buf(),
+            scripts,
+            shell,
+        }))
+    }
+
+    /// Get a script, preferring the current OS variant.
+    fn get(&self, name: &str) -> Option<&str> {
+        script_keys(name)
+            .iter()
+            .find_map(|key| self.scripts.get(key)?.as_str())
+    }
+
+    /// Run a hook script if it is defined.
+    fn run_hook(&self, name: &str, capture: bool) -> ToolResult<()> {
+        if self.get(name).is_none() {
+            return Ok(());
+        }
+        self.run(name, &[], capture)
+    }
+
+    /// Run a defined script with extra arguments.
+    fn run(&self, name: &str, extra_args: &[String], capture: bool) -> ToolResult<()> {
+        let Some(script_cmd) = self.get(name) else {
+            return Ok(());
+        };
+        let kind = shell_kind(&self.shell);
+
+        // Substitute ${__dirname}, ${env.X} and other built-ins, quoted for the shell
+        let script_cmd = substitute_script_vars(script_cmd, &self.dir.to_string_lossy(), kind)?;
+
+        // Build full command with extra args, each passed as a single word
+        let full_cmd = if extra_args.is_empty() {
+            script_cmd
+        } else {
+            let args: Vec<String> = extra_args.iter().map(|a| shell_quote(a, kind)).collect();
+            format!("{} {}", script_cmd, args.join(" "))
+        };
+
+        println!("  {} {}", "Running:".bright_cyan(), full_cmd.bright_white());
+
+        // Execute via the configured shell (platform default otherwise)
+        let mut command = shell_command(&self.shell, &full_cmd, &self.dir);
+        let status = if capture {
+            run_captured(name, command)?
+        } else {
+            command.status()?
+        };
+
+        if !status.success() {
+            return Err(ToolError::Generic(format!(
+                "Script '{}' failed with exit code: {}",
+                name,
+                status.code().unwrap_or(-1)
+            )));
+        }
+
+        Ok(())
+    }
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
 
 /// Run a script from manifest.json `_meta.store.tool.mcpb.scripts`
+///
+/// An OS-specific variant (`build:windows`, `build:macos`, `build:linux`) takes precedence
+/// over the plain script. Scripts run under `_meta.store.tool.mcpb.script_shell` if set,
+/// otherwise `cmd /C` on Windows and `sh -c` elsewhere.
+///
+/// Like npm lifecycle scripts, `pre<name>` runs first and `post<name>` runs after a
+/// successful run, when defined. Hooks don't get hooks of their own.
+///
+/// With `capture`, output is piped instead of inherited: each line is prefixed with the
+/// script name and a timestamp, and the last lines are repeated with the exit code on failure.
 pub async fn run_script(
     script_name: &str,
     path: Option<String>,
     extra_args: Vec<String>,
+    capture: bool,
 ) -> ToolResult<()> {
     let target_dir = resolve_target_dir(&path)?;
 
-    // Load manifest.json
-    let manifest_path = target_dir.join(MCPB_MANIFEST_FILE);
-    if !manifest_path.exists() {
-        return Err(ToolError::Generic(format!(
+    // Warn if the running CLI is outside the project's pinned version range
+    check_cli_version(&target_dir, false)?;
+
+    let scripts = ManifestScripts::load(&target_dir)?.ok_or_else(|| {
+        ToolError::Generic(format!(
             "No manifest.json found in {}\nRun `tool init` to create one.",
             target_dir.display()
-        )));
-    }
-
-    let content = std::fs::read_to_string(&manifest_path)?;
-    let manifest: serde_json::Value = serde_json::from_str(&content)
-        .map_err(|e| ToolError::Generic(format!("Invalid JSON: {}", e)))?;
-
-    // Extract script from _meta.store.tool.mcpb.scripts
-    let script_cmd = manifest
-        .get("_meta")
-        .and_then(|m| m.get("store.tool.mcpb"))
-        .and_then(|r| r.get("scripts"))
-        .and_then(|s| s.get(script_name))
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| {
-            ToolError::Generic(format!(
-                "Script '{}' not found in manifest.json\nDefine it in _meta.store.tool.mcpb.scripts or run it directly (e.g., `tool build`, `tool test`).",
-                script_name
-            ))
-        })?;
-
-    // Substitute ${__dirname} with target directory
-    let dirname = target_dir.to_string_lossy();
-    let script_cmd = script_cmd.replace("${__dirname}", &dirname);
-
-    // Build full command with extra args
-    let full_cmd = if extra_args.is_empty() {
-        script_cmd
-    } else {
-        format!("{} {}", script_cmd, extra_args.join(" "))
-    };
-
-    println!("  {} {}", "Running:".bright_cyan(), full_cmd.bright_white());
-
-    // Execute via shell (platform-specific)
-    let status = if cfg!(windows) {
-        Command::new("cmd")
-            .arg("/C")
-            .arg(&full_cmd)
-            .current_dir(&target_dir)
-            .status()?
-    } else {
-        Command::new("sh")
-            .arg("-c")
-            .arg(&full_cmd)
-            .current_dir(&target_dir)
-            .status()?
-    };
+        ))
+    })?;
 
-    if !status.success() {
+    if scripts.get(script_name).is_none() {
         return Err(ToolError::Generic(format!(
-            "Script '{}' failed with exit code: {}",
-            script_name,
-            status.code().unwrap_or(-1)
+            "Script '{}' not found in manifest.json\nDefine it in _meta.store.tool.mcpb.scripts or run it directly (e.g., `tool build`, `tool test`).",
+            script_name
         )));
     }
 
-    Ok(())
+    scripts.run_hook(&format!("{}{}", PRE_HOOK_PREFIX, script_name), capture)?;
+    scripts.run(script_name, &extra_args, capture)?;
+    scripts.run_hook(&format!("{}{}", POST_HOOK_PREFIX, script_name), capture)
+}
+
+/// Run a lifecycle script such as `prepack` if the manifest defines it.
+pub(super) fn run_lifecycle_script(dir: &Path, name: &str) -> ToolResult<()> {
+    match ManifestScripts::load(dir) {
+        Ok(Some(scripts)) => scripts.run_hook(name, false),
+        // Missing or invalid manifests are reported by the command itself
+        _ => Ok(()),
+    }
 }
 
 /// List available scripts from manifest.json
-pub async fn list_scripts(path: Option<String>) -> ToolResult<()> {
+///
+/// Commands are shown raw, before variable substitution. Exits with status 1 when the
+/// manifest defines no scripts and 3 when there is no manifest.
+pub async fn list_scripts(path: Option<String>, json_output: bool) -> ToolResult<()> {
     let target_dir = resolve_target_dir(&path)?;
-    let manifest_path = target_dir.join(MCPB_MANIFEST_FILE);
-
-    if !manifest_path.exists() {
-        return Err(ToolError::Generic(format!(
-            "No manifest.json found in {}",
-            target_dir.display()
-        )));
-    }
 
-    let content = std::fs::read_to_string(&manifest_path)?;
-    let manifest: serde_json::Value = serde_json::from_str(&content)
-        .map_err(|e| ToolError::Generic(format!("Invalid JSON: {}", e)))?;
+    let Some(manifest) = ManifestScripts::load(&target_dir)? else {
+        let message = format!("No manifest.json found in {}", target_dir.display());
+        if json_output {
+            eprintln!("{}", message);
+        } else {
+            println!("  {} {}", "✗".bright_red(), message);
+        }
+        return Err(ToolError::Reported {
+            code: MISSING_MANIFEST_EXIT_CODE,
+        });
+    };
 
-    let scripts = manifest
-        .get("_meta")
-        .and_then(|m| m.get("store.tool.mcpb"))
-        .and_then(|r| r.get("scripts"))
-        .and_then(|s| s.as_object());
+    let scripts: serde_json::Map<String, serde_json::Value> = manifest
+        .scripts
+        .into_iter()
+        .filter(|(_, cmd)| cmd.is_string())
+        .collect();
 
-    match scripts {
-        Some(scripts) if !scripts.is_empty() => {
-            println!("  {}", "Available scripts:".bright_cyan().bold());
-            for (name, cmd) in scripts {
-                if let Some(cmd_str) = cmd.as_str() {
-                    println!("  · {} {}", name.bright_white(), cmd_str.bright_black());
-                }
+    if json_output {
+        let output = serde_json::json!({ "scripts": scripts });
+        print_json(&output, false)?;
+    } else if scripts.is_empty() {
+        println!("  {}", "No scripts defined in manifest.json".yellow());
+        println!("  · Add scripts to _meta.store.tool.mcpb.scripts");
+    } else {
+        println!("  {}", "Available scripts:".bright_cyan().bold());
+        for (name, cmd) in &scripts {
+            if let Some(cmd_str) = cmd.as_str() {
+                println!("  · {} {}", name.bright_white(), cmd_str.bright_black());
             }
         }
-        _ => {
-            println!("  {}", "No scripts defined in manifest.json".yellow());
-            println!("  · Add scripts to _meta.store.tool.mcpb.scripts");
-        }
     }
 
+    if scripts.is_empty() {
+        return Err(ToolError::Reported {
+            code: NO_SCRIPTS_EXIT_CODE,
+        });
+    }
     Ok(())
 }
 
+/// Names of the scripts defined in `dir/manifest.json`, sorted.
+///
+/// OS-specific variants (`build:linux`) are folded into their base name. Returns an empty
+/// list when there is no manifest.
+pub(super) fn script_names(dir: &Path) -> ToolResult<Vec<String>> {
+    let Some(manifest) = ManifestScripts::load(dir)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut names: Vec<String> = manifest
+        .scripts
+        .iter()
+        .filter(|(_, cmd)| cmd.is_string())
+        .map(|(key, _)| key.split_once(':').map_or(key.as_str(), |(name, _)| name))
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
 /// Run a script from external subcommand (e.g., `tool build ./path -- extra args`)
 pub async fn run_external_script(args: Vec<std::ffi::OsString>) -> ToolResult<()> {
     if args.is_empty() {
@@ -133,12 +283,28 @@ pub async fn run_external_script(args: Vec<std::ffi::OsString>) -> ToolResult<()
     // First arg is the script name
     let script_name = args[0].to_string_lossy().to_string();
 
-    // Parse remaining args: [path] [-- extra_args...]
-    let remaining: Vec<String> = args[1..]
+    // Parse remaining args: [path] [--capture] [-- extra_args...]
+    let mut remaining: Vec<String> = args[1..]
         .iter()
         .map(|s| s.to_string_lossy().into())
         .collect();
 
+    // Extract --capture (only before the "--" separator, so it can still be passed through)
+    let capture_end = remaining
+        .iter()
+        .position(|s| s == "--")
+        .unwrap_or(remaining.len());
+    let capture = match remaining[..capture_end]
+        .iter()
+        .position(|s| s == CAPTURE_FLAG)
+    {
+        Some(pos) => {
+            remaining.remove(pos);
+            true
+        }
+        None => false,
+    };
+
     // Find "--" separator if present
     let separator_pos = remaining.iter().position(|s| s == "--");
 
@@ -159,7 +325,114 @@ pub async fn run_external_script(args: Vec<std::ffi::OsString>) -> ToolResult<()
         }
     };
 
-    run_script(&script_name, path, extra_args).await
+    run_script(&script_name, path, extra_args, capture).await
+}
+
+/// Default shell for scripts on the current platform.
+pub(super) fn default_shell() -> &'static str {
+    if cfg!(windows) { "cmd" } else { "sh" }
+}
+
+/// Shell family of a shell program (`sh`, `bash`, `cmd`, `pwsh`, a full path, ...).
+fn shell_kind(shell: &str) -> ShellKind {
+    let name = Path::new(shell)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match name.as_str() {
+        "cmd" => ShellKind::Cmd,
+        "powershell" | "pwsh" => ShellKind::PowerShell,
+        _ => ShellKind::Posix,
+    }
+}
+
+/// Build a command running a script through the given shell.
+pub(super) fn shell_command(shell: &str, script: &str, dir: &Path) -> Command {
+    let mut command = Command::new(shell);
+    match shell_kind(shell) {
+        ShellKind::Cmd => command.arg("/C"),
+        ShellKind::PowerShell => command.args(["-NoProfile", "-Command"]),
+        ShellKind::Posix => command.arg("-c"),
+    };
+    command.arg(script).current_dir(dir);
+    command
+}
+
+/// Run a command with piped output, annotating each line with the script name and a timestamp.
+///
+/// On failure, the last `CAPTURE_TAIL_LINES` lines are printed again with the exit code.
+fn run_captured(script_name: &str, mut command: Command) -> ToolResult<ExitStatus> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Merge both streams through one channel to keep lines in arrival order
+    let (tx, rx) = mpsc::channel();
+    let readers = [
+        child
+            .stdout
+            .take()
+            .map(|s| forward_lines(s, false, tx.clone())),
+        child
+            .stderr
+            .take()
+            .map(|s| forward_lines(s, true, tx.clone())),
+    ];
+    drop(tx);
+
+    let mut tail: VecDeque<String> = VecDeque::with_capacity(CAPTURE_TAIL_LINES);
+    for (is_stderr, line) in rx {
+        let annotated = format!(
+            "[{} {}{}] {}",
+            script_name,
+            Local::now().format("%H:%M:%S%.3f"),
+            if is_stderr { " stderr" } else { "" },
+            line
+        );
+        println!("{}", annotated);
+        if tail.len() == CAPTURE_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(annotated);
+    }
+
+    for reader in readers.into_iter().flatten() {
+        let _ = reader.join();
+    }
+    let status = child.wait()?;
+
+    if !status.success() {
+        println!();
+        println!(
+            "  {} Script '{}' exited with code {} (last {} lines):",
+            "✗".bright_red(),
+            script_name.bright_white(),
+            status.code().unwrap_or(-1),
+            tail.len()
+        );
+        for line in &tail {
+            println!("    {}", line);
+        }
+        println!();
+    }
+
+    Ok(status)
+}
+
+/// Forward lines from a child stream to a channel, tagged with whether they came from stderr.
+fn forward_lines<R: Read + Send + 'static>(
+    reader: R,
+    is_stderr: bool,
+    tx: mpsc::Sender<(bool, String)>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            if tx.send((is_stderr, line)).is_err() {
+                break;
+            }
+        }
+    })
 }
 
 /// Helper to resolve target directory from optional path
@@ -176,3 +449,95 @@ pub(super) fn resolve_target_dir(path: &Option<String>) -> ToolResult<PathBuf> {
         None => Ok(std::env::current_dir()?),
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::constants::MCPB_MANIFEST_FILE;
+    use tempfile::TempDir;
+
+    fn write_scripts(dir: &Path, scripts: serde_json::Value) {
+        let manifest = serde_json::json!({
+            "name": "hooks",
+            "_meta": { "store.tool.mcpb": { "scripts": scripts } }
+        });
+        std::fs::write(dir.join(MCPB_MANIFEST_FILE), manifest.to_string()).unwrap();
+    }
+
+    fn log(dir: &Path) -> String {
+        std::fs::read_to_string(dir.join("log")).unwrap_or_default()
+    }
+
+    #[test]
+    fn script_names_folds_os_variants() {
+        let dir = TempDir::new().unwrap();
+        write_scripts(
+            dir.path(),
+            serde_json::json!({
+                "test": "cargo test",
+                "build:linux": "make",
+                "build": "make all",
+                "broken": 42,
+            }),
+        );
+
+        assert_eq!(script_names(dir.path()).unwrap(), ["build", "test"]);
+        assert!(
+            script_names(&dir.path().join("missing"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn run_script_runs_pre_and_post_hooks() {
+        let dir = TempDir::new().unwrap();
+        write_scripts(
+            dir.path(),
+            serde_json::json!({
+                "prebuild": "echo pre >> log",
+                "build": "echo build >> log",
+                "postbuild": "echo post >> log",
+                "prepostbuild": "echo nested >> log",
+            }),
+        );
+
+        let path = Some(dir.path().to_string_lossy().to_string());
+        run_script("build", path, Vec::new(), false).await.unwrap();
+        assert_eq!(log(dir.path()), "pre\nbuild\npost\n");
+    }
+
+    #[tokio::test]
+    async fn run_script_skips_post_hook_on_failure() {
+        let dir = TempDir::new().unwrap();
+        write_scripts(
+            dir.path(),
+            serde_json::json!({
+                "test": "echo test >> log; exit 3",
+                "posttest": "echo post >> log",
+            }),
+        );
+
+        let path = Some(dir.path().to_string_lossy().to_string());
+        assert!(run_script("test", path, Vec::new(), false).await.is_err());
+        assert_eq!(log(dir.path()), "test\n");
+    }
+
+    #[test]
+    fn lifecycle_script_is_optional() {
+        let dir = TempDir::new().unwrap();
+        run_lifecycle_script(dir.path(), PREPACK_SCRIPT).unwrap();
+
+        write_scripts(
+            dir.path(),
+            serde_json::json!({ "prepack": "echo prepack >> log" }),
+        );
+        run_lifecycle_script(dir.path(), PREPACK_SCRIPT).unwrap();
+        run_lifecycle_script(dir.path(), POSTPACK_SCRIPT).unwrap();
+        assert_eq!(log(dir.path()), "prepack\n");
+    }
+}
@@ -2,7 +2,10 @@
 
 use crate::error::ToolResult;
 use crate::format::format_description;
-use crate::registry::RegistryClient;
+use crate::output::print_json;
+use crate::registry::{
+    DEFAULT_SEARCH_LIMIT, RegistryClient, SearchOptions, SearchPage, SearchSort,
+};
 use crate::styles::Spinner;
 use colored::Colorize;
 
@@ -11,21 +14,31 @@ use colored::Colorize;
 //--------------------------------------------------------------------------------------------------
 
 /// Search for tools in the registry.
-pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolResult<()> {
+///
+/// Concise output stays a plain header + TSV page for scripting; human output ends with
+/// a "showing N of M" footer and the command for the next page.
+pub async fn search_tools(
+    query: &str,
+    options: &SearchOptions,
+    json_output: bool,
+    concise: bool,
+    no_header: bool,
+) -> ToolResult<()> {
     let client = RegistryClient::new();
 
-    let results = if concise {
-        client.search(query, Some(20)).await?
+    let page = if concise || json_output {
+        client.search(query, options).await?
     } else {
-        let spinner = Spinner::with_indent(format!("Searching for \"{}\"", query), 2);
-        match client.search(query, Some(20)).await {
-            Ok(results) => {
-                if results.is_empty() {
-                    spinner.fail(Some(&format!("No tools found matching: {}", query)));
+        let label = search_label(query, options);
+        let spinner = Spinner::with_indent(format!("Searching for {}", label), 2);
+        match client.search(query, options).await {
+            Ok(page) => {
+                if page.results.is_empty() {
+                    spinner.fail(Some(&format!("No tools found matching: {}", label)));
                 } else {
-                    spinner.succeed(Some(&format!("Found {} tool(s)", results.len())));
+                    spinner.succeed(Some(&format!("Found {} tool(s)", page.results.len())));
                 }
-                results
+                page
             }
             Err(e) => {
                 spinner.fail(Some("Search failed"));
@@ -34,6 +47,37 @@ pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolRe
         }
     };
 
+    let results = &page.results;
+
+    if json_output {
+        let results: Vec<_> = results
+            .iter()
+            .map(|result| {
+                serde_json::json!({
+                    "namespace": result.namespace,
+                    "name": result.name,
+                    "description": result.description,
+                    "latest_version": result.latest_version,
+                    "downloads": result.total_downloads,
+                    "categories": result.categories,
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "query": query,
+            "categories": options.categories,
+            "keywords": options.keywords,
+            "results": results,
+            "page": options.page,
+            "limit": options.limit,
+            "sort": options.sort.to_string(),
+            "total": page.total,
+            "has_more": page.has_more,
+        });
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
     if results.is_empty() {
         return Ok(());
     }
@@ -42,9 +86,9 @@ pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolRe
     if concise {
         use crate::concise::quote;
         if !no_header {
-            println!("#ref\tdescription\tdownloads");
+            println!("#ref\tdescription\tdownloads\tcategories");
         }
-        for result in &results {
+        for result in results {
             let version_str = result
                 .latest_version
                 .as_ref()
@@ -56,19 +100,20 @@ pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolRe
                 .and_then(|d| format_description(d, false, ""))
                 .unwrap_or_default();
             println!(
-                "{}/{}{}\t{}\t{}",
+                "{}/{}{}\t{}\t{}\t{}",
                 result.namespace,
                 result.name,
                 version_str,
                 quote(&desc),
-                result.total_downloads
+                result.total_downloads,
+                quote(&result.categories.join(","))
             );
         }
         return Ok(());
     }
 
     println!();
-    for result in &results {
+    for result in results {
         let version_str = result
             .latest_version
             .as_ref()
@@ -90,6 +135,13 @@ pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolRe
         {
             println!("  · {}", desc.dimmed());
         }
+        if !result.categories.is_empty() {
+            println!(
+                "  · {} {}",
+                "Categories:".dimmed(),
+                result.categories.join(", ")
+            );
+        }
         println!();
     }
 
@@ -103,6 +155,125 @@ pub async fn search_tools(query: &str, concise: bool, no_header: bool) -> ToolRe
         "Install with:".dimmed(),
         format!("tool install {}", install_ref).bright_white()
     );
+    print_page_footer(query, options, &page);
 
     Ok(())
 }
+
+/// Print which results are shown and how to fetch the next page.
+fn print_page_footer(query: &str, options: &SearchOptions, page: &SearchPage) {
+    let first = (options.page - 1) * options.limit + 1;
+    let last = first + page.results.len() - 1;
+    let of_total = page
+        .total
+        .map(|total| format!(" of {}", total))
+        .unwrap_or_default();
+    println!("  · {} {}–{}{}", "Showing".dimmed(), first, last, of_total);
+
+    if page.has_more {
+        println!(
+            "  · {} {}",
+            "Next page:".dimmed(),
+            next_page_command(query, options).bright_white()
+        );
+    }
+}
+
+/// Describe what was searched for, e.g. `"db" (category: storage)`.
+fn search_label(query: &str, options: &SearchOptions) -> String {
+    let mut filters = Vec::new();
+    if !options.categories.is_empty() {
+        filters.push(format!("category: {}", options.categories.join(", ")));
+    }
+    if !options.keywords.is_empty() {
+        filters.push(format!("keyword: {}", options.keywords.join(", ")));
+    }
+    let filters = filters.join("; ");
+
+    match (query.is_empty(), filters.is_empty()) {
+        (true, _) => filters,
+        (false, true) => format!("\"{}\"", query),
+        (false, false) => format!("\"{}\" ({})", query, filters),
+    }
+}
+
+/// `tool search` command for the page after `options.page`, keeping non-default flags.
+fn next_page_command(query: &str, options: &SearchOptions) -> String {
+    let mut command = "tool search".to_string();
+    if !query.is_empty() {
+        command.push_str(&format!(" {}", quote_arg(query)));
+    }
+    for category in &options.categories {
+        command.push_str(&format!(" --category {}", quote_arg(category)));
+    }
+    for keyword in &options.keywords {
+        command.push_str(&format!(" --keyword {}", quote_arg(keyword)));
+    }
+    if options.limit != DEFAULT_SEARCH_LIMIT {
+        command.push_str(&format!(" --limit {}", options.limit));
+    }
+    if options.sort != SearchSort::default() {
+        command.push_str(&format!(" --sort {}", options.sort));
+    }
+    command.push_str(&format!(" --page {}", options.page + 1));
+    command
+}
+
+/// Quote a query for display in a suggested command when it has spaces.
+fn quote_arg(arg: &str) -> String {
+    if arg.contains(char::is_whitespace) {
+        format!("\"{}\"", arg.replace('"', "\\\""))
+    } else {
+        arg.to_string()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_page_command_keeps_non_default_flags() {
+        let options = SearchOptions::default();
+        assert_eq!(
+            next_page_command("bash", &options),
+            "tool search bash --page 2"
+        );
+
+        let options = SearchOptions {
+            limit: 50,
+            page: 2,
+            sort: SearchSort::Updated,
+            ..SearchOptions::default()
+        };
+        assert_eq!(
+            next_page_command("database sql", &options),
+            "tool search \"database sql\" --limit 50 --sort updated --page 3"
+        );
+    }
+
+    #[test]
+    fn category_only_search_omits_query() {
+        let options = SearchOptions {
+            categories: vec!["filesystem".into()],
+            keywords: vec!["git hub".into()],
+            ..SearchOptions::default()
+        };
+        assert_eq!(
+            next_page_command("", &options),
+            "tool search --category filesystem --keyword \"git hub\" --page 2"
+        );
+        assert_eq!(
+            search_label("", &options),
+            "category: filesystem; keyword: git hub"
+        );
+        assert_eq!(
+            search_label("fs", &options),
+            "\"fs\" (category: filesystem; keyword: git hub)"
+        );
+    }
+}
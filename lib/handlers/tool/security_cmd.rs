@@ -0,0 +1,160 @@
+//! Security scan command handlers.
+
+use crate::commands::SecurityCommand;
+use crate::error::{EXIT_FAILURE, ToolError, ToolResult};
+use crate::output::print_json;
+use crate::resolver::FilePluginResolver;
+use crate::security::{SecurityFinding, Severity, scan_manifest};
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+use std::path::PathBuf;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Exit status of `tool security scan` when any high-severity finding is reported.
+const HIGH_SEVERITY_EXIT_CODE: i32 = EXIT_FAILURE;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Scan result for one installed tool.
+#[derive(Serialize)]
+struct ToolScan {
+    /// Tool reference.
+    tool: String,
+
+    /// Installed tool directory.
+    path: PathBuf,
+
+    /// Findings, most severe first.
+    findings: Vec<SecurityFinding>,
+}
+
+/// Finding counts per severity.
+#[derive(Default, Serialize)]
+struct ScanSummary {
+    high: usize,
+    medium: usize,
+    low: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Handle `tool security` subcommands.
+pub async fn security_command(cmd: SecurityCommand, json: bool) -> ToolResult<()> {
+    match cmd {
+        SecurityCommand::Scan { tool, all } => security_scan(tool.as_deref(), all, json).await,
+    }
+}
+
+/// Scan installed tool manifests for risky configurations.
+///
+/// Exits with status 1 if any high-severity finding is reported.
+async fn security_scan(tool: Option<&str>, all: bool, json_output: bool) -> ToolResult<()> {
+    let resolver = FilePluginResolver::default();
+
+    let references: Vec<String> = match tool {
+        Some(reference) if !all => vec![reference.to_string()],
+        _ => resolver
+            .list_tools()
+            .await?
+            .iter()
+            .map(|r| r.to_string())
+            .collect(),
+    };
+
+    let mut scans = Vec::new();
+    for reference in references {
+        let resolved =
+            resolver
+                .resolve_tool(&reference)
+                .await?
+                .ok_or_else(|| ToolError::NotFound {
+                    kind: "tool".into(),
+                    reference: reference.clone(),
+                })?;
+        let path = resolved
+            .path
+            .parent()
+            .unwrap_or(&resolved.path)
+            .to_path_buf();
+        scans.push(ToolScan {
+            findings: scan_manifest(&resolved.template),
+            tool: reference,
+            path,
+        });
+    }
+
+    let mut summary = ScanSummary::default();
+    for finding in scans.iter().flat_map(|s| &s.findings) {
+        match finding.severity {
+            Severity::High => summary.high += 1,
+            Severity::Medium => summary.medium += 1,
+            Severity::Low => summary.low += 1,
+        }
+    }
+
+    if json_output {
+        let output = serde_json::json!({ "tools": scans, "summary": summary });
+        print_json(&output, false)?;
+    } else {
+        output_human(&scans, &summary);
+    }
+
+    if summary.high > 0 {
+        return Err(ToolError::Reported {
+            code: HIGH_SEVERITY_EXIT_CODE,
+        });
+    }
+    Ok(())
+}
+
+/// Print scan results for humans.
+fn output_human(scans: &[ToolScan], summary: &ScanSummary) {
+    if scans.is_empty() {
+        println!("  {} No tools installed", "✗".bright_red());
+        return;
+    }
+
+    for scan in scans {
+        if scan.findings.is_empty() {
+            println!("  {} {}", "✓".bright_green(), scan.tool.bold());
+            continue;
+        }
+
+        println!("  {} {}", "✗".bright_red(), scan.tool.bold());
+        for finding in &scan.findings {
+            println!(
+                "    {} → {}",
+                severity_label(finding.severity, finding.code),
+                finding.location.bold()
+            );
+            println!("      {}", finding.message);
+        }
+    }
+
+    println!();
+    println!(
+        "  Scanned {} tool{}: {} high, {} medium, {} low",
+        scans.len(),
+        if scans.len() == 1 { "" } else { "s" },
+        summary.high,
+        summary.medium,
+        summary.low
+    );
+}
+
+/// Colored `severity[code]` label.
+fn severity_label(severity: Severity, code: &str) -> ColoredString {
+    let label = format!("{}[{}]", severity, code);
+    match severity {
+        Severity::High => label.bright_red().bold(),
+        Severity::Medium => label.bright_yellow().bold(),
+        Severity::Low => label.dimmed(),
+    }
+}
@@ -0,0 +1,389 @@
+//! User-supplied scaffold templates for `tool init --template`.
+//!
+//! A template is a directory (local path or git repository) that is copied into the
+//! target directory. File contents and path names may use these placeholders:
+//!
+//! - `${name}`: package name (`my-tool`)
+//! - `${name_snake}`: package name in snake_case (`my_tool`)
+//! - `${author}`: author name (from `--author` or git config)
+//! - `${description}`: package description
+//! - `${license}`: license identifier (from `--license`)
+//! - `${year}`: current year
+//!
+//! Other `${...}` references (manifest variables, template literals) are kept as-is.
+//! In `manifest.json` and `manifest.json5`, values are escaped as JSON string contents so
+//! quotes and backslashes can't break the manifest.
+
+use crate::constants::{MCPB_MANIFEST_FILE, MCPB_MANIFEST_JSON5_FILE};
+use crate::error::{ToolError, ToolResult};
+use crate::mcpb::McpbManifest;
+use crate::vars::substitute_template_vars;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Template entries that are never copied.
+const SKIPPED_ENTRIES: &[&str] = &[".git"];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Files written by a template, relative to the target directory.
+pub(super) struct AppliedTemplate {
+    /// Every file written.
+    pub files: Vec<PathBuf>,
+
+    /// Files that already existed and were overwritten (only with `force`).
+    pub replaced: Vec<PathBuf>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Copy a template into `target`, substituting placeholders.
+///
+/// Existing files are only overwritten with `force`.
+pub(super) fn apply_template(
+    source: &str,
+    target: &Path,
+    values: &BTreeMap<&str, String>,
+    force: bool,
+) -> ToolResult<AppliedTemplate> {
+    if is_git_url(source) {
+        let checkout = clone_template(source)?;
+        let result = copy_template(&checkout, target, values, force);
+        let _ = std::fs::remove_dir_all(&checkout);
+        result
+    } else {
+        let dir = PathBuf::from(source);
+        if !dir.is_dir() {
+            return Err(ToolError::Generic(format!(
+                "Template directory not found: {}",
+                dir.display()
+            )));
+        }
+        copy_template(&dir, target, values, force)
+    }
+}
+
+/// Check whether a template source refers to a git repository.
+fn is_git_url(source: &str) -> bool {
+    ["https://", "http://", "ssh://", "git://", "git@"]
+        .iter()
+        .any(|prefix| source.starts_with(prefix))
+        || source.ends_with(".git")
+}
+
+/// Shallow-clone a git template into a temporary directory.
+fn clone_template(url: &str) -> ToolResult<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("tool-template-{}", uuid::Uuid::new_v4()));
+
+    let output = Command::new("git")
+        .args(["clone", "--depth", "1", "--quiet", url])
+        .arg(&dir)
+        .output()
+        .map_err(|e| ToolError::Generic(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        let _ = std::fs::remove_dir_all(&dir);
+        return Err(ToolError::Generic(format!(
+            "Failed to clone template {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(dir)
+}
+
+/// Copy a template directory into `target` with placeholder substitution.
+fn copy_template(
+    template: &Path,
+    target: &Path,
+    values: &BTreeMap<&str, String>,
+    force: bool,
+) -> ToolResult<AppliedTemplate> {
+    if !template.join(MCPB_MANIFEST_FILE).is_file() {
+        return Err(ToolError::Generic(format!(
+            "Template has no {} at its root: {}",
+            MCPB_MANIFEST_FILE,
+            template.display()
+        )));
+    }
+
+    let manifest_values = json_escaped(values);
+
+    // Plan every file first so nothing is written if the template is unusable
+    let mut files = Vec::new();
+    for entry in WalkDir::new(template).into_iter().filter_entry(|e| {
+        !SKIPPED_ENTRIES
+            .iter()
+            .any(|skip| e.file_name() == std::ffi::OsStr::new(skip))
+    }) {
+        let entry = entry.map_err(|e| ToolError::Generic(e.to_string()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry
+            .path()
+            .strip_prefix(template)
+            .map_err(|e| ToolError::Generic(e.to_string()))?;
+        let relative = PathBuf::from(substitute_template_vars(
+            &relative.to_string_lossy(),
+            values,
+        ));
+
+        // Substituted values must not move a file outside the target directory
+        if !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(ToolError::Generic(format!(
+                "Template path {} escapes the target directory",
+                relative.display()
+            )));
+        }
+
+        let file_values = if is_manifest_file(&relative) {
+            &manifest_values
+        } else {
+            values
+        };
+        let bytes = std::fs::read(entry.path())?;
+        let contents = match String::from_utf8(bytes) {
+            Ok(text) => substitute_template_vars(&text, file_values).into_bytes(),
+            Err(e) => e.into_bytes(),
+        };
+        let permissions = entry
+            .metadata()
+            .map_err(|e| ToolError::Generic(e.to_string()))?
+            .permissions();
+
+        files.push((relative, contents, permissions));
+    }
+
+    // The rendered manifest must still be a valid manifest
+    if let Some((_, manifest, _)) = files
+        .iter()
+        .find(|(path, _, _)| path == Path::new(MCPB_MANIFEST_FILE))
+    {
+        serde_json::from_slice::<McpbManifest>(manifest).map_err(|e| {
+            ToolError::Generic(format!(
+                "Template {} is invalid after substitution: {}",
+                MCPB_MANIFEST_FILE, e
+            ))
+        })?;
+    }
+
+    if !force {
+        let conflicts: Vec<String> = files
+            .iter()
+            .filter(|(path, _, _)| target.join(path).exists())
+            .map(|(path, _, _)| format!("  · {}", path.display()))
+            .collect();
+        if !conflicts.is_empty() {
+            return Err(ToolError::Generic(format!(
+                "Template files already exist:\n{}\nUse --force to overwrite.",
+                conflicts.join("\n")
+            )));
+        }
+    }
+
+    let mut replaced = Vec::new();
+    for (path, contents, permissions) in &files {
+        let dest = target.join(path);
+        if dest.exists() {
+            replaced.push(path.clone());
+        }
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&dest, contents)?;
+        std::fs::set_permissions(&dest, permissions.clone())?;
+    }
+
+    Ok(AppliedTemplate {
+        files: files.into_iter().map(|(path, _, _)| path).collect(),
+        replaced,
+    })
+}
+
+/// Whether `path` is a manifest, whose placeholders sit inside JSON strings.
+fn is_manifest_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == MCPB_MANIFEST_FILE || name == MCPB_MANIFEST_JSON5_FILE)
+}
+
+/// Escape each value as the contents of a JSON string (without the surrounding quotes).
+fn json_escaped<'a>(values: &BTreeMap<&'a str, String>) -> BTreeMap<&'a str, String> {
+    values
+        .iter()
+        .map(|(key, value)| {
+            let quoted = serde_json::Value::String(value.clone()).to_string();
+            (*key, quoted[1..quoted.len() - 1].to_string())
+        })
+        .collect()
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn values() -> BTreeMap<&'static str, String> {
+        let mut values = BTreeMap::new();
+        values.insert("name", "my-tool".to_string());
+        values.insert("name_snake", "my_tool".to_string());
+        values.insert("author", "Alice".to_string());
+        values
+    }
+
+    #[test]
+    fn test_is_git_url() {
+        assert!(is_git_url("https://github.com/org/template"));
+        assert!(is_git_url("git@github.com:org/template.git"));
+        assert!(is_git_url("../templates/server.git"));
+        assert!(!is_git_url("./templates/server"));
+        assert!(!is_git_url("/opt/templates/server"));
+    }
+
+    #[test]
+    fn test_copy_template_substitutes_placeholders() {
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+
+        fs::write(
+            template.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "${name}", "server": {"type": "node", "entry_point": "server/index.js", "mcp_config": {"command": "node", "args": ["${__dirname}/server/index.js"]}}}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(template.path().join("src/${name_snake}")).unwrap();
+        fs::write(
+            template.path().join("src/${name_snake}/LICENSE"),
+            "Copyright ${author}\n",
+        )
+        .unwrap();
+        fs::create_dir_all(template.path().join(".git")).unwrap();
+        fs::write(template.path().join(".git/HEAD"), "ref").unwrap();
+
+        let applied = copy_template(template.path(), target.path(), &values(), false).unwrap();
+        assert_eq!(applied.files.len(), 2);
+        assert!(applied.replaced.is_empty());
+
+        let manifest = fs::read_to_string(target.path().join("manifest.json")).unwrap();
+        assert!(manifest.contains(r#""name": "my-tool""#));
+        assert!(manifest.contains("${__dirname}/server/index.js"));
+
+        let license = fs::read_to_string(target.path().join("src/my_tool/LICENSE")).unwrap();
+        assert_eq!(license, "Copyright Alice\n");
+        assert!(!target.path().join(".git").exists());
+    }
+
+    #[test]
+    fn test_copy_template_escapes_manifest_values() {
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(
+            template.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "${name}", "description": "${description}", "server": {"type": "node"}}"#,
+        )
+        .unwrap();
+        fs::write(template.path().join("README.md"), "${description}\n").unwrap();
+
+        let mut values = values();
+        let description = r#"The "best" tool \ ever"#;
+        values.insert("description", description.to_string());
+        copy_template(template.path(), target.path(), &values, false).unwrap();
+
+        let manifest: McpbManifest =
+            serde_json::from_str(&fs::read_to_string(target.path().join("manifest.json")).unwrap())
+                .unwrap();
+        assert_eq!(manifest.description.as_deref(), Some(description));
+        let readme = fs::read_to_string(target.path().join("README.md")).unwrap();
+        assert_eq!(readme, format!("{}\n", description));
+    }
+
+    #[test]
+    fn test_copy_template_requires_manifest() {
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(template.path().join("README.md"), "# ${name}\n").unwrap();
+
+        let err = copy_template(template.path(), target.path(), &values(), false).unwrap_err();
+        assert!(err.to_string().contains("manifest.json"));
+        assert!(!target.path().join("README.md").exists());
+    }
+
+    #[test]
+    fn test_copy_template_refuses_to_overwrite() {
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(
+            template.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "${name}", "server": {"type": "node"}}"#,
+        )
+        .unwrap();
+        fs::write(target.path().join("manifest.json"), "{}").unwrap();
+
+        assert!(copy_template(template.path(), target.path(), &values(), false).is_err());
+        let applied = copy_template(template.path(), target.path(), &values(), true).unwrap();
+        assert_eq!(applied.replaced, vec![PathBuf::from("manifest.json")]);
+    }
+
+    #[test]
+    fn test_copy_template_rejects_escaping_paths() {
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(
+            template.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "x", "server": {"type": "node"}}"#,
+        )
+        .unwrap();
+        fs::write(template.path().join("${name}.txt"), "x").unwrap();
+
+        let mut values = values();
+        values.insert("name", "../escaped".to_string());
+
+        let err = copy_template(template.path(), target.path(), &values, false).unwrap_err();
+        assert!(err.to_string().contains("escapes the target directory"));
+        assert!(!target.path().join("manifest.json").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_template_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let template = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        fs::write(
+            template.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "${name}", "server": {"type": "node"}}"#,
+        )
+        .unwrap();
+        let script = template.path().join("run.sh");
+        fs::write(&script, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        copy_template(template.path(), target.path(), &values(), false).unwrap();
+
+        let mode = fs::metadata(target.path().join("run.sh"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+}
@@ -1,9 +1,13 @@
 //! Tool uninstallation command handlers.
 
+use crate::constants::{DEFAULT_OBJECTS_PATH, DEFAULT_TOOLS_PATH, MCPB_MANIFEST_FILE};
 use crate::error::{ToolError, ToolResult};
+use crate::mcpb::McpbManifest;
+use crate::objects::{release_objects, remove_install};
 use crate::resolver::FilePluginResolver;
 use colored::Colorize;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -21,13 +25,39 @@ pub enum UninstallResult {
     Failed(String),
 }
 
+/// Outcome of `tool uninstall` across all requested tools.
+#[derive(Debug, Clone, Default)]
+pub struct UninstallSummary {
+    /// Number of tools removed.
+    pub removed: usize,
+
+    /// Number of orphaned entries cleaned up.
+    pub orphans_cleaned: usize,
+
+    /// Tools that weren't installed.
+    pub not_found: Vec<String>,
+
+    /// Tools or entries that couldn't be removed, with the error message.
+    pub failed: Vec<(String, String)>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl UninstallSummary {
+    /// Whether any requested tool was missing or couldn't be removed.
+    pub fn has_failures(&self) -> bool {
+        !self.not_found.is_empty() || !self.failed.is_empty()
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
 
 /// Remove a single tool and return its result.
 async fn remove_tool(name: &str) -> (String, UninstallResult) {
-    use crate::constants::DEFAULT_TOOLS_PATH;
     use tokio::fs;
 
     let resolver = FilePluginResolver::default();
@@ -58,6 +88,9 @@ async fn remove_tool(name: &str) -> (String, UninstallResult) {
         );
     }
 
+    // Drop deduplicated files no other install links to
+    release_objects(&DEFAULT_OBJECTS_PATH);
+
     // Clean up empty parent namespace directory if applicable
     if let Some(parent_dir) = tool_dir.parent() {
         // Only clean up if the parent is not the root tools directory
@@ -78,9 +111,33 @@ async fn remove_tool(name: &str) -> (String, UninstallResult) {
 }
 
 /// Remove multiple installed tools.
-pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<()> {
+///
+/// When `path` or `bundle` is given, the installation is derived from that source's manifest
+/// instead of from tool names.
+pub async fn remove_tools(
+    names: &[String],
+    all: bool,
+    yes: bool,
+    path: Option<&str>,
+    bundle: Option<&str>,
+) -> ToolResult<UninstallSummary> {
     use futures_util::future::join_all;
 
+    if path.is_some() || bundle.is_some() {
+        if all || !names.is_empty() {
+            return Err(ToolError::Generic(
+                "Cannot combine --path or --bundle with tool names or --all".into(),
+            ));
+        }
+        return remove_tool_by_source(
+            path,
+            bundle,
+            yes,
+            &DEFAULT_TOOLS_PATH,
+            &DEFAULT_OBJECTS_PATH,
+        );
+    }
+
     let resolver = FilePluginResolver::default();
 
     // Get list of tools to remove and orphaned entries
@@ -95,7 +152,7 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
 
         if installed.is_empty() && orphans.is_empty() {
             println!("\n  {} No tools installed.\n", "!".bright_yellow());
-            return Ok(());
+            return Ok(UninstallSummary::default());
         }
         (
             installed.into_iter().map(|t| t.to_string()).collect(),
@@ -147,15 +204,12 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
             println!();
             println!("  {} Cancelled", "✗".bright_red());
             println!();
-            return Ok(());
+            return Ok(UninstallSummary::default());
         }
         println!();
     }
 
-    let mut removed_count = 0usize;
-    let mut not_found_count = 0usize;
-    let mut failed_count = 0usize;
-    let mut orphans_cleaned = 0usize;
+    let mut summary = UninstallSummary::default();
 
     // Remove tools
     if !tools_to_remove.is_empty() {
@@ -174,7 +228,7 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
                         "✓".bright_green(),
                         tool_name.bright_cyan()
                     );
-                    removed_count += 1;
+                    summary.removed += 1;
                 }
                 UninstallResult::NotFound => {
                     println!(
@@ -182,18 +236,152 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
                         "✗".bright_red(),
                         tool_name.bright_white().bold()
                     );
-                    not_found_count += 1;
+                    summary.not_found.push(tool_name.clone());
                 }
                 UninstallResult::Failed(msg) => {
                     println!("  {} {}: {}", "✗".bright_red(), tool_name, msg);
-                    failed_count += 1;
+                    summary.failed.push((tool_name.clone(), msg.clone()));
                 }
             }
         }
     }
 
     // Clean up orphaned entries
+    let (cleaned, failed) = clean_up_orphans(&orphans);
+    summary.orphans_cleaned = cleaned;
+    summary.failed.extend(failed);
+
+    // Print summary if multiple items were processed
+    if total_items > 1 {
+        println!();
+        if summary.removed > 0 {
+            println!(
+                "  Removed {} {}",
+                summary.removed.to_string().bright_green(),
+                if summary.removed == 1 {
+                    "package"
+                } else {
+                    "packages"
+                }
+            );
+        }
+        if summary.orphans_cleaned > 0 {
+            println!(
+                "  Cleaned up {} orphaned {}",
+                summary.orphans_cleaned.to_string().bright_green(),
+                if summary.orphans_cleaned == 1 {
+                    "entry"
+                } else {
+                    "entries"
+                }
+            );
+        }
+        if !summary.not_found.is_empty() {
+            println!(
+                "  Not found: {}",
+                summary.not_found.len().to_string().bright_yellow()
+            );
+        }
+        if !summary.failed.is_empty() {
+            println!(
+                "  Failed: {}",
+                summary.failed.len().to_string().bright_red()
+            );
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Remove orphaned entries (broken symlinks, stale directories) without touching valid tools.
+///
+/// Lists each entry with why it's considered orphaned and asks for confirmation unless
+/// `yes` is set. Fails only if an entry couldn't be removed.
+pub async fn prune_tools(yes: bool) -> ToolResult<()> {
+    let resolver = FilePluginResolver::default();
+    let orphans = resolver.list_orphaned_entries()?;
+
+    if orphans.is_empty() {
+        println!("  {} Nothing to prune", "✓".bright_green());
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "  {} Found {} orphaned {}",
+        "!".bright_yellow(),
+        orphans.len(),
+        if orphans.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        }
+    );
     for orphan_path in &orphans {
+        println!(
+            "    {} {} {}",
+            "·".dimmed(),
+            orphan_path.display().to_string().dimmed(),
+            format!("({})", orphan_reason(orphan_path)).dimmed()
+        );
+    }
+    println!();
+
+    if !yes {
+        print!("  Remove them? [y/N] ");
+        io::stdout().flush().ok();
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(|e| ToolError::Generic(format!("Failed to read input: {}", e)))?;
+
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!();
+            println!("  {} Cancelled", "✗".bright_red());
+            println!();
+            return Ok(());
+        }
+        println!();
+    }
+
+    let (cleaned, failed) = clean_up_orphans(&orphans);
+
+    if orphans.len() > 1 {
+        println!();
+        if cleaned > 0 {
+            println!(
+                "  Cleaned up {} orphaned {}",
+                cleaned.to_string().bright_green(),
+                if cleaned == 1 { "entry" } else { "entries" }
+            );
+        }
+        if !failed.is_empty() {
+            println!("  Failed: {}", failed.len().to_string().bright_red());
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(ToolError::Generic(format!(
+            "Failed to clean up {} orphaned {}",
+            failed.len(),
+            if failed.len() == 1 {
+                "entry"
+            } else {
+                "entries"
+            }
+        )));
+    }
+
+    Ok(())
+}
+
+/// Remove orphaned entries, printing each outcome. Returns the cleaned count and the failures.
+fn clean_up_orphans(orphans: &[PathBuf]) -> (usize, Vec<(String, String)>) {
+    let mut cleaned = 0usize;
+    let mut failed = Vec::new();
+
+    for orphan_path in orphans {
         let display_name = orphan_path
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
@@ -214,7 +402,7 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
                     "✓".bright_green(),
                     display_name.bright_yellow()
                 );
-                orphans_cleaned += 1;
+                cleaned += 1;
             }
             Err(e) => {
                 println!(
@@ -223,46 +411,236 @@ pub async fn remove_tools(names: &[String], all: bool, yes: bool) -> ToolResult<
                     display_name,
                     e
                 );
-                failed_count += 1;
+                failed.push((display_name, e.to_string()));
             }
         }
     }
 
-    // Print summary if multiple items were processed
-    if total_items > 1 {
-        println!();
-        if removed_count > 0 {
-            println!(
-                "  Removed {} {}",
-                removed_count.to_string().bright_green(),
-                if removed_count == 1 {
-                    "package"
-                } else {
-                    "packages"
-                }
-            );
+    if cleaned > 0 {
+        release_objects(&DEFAULT_OBJECTS_PATH);
+    }
+
+    (cleaned, failed)
+}
+
+/// Why an entry from [`FilePluginResolver::list_orphaned_entries`] is orphaned.
+fn orphan_reason(path: &Path) -> String {
+    if path.is_symlink() {
+        return match std::fs::read_link(path) {
+            Ok(target) => format!("broken symlink to {}", target.display()),
+            Err(_) => "broken symlink".into(),
+        };
+    }
+
+    let is_empty = std::fs::read_dir(path)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(false);
+    if is_empty {
+        "empty directory".into()
+    } else {
+        format!("no {} inside", MCPB_MANIFEST_FILE)
+    }
+}
+
+/// Remove the installation that corresponds to a local source directory or bundle file.
+///
+/// Installs live under `tools_dir`; objects they no longer need are collected from `store`.
+fn remove_tool_by_source(
+    path: Option<&str>,
+    bundle: Option<&str>,
+    yes: bool,
+    tools_dir: &Path,
+    store: &Path,
+) -> ToolResult<UninstallSummary> {
+    let (manifest, source_dir) = match (path, bundle) {
+        (Some(_), Some(_)) => {
+            return Err(ToolError::Generic(
+                "Cannot specify both --path and --bundle".into(),
+            ));
         }
-        if orphans_cleaned > 0 {
-            println!(
-                "  Cleaned up {} orphaned {}",
-                orphans_cleaned.to_string().bright_green(),
-                if orphans_cleaned == 1 {
-                    "entry"
-                } else {
-                    "entries"
-                }
-            );
+        (Some(path), None) => {
+            let dir = resolve_source_path(path)?;
+            if !dir.join(MCPB_MANIFEST_FILE).exists() {
+                return Err(ToolError::Generic(format!(
+                    "No {} found in {}",
+                    MCPB_MANIFEST_FILE,
+                    dir.display()
+                )));
+            }
+            (McpbManifest::load(&dir)?, Some(dir))
         }
-        if not_found_count > 0 {
-            println!(
-                "  Not found: {}",
-                not_found_count.to_string().bright_yellow()
-            );
+        (None, Some(bundle)) => {
+            let file = resolve_source_path(bundle)?;
+            let bytes = std::fs::read(&file).map_err(|e| {
+                ToolError::Generic(format!("Failed to read {}: {}", file.display(), e))
+            })?;
+            let (manifest, _) = crate::pack::read_manifest_from_bundle(&bytes).map_err(|e| {
+                ToolError::Generic(format!("Failed to read manifest from bundle: {}", e))
+            })?;
+            (manifest, None)
+        }
+        (None, None) => unreachable!("caller checks that a source is given"),
+    };
+
+    let tool_name = manifest
+        .name
+        .as_ref()
+        .ok_or_else(|| ToolError::Generic("manifest.json must include a name field".into()))?;
+    let target_name = match manifest.version.as_ref() {
+        Some(v) => format!("{}@{}", tool_name, v),
+        None => tool_name.clone(),
+    };
+    let target_path = tools_dir.join(&target_name);
+
+    if !target_path.exists() && !target_path.is_symlink() {
+        println!(
+            "  {} Tool {} not found",
+            "✗".bright_red(),
+            target_name.bright_white().bold()
+        );
+        return Ok(UninstallSummary {
+            not_found: vec![target_name],
+            ..Default::default()
+        });
+    }
+
+    // A linked install must point back at the given source directory
+    let link_target = std::fs::read_link(&target_path).ok();
+    if let (Some(source_dir), Some(link_target)) = (&source_dir, &link_target)
+        && link_target != source_dir
+    {
+        return Err(ToolError::Generic(format!(
+            "{} is linked to {}, not {}",
+            target_name,
+            link_target.display(),
+            source_dir.display()
+        )));
+    }
+
+    if !yes {
+        println!();
+        println!(
+            "  {} This will uninstall {}",
+            "!".bright_yellow(),
+            target_name.bright_cyan()
+        );
+        match &link_target {
+            Some(link_target) => println!(
+                "    {} {} → {}",
+                "·".dimmed(),
+                target_path.display().to_string().dimmed(),
+                link_target.display().to_string().dimmed()
+            ),
+            None => println!(
+                "    {} {}",
+                "·".dimmed(),
+                target_path.display().to_string().dimmed()
+            ),
         }
-        if failed_count > 0 {
-            println!("  Failed: {}", failed_count.to_string().bright_red());
+        println!();
+        print!("  Continue? [y/N] ");
+        io::stdout().flush().ok();
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(|e| ToolError::Generic(format!("Failed to read input: {}", e)))?;
+
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!();
+            println!("  {} Cancelled", "✗".bright_red());
+            println!();
+            return Ok(UninstallSummary::default());
         }
+        println!();
     }
 
-    Ok(())
+    // Only the link is removed for linked tools; the source directory is left untouched
+    remove_install(&target_path, store)
+        .map_err(|e| ToolError::Generic(format!("Failed to remove: {}", e)))?;
+
+    println!(
+        "  {} Removed {}",
+        "✓".bright_green(),
+        target_name.bright_cyan()
+    );
+
+    Ok(UninstallSummary {
+        removed: 1,
+        ..Default::default()
+    })
+}
+
+/// Resolve a user-supplied source path, expanding `~` and canonicalizing.
+fn resolve_source_path(path: &str) -> ToolResult<PathBuf> {
+    let expanded = match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .ok_or_else(|| ToolError::Generic("Could not determine home directory".into()))?
+            .join(rest),
+        None => Path::new(path).to_path_buf(),
+    };
+
+    expanded
+        .canonicalize()
+        .map_err(|_| ToolError::Generic(format!("Path not found: {}", path)))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orphan_reason() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let empty = dir.path().join("empty");
+        std::fs::create_dir(&empty).unwrap();
+        assert_eq!(orphan_reason(&empty), "empty directory");
+
+        let stale = dir.path().join("stale");
+        std::fs::create_dir_all(stale.join("node_modules")).unwrap();
+        assert_eq!(orphan_reason(&stale), "no manifest.json inside");
+
+        #[cfg(unix)]
+        {
+            let link = dir.path().join("link");
+            std::os::unix::fs::symlink(dir.path().join("missing"), &link).unwrap();
+            assert!(orphan_reason(&link).starts_with("broken symlink to "));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_tool_by_source_collects_objects() {
+        let dir = tempfile::tempdir().unwrap();
+        let (tools_dir, store) = (dir.path().join("tools"), dir.path().join("objects"));
+        let source = dir.path().join("src");
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(
+            source.join(MCPB_MANIFEST_FILE),
+            r#"{"manifest_version":"0.3","name":"my-tool","version":"1.0.0","server":{"type":"node","entry_point":"index.js","mcp_config":{"command":"node"}}}"#,
+        )
+        .unwrap();
+
+        let install = tools_dir.join("my-tool@1.0.0");
+        std::fs::create_dir_all(&install).unwrap();
+        std::fs::write(install.join("index.js"), "module.exports = 1;").unwrap();
+        crate::objects::dedup_dir(&install, &store).unwrap();
+
+        let summary = remove_tool_by_source(
+            Some(source.to_str().unwrap()),
+            None,
+            true,
+            &tools_dir,
+            &store,
+        )
+        .unwrap();
+        assert_eq!(summary.removed, 1);
+        assert!(!install.exists());
+        assert_eq!(std::fs::read_dir(&store).unwrap().count(), 0);
+    }
 }
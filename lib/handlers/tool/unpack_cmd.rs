@@ -0,0 +1,113 @@
+//! Bundle unpack command handler.
+
+use crate::error::{ToolError, ToolResult};
+use crate::extract::{
+    ExtractLimits, extract_archive, restore_entry_point_mode, verify_entry_point,
+};
+use crate::format::format_size;
+use crate::mcpb::McpbManifest;
+use crate::tree::FileTree;
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Expand a bundle into a directory without installing it.
+///
+/// `out` defaults to the bundle's file name without its extension, in the current
+/// directory. A non-empty target directory is only written into with `force`.
+pub fn unpack_bundle(bundle: String, out: Option<String>, force: bool) -> ToolResult<()> {
+    let bundle_path = PathBuf::from(&bundle);
+    if !bundle_path.is_file() {
+        return Err(ToolError::Generic(format!(
+            "Bundle file not found: {}",
+            bundle
+        )));
+    }
+
+    let target_dir = match out {
+        Some(out) => PathBuf::from(out),
+        None => default_unpack_dir(&bundle_path)?,
+    };
+
+    if target_dir.is_file() {
+        return Err(ToolError::Generic(format!(
+            "{} exists and is not a directory",
+            target_dir.display()
+        )));
+    }
+    let created = !target_dir.exists();
+    if !created && !force && !is_empty_dir(&target_dir)? {
+        return Err(ToolError::Generic(format!(
+            "{} is not empty. Use --force to unpack into it anyway.",
+            target_dir.display()
+        )));
+    }
+
+    let file = std::fs::File::open(&bundle_path)
+        .map_err(|e| ToolError::Generic(format!("Failed to open bundle: {}", e)))?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| ToolError::Generic(format!("Failed to read ZIP archive: {}", e)))?;
+
+    let mut tree = FileTree::new();
+    let mut file_count = 0;
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i)?;
+        if !entry.is_dir() {
+            tree.insert(entry.name(), entry.size());
+            file_count += 1;
+        }
+    }
+
+    extract_archive(&mut archive, &target_dir, &ExtractLimits::from_env(), || {}).map_err(|e| {
+        // Only clean up a directory this command created
+        if created {
+            let _ = std::fs::remove_dir_all(&target_dir);
+        }
+        ToolError::Generic(format!("Failed to extract bundle: {}", e))
+    })?;
+    restore_entry_point_mode(&target_dir)?;
+
+    // Unpacking is for inspection, so a broken bundle is reported rather than removed
+    if let Err(e) = verify_entry_point(&target_dir) {
+        println!("  {} {}", "!".bright_yellow(), e);
+    }
+    if let Ok(manifest) = McpbManifest::load(&target_dir)
+        && let Some(mismatch) = manifest.bundle_extension_mismatch(&bundle_path)
+    {
+        println!("  {} {}", "!".bright_yellow(), mismatch);
+    }
+    println!(
+        "  {} Unpacked {} to {}",
+        "✓".bright_green(),
+        bundle_path.display(),
+        target_dir.display().to_string().bold()
+    );
+    println!();
+    for line in tree.render(&format!("{}/", target_dir.display())).lines() {
+        println!("  {}", line);
+    }
+    println!(
+        "\n  · {}  {} files, {}",
+        "Total".dimmed(),
+        file_count,
+        format_size(tree.total_size())
+    );
+
+    Ok(())
+}
+
+/// Default unpack directory: the bundle file name without its extension.
+fn default_unpack_dir(bundle_path: &Path) -> ToolResult<PathBuf> {
+    bundle_path.file_stem().map(PathBuf::from).ok_or_else(|| {
+        ToolError::Generic(format!("Invalid bundle path: {}", bundle_path.display()))
+    })
+}
+
+/// Whether `dir` is a directory with no entries.
+fn is_empty_dir(dir: &Path) -> ToolResult<bool> {
+    Ok(std::fs::read_dir(dir)?.next().is_none())
+}
@@ -1,49 +1,422 @@
 //! Tool validation command handlers.
 
-use crate::error::ToolResult;
-use crate::mcpb::McpbManifest;
-use crate::validate::{ValidationResult, validate_manifest};
+use crate::error::{EXIT_VALIDATION, ToolError, ToolResult};
+use crate::mcp::get_tool_info;
+use crate::mcpb::{McpbManifest, find_manifest_path, from_str_relaxed, strip_jsonc};
+use crate::output::print_json;
+use crate::validate::{
+    ManifestFix, ValidationIssue, ValidationResult, WarningCode, annotate_positions, fix_manifest,
+    to_sarif, validate_manifest, validate_manifest_content,
+};
 use colored::Colorize;
-use std::path::PathBuf;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use super::common::{PrepareToolOptions, prepare_tool};
+use super::manifest_cmd::ensure_rewritable;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A tool, prompt or resource as declared in the manifest or exposed by the server.
+struct Capability {
+    /// Name (or URI for resources).
+    id: String,
+
+    /// Tool input schema, if known.
+    schema: Option<Value>,
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
 
 /// Validate a tool manifest.
+///
+/// `format` selects machine-readable output (`json` or `sarif`) instead of the report.
 pub async fn validate_mcpb(
     path: Option<String>,
     strict: bool,
-    json_output: bool,
+    format: Option<&str>,
     quiet: bool,
+    fix: bool,
+    dry_run: bool,
+    connect: bool,
 ) -> ToolResult<()> {
     let dir = path
         .map(PathBuf::from)
         .unwrap_or_else(|| std::env::current_dir().unwrap());
 
-    let result = validate_manifest(&dir);
+    let (mut result, fixes) = if fix {
+        let (result, fixes) = apply_fixes(&dir, dry_run)?;
+        (result, Some(fixes))
+    } else {
+        (validate_manifest(&dir), None)
+    };
+
+    if connect {
+        check_running_server(&dir, format.is_some(), &mut result).await;
+    }
     let format_name = "manifest.json";
     let is_mcpbx = McpbManifest::load(&dir)
         .map(|m| m.requires_mcpbx())
         .unwrap_or(false);
 
-    if json_output {
-        output_json(&result, format_name, is_mcpbx)?;
-        return check_exit_status(&result, strict);
+    match format {
+        Some("json") => {
+            output_json(&result, format_name, is_mcpbx, fixes.as_deref())?;
+            return check_exit_status(&result, strict);
+        }
+        Some("sarif") => {
+            output_sarif(&result, &dir, strict)?;
+            return check_exit_status(&result, strict);
+        }
+        _ => {}
     }
 
     if quiet {
         output_quiet(&result);
     } else {
+        if let Some(fixes) = &fixes {
+            print_fixes(fixes, dry_run);
+        }
         output_full(&result, strict, format_name, is_mcpbx);
     }
 
     check_exit_status(&result, strict)
 }
 
+/// Fix mechanical manifest issues, then validate the result.
+///
+/// With `dry_run`, the fixed manifest is validated in memory and nothing is written.
+/// Fixed manifests are written as strict JSON, so manifests with comments are refused.
+fn apply_fixes(dir: &Path, dry_run: bool) -> ToolResult<(ValidationResult, Vec<ManifestFix>)> {
+    let manifest_path = find_manifest_path(dir);
+
+    // Missing or unparseable manifests are reported by validation itself
+    let Some(mut raw) = std::fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|content| from_str_relaxed(&content).ok())
+    else {
+        return Ok((validate_manifest(dir), Vec::new()));
+    };
+
+    let fixes = fix_manifest(&mut raw);
+    if fixes.is_empty() {
+        return Ok((validate_manifest(dir), fixes));
+    }
+
+    let content = format!("{}\n", serde_json::to_string_pretty(&raw)?);
+    if dry_run {
+        return Ok((validate_manifest_content(dir, &content), fixes));
+    }
+
+    ensure_rewritable(&manifest_path)?;
+    std::fs::write(&manifest_path, content)?;
+    Ok((validate_manifest(dir), fixes))
+}
+
+/// Launch the server and compare its capabilities with the manifest declarations.
+///
+/// Skipped (with a note) when the manifest has errors, the entry point isn't built,
+/// or the server can't be started.
+async fn check_running_server(dir: &Path, non_interactive: bool, result: &mut ValidationResult) {
+    let skip = |reason: &str| {
+        eprintln!(
+            "  {} Skipping server check: {}\n",
+            "·".dimmed(),
+            reason.dimmed()
+        );
+    };
+
+    if !result.is_valid() {
+        return skip("fix manifest errors first");
+    }
+
+    let Ok(manifest) = McpbManifest::load(dir) else {
+        return skip("manifest could not be loaded");
+    };
+    if let Some(entry_point) = &manifest.server.entry_point
+        && !dir.join(entry_point).exists()
+    {
+        return skip(&format!("entry point `{}` is not built", entry_point));
+    }
+
+    let options = PrepareToolOptions {
+        config: &[],
+        config_file: None,
+        env_file: None,
+        cwd: None,
+        no_save: true,
+        yes: non_interactive,
+    };
+    let capabilities = match prepare_tool(&dir.to_string_lossy(), options).await {
+        Ok(prepared) => get_tool_info(&prepared.resolved, &prepared.tool_name, false).await,
+        Err(e) => Err(e),
+    };
+    let capabilities = match capabilities {
+        Ok(capabilities) => capabilities,
+        Err(e) => return skip(&format!("server failed to start ({})", e)),
+    };
+
+    let statics = manifest.static_responses().unwrap_or_default();
+    let static_location = r#"manifest.json:_meta["store.tool.mcpb"].static_responses"#;
+
+    // Tools: static responses take precedence over the top-level declarations
+    let declared_tools = match (&statics.tools_list, &manifest.tools) {
+        (Some(list), _) => Some((
+            format!(r#"{}["tools/list"].tools"#, static_location),
+            list.tools
+                .iter()
+                .map(|t| Capability {
+                    id: t.name.clone(),
+                    schema: t.input_schema.clone(),
+                })
+                .collect::<Vec<_>>(),
+        )),
+        (None, Some(tools)) => Some((
+            "manifest.json:tools".to_string(),
+            tools
+                .iter()
+                .map(|t| Capability {
+                    id: t.name.clone(),
+                    schema: None,
+                })
+                .collect(),
+        )),
+        (None, None) => None,
+    };
+    if let Some((location, declared)) = declared_tools {
+        let actual: Vec<_> = capabilities
+            .tools
+            .iter()
+            .map(|t| Capability {
+                id: t.name.to_string(),
+                schema: Some(Value::Object((*t.input_schema).clone())),
+            })
+            .collect();
+        let allow_extra = manifest.tools_generated == Some(true);
+        compare_capabilities(
+            "tool",
+            "name",
+            &location,
+            &declared,
+            &actual,
+            allow_extra,
+            result,
+        );
+    }
+
+    let declared_prompts = match (&statics.prompts_list, &manifest.prompts) {
+        (Some(list), _) => Some((
+            format!(r#"{}["prompts/list"].prompts"#, static_location),
+            &list.prompts,
+        )),
+        (None, Some(prompts)) => Some(("manifest.json:prompts".to_string(), prompts)),
+        (None, None) => None,
+    };
+    if let Some((location, prompts)) = declared_prompts {
+        let declared: Vec<_> = prompts
+            .iter()
+            .map(|p| Capability {
+                id: p.name.clone(),
+                schema: None,
+            })
+            .collect();
+        let actual: Vec<_> = capabilities
+            .prompts
+            .iter()
+            .map(|p| Capability {
+                id: p.name.to_string(),
+                schema: None,
+            })
+            .collect();
+        let allow_extra = manifest.prompts_generated == Some(true);
+        compare_capabilities(
+            "prompt",
+            "name",
+            &location,
+            &declared,
+            &actual,
+            allow_extra,
+            result,
+        );
+    }
+
+    if let Some(list) = &statics.resources_list {
+        let location = format!(r#"{}["resources/list"].resources"#, static_location);
+        let declared: Vec<_> = list
+            .resources
+            .iter()
+            .map(|r| Capability {
+                id: r.uri.clone(),
+                schema: None,
+            })
+            .collect();
+        let actual: Vec<_> = capabilities
+            .resources
+            .iter()
+            .map(|r| Capability {
+                id: r.uri.to_string(),
+                schema: None,
+            })
+            .collect();
+        compare_capabilities(
+            "resource", "uri", &location, &declared, &actual, false, result,
+        );
+    }
+
+    // Point the new warnings at their declarations
+    if let Ok(content) = std::fs::read_to_string(find_manifest_path(dir)) {
+        annotate_positions(&strip_jsonc(&content), result);
+    }
+}
+
+/// Report missing, extra and signature-divergent capabilities as warnings.
+fn compare_capabilities(
+    kind: &str,
+    key: &str,
+    location: &str,
+    declared: &[Capability],
+    actual: &[Capability],
+    allow_extra: bool,
+    result: &mut ValidationResult,
+) {
+    for capability in declared {
+        let entry = format!(r#"{}[{}="{}"]"#, location, key, capability.id);
+        let Some(exposed) = actual.iter().find(|a| a.id == capability.id) else {
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::DeclaredCapabilityMissing,
+                    format!("declared {} not exposed by server", kind),
+                    entry,
+                    format!(
+                        "`{}` is declared but the running server doesn't list it",
+                        capability.id
+                    ),
+                )
+                .with_help(format!("remove the declaration or implement the {}", kind)),
+            );
+            continue;
+        };
+
+        if let (Some(declared_schema), Some(actual_schema)) = (&capability.schema, &exposed.schema)
+            && let Some(details) = signature_difference(declared_schema, actual_schema)
+        {
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::ToolSignatureMismatch,
+                    "tool signature differs from server",
+                    entry,
+                    format!("`{}`: {}", capability.id, details),
+                )
+                .with_help("update the declared inputSchema to match the server"),
+            );
+        }
+    }
+
+    if allow_extra {
+        return;
+    }
+
+    for capability in actual {
+        if !declared.iter().any(|d| d.id == capability.id) {
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::UndeclaredCapability,
+                    format!("server exposes undeclared {}", kind),
+                    location.to_string(),
+                    format!(
+                        "the running server lists `{}` but the manifest doesn't declare it",
+                        capability.id
+                    ),
+                )
+                .with_help(format!("declare the {} in the manifest", kind)),
+            );
+        }
+    }
+}
+
+/// Describe how two input schemas differ in parameter names or required parameters.
+fn signature_difference(declared: &Value, actual: &Value) -> Option<String> {
+    let properties = |schema: &Value| -> BTreeSet<String> {
+        schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|p| p.keys().cloned().collect())
+            .unwrap_or_default()
+    };
+    let required = |schema: &Value| -> BTreeSet<String> {
+        schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| {
+                r.iter()
+                    .filter_map(Value::as_str)
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let list = |set: BTreeSet<String>| set.into_iter().collect::<Vec<_>>().join(", ");
+
+    let (declared_props, actual_props) = (properties(declared), properties(actual));
+    if declared_props != actual_props {
+        return Some(format!(
+            "declared parameters [{}], server has [{}]",
+            list(declared_props),
+            list(actual_props)
+        ));
+    }
+
+    let (declared_required, actual_required) = (required(declared), required(actual));
+    if declared_required != actual_required {
+        return Some(format!(
+            "declared required [{}], server requires [{}]",
+            list(declared_required),
+            list(actual_required)
+        ));
+    }
+
+    None
+}
+
+/// Print the changes made (or that would be made) by `--fix`.
+fn print_fixes(fixes: &[ManifestFix], dry_run: bool) {
+    if fixes.is_empty() {
+        println!("  {} Nothing to fix\n", "·".dimmed());
+        return;
+    }
+
+    let (marker, verb) = if dry_run {
+        ("~".bright_yellow(), "Would fix")
+    } else {
+        ("✓".bright_green(), "Fixed")
+    };
+    for fix in fixes {
+        let before = fix.before.as_deref().unwrap_or("(missing)");
+        println!(
+            "  {} {} {}: {} → {}",
+            marker,
+            verb,
+            fix.field.bold(),
+            before.dimmed(),
+            fix.after.bright_cyan()
+        );
+    }
+    println!();
+}
+
 /// Output validation result as JSON.
-fn output_json(result: &ValidationResult, format_name: &str, is_mcpbx: bool) -> ToolResult<()> {
-    let output = serde_json::json!({
+fn output_json(
+    result: &ValidationResult,
+    format_name: &str,
+    is_mcpbx: bool,
+    fixes: Option<&[ManifestFix]>,
+) -> ToolResult<()> {
+    let mut output = serde_json::json!({
         "bundle_format": if is_mcpbx { "mcpbx" } else { "mcpb" },
         "format": format_name,
         "valid": result.is_valid(),
@@ -55,6 +428,8 @@ fn output_json(result: &ValidationResult, format_name: &str, is_mcpbx: bool) ->
                 "location": e.location,
                 "details": e.details,
                 "help": e.help,
+                "line": e.line,
+                "column": e.column,
             })
         }).collect::<Vec<_>>(),
         "warnings": result.warnings.iter().map(|w| {
@@ -64,10 +439,45 @@ fn output_json(result: &ValidationResult, format_name: &str, is_mcpbx: bool) ->
                 "location": w.location,
                 "details": w.details,
                 "help": w.help,
+                "line": w.line,
+                "column": w.column,
             })
         }).collect::<Vec<_>>(),
     });
-    println!("{}", serde_json::to_string_pretty(&output)?);
+    if let Some(fixes) = fixes {
+        output["fixes"] = fixes
+            .iter()
+            .map(|f| {
+                serde_json::json!({
+                    "field": f.field,
+                    "before": f.before,
+                    "after": f.after,
+                })
+            })
+            .collect();
+    }
+    print_json(&output, false)?;
+    Ok(())
+}
+
+/// Output validation result as a SARIF log.
+fn output_sarif(result: &ValidationResult, dir: &Path, strict: bool) -> ToolResult<()> {
+    let manifest_path = find_manifest_path(dir);
+    let content = std::fs::read_to_string(&manifest_path).unwrap_or_default();
+
+    // Absolute paths (the default directory) are reported relative to the working directory
+    let uri = if dir.is_absolute() {
+        std::env::current_dir()
+            .ok()
+            .and_then(|cwd| manifest_path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+            .unwrap_or_else(|| manifest_path.clone())
+    } else {
+        manifest_path.clone()
+    };
+    let uri = uri.to_string_lossy().replace('\\', "/");
+
+    let log = to_sarif(result, &uri, &content, strict);
+    println!("{}", serde_json::to_string_pretty(&log)?);
     Ok(())
 }
 
@@ -114,7 +524,15 @@ fn output_full(result: &ValidationResult, strict: bool, format_name: &str, is_mc
         } else {
             format!("warning[{}]", issue.code).bright_yellow().bold()
         };
-        println!("  {}: → {}", label, issue.location.bold());
+        match (issue.line, issue.column) {
+            (Some(line), Some(column)) => println!(
+                "  {}: → {} {}",
+                label,
+                issue.location.bold(),
+                format!("({}:{})", line, column).dimmed()
+            ),
+            _ => println!("  {}: → {}", label, issue.location.bold()),
+        }
         println!("  · {}", issue.details.dimmed());
         if let Some(help) = &issue.help {
             println!("  · {}: {}", "help".bright_green().dimmed(), help.dimmed());
@@ -181,10 +599,89 @@ fn output_full(result: &ValidationResult, strict: bool, format_name: &str, is_mc
 fn check_exit_status(result: &ValidationResult, strict: bool) -> ToolResult<()> {
     if strict {
         if !result.is_strict_valid() {
-            std::process::exit(1);
+            return Err(ToolError::Reported {
+                code: EXIT_VALIDATION,
+            });
         }
     } else if !result.is_valid() {
-        std::process::exit(1);
+        return Err(ToolError::Reported {
+            code: EXIT_VALIDATION,
+        });
     }
     Ok(())
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool(id: &str, schema: Option<Value>) -> Capability {
+        Capability {
+            id: id.to_string(),
+            schema,
+        }
+    }
+
+    #[test]
+    fn test_compare_capabilities() {
+        let declared = [
+            tool("read", Some(json!({ "properties": { "path": {} } }))),
+            tool("gone", None),
+        ];
+        let actual = [
+            tool(
+                "read",
+                Some(json!({ "properties": { "path": {}, "limit": {} } })),
+            ),
+            tool("extra", None),
+        ];
+
+        let mut result = ValidationResult::default();
+        compare_capabilities(
+            "tool",
+            "name",
+            "manifest.json:tools",
+            &declared,
+            &actual,
+            false,
+            &mut result,
+        );
+
+        let codes: Vec<_> = result.warnings.iter().map(|w| w.code.to_string()).collect();
+        assert_eq!(codes, ["W022", "W020", "W021"]);
+        assert_eq!(
+            result.warnings[1].location,
+            r#"manifest.json:tools[name="gone"]"#
+        );
+
+        // Generated tools may add extras without warnings
+        let mut result = ValidationResult::default();
+        compare_capabilities(
+            "tool",
+            "name",
+            "manifest.json:tools",
+            &declared,
+            &actual,
+            true,
+            &mut result,
+        );
+        assert_eq!(result.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_signature_difference() {
+        let declared = json!({ "properties": { "a": {} }, "required": ["a"] });
+        assert_eq!(signature_difference(&declared, &declared), None);
+
+        let optional = json!({ "properties": { "a": {} } });
+        assert_eq!(
+            signature_difference(&declared, &optional).unwrap(),
+            "declared required [a], server requires []"
+        );
+    }
+}
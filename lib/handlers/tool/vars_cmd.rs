@@ -0,0 +1,270 @@
+//! Variable resolution report handler.
+
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::{EXIT_FAILURE, ToolError, ToolResult};
+use crate::mcpb::{McpbManifest, resolve_platform_overrides};
+use crate::output::print_json;
+use crate::system_config::allocate_system_config;
+use crate::vars::{VarSource, resolve_var_references};
+
+use super::call::{apply_user_config_defaults, parse_user_config};
+use super::common::{ResolvedTool, resolve_tool};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Placeholder shown instead of sensitive values.
+const REDACTED: &str = "********";
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// One `${...}` reference in the server config and how it resolved.
+#[derive(Debug, Clone, Serialize)]
+struct VarReport {
+    /// Config field containing the reference (e.g. `args[1]`, `env.API_KEY`).
+    field: String,
+
+    /// Expression inside `${...}`.
+    expr: String,
+
+    /// Where the value comes from: `user_config`, `default`, `system_config`,
+    /// `builtin`, `environment` or `expression`.
+    source: &'static str,
+
+    /// Resolved value (redacted when sensitive), or `None` if unresolved.
+    value: Option<String>,
+
+    /// Whether the value was redacted.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    sensitive: bool,
+
+    /// Evaluation error, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Report how each `${...}` reference in a tool's server config resolves.
+///
+/// Uses the same config sources and substitution as `tool call` and `tool run`, without
+/// prompting or starting the server. Exits with status 1 if any reference is unresolved.
+pub async fn tool_vars(
+    tool: &str,
+    config: &[String],
+    config_file: Option<&str>,
+    json_output: bool,
+) -> ToolResult<()> {
+    let ResolvedTool {
+        plugin, plugin_ref, ..
+    } = resolve_tool(tool, false, true).await?;
+    let manifest = &plugin.template;
+    let schema = manifest.user_config.as_ref();
+
+    let (mut user_config, _, _) = parse_user_config(config, config_file, &plugin_ref, schema)?;
+    let provided: BTreeSet<String> = user_config.keys().cloned().collect();
+    apply_user_config_defaults(schema, &mut user_config);
+    let system_config = allocate_system_config(manifest.system_config.as_ref())?;
+
+    let sensitive: Vec<&String> = schema
+        .map(|s| {
+            s.iter()
+                .filter(|(_, field)| field.sensitive.unwrap_or(false))
+                .map(|(key, _)| key)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let dirname = manifest
+        .bundle_path
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let mut reports = Vec::new();
+    for (field, text) in config_fields(manifest) {
+        for reference in resolve_var_references(&text, &dirname, &user_config, &system_config) {
+            let source = match reference.source {
+                _ if reference.fallback => "default",
+                VarSource::UserConfig => {
+                    let key = reference
+                        .expr
+                        .trim_start_matches("user_config.")
+                        .split(":-")
+                        .next()
+                        .unwrap_or_default()
+                        .trim();
+                    if provided.contains(key) || !user_config.contains_key(key) {
+                        "user_config"
+                    } else {
+                        "default"
+                    }
+                }
+                VarSource::SystemConfig => "system_config",
+                VarSource::Builtin => "builtin",
+                VarSource::Environment => "environment",
+                VarSource::Expression => "expression",
+            };
+            let is_sensitive = sensitive
+                .iter()
+                .any(|key| references_key(&reference.expr, key));
+
+            reports.push(VarReport {
+                field: field.clone(),
+                source,
+                value: reference.value.map(|v| {
+                    if is_sensitive {
+                        REDACTED.to_string()
+                    } else {
+                        v
+                    }
+                }),
+                sensitive: is_sensitive,
+                error: reference.error,
+                expr: reference.expr,
+            });
+        }
+    }
+
+    let unresolved = reports.iter().filter(|r| r.value.is_none()).count();
+
+    if json_output {
+        let output = serde_json::json!({
+            "tool": tool,
+            "variables": reports,
+            "unresolved": unresolved,
+        });
+        print_json(&output, false)?;
+    } else {
+        print_reports(&reports, unresolved);
+    }
+
+    if unresolved > 0 {
+        return Err(ToolError::Reported { code: EXIT_FAILURE });
+    }
+    Ok(())
+}
+
+/// Print the human-readable report.
+fn print_reports(reports: &[VarReport], unresolved: usize) {
+    if reports.is_empty() {
+        println!("  {} No variables referenced", "✓".bright_green());
+        return;
+    }
+
+    let mut field = "";
+    for report in reports {
+        if report.field != field {
+            field = &report.field;
+            println!("  {}", field.bold());
+        }
+
+        let expr = format!("${{{}}}", report.expr);
+        match &report.value {
+            Some(value) => {
+                let value = if value.is_empty() {
+                    "(empty)".bright_yellow().to_string()
+                } else {
+                    value.bright_cyan().to_string()
+                };
+                println!(
+                    "    {} {} = {}  {}",
+                    "✓".bright_green(),
+                    expr,
+                    value,
+                    report.source.dimmed()
+                );
+            }
+            None => {
+                let reason = report.error.as_deref().unwrap_or("not set");
+                println!(
+                    "    {} {}  {}",
+                    "✗".bright_red(),
+                    expr.bright_red(),
+                    format!("{} ({})", reason, report.source).dimmed()
+                );
+            }
+        }
+    }
+
+    println!();
+    if unresolved == 0 {
+        println!(
+            "  {} {} variable(s) resolved",
+            "✓".bright_green(),
+            reports.len()
+        );
+    } else {
+        println!(
+            "  {} {} of {} variable(s) unresolved",
+            "✗".bright_red(),
+            unresolved,
+            reports.len()
+        );
+    }
+}
+
+/// Server config fields that may contain variables, after platform overrides.
+fn config_fields(manifest: &McpbManifest) -> Vec<(String, String)> {
+    let Some(cfg) = &manifest.server.mcp_config else {
+        return Vec::new();
+    };
+    let cfg = resolve_platform_overrides(cfg, manifest.meta.as_ref());
+
+    let mut fields = Vec::new();
+    if let Some(command) = cfg.command {
+        fields.push(("command".to_string(), command));
+    }
+    for (i, arg) in cfg.args.into_iter().enumerate() {
+        fields.push((format!("args[{}]", i), arg));
+    }
+    fields.extend(prefixed_fields("env", cfg.env));
+    if let Some(url) = cfg.url {
+        fields.push(("url".to_string(), url));
+    }
+    fields.extend(prefixed_fields("headers", cfg.headers));
+    fields
+}
+
+/// Map entries keyed as `section.key`.
+fn prefixed_fields(
+    section: &str,
+    entries: BTreeMap<String, String>,
+) -> impl Iterator<Item = (String, String)> {
+    entries
+        .into_iter()
+        .map(move |(key, value)| (format!("{}.{}", section, key), value))
+}
+
+/// Whether an expression reads `user_config.<key>`.
+fn references_key(expr: &str, key: &str) -> bool {
+    let needle = format!("user_config.{}", key);
+    expr.match_indices(&needle).any(|(i, _)| {
+        !expr[i + needle.len()..]
+            .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    })
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_key_matches_whole_key() {
+        assert!(references_key("user_config.token", "token"));
+        assert!(references_key("upper(user_config.token)", "token"));
+        assert!(!references_key("user_config.token_file", "token"));
+        assert!(!references_key("user_config.api", "token"));
+    }
+}
@@ -0,0 +1,277 @@
+//! Version bump command handler.
+
+use colored::Colorize;
+use semver::{Prerelease, Version};
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use crate::constants::MCPB_MANIFEST_FILE;
+use crate::error::{ToolError, ToolResult};
+
+use super::manifest_cmd::{read_raw_manifest, update_manifest_field};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Version component to increment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    /// `1.2.3` → `2.0.0`
+    Major,
+
+    /// `1.2.3` → `1.3.0`
+    Minor,
+
+    /// `1.2.3` → `1.2.4`
+    Patch,
+
+    /// `1.2.3` → `1.2.4-0`, `1.2.4-rc.1` → `1.2.4-rc.2`
+    Prerelease,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl VersionBump {
+    /// Apply the bump to a version.
+    ///
+    /// Like npm, bumping a prerelease to the release it leads up to just drops the
+    /// prerelease (`1.3.0-rc.1` → `1.3.0` for minor).
+    pub fn apply(self, version: &Version) -> ToolResult<Version> {
+        let is_pre = !version.pre.is_empty();
+        let mut next = Version::new(version.major, version.minor, version.patch);
+
+        match self {
+            VersionBump::Major => {
+                if !(is_pre && version.minor == 0 && version.patch == 0) {
+                    next.major += 1;
+                    next.minor = 0;
+                    next.patch = 0;
+                }
+            }
+            VersionBump::Minor => {
+                if !(is_pre && version.patch == 0) {
+                    next.minor += 1;
+                    next.patch = 0;
+                }
+            }
+            VersionBump::Patch => {
+                if !is_pre {
+                    next.patch += 1;
+                }
+            }
+            VersionBump::Prerelease => {
+                let pre = if is_pre {
+                    next_prerelease(version.pre.as_str())
+                } else {
+                    next.patch += 1;
+                    "0".to_string()
+                };
+                next.pre = Prerelease::new(&pre)
+                    .map_err(|e| ToolError::Generic(format!("Invalid prerelease: {}", e)))?;
+            }
+        }
+
+        Ok(next)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl FromStr for VersionBump {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "major" => Ok(VersionBump::Major),
+            "minor" => Ok(VersionBump::Minor),
+            "patch" => Ok(VersionBump::Patch),
+            "prerelease" | "pre" => Ok(VersionBump::Prerelease),
+            _ => Err(format!(
+                "Invalid version bump '{}'. Expected major, minor, patch or prerelease",
+                s
+            )),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Bump or set the manifest version.
+pub fn bump_version(
+    bump: Option<String>,
+    set: Option<String>,
+    path: &str,
+    git_tag: bool,
+    concise: bool,
+) -> ToolResult<()> {
+    let (_, raw) = read_raw_manifest(path)?;
+    let current = raw.get("version").and_then(|v| v.as_str());
+
+    let next = match (bump, set) {
+        (Some(_), Some(_)) => {
+            return Err(ToolError::Generic(
+                "Cannot combine a version bump with --set".into(),
+            ));
+        }
+        (None, None) => {
+            return Err(ToolError::Generic(
+                "Specify major, minor, patch, prerelease or --set <VERSION>".into(),
+            ));
+        }
+        (None, Some(set)) => Version::parse(set.trim_start_matches('v'))
+            .map_err(|e| ToolError::Generic(format!("Invalid version '{}': {}", set, e)))?,
+        (Some(bump), None) => {
+            let bump: VersionBump = bump.parse().map_err(ToolError::Generic)?;
+            let current = current
+                .ok_or_else(|| ToolError::Generic("manifest.json has no version field".into()))?;
+            let current = Version::parse(current).map_err(|e| {
+                ToolError::Generic(format!(
+                    "Current version '{}' is not valid semver: {}",
+                    current, e
+                ))
+            })?;
+            bump.apply(&current)?
+        }
+    };
+
+    // The tag has to point at the bump, so refuse to mix it with other changes
+    let dir = Path::new(path);
+    if git_tag {
+        ensure_clean_tree(dir)?;
+    }
+
+    let manifest_path = update_manifest_field(path, "version", Value::String(next.to_string()))?;
+
+    if git_tag {
+        let manifest_file = manifest_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(MCPB_MANIFEST_FILE);
+        let tag = format!("v{}", next);
+        commit_and_tag(dir, manifest_file, &tag).map_err(|e| {
+            ToolError::Generic(format!(
+                "{} was already updated to {}, but tagging failed: {}",
+                manifest_file, next, e
+            ))
+        })?;
+    }
+
+    if concise {
+        println!("{}", next);
+    } else {
+        println!(
+            "  {} Version {} → {}",
+            "✓".bright_green(),
+            current.unwrap_or("none").dimmed(),
+            next.to_string().bright_cyan()
+        );
+        if git_tag {
+            println!("  · {} v{}", "Tagged".dimmed(), next);
+        }
+    }
+
+    Ok(())
+}
+
+/// Increment the last numeric identifier of a prerelease, or append `.0`.
+fn next_prerelease(pre: &str) -> String {
+    let mut parts: Vec<String> = pre.split('.').map(String::from).collect();
+    match parts.last().and_then(|p| p.parse::<u64>().ok()) {
+        Some(n) => {
+            let last = parts.len() - 1;
+            parts[last] = (n + 1).to_string();
+        }
+        None => parts.push("0".to_string()),
+    }
+    parts.join(".")
+}
+
+/// Refuse to tag when the tool directory has uncommitted changes.
+fn ensure_clean_tree(dir: &Path) -> ToolResult<()> {
+    let status = run_git(dir, &["status", "--porcelain", "--", "."])?;
+    if !status.trim().is_empty() {
+        return Err(ToolError::Generic(
+            "Working tree has uncommitted changes; commit or stash them before using --git-tag"
+                .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Commit the bumped manifest and create an annotated `tag` on that commit.
+fn commit_and_tag(dir: &Path, manifest_file: &str, tag: &str) -> ToolResult<()> {
+    run_git(dir, &["commit", "-m", tag, "--", manifest_file])?;
+    run_git(dir, &["tag", "-a", tag, "-m", tag])?;
+    Ok(())
+}
+
+/// Run a git command in `dir`, returning its stdout.
+fn run_git(dir: &Path, args: &[&str]) -> ToolResult<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| ToolError::Generic(format!("Failed to run git: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(ToolError::Generic(format!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bump(bump: VersionBump, version: &str) -> String {
+        bump.apply(&Version::parse(version).unwrap())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_bump_release_versions() {
+        assert_eq!(bump(VersionBump::Major, "1.2.3"), "2.0.0");
+        assert_eq!(bump(VersionBump::Minor, "1.2.3"), "1.3.0");
+        assert_eq!(bump(VersionBump::Patch, "1.2.3"), "1.2.4");
+        assert_eq!(bump(VersionBump::Prerelease, "1.2.3"), "1.2.4-0");
+        assert_eq!(bump(VersionBump::Patch, "1.2.3+build.5"), "1.2.4");
+    }
+
+    #[test]
+    fn test_bump_prerelease_versions() {
+        assert_eq!(bump(VersionBump::Prerelease, "1.2.4-rc.1"), "1.2.4-rc.2");
+        assert_eq!(bump(VersionBump::Prerelease, "1.2.4-beta"), "1.2.4-beta.0");
+
+        // Releasing a prerelease drops the prerelease tag
+        assert_eq!(bump(VersionBump::Patch, "1.2.4-rc.1"), "1.2.4");
+        assert_eq!(bump(VersionBump::Minor, "1.3.0-rc.1"), "1.3.0");
+        assert_eq!(bump(VersionBump::Major, "2.0.0-rc.1"), "2.0.0");
+        assert_eq!(bump(VersionBump::Minor, "1.2.4-rc.1"), "1.3.0");
+    }
+
+    #[test]
+    fn test_parse_version_bump() {
+        assert_eq!("patch".parse::<VersionBump>(), Ok(VersionBump::Patch));
+        assert_eq!("Major".parse::<VersionBump>(), Ok(VersionBump::Major));
+        assert!("huge".parse::<VersionBump>().is_err());
+    }
+}
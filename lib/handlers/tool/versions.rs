@@ -0,0 +1,346 @@
+//! Registry version listing handler.
+
+use crate::error::{ToolError, ToolResult};
+use crate::format::format_size;
+use crate::output::print_json;
+use crate::references::PluginRef;
+use crate::registry::{RegistryClient, VersionInfo};
+use crate::styles::Spinner;
+use colored::Colorize;
+use std::collections::BTreeMap;
+
+use super::common::compare_versions_desc;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Bundle filename fragments that mark a platform-specific build.
+const PLATFORM_MARKERS: [&str; 3] = ["-darwin-", "-linux-", "-win32-"];
+
+/// Platform label for bundles that run everywhere.
+const UNIVERSAL_PLATFORM: &str = "universal";
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// List every published version of a registry tool, newest first.
+pub async fn tool_versions(
+    tool: String,
+    json_output: bool,
+    concise: bool,
+    no_header: bool,
+) -> ToolResult<()> {
+    let plugin_ref = PluginRef::parse(&tool)?;
+    let namespace = plugin_ref.namespace().ok_or_else(|| {
+        ToolError::InvalidReference(
+            "Listing versions requires a registry reference (namespace/name).".into(),
+        )
+    })?;
+    let name = plugin_ref.name();
+    let tool_ref = format!("{}/{}", namespace, name);
+
+    let show_spinner = !json_output && !concise;
+    let spinner = show_spinner.then(|| Spinner::new(format!("Fetching versions of {}", tool_ref)));
+
+    let client = RegistryClient::new();
+    let mut versions = client.list_versions(namespace, name).await?;
+
+    // Dist-tags are informational; older registries don't report them
+    let tags = client
+        .get_artifact(namespace, name)
+        .await
+        .map(|artifact| artifact.dist_tags)
+        .unwrap_or_default();
+
+    if let Some(s) = spinner {
+        s.done();
+    }
+
+    if versions.is_empty() {
+        return Err(ToolError::NotFound {
+            kind: "tool".to_string(),
+            reference: tool_ref,
+        });
+    }
+
+    versions.sort_by(|a, b| compare_versions_desc(&a.version, &b.version));
+    let latest = latest_version(&versions);
+
+    if json_output {
+        let rows: Vec<_> = versions
+            .iter()
+            .map(|v| {
+                serde_json::json!({
+                    "version": v.version,
+                    "published_at": v.published_at,
+                    "size": download_size(v),
+                    "platforms": bundle_platforms(v),
+                    "latest": Some(v.version.as_str()) == latest,
+                    "yanked": v.yanked,
+                    "deprecated": v.deprecated,
+                    "deprecation_message": v.deprecation_message,
+                    "tags": version_tags(&tags, &v.version),
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "tool": tool_ref,
+            "latest": latest,
+            "tags": tags,
+            "versions": rows,
+        });
+        print_json(&output, concise)?;
+        return Ok(());
+    }
+
+    // Concise output: Header + TSV format
+    if concise {
+        use crate::concise::quote;
+        if !no_header {
+            println!("#version\tpublished\tsize\tplatforms\tstatus\ttags");
+        }
+        for v in &versions {
+            let status = version_status(v, latest);
+            println!(
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                v.version,
+                published_date(v).unwrap_or_default(),
+                download_size(v).unwrap_or_default(),
+                quote(&bundle_platforms(v).join(",")),
+                status,
+                version_tags(&tags, &v.version).join(",")
+            );
+        }
+        return Ok(());
+    }
+
+    println!(
+        "  {} {} {}\n",
+        "✓".bright_green(),
+        tool_ref.bold(),
+        format!("{} version(s)", versions.len()).dimmed()
+    );
+
+    let width = versions.iter().map(|v| v.version.len()).max().unwrap_or(0);
+    for v in &versions {
+        let status = version_status(v, latest);
+        let version = format!("{:<width$}", v.version, width = width);
+        let (version, status) = match status {
+            "latest" => (version.bright_cyan().bold(), status.bright_green()),
+            "yanked" => (version.dimmed().strikethrough(), status.bright_red()),
+            "deprecated" => (version.normal(), status.bright_yellow()),
+            _ => (version.normal(), status.normal()),
+        };
+        let size = download_size(v).map(format_size).unwrap_or_default();
+
+        println!(
+            "  {}  {:<10}  {:<10}  {:>9}  {}",
+            version,
+            status,
+            published_date(v).unwrap_or("-").dimmed(),
+            size,
+            bundle_platforms(v).join(", ").dimmed()
+        );
+        if let Some(message) = &v.deprecation_message {
+            println!("  {:width$}  · {}", "", message.dimmed(), width = width);
+        }
+    }
+    println!();
+
+    if !tags.is_empty() {
+        let listed: Vec<String> = tags
+            .iter()
+            .map(|(tag, version)| format!("{} → {}", tag.bright_cyan(), version))
+            .collect();
+        println!("  · {} {}", "Tags:".dimmed(), listed.join(", "));
+    }
+    println!(
+        "  · {} {}",
+        "Install with:".dimmed(),
+        format!("tool install {}@<version|tag>", tool_ref).bright_white()
+    );
+
+    Ok(())
+}
+
+/// The version installs resolve to: the newest stable release that isn't yanked, else the
+/// newest that isn't yanked.
+///
+/// Expects `versions` sorted newest first.
+fn latest_version(versions: &[VersionInfo]) -> Option<&str> {
+    let mut candidates = versions.iter().filter(|v| !v.yanked);
+    candidates
+        .clone()
+        .find(|v| semver::Version::parse(&v.version).is_ok_and(|s| s.pre.is_empty()))
+        .or_else(|| candidates.next())
+        .map(|v| v.version.as_str())
+}
+
+/// Dist-tags pointing at `version`.
+fn version_tags<'a>(tags: &'a BTreeMap<String, String>, version: &str) -> Vec<&'a str> {
+    tags.iter()
+        .filter(|(_, v)| v.as_str() == version)
+        .map(|(tag, _)| tag.as_str())
+        .collect()
+}
+
+/// Status label: `yanked`, `deprecated`, `latest`, or empty.
+fn version_status(version: &VersionInfo, latest: Option<&str>) -> &'static str {
+    if version.yanked {
+        "yanked"
+    } else if version.deprecated {
+        "deprecated"
+    } else if Some(version.version.as_str()) == latest {
+        "latest"
+    } else {
+        ""
+    }
+}
+
+/// Publish date (`YYYY-MM-DD`) from the registry timestamp.
+fn published_date(version: &VersionInfo) -> Option<&str> {
+    let published_at = version.published_at.as_deref()?;
+    Some(published_at.get(..10).unwrap_or(published_at))
+}
+
+/// Size of the main download, falling back to the largest bundle file.
+fn download_size(version: &VersionInfo) -> Option<u64> {
+    version.main_download_size.or_else(|| {
+        version
+            .files
+            .as_ref()
+            .and_then(|files| files.values().map(|f| f.size).max())
+    })
+}
+
+/// Platforms with a published bundle, e.g. `darwin-arm64` or `universal`.
+fn bundle_platforms(version: &VersionInfo) -> Vec<String> {
+    let mut platforms: Vec<String> = version
+        .files
+        .iter()
+        .flat_map(|files| files.keys())
+        .filter_map(|filename| {
+            filename
+                .strip_suffix(".mcpbx")
+                .or_else(|| filename.strip_suffix(".mcpb"))
+        })
+        .map(|stem| {
+            PLATFORM_MARKERS
+                .iter()
+                .filter_map(|marker| stem.rfind(marker))
+                .max()
+                .map(|i| stem[i + 1..].to_string())
+                .unwrap_or_else(|| UNIVERSAL_PLATFORM.to_string())
+        })
+        .collect();
+
+    if platforms.is_empty()
+        && version
+            .main_download_url
+            .as_deref()
+            .is_some_and(|url| url.ends_with(".mcpb") || url.ends_with(".mcpbx"))
+    {
+        platforms.push(UNIVERSAL_PLATFORM.to_string());
+    }
+
+    platforms.sort();
+    platforms.dedup();
+    platforms
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::FileInfo;
+    use std::collections::HashMap;
+
+    fn version(version: &str, files: &[&str]) -> VersionInfo {
+        let files: HashMap<String, FileInfo> = files
+            .iter()
+            .map(|name| {
+                let info = FileInfo {
+                    url: format!("https://cdn.example.com/{}", name),
+                    size: 1024,
+                    checksum: String::new(),
+                };
+                (name.to_string(), info)
+            })
+            .collect();
+        VersionInfo {
+            version: version.to_string(),
+            icons: None,
+            main_download_size: None,
+            main_download_checksum: None,
+            main_download_url: None,
+            files: (!files.is_empty()).then_some(files),
+            manifest: None,
+            published_at: Some("2026-03-14T09:26:53Z".to_string()),
+            yanked: false,
+            deprecated: false,
+            deprecation_message: None,
+        }
+    }
+
+    #[test]
+    fn test_bundle_platforms() {
+        let v = version(
+            "1.0.0",
+            &[
+                "bash-1.0.0-darwin-arm64.mcpb",
+                "bash-1.0.0-linux-x64.mcpb",
+                "bash-1.0.0.mcpb",
+                "README.md",
+            ],
+        );
+        assert_eq!(
+            bundle_platforms(&v),
+            vec!["darwin-arm64", "linux-x64", "universal"]
+        );
+        assert!(bundle_platforms(&version("1.0.0", &[])).is_empty());
+    }
+
+    #[test]
+    fn test_latest_version_prefers_stable_and_skips_yanked() {
+        let mut versions = vec![
+            version("1.2.0", &[]),
+            version("2.0.0-beta.1", &[]),
+            version("1.10.0", &[]),
+        ];
+        versions.sort_by(|a, b| compare_versions_desc(&a.version, &b.version));
+
+        assert_eq!(versions[0].version, "2.0.0-beta.1");
+        assert_eq!(latest_version(&versions), Some("1.10.0"));
+        assert_eq!(
+            latest_version(&[version("0.1.0-alpha", &[])]),
+            Some("0.1.0-alpha")
+        );
+
+        versions[1].yanked = true;
+        assert_eq!(versions[1].version, "1.10.0");
+        assert_eq!(latest_version(&versions), Some("1.2.0"));
+        assert_eq!(version_status(&versions[1], Some("1.2.0")), "yanked");
+        assert_eq!(version_status(&versions[2], Some("1.2.0")), "latest");
+    }
+
+    #[test]
+    fn test_version_tags() {
+        let tags = BTreeMap::from([
+            ("beta".to_string(), "2.0.0-beta.1".to_string()),
+            ("latest".to_string(), "1.2.0".to_string()),
+            ("stable".to_string(), "1.2.0".to_string()),
+        ]);
+        assert_eq!(version_tags(&tags, "1.2.0"), ["latest", "stable"]);
+        assert!(version_tags(&tags, "1.0.0").is_empty());
+    }
+
+    #[test]
+    fn test_published_date() {
+        assert_eq!(published_date(&version("1.0.0", &[])), Some("2026-03-14"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Version yank and deprecation command handlers.
+
+use crate::error::{ToolError, ToolResult};
+use crate::handlers::auth::get_registry_token;
+use crate::output::print_json;
+use crate::references::PluginRef;
+use crate::registry::RegistryClient;
+use crate::styles::Spinner;
+use colored::Colorize;
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Yank a published version so new installs skip it.
+pub async fn yank_version(tool: String, json_output: bool) -> ToolResult<()> {
+    let (namespace, name, version) = parse_version_ref(&tool)?;
+    let client = authenticated_client().await?;
+
+    let spinner = (!json_output)
+        .then(|| Spinner::with_indent(format!("Yanking {}/{}@{}", namespace, name, version), 2));
+    let result = client.yank_version(&namespace, &name, &version).await;
+    finish_spinner(spinner, &result, || {
+        format!("Yanked {}/{}@{}", namespace, name, version)
+    });
+    result?;
+
+    if json_output {
+        let output = serde_json::json!({
+            "tool": format!("{}/{}", namespace, name),
+            "version": version,
+            "yanked": true,
+        });
+        print_json(&output, false)?;
+    } else {
+        println!(
+            "  · {}",
+            "Existing installs keep working; new installs of the latest version skip it.".dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Deprecate a published version; installs still succeed but show `message`.
+pub async fn deprecate_version(tool: String, message: String, json_output: bool) -> ToolResult<()> {
+    let (namespace, name, version) = parse_version_ref(&tool)?;
+    if message.trim().is_empty() {
+        return Err(ToolError::Generic(
+            "Deprecation message cannot be empty".into(),
+        ));
+    }
+    let client = authenticated_client().await?;
+
+    let spinner = (!json_output).then(|| {
+        Spinner::with_indent(format!("Deprecating {}/{}@{}", namespace, name, version), 2)
+    });
+    let result = client
+        .deprecate_version(&namespace, &name, &version, &message)
+        .await;
+    finish_spinner(spinner, &result, || {
+        format!("Deprecated {}/{}@{}", namespace, name, version)
+    });
+    result?;
+
+    if json_output {
+        let output = serde_json::json!({
+            "tool": format!("{}/{}", namespace, name),
+            "version": version,
+            "deprecated": true,
+            "deprecation_message": message,
+        });
+        print_json(&output, false)?;
+    } else {
+        println!("  · {} {}", "Message:".dimmed(), message);
+    }
+
+    Ok(())
+}
+
+/// Split a `namespace/name@version` reference; both namespace and version are required.
+fn parse_version_ref(tool: &str) -> ToolResult<(String, String, String)> {
+    let plugin_ref = PluginRef::parse(tool)?;
+    match (plugin_ref.namespace(), plugin_ref.version_str()) {
+        (Some(namespace), Some(version)) => Ok((
+            namespace.to_string(),
+            plugin_ref.name().to_string(),
+            version.to_string(),
+        )),
+        _ => Err(ToolError::InvalidReference(format!(
+            "'{}' must name a published version (namespace/name@version)",
+            tool
+        ))),
+    }
+}
+
+/// Registry client authenticated with the current token.
+async fn authenticated_client() -> ToolResult<RegistryClient> {
+    let token = get_registry_token().await?.ok_or_else(|| {
+        ToolError::Generic("Authentication required. Run `tool login` first.".into())
+    })?;
+    Ok(RegistryClient::new().with_auth_token(token))
+}
+
+/// Resolve a spinner with the outcome of a registry call.
+fn finish_spinner(
+    spinner: Option<Spinner>,
+    result: &ToolResult<()>,
+    success: impl FnOnce() -> String,
+) {
+    let Some(spinner) = spinner else {
+        return;
+    };
+    match result {
+        Ok(()) => spinner.succeed(Some(&success())),
+        Err(_) => spinner.fail(None),
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_ref_requires_namespace_and_version() {
+        let (namespace, name, version) = parse_version_ref("acme/weather@1.2.0").unwrap();
+        assert_eq!(
+            (namespace.as_str(), name.as_str(), version.as_str()),
+            ("acme", "weather", "1.2.0")
+        );
+
+        assert!(parse_version_ref("acme/weather").is_err());
+        assert!(parse_version_ref("weather@1.2.0").is_err());
+    }
+}
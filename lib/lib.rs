@@ -1,19 +1,24 @@
 //! `tool-cli` library.
 
+pub mod cli_version;
 pub mod commands;
 pub mod concise;
 pub mod constants;
 pub mod detect;
 pub mod error;
+pub mod extract;
 pub mod format;
 pub mod handlers;
 pub mod hosts;
 pub mod mcp;
 pub mod mcpb;
 pub mod oauth;
+pub mod objects;
 pub mod output;
 pub mod pack;
+pub mod progress;
 pub mod prompt;
+pub mod provenance;
 pub mod proxy;
 pub mod references;
 pub mod registry;
@@ -21,6 +26,7 @@ pub mod resolver;
 pub mod scaffold;
 pub mod security;
 pub mod self_update;
+pub mod settings;
 pub mod styles;
 pub mod suggest;
 pub mod system_config;
@@ -32,11 +38,13 @@ pub mod vars;
 // Re-Exports
 //--------------------------------------------------------------------------------------------------
 
+pub use cli_version::*;
 pub use commands::*;
 pub use concise::*;
 pub use constants::*;
 pub use detect::*;
 pub use error::*;
+pub use extract::*;
 pub use handlers::*;
 pub use hosts::*;
 pub use mcp::*;
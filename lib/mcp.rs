@@ -4,8 +4,11 @@
 
 use colored::Colorize;
 
+use crate::constants::{DEFAULT_LOGS_PATH, TOOL_HANDSHAKE_TIMEOUT_ENV};
 use crate::error::{ToolError, ToolResult};
 use crate::mcpb::{McpbManifest, McpbTransport, ResolvedMcpbManifest};
+use crate::security::Redactor;
+use crate::suggest::check_arguments_against_schema;
 use rmcp::model::{CallToolRequestParam, CallToolResult, ClientInfo, Tool};
 use rmcp::service::RunningService;
 use rmcp::transport::StreamableHttpClientTransport;
@@ -13,14 +16,40 @@ use rmcp::transport::TokioChildProcess;
 use rmcp::transport::auth::AuthClient;
 use rmcp::transport::streamable_http_client::StreamableHttpClientTransportConfig;
 use rmcp::{RoleClient, serve_client};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
 use std::process::{Child, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::process::Command;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{ChildStderr, Command};
 
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Maximum number of initialize attempts for a stdio server.
+const STDIO_STARTUP_ATTEMPTS: u32 = 3;
+
+/// Default time allowed for a single stdio initialize handshake.
+const STDIO_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Delay before the first stdio startup retry (doubled on each subsequent retry).
+const STDIO_RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Grace period for stderr to close after a failed handshake before assuming the server is alive.
+const STDERR_CLOSE_GRACE: Duration = Duration::from_millis(200);
+
+/// Number of trailing stderr lines included in startup errors.
+const STDERR_TAIL_LINES: usize = 20;
+
+/// Size at which a server log is rotated to `<name>.log.1` before a new run appends to it.
+const SERVER_LOG_MAX_SIZE: u64 = 1024 * 1024;
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -54,6 +83,49 @@ pub struct ToolCallResult {
     pub result: CallToolResult,
 }
 
+/// How [`call_tool`] checks arguments before sending them.
+#[derive(Debug, Clone)]
+pub enum ArgumentCheck {
+    /// Send the arguments as they are.
+    Skip,
+    /// Check against the input schema the server lists for the method, or `fallback`
+    /// (e.g. the manifest's static responses) when the server can't list its tools.
+    Schema { fallback: Option<serde_json::Value> },
+}
+
+/// Outcome of [`call_tool`].
+#[derive(Debug)]
+pub enum CallOutcome {
+    /// The server ran the call.
+    Called(ToolCallResult),
+    /// The arguments don't match the input schema, so the call wasn't sent.
+    Rejected {
+        /// One message per problem found.
+        problems: Vec<String>,
+        /// The schema the arguments were checked against.
+        schema: serde_json::Value,
+    },
+}
+
+/// Outcome of a single stdio server startup attempt.
+enum StdioStartup {
+    /// Initialize handshake succeeded.
+    Connected(RunningService<RoleClient, ClientInfo>),
+    /// Server process exited during startup (not retried).
+    Exited { error: String, stderr: StderrTail },
+    /// Handshake timed out or failed while the server was still running.
+    Retryable { error: String, stderr: StderrTail },
+}
+
+/// Trailing lines of a child process's stderr, captured in the background.
+#[derive(Clone, Default)]
+struct StderrTail {
+    /// Most recent stderr lines (bounded by `STDERR_TAIL_LINES`).
+    lines: Arc<Mutex<VecDeque<String>>>,
+    /// Set once stderr reaches EOF (the process has exited or closed stderr).
+    closed: Arc<AtomicBool>,
+}
+
 /// Tool type for display purposes.
 #[derive(Debug, Clone, Copy)]
 pub enum ToolType {
@@ -132,6 +204,81 @@ impl McpConnection {
     }
 }
 
+impl StderrTail {
+    /// Start draining a child's stderr into a bounded tail buffer.
+    ///
+    /// Draining continues for the life of the process so a chatty server never blocks
+    /// on a full pipe. When `echo` is set, each line is also forwarded to our stderr,
+    /// and when `log` is set it's appended there. Lines are redacted before they are
+    /// echoed, logged, or kept.
+    fn capture(
+        stderr: Option<ChildStderr>,
+        echo: bool,
+        redactor: Redactor,
+        log: Option<std::fs::File>,
+    ) -> Self {
+        let tail = Self::default();
+        let Some(stderr) = stderr else {
+            return tail;
+        };
+
+        let lines = tail.lines.clone();
+        let closed = tail.closed.clone();
+        tokio::spawn(async move {
+            let mut log = log.map(tokio::fs::File::from_std);
+            let mut reader = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = reader.next_line().await {
+                let line = redactor.redact(&line);
+                if echo {
+                    eprintln!("{}", line);
+                }
+                if let Some(file) = &mut log
+                    && file
+                        .write_all(format!("{}\n", line).as_bytes())
+                        .await
+                        .is_err()
+                {
+                    log = None;
+                }
+                let mut lines = lines.lock().unwrap();
+                if lines.len() == STDERR_TAIL_LINES {
+                    lines.pop_front();
+                }
+                lines.push_back(line);
+            }
+            closed.store(true, Ordering::SeqCst);
+        });
+
+        tail
+    }
+
+    /// Wait up to `grace` for stderr to close. Returns true if it closed.
+    async fn wait_closed(&self, grace: Duration) -> bool {
+        let start = std::time::Instant::now();
+        while start.elapsed() < grace {
+            if self.closed.load(Ordering::SeqCst) {
+                return true;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Render the captured tail for inclusion in an error message.
+    fn render(&self) -> String {
+        let lines = self.lines.lock().unwrap();
+        if lines.is_empty() {
+            return String::new();
+        }
+        let body = lines
+            .iter()
+            .map(|l| format!("  {}", l))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("\n\nServer stderr (last {} lines):\n{}", lines.len(), body)
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -159,7 +306,10 @@ fn check_entry_point_exists(resolved: &ResolvedMcpbManifest) -> ToolResult<()> {
     }
 
     // Entry point doesn't exist - return structured error
-    let build_script = resolved.manifest.scripts().and_then(|s| s.build.clone());
+    let build_script = resolved
+        .manifest
+        .scripts()
+        .and_then(|s| s.for_current_os("build").map(String::from));
 
     Err(ToolError::EntryPointNotFound {
         entry_point: entry_point.clone(),
@@ -172,16 +322,17 @@ fn check_entry_point_exists(resolved: &ResolvedMcpbManifest) -> ToolResult<()> {
 /// Connect to an MCP server based on resolved manifest configuration.
 ///
 /// Returns `ConnectResult::Connected` on success, or `ConnectResult::AuthRequired`
-/// if OAuth authentication is needed.
+/// if OAuth authentication is needed. Sensitive config values are redacted from errors.
 pub async fn connect(resolved: &ResolvedMcpbManifest, verbose: bool) -> ToolResult<ConnectResult> {
     // Check entry point exists before attempting to connect
     check_entry_point_exists(resolved)?;
 
-    match resolved.transport {
+    let result = match resolved.transport {
         McpbTransport::Stdio => {
             // Stdio connections don't require OAuth
-            let conn = connect_stdio(resolved, verbose).await?;
-            Ok(ConnectResult::Connected(conn))
+            connect_stdio(resolved, verbose)
+                .await
+                .map(ConnectResult::Connected)
         }
         McpbTransport::Http => {
             // For HTTP, we need to spawn the server first if it's a bundle
@@ -191,10 +342,17 @@ pub async fn connect(resolved: &ResolvedMcpbManifest, verbose: bool) -> ToolResu
                 connect_http_spawned(resolved, verbose).await
             }
         }
-    }
+    };
+
+    // Spawn errors and server stderr can echo substituted config values
+    result.map_err(|e| resolved.redactor.redact_error(e))
 }
 
 /// Connect via stdio transport.
+///
+/// The initialize handshake is retried with backoff when it times out or fails while
+/// the server is still running. A server that exits during startup is not retried;
+/// its stderr tail is included in the error instead.
 async fn connect_stdio(
     resolved: &ResolvedMcpbManifest,
     verbose: bool,
@@ -203,11 +361,81 @@ async fn connect_stdio(
         ToolError::Generic("stdio transport requires 'command' in mcp_config".into())
     })?;
 
+    let mut last_failure = String::new();
+    let mut last_stderr = StderrTail::default();
+
+    for attempt in 1..=STDIO_STARTUP_ATTEMPTS {
+        if attempt > 1 {
+            // Backoff: 250ms, 500ms, ...
+            let delay = STDIO_RETRY_BASE_DELAY * 2u32.pow(attempt - 2);
+            if verbose {
+                eprintln!(
+                    "Retrying server startup in {}ms (attempt {}/{})",
+                    delay.as_millis(),
+                    attempt,
+                    STDIO_STARTUP_ATTEMPTS
+                );
+            }
+            tokio::time::sleep(delay).await;
+        }
+
+        match start_stdio(command, resolved, verbose).await? {
+            StdioStartup::Connected(client) => {
+                if verbose && let Some(info) = client.peer_info() {
+                    eprintln!(
+                        "Connected: {} v{}",
+                        info.server_info.name, info.server_info.version
+                    );
+                }
+
+                return Ok(McpConnection {
+                    client,
+                    child: None,
+                    #[cfg(unix)]
+                    pgid: None,
+                });
+            }
+            StdioStartup::Exited { error, stderr } => {
+                return Err(ToolError::Generic(format!(
+                    "MCP server exited during startup: {}{}",
+                    error,
+                    stderr.render()
+                )));
+            }
+            StdioStartup::Retryable { error, stderr } => {
+                if verbose {
+                    eprintln!("Server startup failed: {}", error);
+                }
+                last_failure = error;
+                last_stderr = stderr;
+            }
+        }
+    }
+
+    Err(ToolError::Generic(format!(
+        "Failed to connect to MCP server after {} attempts: {}{}",
+        STDIO_STARTUP_ATTEMPTS,
+        last_failure,
+        last_stderr.render()
+    )))
+}
+
+/// Spawn a stdio server and perform a single initialize handshake.
+async fn start_stdio(
+    command: &str,
+    resolved: &ResolvedMcpbManifest,
+    verbose: bool,
+) -> ToolResult<StdioStartup> {
     let args = &resolved.mcp_config.args;
     let env = &resolved.mcp_config.env;
+    let redactor = &resolved.redactor;
 
     if verbose {
-        eprintln!("Spawning: {} {:?}", command, args);
+        eprintln!(
+            "Spawning: {} {:?}",
+            redactor.redact(command),
+            redactor.redact_args(args)
+        );
     }
 
     // Build the command
@@ -222,40 +450,117 @@ async fn connect_stdio(
         cmd.env("RUST_LOG", "off");
     }
 
-    // Set working directory if bundle_path is available
-    if let Some(ref bundle_path) = resolved.manifest.bundle_path {
-        cmd.current_dir(bundle_path);
+    // Run in the requested working directory, or the bundle directory by default
+    if let Some(dir) = server_working_dir(resolved) {
+        cmd.current_dir(dir);
     }
 
-    // Use builder to control stderr - TokioChildProcess::new() ignores Command's stderr setting
-    let mut builder = TokioChildProcess::builder(cmd);
-    if verbose {
-        builder = builder.stderr(Stdio::inherit());
-    } else {
-        builder = builder.stderr(Stdio::null());
-    }
-    let (transport, _stderr) = builder
+    tracing::debug!(
+        "Spawning stdio server: {} {:?} (cwd: {:?})",
+        redactor.redact(command),
+        redactor.redact_args(args),
+        server_working_dir(resolved)
+    );
+
+    // Use builder to control stderr - TokioChildProcess::new() ignores Command's stderr setting.
+    // Stderr is always piped so startup failures can report its tail; verbose mode echoes it.
+    let (transport, stderr) = TokioChildProcess::builder(cmd)
+        .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| ToolError::Generic(format!("Failed to create transport: {}", e)))?;
+    let log = resolved
+        .manifest
+        .name
+        .as_deref()
+        .and_then(|name| open_server_log(name).ok());
+    let stderr = StderrTail::capture(stderr, verbose, redactor.clone(), log);
 
     let client_info = ClientInfo::default();
-    let client = serve_client(client_info, transport)
-        .await
-        .map_err(|e| ToolError::Generic(format!("Failed to connect to MCP server: {}", e)))?;
+    let start = std::time::Instant::now();
+    let limit = handshake_timeout();
+    let error = match tokio::time::timeout(limit, serve_client(client_info, transport)).await {
+        Ok(Ok(client)) => {
+            tracing::debug!(
+                "Initialize handshake completed in {}ms",
+                start.elapsed().as_millis()
+            );
+            return Ok(StdioStartup::Connected(client));
+        }
+        Ok(Err(e)) => {
+            tracing::debug!(
+                "Initialize handshake failed after {}ms: {}",
+                start.elapsed().as_millis(),
+                e
+            );
+            e.to_string()
+        }
+        Err(_) => {
+            tracing::debug!("Initialize handshake timed out");
+            return Ok(StdioStartup::Retryable {
+                error: format!(
+                    "initialize handshake timed out after {}s (set {} to wait longer)",
+                    limit.as_secs(),
+                    TOOL_HANDSHAKE_TIMEOUT_ENV
+                ),
+                stderr,
+            });
+        }
+    };
 
-    if verbose && let Some(info) = client.peer_info() {
-        eprintln!(
-            "Connected: {} v{}",
-            info.server_info.name, info.server_info.version
-        );
+    // A closed stderr means the process is gone; retrying would fail the same way
+    if stderr.wait_closed(STDERR_CLOSE_GRACE).await {
+        Ok(StdioStartup::Exited { error, stderr })
+    } else {
+        Ok(StdioStartup::Retryable { error, stderr })
     }
+}
 
-    Ok(McpConnection {
-        client,
-        child: None,
-        #[cfg(unix)]
-        pgid: None,
-    })
+/// Limit on each stdio initialize handshake: `TOOL_HANDSHAKE_TIMEOUT` if set, else
+/// [`STDIO_HANDSHAKE_TIMEOUT`].
+fn handshake_timeout() -> Duration {
+    std::env::var(TOOL_HANDSHAKE_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(STDIO_HANDSHAKE_TIMEOUT)
+}
+
+/// Working directory for a spawned server: `--cwd` if given, else the bundle directory.
+fn server_working_dir(resolved: &ResolvedMcpbManifest) -> Option<&std::path::Path> {
+    resolved
+        .working_dir
+        .as_deref()
+        .or(resolved.manifest.bundle_path.as_deref())
+}
+
+/// Path of the captured stderr log for a tool.
+pub fn server_log_path(tool_name: &str) -> PathBuf {
+    DEFAULT_LOGS_PATH.join(format!("{}.log", tool_name.replace(['/', '\\'], "__")))
+}
+
+/// Open a tool's server log for appending, rotating it first if it's grown too large.
+///
+/// Each run starts with a timestamped marker line.
+fn open_server_log(tool_name: &str) -> std::io::Result<std::fs::File> {
+    use std::io::Write;
+
+    let path = server_log_path(tool_name);
+    std::fs::create_dir_all(&*DEFAULT_LOGS_PATH)?;
+    if std::fs::metadata(&path).is_ok_and(|m| m.len() > SERVER_LOG_MAX_SIZE) {
+        std::fs::rename(&path, path.with_extension("log.1"))?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(
+        file,
+        "--- server started {} ---",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    )?;
+    Ok(file)
 }
 
 /// Check if an error indicates OAuth authentication is required.
@@ -277,7 +582,7 @@ async fn connect_http_direct(
         })?;
 
     if verbose {
-        eprintln!("Connecting to: {}", url);
+        eprintln!("Connecting to: {}", resolved.redactor.redact(url));
     }
 
     // Build config with optional Authorization header from manifest
@@ -357,9 +662,14 @@ async fn connect_http_spawned(
 
     let args = &resolved.mcp_config.args;
     let env = &resolved.mcp_config.env;
+    let redactor = &resolved.redactor;
 
     if verbose {
-        eprintln!("Spawning: {} {:?}", command, args);
+        eprintln!(
+            "Spawning: {} {:?}",
+            redactor.redact(command),
+            redactor.redact_args(args)
+        );
     }
 
     // Build and spawn the command in its own process group
@@ -383,9 +693,9 @@ async fn connect_http_spawned(
         cmd.env("RUST_LOG", "off");
     }
 
-    // Set working directory if bundle_path is available
-    if let Some(ref bundle_path) = resolved.manifest.bundle_path {
-        cmd.current_dir(bundle_path);
+    // Run in the requested working directory, or the bundle directory by default
+    if let Some(dir) = server_working_dir(resolved) {
+        cmd.current_dir(dir);
     }
 
     // On Unix, spawn in its own process group so we can kill the entire tree
@@ -403,16 +713,22 @@ async fn connect_http_spawned(
     if verbose {
         eprintln!("Spawned process PID: {}", child.id());
     }
+    tracing::debug!(
+        "Spawned HTTP server {} (pid {}, cwd: {:?})",
+        redactor.redact(command),
+        child.id(),
+        server_working_dir(resolved)
+    );
 
     // Wait for server to be ready
     if verbose {
-        eprintln!("Waiting for server at {}...", url);
+        eprintln!("Waiting for server at {}...", redactor.redact(url));
     }
 
-    wait_for_server_ready(url, &mut child, Duration::from_secs(30), verbose).await?;
+    wait_for_server_ready(url, &mut child, Duration::from_secs(30), verbose, redactor).await?;
 
     if verbose {
-        eprintln!("Server ready at {}", url);
+        eprintln!("Server ready at {}", redactor.redact(url));
     }
 
     // Build config with optional Authorization header from manifest
@@ -483,6 +799,7 @@ async fn wait_for_server_ready(
     child: &mut Child,
     timeout: Duration,
     verbose: bool,
+    redactor: &Redactor,
 ) -> ToolResult<()> {
     let start = std::time::Instant::now();
     let client = reqwest::Client::new();
@@ -555,7 +872,10 @@ async fn wait_for_server_ready(
             }
             Err(e) => {
                 if verbose {
-                    eprintln!("Waiting for server... ({})", e);
+                    eprintln!(
+                        "Waiting for server... ({})",
+                        redactor.redact(&e.to_string())
+                    );
                 }
                 // Exponential backoff: 10ms, 20ms, 40ms, 80ms, 160ms, 320ms, 500ms (capped)
                 let delay_ms = (10 * 2u64.pow(attempts.min(5))).min(500);
@@ -725,7 +1045,10 @@ pub async fn connect_with_oauth(
         && resolved.is_reference
         && let Some(url) = &resolved.mcp_config.url
     {
-        match try_connect_with_stored_credentials(url, tool_ref, verbose).await? {
+        match try_connect_with_stored_credentials(url, tool_ref, verbose)
+            .await
+            .map_err(|e| resolved.redactor.redact_error(e))?
+        {
             StoredCredentialsResult::Connected(conn) => return Ok(conn),
             StoredCredentialsResult::NotAvailable => {
                 // Fall through to normal connect flow
@@ -951,9 +1274,10 @@ pub async fn call_tool(
     resolved: &ResolvedMcpbManifest,
     tool_name: &str,
     method: &str,
-    arguments: BTreeMap<String, serde_json::Value>,
+    mut arguments: BTreeMap<String, serde_json::Value>,
+    check: ArgumentCheck,
     verbose: bool,
-) -> ToolResult<ToolCallResult> {
+) -> ToolResult<CallOutcome> {
     let connection = connect_with_oauth(resolved, tool_name, verbose).await?;
 
     if verbose && let Some(info) = connection.peer_info() {
@@ -963,6 +1287,31 @@ pub async fn call_tool(
         );
     }
 
+    // The live server's schema wins over anything recorded in the manifest
+    if let ArgumentCheck::Schema { fallback } = check {
+        if verbose {
+            eprintln!("-> tools/list");
+        }
+        let schema = match connection.peer().list_tools(None).await {
+            Ok(response) => response
+                .tools
+                .into_iter()
+                .find(|t| t.name == method)
+                .map(|t| serde_json::Value::Object((*t.input_schema).clone())),
+            Err(e) => {
+                tracing::debug!("Failed to list tools, using the manifest schema: {}", e);
+                fallback
+            }
+        };
+
+        if let Some(schema) = schema {
+            let problems = check_arguments_against_schema(&mut arguments, &schema);
+            if !problems.is_empty() {
+                return Ok(CallOutcome::Rejected { problems, schema });
+            }
+        }
+    }
+
     // Call the tool
     // Always send arguments as an object (even empty) to satisfy input schema validation
     let params = CallToolRequestParam {
@@ -980,5 +1329,51 @@ pub async fn call_tool(
         eprintln!("<- {} content block(s)", result.content.len());
     }
 
-    Ok(ToolCallResult { result })
+    Ok(CallOutcome::Called(ToolCallResult { result }))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn connect_error_redacts_sensitive_config() {
+        let manifest: McpbManifest = serde_json::from_value(json!({
+            "manifest_version": "0.3",
+            "name": "leaky",
+            "server": {
+                "mcp_config": {
+                    "command": "sh",
+                    "args": ["-c", "echo \"bad key ${user_config.api_key}\" >&2; exit 1"]
+                }
+            },
+            "user_config": {
+                "api_key": { "type": "string", "title": "API key", "sensitive": true }
+            }
+        }))
+        .unwrap();
+        let user_config = BTreeMap::from([("api_key".to_string(), "sk-live-secret".to_string())]);
+        let resolved = manifest.resolve(&user_config, &BTreeMap::new()).unwrap();
+
+        let err = match connect(&resolved, false).await {
+            Ok(_) => panic!("server should fail to start"),
+            Err(e) => e.to_string(),
+        };
+        assert!(err.contains("bad key ***"), "{}", err);
+        assert!(!err.contains("sk-live-secret"), "{}", err);
+    }
+
+    #[test]
+    fn test_server_log_path() {
+        assert_eq!(server_log_path("bash").file_name().unwrap(), "bash.log");
+        assert_eq!(
+            server_log_path("appcypher/bash").file_name().unwrap(),
+            "appcypher__bash.log"
+        );
+    }
 }
@@ -1,7 +1,8 @@
 //! MCPB manifest structure and methods.
 
-use crate::constants::MCPB_MANIFEST_FILE;
 use crate::error::{ToolError, ToolResult};
+use crate::security::Redactor;
+use crate::validate::{ValidationResult, validate_manifest_fields};
 use crate::vars;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -9,6 +10,7 @@ use std::path::{Path, PathBuf};
 
 use super::init_mode::InitMode;
 use super::platform::{detect_platform, resolve_platform_overrides};
+use super::relaxed::{find_manifest_path, from_str_relaxed};
 use super::resolved::{ResolvedMcpConfig, ResolvedMcpbManifest};
 use super::types::{
     McpbAuthor, McpbCompatibility, McpbIcon, McpbLocalization, McpbMcpConfig, McpbPlatform,
@@ -136,11 +138,26 @@ pub struct McpbManifest {
 //--------------------------------------------------------------------------------------------------
 
 impl McpbManifest {
+    /// Validate the manifest in memory, without reading anything from disk.
+    ///
+    /// Runs the same field checks as [`validate_manifest`](crate::validate::validate_manifest)
+    /// (names, versions, transport config, tool definitions, ...). Checks that look at the
+    /// bundle directory (file references, bundled dependencies, binary paths) and validators
+    /// registered with [`register_validator`](crate::validate::register_validator) are skipped.
+    pub fn validate(&self) -> ValidationResult {
+        let mut result = ValidationResult::default();
+        let raw_json = serde_json::to_value(self).unwrap_or_default();
+        validate_manifest_fields(self, &raw_json, &mut result);
+        result
+    }
+
     /// Load manifest from a directory.
+    ///
+    /// Reads `manifest.json` (or `manifest.json5`), accepting comments and trailing commas.
     pub fn load(dir: &Path) -> ToolResult<Self> {
-        let manifest_path = dir.join(MCPB_MANIFEST_FILE);
+        let manifest_path = find_manifest_path(dir);
         let content = std::fs::read_to_string(&manifest_path)?;
-        let mut manifest: McpbManifest = serde_json::from_str(&content)?;
+        let mut manifest: McpbManifest = from_str_relaxed(&content)?;
         manifest.bundle_path = Some(dir.to_path_buf());
         Ok(manifest)
     }
@@ -269,6 +286,8 @@ impl McpbManifest {
             mcp_config,
             transport: self.transport(),
             is_reference: self.is_reference(),
+            working_dir: None,
+            redactor: Redactor::from_user_config(self.user_config.as_ref(), user_config),
         })
     }
 
@@ -689,6 +708,60 @@ impl McpbManifest {
         self
     }
 
+    /// Point a Node.js manifest at compiled TypeScript output.
+    ///
+    /// Rewrites `server/index.js` references to `dist/index.js` and makes the
+    /// build script compile after installing dependencies.
+    pub fn with_typescript(mut self, package_manager: NodePackageManager) -> Self {
+        self.server.entry_point = Some("dist/index.js".to_string());
+        if let Some(config) = self.server.mcp_config.as_mut() {
+            for arg in &mut config.args {
+                *arg = arg.replace("server/index.js", "dist/index.js");
+            }
+        }
+        self.meta = Some(serde_json::json!({
+            "store.tool.mcpb": {
+                "scripts": {
+                    "build": package_manager.typescript_build_command()
+                }
+            }
+        }));
+        self
+    }
+
+    /// Run the server from a container image over HTTP.
+    ///
+    /// Switches to HTTP transport and rewrites `mcp_config` to `docker run` the image,
+    /// publishing the container's port 3000 on the configured host and port.
+    pub fn with_container(mut self, image: &str) -> Self {
+        self.server.transport = McpbTransport::Http;
+        self.server.mcp_config = Some(McpbMcpConfig {
+            command: Some("docker".to_string()),
+            args: vec![
+                "run".to_string(),
+                "--rm".to_string(),
+                "-p".to_string(),
+                "${user_config.host}:${system_config.port}:3000".to_string(),
+                image.to_string(),
+            ],
+            env: BTreeMap::new(),
+            url: Some("http://${user_config.host}:${system_config.port}/mcp".to_string()),
+            headers: BTreeMap::new(),
+            oauth_config: None,
+            platform_overrides: BTreeMap::new(),
+        });
+        self.user_config = Some(create_http_user_config());
+        self.system_config = Some(create_http_system_config());
+        self.meta = Some(serde_json::json!({
+            "store.tool.mcpb": {
+                "scripts": {
+                    "build": format!("docker build -t {} .", image)
+                }
+            }
+        }));
+        self
+    }
+
     /// Returns true if this manifest requires the `.mcpbx` format.
     ///
     /// A manifest requires `.mcpbx` if it uses any feature beyond the base MCPB spec:
@@ -730,6 +803,23 @@ impl McpbManifest {
         }
     }
 
+    /// Describe a mismatch between a bundle file's extension and [`Self::bundle_extension`].
+    ///
+    /// Both formats extract the same way, but `.mcpbx` bundles need a client that supports
+    /// the extended format, so a bundle named for the wrong one is misleading.
+    pub fn bundle_extension_mismatch(&self, bundle_path: &Path) -> Option<String> {
+        let actual = bundle_path.extension()?.to_string_lossy().to_lowercase();
+        let expected = self.bundle_extension();
+        (actual != expected).then(|| {
+            format!(
+                "{} is named .{} but its manifest is a .{} bundle",
+                bundle_path.display(),
+                actual,
+                expected
+            )
+        })
+    }
+
     /// Serialize to pretty-printed JSON.
     pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
@@ -0,0 +1,225 @@
+//! Manifest schema migrations.
+//!
+//! Older manifests are upgraded by running each migration in order over the raw JSON,
+//! so fields the typed manifest doesn't model are preserved.
+
+use crate::error::{ToolError, ToolResult};
+use crate::validate::DEFAULT_MANIFEST_VERSION;
+use serde_json::{Map, Value};
+
+use super::manifest::McpbManifest;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Ordered migrations; each one sees the output of the previous.
+const MIGRATIONS: &[Migration] = &[
+    migrate_dxt_version,
+    migrate_manifest_version,
+    migrate_author_string,
+    migrate_legacy_icon,
+];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A single change applied while migrating a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestMigration {
+    /// Field that was changed (e.g. `icons`).
+    pub field: String,
+
+    /// What was changed.
+    pub description: String,
+}
+
+/// A migration step over the manifest's top-level object.
+type Migration = fn(&mut Map<String, Value>, &mut Vec<ManifestMigration>);
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl McpbManifest {
+    /// Upgrade a manifest parsed as loose JSON to the current schema.
+    ///
+    /// Returns the typed manifest and the changes that were applied.
+    pub fn migrate(mut raw: Value) -> ToolResult<(Self, Vec<ManifestMigration>)> {
+        let changes = migrate_raw(&mut raw);
+        let manifest = serde_json::from_value(raw).map_err(|e| {
+            ToolError::Generic(format!("Manifest is invalid after migration: {}", e))
+        })?;
+        Ok((manifest, changes))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Apply all migrations to a raw manifest in place.
+///
+/// Non-object values are left untouched.
+pub fn migrate_raw(raw: &mut Value) -> Vec<ManifestMigration> {
+    let mut changes = Vec::new();
+    if let Some(obj) = raw.as_object_mut() {
+        for migration in MIGRATIONS {
+            migration(obj, &mut changes);
+        }
+    }
+    changes
+}
+
+/// Rename the DXT-era `dxt_version` field to `manifest_version`.
+fn migrate_dxt_version(obj: &mut Map<String, Value>, changes: &mut Vec<ManifestMigration>) {
+    let Some(version) = obj.remove("dxt_version") else {
+        return;
+    };
+
+    if obj.contains_key("manifest_version") {
+        changes.push(ManifestMigration {
+            field: "dxt_version".into(),
+            description: "removed (superseded by manifest_version)".into(),
+        });
+    } else {
+        obj.insert("manifest_version".into(), version);
+        changes.push(ManifestMigration {
+            field: "manifest_version".into(),
+            description: "renamed from dxt_version".into(),
+        });
+    }
+}
+
+/// Bump older `manifest_version` values to the current schema version.
+fn migrate_manifest_version(obj: &mut Map<String, Value>, changes: &mut Vec<ManifestMigration>) {
+    let before = match obj.get("manifest_version") {
+        Some(Value::String(v)) if v == DEFAULT_MANIFEST_VERSION => return,
+        Some(Value::String(v)) => v.clone(),
+        Some(other) => other.to_string(),
+        None => "(missing)".to_string(),
+    };
+
+    obj.insert(
+        "manifest_version".into(),
+        Value::String(DEFAULT_MANIFEST_VERSION.into()),
+    );
+    changes.push(ManifestMigration {
+        field: "manifest_version".into(),
+        description: format!("{} → {}", before, DEFAULT_MANIFEST_VERSION),
+    });
+}
+
+/// Expand a plain-string `author` into an author object.
+fn migrate_author_string(obj: &mut Map<String, Value>, changes: &mut Vec<ManifestMigration>) {
+    let Some(Value::String(author)) = obj.get("author") else {
+        return;
+    };
+
+    let author = author.clone();
+    obj.insert("author".into(), serde_json::json!({ "name": author }));
+    changes.push(ManifestMigration {
+        field: "author".into(),
+        description: format!("converted \"{}\" to {{ \"name\": ... }}", author),
+    });
+}
+
+/// Move a legacy `icon` path into the front of the `icons` array.
+fn migrate_legacy_icon(obj: &mut Map<String, Value>, changes: &mut Vec<ManifestMigration>) {
+    let Some(Value::String(icon)) = obj.get("icon") else {
+        return;
+    };
+    let icon = icon.clone();
+
+    let icons = obj
+        .entry("icons")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    let Some(icons) = icons.as_array_mut() else {
+        return;
+    };
+
+    let already_listed = icons
+        .iter()
+        .any(|i| i.get("src").and_then(Value::as_str) == Some(icon.as_str()));
+    if !already_listed {
+        icons.insert(0, serde_json::json!({ "src": icon }));
+    }
+
+    obj.remove("icon");
+    changes.push(ManifestMigration {
+        field: "icon".into(),
+        description: if already_listed {
+            "removed (already listed in icons)".into()
+        } else {
+            format!("moved \"{}\" into icons", icon)
+        },
+    });
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_legacy_manifest() {
+        let raw = json!({
+            "dxt_version": "0.1",
+            "name": "legacy",
+            "version": "1.0.0",
+            "author": "Alice",
+            "icon": "icon.png",
+            "icons": [{"src": "icon-32.png", "size": "32x32"}],
+            "server": {"type": "node", "entry_point": "server/index.js"}
+        });
+
+        let (manifest, changes) = McpbManifest::migrate(raw).unwrap();
+        assert_eq!(manifest.manifest_version, DEFAULT_MANIFEST_VERSION);
+        assert_eq!(manifest.author.unwrap().name, "Alice");
+        assert!(manifest.icon.is_none());
+
+        let icons = manifest.icons.unwrap();
+        assert_eq!(icons.len(), 2);
+        assert_eq!(icons[0].src, "icon.png");
+
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["manifest_version", "manifest_version", "author", "icon"]
+        );
+    }
+
+    #[test]
+    fn test_migrate_current_manifest_is_unchanged() {
+        let mut raw = json!({
+            "manifest_version": "0.3",
+            "name": "current",
+            "author": {"name": "Alice"},
+            "icons": [{"src": "icon.png"}],
+            "server": {"type": "node"}
+        });
+        let before = raw.clone();
+
+        assert!(migrate_raw(&mut raw).is_empty());
+        assert_eq!(raw, before);
+    }
+
+    #[test]
+    fn test_migrate_icon_already_listed() {
+        let mut raw = json!({
+            "manifest_version": "0.3",
+            "icon": "icon.png",
+            "icons": [{"src": "icon.png"}]
+        });
+
+        let changes = migrate_raw(&mut raw);
+        assert_eq!(changes.len(), 1);
+        assert!(raw.get("icon").is_none());
+        assert_eq!(raw["icons"].as_array().unwrap().len(), 1);
+    }
+}
@@ -2,7 +2,9 @@
 
 mod init_mode;
 mod manifest;
+mod migrate;
 mod platform;
+mod relaxed;
 mod resolved;
 mod types;
 
@@ -19,9 +21,13 @@ pub const TOOL_STORE_NAMESPACE: &str = "store.tool.mcpb";
 
 pub use init_mode::InitMode;
 pub use manifest::McpbManifest;
+pub use migrate::{ManifestMigration, migrate_raw};
 pub use platform::{
     detect_platform, get_current_arch, get_current_os, get_current_platform,
-    resolve_platform_overrides,
+    resolve_platform_overrides, script_keys,
+};
+pub use relaxed::{
+    canonical_manifest_bytes, find_manifest_path, from_str_relaxed, has_comments, strip_jsonc,
 };
 pub use resolved::{ResolvedMcpConfig, ResolvedMcpbManifest};
 pub use types::{
@@ -40,6 +40,27 @@ pub fn get_current_arch() -> &'static str {
     }
 }
 
+/// Script keys to look up for `name` on the current OS, most specific first.
+///
+/// `build` resolves to `build:<os>` before `build`, where `<os>` is the MCPB name
+/// (`win32`, `darwin`, `linux`) or its common alias (`windows`, `macos`).
+pub fn script_keys(name: &str) -> Vec<String> {
+    let os = get_current_os();
+    let alias = match os {
+        "win32" => Some("windows"),
+        "darwin" => Some("macos"),
+        _ => None,
+    };
+
+    let mut keys: Vec<String> = alias
+        .into_iter()
+        .chain([os])
+        .map(|os| format!("{}:{}", name, os))
+        .collect();
+    keys.push(name.to_string());
+    keys
+}
+
 /// Resolve platform-specific overrides for mcp_config.
 ///
 /// Resolution order:
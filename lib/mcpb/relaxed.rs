@@ -0,0 +1,222 @@
+//! Relaxed manifest parsing for hand-authored manifests.
+//!
+//! Manifests may be written as `manifest.json5`, or as a `manifest.json` containing
+//! comments, using the JSONC subset of JSON5: `//` and `/* */` comments and trailing
+//! commas. Anything written back out (init, `validate --fix`, packed bundles) is strict JSON,
+//! so commands that edit a manifest in place refuse to touch one with comments.
+
+use crate::constants::{MCPB_MANIFEST_FILE, MCPB_MANIFEST_JSON5_FILE};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Locate the manifest in `dir`, preferring `manifest.json` over `manifest.json5`.
+///
+/// Returns the `manifest.json` path when neither exists so callers report the usual name.
+pub fn find_manifest_path(dir: &Path) -> PathBuf {
+    let manifest = dir.join(MCPB_MANIFEST_FILE);
+    if manifest.exists() {
+        return manifest;
+    }
+
+    let json5 = dir.join(MCPB_MANIFEST_JSON5_FILE);
+    if json5.exists() { json5 } else { manifest }
+}
+
+/// Parse manifest content, accepting comments and trailing commas.
+///
+/// Strict JSON is tried first. Error positions refer to the original content.
+pub fn from_str_relaxed<T: DeserializeOwned>(content: &str) -> serde_json::Result<T> {
+    match serde_json::from_str(content) {
+        Ok(value) => Ok(value),
+        Err(e) => {
+            let stripped = strip_jsonc(content);
+            if stripped == content {
+                return Err(e);
+            }
+            serde_json::from_str(&stripped)
+        }
+    }
+}
+
+/// Return manifest bytes as strict JSON for bundling.
+///
+/// Strict manifests are returned unchanged; relaxed ones are re-serialized.
+pub fn canonical_manifest_bytes(content: &[u8]) -> serde_json::Result<Vec<u8>> {
+    if serde_json::from_slice::<serde::de::IgnoredAny>(content).is_ok() {
+        return Ok(content.to_vec());
+    }
+
+    let text = String::from_utf8_lossy(content);
+    let value: serde_json::Value = from_str_relaxed(&text)?;
+    let mut bytes = serde_json::to_vec_pretty(&value)?;
+    bytes.push(b'\n');
+    Ok(bytes)
+}
+
+/// Blank out comments and trailing commas, keeping every other character in place.
+///
+/// Removed characters become spaces (newlines are kept), so line and column numbers
+/// in the result match the original content.
+pub fn strip_jsonc(content: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut out = chars.clone();
+    let mut in_string = false;
+    let mut i = 0;
+
+    // Pass 1: comments
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            match c {
+                '\\' => i += 1,
+                '"' => in_string = false,
+                _ => {}
+            }
+            i += 1;
+            continue;
+        }
+
+        match (c, chars.get(i + 1)) {
+            ('"', _) => in_string = true,
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    out[i] = ' ';
+                    i += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                let start = i;
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                let end = (i + 2).min(chars.len());
+                for ch in &mut out[start..end] {
+                    if *ch != '\n' {
+                        *ch = ' ';
+                    }
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    // Pass 2: trailing commas before a closing bracket
+    let mut in_string = false;
+    let mut i = 0;
+    while i < out.len() {
+        let c = out[i];
+        if in_string {
+            match c {
+                '\\' => i += 1,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = out[i + 1..].iter().find(|ch| !ch.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                out[i] = ' ';
+            }
+        }
+        i += 1;
+    }
+
+    out.into_iter().collect()
+}
+
+/// Whether manifest content has comments, which rewriting it as strict JSON would drop.
+///
+/// Trailing commas don't count: nothing is lost when they are removed.
+pub fn has_comments(content: &str) -> bool {
+    content
+        .chars()
+        .zip(strip_jsonc(content).chars())
+        .any(|(original, stripped)| original != stripped && original != ',')
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const RELAXED: &str = r#"{
+  // Package name
+  "name": "my-tool", /* inline */
+  "description": "Uses // and /* */ in a string",
+  "keywords": ["a", "b",],
+}
+"#;
+
+    #[test]
+    fn test_strip_jsonc_keeps_positions() {
+        let stripped = strip_jsonc(RELAXED);
+        assert_eq!(stripped.len(), RELAXED.len());
+        assert_eq!(stripped.lines().count(), RELAXED.lines().count());
+        assert!(stripped.contains(r#""Uses // and /* */ in a string""#));
+        assert!(!stripped.contains("Package name"));
+        assert!(!stripped.contains("inline"));
+    }
+
+    #[test]
+    fn test_has_comments() {
+        assert!(has_comments(RELAXED));
+        assert!(!has_comments(r#"{"name": "a // b", "keywords": ["a",],}"#));
+    }
+
+    #[test]
+    fn test_from_str_relaxed() {
+        let value: serde_json::Value = from_str_relaxed(RELAXED).unwrap();
+        assert_eq!(value["name"], "my-tool");
+        assert_eq!(value["keywords"], serde_json::json!(["a", "b"]));
+
+        // Errors still point at the original line
+        let err =
+            from_str_relaxed::<serde_json::Value>("{\n  // note\n  \"a\": nope\n}").unwrap_err();
+        assert_eq!(err.line(), 3);
+    }
+
+    #[test]
+    fn test_canonical_manifest_bytes() {
+        let strict = br#"{"name":"my-tool"}"#;
+        assert_eq!(canonical_manifest_bytes(strict).unwrap(), strict.to_vec());
+
+        let canonical = canonical_manifest_bytes(RELAXED.as_bytes()).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&canonical).unwrap();
+        assert_eq!(value["name"], "my-tool");
+    }
+
+    #[test]
+    fn test_find_manifest_path() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(
+            find_manifest_path(dir.path()),
+            dir.path().join("manifest.json")
+        );
+
+        std::fs::write(dir.path().join("manifest.json5"), RELAXED).unwrap();
+        assert_eq!(
+            find_manifest_path(dir.path()),
+            dir.path().join("manifest.json5")
+        );
+
+        std::fs::write(dir.path().join("manifest.json"), "{}").unwrap();
+        assert_eq!(
+            find_manifest_path(dir.path()),
+            dir.path().join("manifest.json")
+        );
+    }
+}
@@ -1,6 +1,9 @@
 //! Resolved manifest types with template expressions evaluated.
 
 use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::security::Redactor;
 
 use super::manifest::McpbManifest;
 use super::types::{McpbTransport, OAuthConfig};
@@ -37,4 +40,8 @@ pub struct ResolvedMcpbManifest {
     pub transport: McpbTransport,
     /// Whether this is reference mode (no entry_point).
     pub is_reference: bool,
+    /// Working directory for the server process; `None` uses the bundle directory.
+    pub working_dir: Option<PathBuf>,
+    /// Masks sensitive user_config values in output.
+    pub redactor: Redactor,
 }
@@ -76,6 +76,9 @@ pub enum McpbTransport {
 }
 
 impl McpbTransport {
+    /// Every transport, in the order `tool init --list-types` shows them.
+    pub const ALL: [Self; 2] = [Self::Stdio, Self::Http];
+
     /// Check if this is stdio transport (for skip_serializing_if).
     pub fn is_stdio(&self) -> bool {
         matches!(self, McpbTransport::Stdio)
@@ -107,6 +110,9 @@ pub enum NodePackageManager {
 }
 
 impl NodePackageManager {
+    /// Every supported Node.js package manager.
+    pub const ALL: [Self; 4] = [Self::Npm, Self::Pnpm, Self::Bun, Self::Yarn];
+
     /// Get the build command for this package manager.
     pub fn build_command(&self) -> &'static str {
         match self {
@@ -116,6 +122,16 @@ impl NodePackageManager {
             Self::Yarn => "yarn install",
         }
     }
+
+    /// Get the build command for a TypeScript project (install, then compile).
+    pub fn typescript_build_command(&self) -> &'static str {
+        match self {
+            Self::Npm => "npm install && npm run build",
+            Self::Pnpm => "pnpm install && pnpm run build",
+            Self::Bun => "bun install && bun run build",
+            Self::Yarn => "yarn install && yarn build",
+        }
+    }
 }
 
 impl std::fmt::Display for NodePackageManager {
@@ -143,6 +159,9 @@ pub enum PythonPackageManager {
 }
 
 impl PythonPackageManager {
+    /// Every supported Python package manager.
+    pub const ALL: [Self; 3] = [Self::Uv, Self::Pip, Self::Poetry];
+
     /// Get the build command for this package manager.
     pub fn build_command(&self) -> &'static str {
         match self {
@@ -190,6 +209,24 @@ pub enum PackageManager {
     Python(PythonPackageManager),
 }
 
+impl PackageManager {
+    /// Every supported package manager, Node.js first.
+    pub fn all() -> impl Iterator<Item = Self> {
+        NodePackageManager::ALL
+            .into_iter()
+            .map(Self::Node)
+            .chain(PythonPackageManager::ALL.into_iter().map(Self::Python))
+    }
+
+    /// The server type this package manager builds.
+    pub fn server_type(&self) -> McpbServerType {
+        match self {
+            Self::Node(_) => McpbServerType::Node,
+            Self::Python(_) => McpbServerType::Python,
+        }
+    }
+}
+
 impl std::fmt::Display for PackageManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -606,3 +643,16 @@ pub struct Scripts {
     #[serde(flatten)]
     pub custom: BTreeMap<String, String>,
 }
+
+impl Scripts {
+    /// Get a script for the current OS, preferring variants like `build:windows`.
+    pub fn for_current_os(&self, name: &str) -> Option<&str> {
+        super::platform::script_keys(name)
+            .iter()
+            .find_map(|key| match key.as_str() {
+                "build" => self.build.as_deref(),
+                "test" => self.test.as_deref(),
+                key => self.custom.get(key).map(String::as_str),
+            })
+    }
+}
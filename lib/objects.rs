@@ -0,0 +1,216 @@
+//! Content-addressed storage for installed tool files.
+//!
+//! With `"dedup": true` in `~/.tool/settings.json` (or `TOOL_DEDUP=1`), files extracted by `tool install` are hashed and hard-linked to
+//! a shared copy under `~/.tool/objects`, so versions that ship identical files share
+//! disk space. An object's references are its hard links: once no installed tool links
+//! to it, [`collect_garbage`] removes it.
+//!
+//! Linked files share their contents, so a tool that rewrites its own installed files
+//! in place would change them for every install sharing them. Dedup is opt-in for that
+//! reason, and only supported on Unix; elsewhere installs are always plain copies.
+
+use crate::constants::TOOL_DEDUP_ENV;
+use crate::settings::Settings;
+use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// What [`dedup_dir`] did to a directory.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DedupStats {
+    /// Files replaced by a link to an existing object.
+    pub linked: usize,
+
+    /// Files added to the store as new objects.
+    pub stored: usize,
+
+    /// Bytes no longer duplicated on disk.
+    pub saved_bytes: u64,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Whether installs should be deduplicated: `TOOL_DEDUP` if set, else the `dedup` setting.
+pub fn dedup_enabled() -> bool {
+    match std::env::var(TOOL_DEDUP_ENV) {
+        Ok(v) => matches!(v.trim(), "1" | "true" | "yes"),
+        Err(_) => Settings::load().dedup,
+    }
+}
+
+/// Replace each regular file under `dir` with a hard link to its object in `store`.
+///
+/// Files seen for the first time become new objects. Files that can't be linked (e.g.
+/// the store is on another filesystem) are left as they are.
+#[cfg(unix)]
+pub fn dedup_dir(dir: &Path, store: &Path) -> std::io::Result<DedupStats> {
+    use std::os::unix::fs::MetadataExt;
+
+    let mut stats = DedupStats::default();
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry.map_err(std::io::Error::other)?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let path = entry.path();
+        let metadata = entry.metadata().map_err(std::io::Error::other)?;
+        let hash = crate::pack::compute_file_sha256(path)?;
+        let object = object_path(store, &hash, metadata.mode());
+
+        match std::fs::metadata(&object) {
+            Ok(existing) if existing.ino() == metadata.ino() => {}
+            Ok(_) => {
+                // Link beside the file, then swap it in so the file is never missing
+                let mut tmp = path.as_os_str().to_owned();
+                tmp.push(".dedup");
+                let tmp = PathBuf::from(tmp);
+                if std::fs::hard_link(&object, &tmp).is_ok() {
+                    std::fs::rename(&tmp, path)?;
+                    stats.linked += 1;
+                    stats.saved_bytes += metadata.len();
+                }
+            }
+            Err(_) => {
+                if let Some(parent) = object.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                if std::fs::hard_link(path, &object).is_ok() {
+                    stats.stored += 1;
+                }
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Deduplication is Unix-only; elsewhere this does nothing.
+#[cfg(not(unix))]
+pub fn dedup_dir(_dir: &Path, _store: &Path) -> std::io::Result<DedupStats> {
+    Ok(DedupStats::default())
+}
+
+/// Remove objects that no installed file links to any more. Returns how many were removed.
+#[cfg(unix)]
+pub fn collect_garbage(store: &Path) -> std::io::Result<usize> {
+    use std::os::unix::fs::MetadataExt;
+
+    if !store.is_dir() {
+        return Ok(0);
+    }
+
+    let mut removed = 0;
+    for entry in walkdir::WalkDir::new(store).min_depth(1) {
+        let entry = entry.map_err(std::io::Error::other)?;
+        if entry.file_type().is_file()
+            && entry.metadata().map_err(std::io::Error::other)?.nlink() <= 1
+        {
+            std::fs::remove_file(entry.path())?;
+            removed += 1;
+        }
+    }
+
+    // Drop fan-out directories left empty (non-empty ones refuse to be removed)
+    for entry in std::fs::read_dir(store)? {
+        let _ = std::fs::remove_dir(entry?.path());
+    }
+
+    Ok(removed)
+}
+
+/// Deduplication is Unix-only; elsewhere there is nothing to collect.
+#[cfg(not(unix))]
+pub fn collect_garbage(_store: &Path) -> std::io::Result<usize> {
+    Ok(0)
+}
+
+/// Remove an installed tool (its directory, or the link of a linked install), then the
+/// objects in `store` that nothing links to any more.
+pub fn remove_install(path: &Path, store: &Path) -> std::io::Result<()> {
+    if path.is_symlink() || path.is_file() {
+        std::fs::remove_file(path)?;
+    } else {
+        std::fs::remove_dir_all(path)?;
+    }
+    release_objects(store);
+    Ok(())
+}
+
+/// Run [`collect_garbage`] after installs were removed. Leftover objects only cost disk
+/// space, so failures are logged rather than returned.
+pub fn release_objects(store: &Path) {
+    if let Err(e) = collect_garbage(store) {
+        tracing::warn!("Failed to clean up the object store: {}", e);
+    }
+}
+
+/// Where the object for a file lives: `<store>/<2 hex chars>/<rest>-<mode>`.
+///
+/// The permission bits are part of the key because hard links share them.
+#[cfg(unix)]
+fn object_path(store: &Path, hash: &str, mode: u32) -> PathBuf {
+    store
+        .join(&hash[..2])
+        .join(format!("{}-{:o}", &hash[2..], mode & 0o7777))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::MetadataExt;
+
+    #[test]
+    fn test_dedup_and_collect_garbage() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = tmp.path().join("objects");
+        let (v1, v2) = (tmp.path().join("tool@1.0.0"), tmp.path().join("tool@1.1.0"));
+        for dir in [&v1, &v2] {
+            std::fs::create_dir_all(dir.join("lib")).unwrap();
+            std::fs::write(dir.join("lib/shared.js"), "module.exports = 1;").unwrap();
+        }
+        std::fs::write(v2.join("lib/new.js"), "module.exports = 2;").unwrap();
+
+        let first = dedup_dir(&v1, &store).unwrap();
+        assert_eq!((first.stored, first.linked), (1, 0));
+        let second = dedup_dir(&v2, &store).unwrap();
+        assert_eq!((second.stored, second.linked), (1, 1));
+
+        let inode = |p: PathBuf| std::fs::metadata(p).unwrap().ino();
+        assert_eq!(
+            inode(v1.join("lib/shared.js")),
+            inode(v2.join("lib/shared.js"))
+        );
+
+        // The shared object survives while one install still links to it
+        std::fs::remove_dir_all(&v2).unwrap();
+        assert_eq!(collect_garbage(&store).unwrap(), 1);
+        std::fs::remove_dir_all(&v1).unwrap();
+        assert_eq!(collect_garbage(&store).unwrap(), 1);
+        assert_eq!(std::fs::read_dir(&store).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_remove_install_collects_objects() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = tmp.path().join("objects");
+        let install = tmp.path().join("tools/tool@1.0.0");
+        std::fs::create_dir_all(&install).unwrap();
+        std::fs::write(install.join("index.js"), "module.exports = 1;").unwrap();
+        assert_eq!(dedup_dir(&install, &store).unwrap().stored, 1);
+
+        remove_install(&install, &store).unwrap();
+        assert!(!install.exists());
+        assert_eq!(std::fs::read_dir(&store).unwrap().count(), 0);
+    }
+}
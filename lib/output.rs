@@ -3,14 +3,71 @@
 //! These types provide a consistent structure for JSON output across commands
 //! like `list`, `info`, and `grep`. All collections use object-keyed structures
 //! (BTreeMap) instead of arrays for self-describing paths.
+//!
+//! Every `--json` result is wrapped in a [`JsonEnvelope`] (`{ ok, data, error }`) so
+//! scripts can rely on one shape regardless of subcommand.
 
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
 
+use crate::error::ToolError;
 use crate::mcp::ToolCapabilities;
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Output format selected by the global `--json`/`--concise` flags.
+static OUTPUT_FORMAT: AtomicU8 = AtomicU8::new(OutputFormat::Human as u8);
+
+//--------------------------------------------------------------------------------------------------
+// Types: Output Format
+//--------------------------------------------------------------------------------------------------
+
+/// How a command renders its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum OutputFormat {
+    /// Styled output for terminals.
+    #[default]
+    Human,
+
+    /// A [`JsonEnvelope`] on stdout.
+    Json,
+
+    /// Minimal, machine-parseable text for AI agents.
+    Concise,
+}
+
+/// Envelope wrapping every `--json` result.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonEnvelope<T> {
+    /// Whether the command succeeded.
+    pub ok: bool,
+
+    /// Command result, present when `ok`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+
+    /// Failure details, present when not `ok`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonError>,
+}
+
+/// Error details in a failed [`JsonEnvelope`].
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonError {
+    /// Registry error code, when the failure came from the registry API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// Human-readable error message.
+    pub message: String,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Types: List Output
 //--------------------------------------------------------------------------------------------------
@@ -274,14 +331,14 @@ impl ToolInfoOutput {
         }
     }
 
-    /// Serialize to JSON string.
+    /// Serialize to an enveloped JSON string.
     pub fn to_json(&self) -> serde_json::Result<String> {
-        serde_json::to_string(self)
+        json_envelope(self, true)
     }
 
-    /// Serialize to pretty JSON string.
+    /// Serialize to an enveloped, pretty JSON string.
     pub fn to_json_pretty(&self) -> serde_json::Result<String> {
-        serde_json::to_string_pretty(self)
+        json_envelope(self, false)
     }
 }
 
@@ -361,14 +418,14 @@ impl GrepOutput {
         });
     }
 
-    /// Serialize to JSON string.
+    /// Serialize to an enveloped JSON string.
     pub fn to_json(&self) -> serde_json::Result<String> {
-        serde_json::to_string(self)
+        json_envelope(self, true)
     }
 
-    /// Serialize to pretty JSON string.
+    /// Serialize to an enveloped, pretty JSON string.
     pub fn to_json_pretty(&self) -> serde_json::Result<String> {
-        serde_json::to_string_pretty(self)
+        json_envelope(self, false)
     }
 }
 
@@ -386,14 +443,65 @@ impl GrepOutputPathOnly {
         self.matches.push(GrepMatchPathOnly { path });
     }
 
-    /// Serialize to JSON string.
+    /// Serialize to an enveloped JSON string.
     pub fn to_json(&self) -> serde_json::Result<String> {
-        serde_json::to_string(self)
+        json_envelope(self, true)
     }
 
-    /// Serialize to pretty JSON string.
+    /// Serialize to an enveloped, pretty JSON string.
     pub fn to_json_pretty(&self) -> serde_json::Result<String> {
-        serde_json::to_string_pretty(self)
+        json_envelope(self, false)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Output Format
+//--------------------------------------------------------------------------------------------------
+
+impl OutputFormat {
+    /// Select the format from the global flags. `--json` takes precedence over `--concise`.
+    pub fn from_flags(json: bool, concise: bool) -> Self {
+        if json {
+            Self::Json
+        } else if concise {
+            Self::Concise
+        } else {
+            Self::Human
+        }
+    }
+
+    /// Whether results are emitted as a JSON envelope.
+    pub fn is_json(self) -> bool {
+        self == Self::Json
+    }
+}
+
+impl<T: Serialize> JsonEnvelope<T> {
+    /// Successful result.
+    pub fn ok(data: T) -> Self {
+        Self {
+            ok: true,
+            data: Some(data),
+            error: None,
+        }
+    }
+}
+
+impl JsonEnvelope<()> {
+    /// Failed result.
+    pub fn error(error: &ToolError) -> Self {
+        let code = match error {
+            ToolError::RegistryApi { code, .. } => Some(code.clone()),
+            _ => None,
+        };
+        Self {
+            ok: false,
+            data: None,
+            error: Some(JsonError {
+                code,
+                message: error.to_string(),
+            }),
+        }
     }
 }
 
@@ -401,26 +509,65 @@ impl GrepOutputPathOnly {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
-/// Serialize a BTreeMap of ServerOutput to JSON.
+/// Set the process-wide output format (from the global flags, once at startup).
+pub fn set_output_format(format: OutputFormat) {
+    OUTPUT_FORMAT.store(format as u8, Ordering::SeqCst);
+}
+
+/// The process-wide output format.
+pub fn output_format() -> OutputFormat {
+    match OUTPUT_FORMAT.load(Ordering::SeqCst) {
+        x if x == OutputFormat::Json as u8 => OutputFormat::Json,
+        x if x == OutputFormat::Concise as u8 => OutputFormat::Concise,
+        _ => OutputFormat::Human,
+    }
+}
+
+/// Serialize a result in a successful [`JsonEnvelope`]. `compact` skips pretty-printing
+/// (used with `--concise`).
+pub fn json_envelope<T: Serialize>(data: &T, compact: bool) -> serde_json::Result<String> {
+    let envelope = JsonEnvelope::ok(data);
+    if compact {
+        serde_json::to_string(&envelope)
+    } else {
+        serde_json::to_string_pretty(&envelope)
+    }
+}
+
+/// Print a result in a successful [`JsonEnvelope`] to stdout.
+pub fn print_json<T: Serialize>(data: &T, compact: bool) -> serde_json::Result<()> {
+    println!("{}", json_envelope(data, compact)?);
+    Ok(())
+}
+
+/// Print an error in a failed [`JsonEnvelope`] to stdout.
+pub fn print_json_error(error: &ToolError) {
+    let envelope = JsonEnvelope::error(error);
+    if let Ok(json) = serde_json::to_string_pretty(&envelope) {
+        println!("{}", json);
+    }
+}
+
+/// Serialize a BTreeMap of ServerOutput to an enveloped JSON result.
 pub fn list_to_json(items: &BTreeMap<String, ServerOutput>) -> serde_json::Result<String> {
-    serde_json::to_string(items)
+    json_envelope(items, true)
 }
 
-/// Serialize a BTreeMap of ServerOutput to pretty JSON.
+/// Serialize a BTreeMap of ServerOutput to an enveloped, pretty JSON result.
 pub fn list_to_json_pretty(items: &BTreeMap<String, ServerOutput>) -> serde_json::Result<String> {
-    serde_json::to_string_pretty(items)
+    json_envelope(items, false)
 }
 
-/// Serialize a BTreeMap of FullServerOutput to JSON.
+/// Serialize a BTreeMap of FullServerOutput to an enveloped JSON result.
 pub fn full_list_to_json(items: &BTreeMap<String, FullServerOutput>) -> serde_json::Result<String> {
-    serde_json::to_string(items)
+    json_envelope(items, true)
 }
 
-/// Serialize a BTreeMap of FullServerOutput to pretty JSON.
+/// Serialize a BTreeMap of FullServerOutput to an enveloped, pretty JSON result.
 pub fn full_list_to_json_pretty(
     items: &BTreeMap<String, FullServerOutput>,
 ) -> serde_json::Result<String> {
-    serde_json::to_string_pretty(items)
+    json_envelope(items, false)
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -532,3 +679,39 @@ pub fn path_schema_field_prop(
         prop.to_string(),
     ]
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_envelope_wraps_data() {
+        let json = json_envelope(&serde_json::json!({ "count": 2 }), true).unwrap();
+        assert_eq!(json, r#"{"ok":true,"data":{"count":2}}"#);
+    }
+
+    #[test]
+    fn json_envelope_reports_errors() {
+        let error = ToolError::RegistryApi {
+            operation: "Search".into(),
+            code: "BAD_REQUEST".into(),
+            message: "query too short".into(),
+            status: 400,
+        };
+        let value = serde_json::to_value(JsonEnvelope::error(&error)).unwrap();
+        assert_eq!(value["ok"], false);
+        assert_eq!(value["error"]["code"], "BAD_REQUEST");
+        assert!(value.get("data").is_none());
+    }
+
+    #[test]
+    fn output_format_prefers_json() {
+        assert_eq!(OutputFormat::from_flags(true, true), OutputFormat::Json);
+        assert_eq!(OutputFormat::from_flags(false, true), OutputFormat::Concise);
+        assert_eq!(OutputFormat::from_flags(false, false), OutputFormat::Human);
+    }
+}
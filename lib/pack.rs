@@ -1,19 +1,22 @@
 //! MCPB bundle packing.
+//!
+//! Packing never writes to stdout; callers observe progress through
+//! [`PackOptions::on_progress`] and render it however they like.
 
-use crate::constants::MCPB_MANIFEST_FILE;
-use crate::mcpb::McpbManifest;
-use crate::validate::{ValidationResult, validate_manifest};
+use crate::constants::{CHECKSUMS_FILE, MCPB_EXT, MCPB_MANIFEST_FILE, MCPBX_EXT};
+use crate::mcpb::{McpbManifest, canonical_manifest_bytes, find_manifest_path, from_str_relaxed};
+use crate::validate::{ValidationResult, validate_manifest, validate_manifest_content};
 use flate2::Compression;
-use flate2::write::GzEncoder;
-use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder, Glob};
 use sha2::{Digest, Sha256};
 use std::fs::File;
 use std::io::{Read, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 use tar::Builder;
 use thiserror::Error;
-use walkdir::WalkDir;
+use walkdir::{DirEntry, WalkDir};
 use zip::DateTime as ZipDateTime;
 use zip::ZipWriter;
 use zip::write::SimpleFileOptions;
@@ -22,18 +25,36 @@ use zip::write::SimpleFileOptions;
 // Types
 //--------------------------------------------------------------------------------------------------
 
-/// Progress event emitted during packing.
-#[derive(Debug, Clone)]
-pub enum PackProgress {
-    /// Starting to pack, with total file count.
-    Started { total_files: usize },
-    /// A file was added to the bundle.
-    FileAdded { path: String, current: usize },
-    /// Packing completed.
+/// Stage of a packing operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackStage {
+    /// Files were collected and archiving is about to start.
+    Started,
+
+    /// A file was added to the bundle (relative path).
+    FileAdded(String),
+
+    /// The bundle was written.
     Finished,
 }
 
-/// Callback type for progress events.
+/// Progress snapshot emitted during packing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackProgress {
+    /// Stage this snapshot reports.
+    pub stage: PackStage,
+
+    /// Files added so far.
+    pub files_done: usize,
+
+    /// Files that will be added in total.
+    pub total_files: usize,
+
+    /// Uncompressed bytes added so far.
+    pub bytes_done: u64,
+}
+
+/// Callback type for progress snapshots.
 pub type ProgressCallback = Arc<dyn Fn(PackProgress) + Send + Sync>;
 
 /// Error types for pack operations.
@@ -70,6 +91,18 @@ pub enum PackError {
     /// Manifest not found.
     #[error("manifest.json not found in {0}")]
     ManifestNotFound(PathBuf),
+
+    /// Manifest override path does not exist.
+    #[error("manifest not found: {0}")]
+    ManifestPathNotFound(PathBuf),
+
+    /// A path would be written outside the bundle root.
+    #[error("unsafe path in bundle: {0}")]
+    UnsafePath(String),
+
+    /// Manifest override lies outside the packed directory.
+    #[error("manifest {manifest} is outside the packed directory {dir}")]
+    ManifestOutsideDir { manifest: PathBuf, dir: PathBuf },
 }
 
 /// Options for packing.
@@ -78,6 +111,12 @@ pub struct PackOptions {
     /// Output file path.
     pub output: Option<PathBuf>,
 
+    /// Manifest to bundle as the root `manifest.json` instead of the directory's own.
+    ///
+    /// Must be inside the packed directory; relative paths resolve against it. Paths the
+    /// manifest references still resolve from the directory root.
+    pub manifest: Option<PathBuf>,
+
     /// Whether to validate before packing.
     pub validate: bool,
 
@@ -87,6 +126,18 @@ pub struct PackOptions {
     /// Whether to extract icon as a separate file (for registry upload).
     pub extract_icon: bool,
 
+    /// Whether to add a `checksums.txt` listing each file's SHA-256 to the bundle.
+    pub emit_checksums: bool,
+
+    /// Extra ignore patterns applied after `.mcpbignore`.
+    pub exclude: Vec<String>,
+
+    /// Patterns re-included after `.mcpbignore` and `exclude`.
+    pub include: Vec<String>,
+
+    /// Dereference symlinks that resolve outside the directory instead of skipping them.
+    pub follow_symlinks: bool,
+
     /// Progress callback for reporting packing progress.
     pub on_progress: Option<ProgressCallback>,
 }
@@ -95,9 +146,14 @@ impl Default for PackOptions {
     fn default() -> Self {
         Self {
             output: None,
+            manifest: None,
             validate: true,
             verbose: false,
             extract_icon: false,
+            emit_checksums: false,
+            exclude: Vec::new(),
+            include: Vec::new(),
+            follow_symlinks: false,
             on_progress: None,
         }
     }
@@ -107,9 +163,14 @@ impl std::fmt::Debug for PackOptions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PackOptions")
             .field("output", &self.output)
+            .field("manifest", &self.manifest)
             .field("validate", &self.validate)
             .field("verbose", &self.verbose)
             .field("extract_icon", &self.extract_icon)
+            .field("emit_checksums", &self.emit_checksums)
+            .field("exclude", &self.exclude)
+            .field("include", &self.include)
+            .field("follow_symlinks", &self.follow_symlinks)
             .field("on_progress", &self.on_progress.is_some())
             .finish()
     }
@@ -152,6 +213,9 @@ pub struct PackResult {
     /// Files that were ignored.
     pub ignored_files: Vec<String>,
 
+    /// Symlinks skipped because they resolve outside the packed directory.
+    pub skipped_symlinks: Vec<String>,
+
     /// Bundle format extension (`"mcpb"` or `"mcpbx"`).
     pub extension: String,
 
@@ -167,6 +231,15 @@ pub struct PackResult {
 pub struct CollectOptions {
     /// Track ignored files for verbose output.
     pub track_ignored: bool,
+
+    /// Dereference symlinks that resolve outside the directory instead of skipping them.
+    pub follow_symlinks: bool,
+
+    /// Extra gitignore-style patterns excluded after `.mcpbignore`.
+    pub exclude: Vec<String>,
+
+    /// Patterns re-included after `.mcpbignore` and `exclude`.
+    pub include: Vec<String>,
 }
 
 /// A file entry collected for bundling.
@@ -205,6 +278,24 @@ pub struct CollectResult {
 /// Built-in ignore patterns (cannot be overridden).
 const BUILTIN_IGNORES: &[&str] = &[".git", "*.mcpb", "*.mcpbx"];
 
+/// Source recorded for `--exclude` rules, used to label what they ignored.
+const EXCLUDE_RULE_SOURCE: &str = "--exclude";
+
+/// Source recorded for `--include` rules.
+const INCLUDE_RULE_SOURCE: &str = "--include";
+
+/// Files larger than this are estimated from a sample instead of compressed in full.
+const ESTIMATE_SAMPLE_THRESHOLD: usize = 8 * 1024 * 1024;
+
+/// Bytes taken from the start of a large file to estimate its compression ratio.
+const ESTIMATE_SAMPLE_SIZE: usize = 1024 * 1024;
+
+/// Fixed zip bytes per entry: local file header (30) plus central directory record (46).
+const ZIP_ENTRY_OVERHEAD: u64 = 30 + 46;
+
+/// Fixed zip bytes for the end of central directory record.
+const ZIP_END_OVERHEAD: u64 = 22;
+
 /// Default ignore patterns (can be overridden with !pattern in .mcpbignore).
 const DEFAULT_IGNORES: &[&str] = &[
     ".DS_Store",
@@ -223,22 +314,20 @@ const DEFAULT_IGNORES: &[&str] = &[
 
 /// Pack a directory into an MCPB bundle.
 pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, PackError> {
-    // 1. Check manifest exists
-    let manifest_path = dir.join(MCPB_MANIFEST_FILE);
-    if !manifest_path.exists() {
-        return Err(PackError::ManifestNotFound(dir.to_path_buf()));
-    }
+    // 1. Locate manifest
+    let manifest_path = resolve_manifest_path(dir, options.manifest.as_deref())?;
+    let manifest_content = std::fs::read_to_string(&manifest_path)?;
 
     // 2. Validate first (unless skipped)
     if options.validate {
-        let validation = validate_manifest(dir);
+        let validation = validate_manifest_content(dir, &manifest_content);
         if !validation.is_valid() {
             return Err(PackError::ValidationFailed(validation));
         }
     }
 
     // 3. Read manifest for name/version
-    let manifest: McpbManifest = serde_json::from_str(&std::fs::read_to_string(&manifest_path)?)?;
+    let manifest: McpbManifest = from_str_relaxed(&manifest_content)?;
 
     let name = manifest.name.as_deref().unwrap_or("bundle");
     let version = manifest.version.as_deref().unwrap_or("0.0.0");
@@ -250,18 +339,18 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
         .clone()
         .unwrap_or_else(|| dir.join(format!("{}-{}.{}", name, version, ext)));
 
+    tracing::debug!("Packing {} into {}", dir.display(), output_path.display());
+
     // 5. Build ignore matcher
-    let ignore_matcher = build_ignore_matcher(dir)?;
+    let ignore_matcher = build_pack_ignore_matcher(dir, &options.exclude, &options.include)?;
 
     // 6. Collect all files first (for progress reporting)
     let mut entries_to_add: Vec<(PathBuf, String, bool)> = Vec::new();
     let mut ignored_files = Vec::new();
 
-    for entry in WalkDir::new(dir)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| !is_builtin_ignored(e.path(), dir))
-    {
+    let mut skipped_symlinks = Vec::new();
+
+    for entry in walk_pack_dir(dir, options.follow_symlinks, &mut skipped_symlinks)? {
         let entry = entry?;
         let path = entry.path();
 
@@ -271,20 +360,31 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
 
         let relative_path = path.strip_prefix(dir)?;
         let path_str = relative_path.to_string_lossy().to_string();
+        if !is_enclosed(relative_path) {
+            return Err(PackError::UnsafePath(path_str));
+        }
         let is_dir = entry.file_type().is_dir();
 
-        if ignore_matcher
-            .matched_path_or_any_parents(relative_path, is_dir)
-            .is_ignore()
-        {
+        let matched = ignore_matcher.matched_path_or_any_parents(relative_path, is_dir);
+        if matched.is_ignore() {
+            tracing::trace!("Ignoring {}", path_str);
             if options.verbose {
-                ignored_files.push(path_str);
+                ignored_files.push(describe_ignored(path_str, matched.inner().copied()));
+            }
+            continue;
+        }
+
+        // A generated listing replaces any checksums file in the source
+        if options.emit_checksums && path_str == CHECKSUMS_FILE {
+            if options.verbose {
+                ignored_files.push(format!("{} (replaced by generated checksums)", path_str));
             }
             continue;
         }
 
         entries_to_add.push((path.to_path_buf(), path_str, is_dir));
     }
+    place_manifest_entry(&mut entries_to_add, dir, &manifest_path);
 
     // Count only files (not directories)
     let total_files = entries_to_add
@@ -292,10 +392,13 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
         .filter(|(_, _, is_dir)| !is_dir)
         .count();
 
-    // Emit started event
-    if let Some(ref cb) = options.on_progress {
-        cb(PackProgress::Started { total_files });
-    }
+    let mut progress = PackProgress {
+        stage: PackStage::Started,
+        files_done: 0,
+        total_files,
+        bytes_done: 0,
+    };
+    report_progress(options, &progress);
 
     // 7. Create zip archive
     let file = File::create(&output_path)?;
@@ -307,6 +410,7 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
 
     let mut file_count = 0;
     let mut total_size = 0u64;
+    let mut checksums = Vec::new();
 
     // 8. Add files to archive with progress
     for (path, path_str, is_dir) in entries_to_add {
@@ -339,34 +443,50 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
             let mut contents = Vec::new();
             file.read_to_end(&mut contents)?;
 
+            // The bundled manifest is always strict JSON
+            if path_str == MCPB_MANIFEST_FILE {
+                contents = canonical_manifest_bytes(&contents)?;
+            }
+
             total_size += contents.len() as u64;
             file_count += 1;
 
+            tracing::trace!("Adding {} ({} bytes)", path_str, contents.len());
             zip.start_file(&path_str, file_options)?;
             zip.write_all(&contents)?;
 
-            // Emit progress
-            if let Some(ref cb) = options.on_progress {
-                cb(PackProgress::FileAdded {
-                    path: path_str,
-                    current: file_count,
-                });
+            if options.emit_checksums {
+                checksums.push((path_str.clone(), compute_sha256(&contents)));
             }
+
+            progress.stage = PackStage::FileAdded(path_str);
+            progress.files_done = file_count;
+            progress.bytes_done = total_size;
+            report_progress(options, &progress);
         }
     }
 
+    if options.emit_checksums {
+        zip.start_file(CHECKSUMS_FILE, zip_options)?;
+        zip.write_all(render_checksums(&mut checksums).as_bytes())?;
+    }
+
     zip.finish()?;
 
-    // Emit finished event
-    if let Some(ref cb) = options.on_progress {
-        cb(PackProgress::Finished);
-    }
+    progress.stage = PackStage::Finished;
+    report_progress(options, &progress);
 
     let compressed_size = std::fs::metadata(&output_path)?.len();
 
     // Compute SHA-256 checksum of the bundle
-    let bundle_bytes = std::fs::read(&output_path)?;
-    let checksum = compute_sha256(&bundle_bytes);
+    let checksum = compute_file_sha256(&output_path)?;
+    tracing::debug!(
+        "Packed {} files ({} bytes, {} compressed), sha256 {}",
+        file_count,
+        total_size,
+        compressed_size,
+        checksum
+    );
 
     // Extract icons if requested (for registry upload)
     let icons = if options.extract_icon {
@@ -381,6 +501,7 @@ pub fn pack_bundle(dir: &Path, options: &PackOptions) -> Result<PackResult, Pack
         total_size,
         compressed_size,
         ignored_files,
+        skipped_symlinks,
         extension: ext.to_string(),
         checksum,
         icons,
@@ -397,23 +518,20 @@ pub fn pack_bundle_for_platform(
     options: &PackOptions,
     platform: Option<&str>,
 ) -> Result<PackResult, PackError> {
-    // 1. Check manifest exists
-    let manifest_path = dir.join(MCPB_MANIFEST_FILE);
-    if !manifest_path.exists() {
-        return Err(PackError::ManifestNotFound(dir.to_path_buf()));
-    }
+    // 1. Locate manifest
+    let manifest_path = resolve_manifest_path(dir, options.manifest.as_deref())?;
+    let manifest_content = std::fs::read_to_string(&manifest_path)?;
 
     // 2. Validate first (unless skipped)
     if options.validate {
-        let validation = validate_manifest(dir);
+        let validation = validate_manifest_content(dir, &manifest_content);
         if !validation.is_valid() {
             return Err(PackError::ValidationFailed(validation));
         }
     }
 
-    // 3. Read and potentially modify manifest for platform
-    let manifest_content = std::fs::read_to_string(&manifest_path)?;
-    let mut manifest_json: serde_json::Value = serde_json::from_str(&manifest_content)?;
+    // 3. Potentially modify manifest for platform
+    let mut manifest_json: serde_json::Value = from_str_relaxed(&manifest_content)?;
 
     // Modify manifest to contain only the specific platform's mcp_config
     if let Some(platform_key) = platform {
@@ -435,12 +553,14 @@ pub fn pack_bundle_for_platform(
         .clone()
         .unwrap_or_else(|| dir.join(&output_filename));
 
+    tracing::debug!("Packing {} into {}", dir.display(), output_path.display());
+
     // 5. Build ignore matcher
-    let ignore_matcher = build_ignore_matcher(dir)?;
+    let ignore_matcher = build_pack_ignore_matcher(dir, &options.exclude, &options.include)?;
 
     // 6. Get platform-specific binary paths for filtering
     let (all_binary_paths, target_binary_path) = if platform.is_some() {
-        let manifest_for_paths = serde_json::from_str::<serde_json::Value>(&manifest_content)?;
+        let manifest_for_paths = from_str_relaxed::<serde_json::Value>(&manifest_content)?;
         let all_paths = get_all_platform_binary_paths(&manifest_for_paths);
         let target_path = platform.and_then(|p| get_platform_binary_path(&manifest_for_paths, p));
         (all_paths, target_path)
@@ -452,11 +572,9 @@ pub fn pack_bundle_for_platform(
     let mut entries_to_add: Vec<(PathBuf, String, bool)> = Vec::new();
     let mut ignored_files = Vec::new();
 
-    for entry in WalkDir::new(dir)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| !is_builtin_ignored(e.path(), dir))
-    {
+    let mut skipped_symlinks = Vec::new();
+
+    for entry in walk_pack_dir(dir, options.follow_symlinks, &mut skipped_symlinks)? {
         let entry = entry?;
         let path = entry.path();
 
@@ -466,14 +584,24 @@ pub fn pack_bundle_for_platform(
 
         let relative_path = path.strip_prefix(dir)?;
         let path_str = relative_path.to_string_lossy().to_string();
+        if !is_enclosed(relative_path) {
+            return Err(PackError::UnsafePath(path_str));
+        }
         let is_dir = entry.file_type().is_dir();
 
-        if ignore_matcher
-            .matched_path_or_any_parents(relative_path, is_dir)
-            .is_ignore()
-        {
+        let matched = ignore_matcher.matched_path_or_any_parents(relative_path, is_dir);
+        if matched.is_ignore() {
+            tracing::trace!("Ignoring {}", path_str);
             if options.verbose {
-                ignored_files.push(path_str);
+                ignored_files.push(describe_ignored(path_str, matched.inner().copied()));
+            }
+            continue;
+        }
+
+        // A generated listing replaces any checksums file in the source
+        if options.emit_checksums && path_str == CHECKSUMS_FILE {
+            if options.verbose {
+                ignored_files.push(format!("{} (replaced by generated checksums)", path_str));
             }
             continue;
         }
@@ -496,6 +624,7 @@ pub fn pack_bundle_for_platform(
 
         entries_to_add.push((path.to_path_buf(), path_str, is_dir));
     }
+    place_manifest_entry(&mut entries_to_add, dir, &manifest_path);
 
     // Count only files (not directories)
     let total_files = entries_to_add
@@ -503,10 +632,13 @@ pub fn pack_bundle_for_platform(
         .filter(|(_, _, is_dir)| !is_dir)
         .count();
 
-    // Emit started event
-    if let Some(ref cb) = options.on_progress {
-        cb(PackProgress::Started { total_files });
-    }
+    let mut progress = PackProgress {
+        stage: PackStage::Started,
+        files_done: 0,
+        total_files,
+        bytes_done: 0,
+    };
+    report_progress(options, &progress);
 
     // 8. Create zip archive
     let file = File::create(&output_path)?;
@@ -518,6 +650,7 @@ pub fn pack_bundle_for_platform(
 
     let mut file_count = 0;
     let mut total_size = 0u64;
+    let mut checksums = Vec::new();
 
     // 9. Add files to archive with progress
     for (path, path_str, is_dir) in entries_to_add {
@@ -556,29 +689,40 @@ pub fn pack_bundle_for_platform(
             total_size += contents.len() as u64;
             file_count += 1;
 
+            tracing::trace!("Adding {} ({} bytes)", path_str, contents.len());
             zip.start_file(&path_str, file_options)?;
             zip.write_all(&contents)?;
 
-            // Emit progress
-            if let Some(ref cb) = options.on_progress {
-                cb(PackProgress::FileAdded {
-                    path: path_str,
-                    current: file_count,
-                });
+            if options.emit_checksums {
+                checksums.push((path_str.clone(), compute_sha256(&contents)));
             }
+
+            progress.stage = PackStage::FileAdded(path_str);
+            progress.files_done = file_count;
+            progress.bytes_done = total_size;
+            report_progress(options, &progress);
         }
     }
 
+    if options.emit_checksums {
+        zip.start_file(CHECKSUMS_FILE, zip_options)?;
+        zip.write_all(render_checksums(&mut checksums).as_bytes())?;
+    }
+
     zip.finish()?;
 
-    // Emit finished event
-    if let Some(ref cb) = options.on_progress {
-        cb(PackProgress::Finished);
-    }
+    progress.stage = PackStage::Finished;
+    report_progress(options, &progress);
 
     let compressed_size = std::fs::metadata(&output_path)?.len();
-    let bundle_bytes = std::fs::read(&output_path)?;
-    let checksum = compute_sha256(&bundle_bytes);
+    let checksum = compute_file_sha256(&output_path)?;
+    tracing::debug!(
+        "Packed {} files ({} bytes, {} compressed), sha256 {}",
+        file_count,
+        total_size,
+        compressed_size,
+        checksum
+    );
 
     // Extract icons if requested (for registry upload)
     let icons = if options.extract_icon {
@@ -593,6 +737,7 @@ pub fn pack_bundle_for_platform(
         total_size,
         compressed_size,
         ignored_files,
+        skipped_symlinks,
         extension: ext.to_string(),
         checksum,
         icons,
@@ -601,19 +746,21 @@ pub fn pack_bundle_for_platform(
 
 /// Extract the binary path for a specific platform from the manifest.
 /// Returns the path relative to the bundle root (e.g., "dist/system-darwin-arm64").
-///
-/// Resolution order per mcpbx.md:
-/// 1. _meta["store.tool.mcpb"].mcp_config.platform_overrides["{os}-{arch}"] (exact match)
-/// 2. server.mcp_config.platform_overrides["{os}"] (os-only fallback)
-/// 3. server.mcp_config.command (base config)
 fn get_platform_binary_path(manifest: &serde_json::Value, platform: &str) -> Option<String> {
-    // Helper to extract path from command string (removes ${__dirname}/ prefix)
-    fn extract_path_from_command(command: &str) -> String {
+    get_platform_command(manifest, platform).map(|command| {
         command
             .replace("${__dirname}/", "")
             .replace("${__dirname}", "")
-    }
+    })
+}
 
+/// Resolve the raw `command` a platform bundle will run.
+///
+/// Resolution order per mcpbx.md:
+/// 1. _meta["store.tool.mcpb"].mcp_config.platform_overrides["{os}-{arch}"] (exact match)
+/// 2. server.mcp_config.platform_overrides["{os}"] (os-only fallback)
+/// 3. server.mcp_config.command (base config)
+fn get_platform_command<'a>(manifest: &'a serde_json::Value, platform: &str) -> Option<&'a str> {
     // 1. Check _meta["store.tool.mcpb"].mcp_config.platform_overrides[platform]
     if let Some(command) = manifest
         .get("_meta")
@@ -624,7 +771,7 @@ fn get_platform_binary_path(manifest: &serde_json::Value, platform: &str) -> Opt
         .and_then(|p| p.get("command"))
         .and_then(|c| c.as_str())
     {
-        return Some(extract_path_from_command(command));
+        return Some(command);
     }
 
     // 2. Check server.mcp_config.platform_overrides[os] (os-only fallback)
@@ -637,20 +784,37 @@ fn get_platform_binary_path(manifest: &serde_json::Value, platform: &str) -> Opt
         .and_then(|p| p.get("command"))
         .and_then(|c| c.as_str())
     {
-        return Some(extract_path_from_command(command));
+        return Some(command);
     }
 
     // 3. Fall back to base command
-    if let Some(command) = manifest
+    manifest
         .get("server")
         .and_then(|s| s.get("mcp_config"))
         .and_then(|c| c.get("command"))
         .and_then(|c| c.as_str())
-    {
-        return Some(extract_path_from_command(command));
-    }
+}
 
-    None
+/// Find platforms whose bundled binary is missing from `dir`.
+///
+/// Only commands under `${__dirname}` are checked; commands run from the host
+/// (e.g. `node`) aren't part of the bundle. Returns `(platform, path)` pairs.
+pub fn find_missing_platform_binaries(
+    dir: &Path,
+    manifest: &serde_json::Value,
+    platforms: &[String],
+) -> Vec<(String, String)> {
+    platforms
+        .iter()
+        .filter(|platform| {
+            get_platform_command(manifest, platform)
+                .is_some_and(|command| command.contains("${__dirname}"))
+        })
+        .filter_map(|platform| {
+            let path = get_platform_binary_path(manifest, platform)?;
+            (!dir.join(&path).is_file()).then(|| (platform.clone(), path))
+        })
+        .collect()
 }
 
 /// Get all binary paths from platform overrides (to know what to exclude).
@@ -827,17 +991,16 @@ pub fn collect_bundle_files(
     dir: &Path,
     options: &CollectOptions,
 ) -> Result<CollectResult, PackError> {
-    let ignore_matcher = build_ignore_matcher(dir)?;
+    let ignore_matcher = build_pack_ignore_matcher(dir, &options.exclude, &options.include)?;
+    let manifest_path = find_manifest_path(dir);
 
     let mut entries = Vec::new();
     let mut ignored_files = Vec::new();
     let mut total_size = 0u64;
 
-    for entry in WalkDir::new(dir)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| !is_builtin_ignored(e.path(), dir))
-    {
+    let mut skipped_symlinks = Vec::new();
+
+    for entry in walk_pack_dir(dir, options.follow_symlinks, &mut skipped_symlinks)? {
         let entry = entry?;
         let path = entry.path();
 
@@ -848,6 +1011,9 @@ pub fn collect_bundle_files(
 
         let relative_path = path.strip_prefix(dir)?;
         let path_str = relative_path.to_string_lossy().to_string();
+        if !is_enclosed(relative_path) {
+            return Err(PackError::UnsafePath(path_str));
+        }
         let is_dir = entry.file_type().is_dir();
 
         // Check if should be ignored by .mcpbignore patterns
@@ -875,10 +1041,18 @@ pub fn collect_bundle_files(
             let mut contents = Vec::new();
             file.read_to_end(&mut contents)?;
 
+            // The manifest is always bundled as a strict `manifest.json`
+            let relative_path = if path == manifest_path {
+                contents = canonical_manifest_bytes(&contents)?;
+                MCPB_MANIFEST_FILE.to_string()
+            } else {
+                path_str
+            };
+
             total_size += contents.len() as u64;
 
             entries.push(BundleEntry {
-                relative_path: path_str,
+                relative_path,
                 is_dir: false,
                 contents,
                 modified,
@@ -886,6 +1060,14 @@ pub fn collect_bundle_files(
         }
     }
 
+    if options.track_ignored {
+        ignored_files.extend(
+            skipped_symlinks
+                .into_iter()
+                .map(|path| format!("{} (symlink outside bundle)", path)),
+        );
+    }
+
     Ok(CollectResult {
         entries,
         ignored_files,
@@ -899,7 +1081,7 @@ pub fn collect_bundle_files(
 /// but outputs tar.gz bytes suitable for registry upload.
 pub fn create_tool_bundle(dir: &Path) -> Result<Vec<u8>, PackError> {
     // 1. Check manifest exists
-    let manifest_path = dir.join(MCPB_MANIFEST_FILE);
+    let manifest_path = find_manifest_path(dir);
     if !manifest_path.exists() {
         return Err(PackError::ManifestNotFound(dir.to_path_buf()));
     }
@@ -946,8 +1128,75 @@ pub fn create_tool_bundle(dir: &Path) -> Result<Vec<u8>, PackError> {
     Ok(compressed)
 }
 
+/// Resolve the manifest to pack, checking that an override stays inside `dir`.
+///
+/// Returns `dir/manifest.json` (or `dir/manifest.json5`) without an override, otherwise
+/// the override joined onto `dir` so it compares equal to the path found while walking
+/// the directory.
+pub fn resolve_manifest_path(dir: &Path, manifest: Option<&Path>) -> Result<PathBuf, PackError> {
+    let Some(manifest) = manifest else {
+        let path = find_manifest_path(dir);
+        if !path.exists() {
+            return Err(PackError::ManifestNotFound(dir.to_path_buf()));
+        }
+        return Ok(path);
+    };
+
+    let path = dir.join(manifest);
+    if !path.is_file() {
+        return Err(PackError::ManifestPathNotFound(path));
+    }
+
+    let canonical_dir = dir.canonicalize()?;
+    let canonical = path.canonicalize()?;
+    match canonical.strip_prefix(&canonical_dir) {
+        Ok(relative) => Ok(dir.join(relative)),
+        Err(_) => Err(PackError::ManifestOutsideDir {
+            manifest: canonical,
+            dir: canonical_dir,
+        }),
+    }
+}
+
+/// Bundle the resolved manifest as the root `manifest.json`.
+///
+/// With an override, it replaces both the directory's own `manifest.json` and its copy at
+/// the override location.
+fn place_manifest_entry(
+    entries: &mut Vec<(PathBuf, String, bool)>,
+    dir: &Path,
+    manifest_path: &Path,
+) {
+    if manifest_path == dir.join(MCPB_MANIFEST_FILE) {
+        return;
+    }
+
+    entries.retain(|(path, path_str, _)| path_str != MCPB_MANIFEST_FILE && path != manifest_path);
+    entries.insert(
+        0,
+        (
+            manifest_path.to_path_buf(),
+            MCPB_MANIFEST_FILE.to_string(),
+            false,
+        ),
+    );
+}
+
 /// Build gitignore-style matcher from default patterns and .mcpbignore.
-fn build_ignore_matcher(dir: &Path) -> Result<Gitignore, PackError> {
+pub(crate) fn build_ignore_matcher(dir: &Path) -> Result<Gitignore, PackError> {
+    build_pack_ignore_matcher(dir, &[], &[])
+}
+
+/// Build the ignore matcher with `--exclude`/`--include` rules layered after .mcpbignore.
+///
+/// Later rules win, so `include` re-includes paths matched by `.mcpbignore` or `exclude`.
+/// As with gitignore, a file cannot be re-included if one of its parent directories is
+/// excluded.
+fn build_pack_ignore_matcher(
+    dir: &Path,
+    exclude: &[String],
+    include: &[String],
+) -> Result<Gitignore, PackError> {
     let mut builder = GitignoreBuilder::new(dir);
 
     // Add default patterns
@@ -961,11 +1210,72 @@ fn build_ignore_matcher(dir: &Path) -> Result<Gitignore, PackError> {
         builder.add(&mcpbignore);
     }
 
+    for pattern in exclude {
+        builder.add_line(Some(PathBuf::from(EXCLUDE_RULE_SOURCE)), pattern)?;
+    }
+    for pattern in include {
+        builder.add_line(
+            Some(PathBuf::from(INCLUDE_RULE_SOURCE)),
+            &format!("!{}", pattern),
+        )?;
+    }
+
     Ok(builder.build()?)
 }
 
+/// Label an ignored path, noting the `--exclude` rule that matched it.
+fn describe_ignored(path_str: String, glob: Option<&Glob>) -> String {
+    match glob {
+        Some(glob) if glob.from() == Some(Path::new(EXCLUDE_RULE_SOURCE)) => {
+            format!("{} (--exclude {})", path_str, glob.original())
+        }
+        _ => path_str,
+    }
+}
+
+/// Walk a directory for packing, pruning built-in ignores.
+///
+/// Symlinks are dereferenced so in-bundle links extract as plain files. Unless
+/// `follow_symlinks` is set, links resolving outside `dir` (or dangling) are pruned and
+/// their relative paths recorded in `skipped`, so nothing from elsewhere on disk leaks in.
+fn walk_pack_dir<'a>(
+    dir: &'a Path,
+    follow_symlinks: bool,
+    skipped: &'a mut Vec<String>,
+) -> Result<impl Iterator<Item = walkdir::Result<DirEntry>> + 'a, PackError> {
+    let root = dir.canonicalize()?;
+
+    Ok(WalkDir::new(dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(move |e| {
+            if is_builtin_ignored(e.path(), dir) {
+                return false;
+            }
+            if follow_symlinks || !e.path_is_symlink() {
+                return true;
+            }
+
+            let inside = e
+                .path()
+                .canonicalize()
+                .is_ok_and(|target| target.starts_with(&root));
+            if !inside {
+                let relative = e.path().strip_prefix(dir).unwrap_or(e.path());
+                skipped.push(relative.to_string_lossy().to_string());
+            }
+            inside
+        }))
+}
+
+/// Whether a relative path stays inside the bundle root (the write-side counterpart of
+/// `enclosed_name` on extraction).
+fn is_enclosed(path: &Path) -> bool {
+    path.components().all(|c| matches!(c, Component::Normal(_)))
+}
+
 /// Check if a path matches builtin ignore patterns (cannot be overridden).
-fn is_builtin_ignored(path: &Path, base: &Path) -> bool {
+pub(crate) fn is_builtin_ignored(path: &Path, base: &Path) -> bool {
     let relative = match path.strip_prefix(base) {
         Ok(r) => r,
         Err(_) => return false,
@@ -990,6 +1300,22 @@ fn is_builtin_ignored(path: &Path, base: &Path) -> bool {
     false
 }
 
+/// Render a `sha256sum`-style listing, sorted by path so output is deterministic.
+fn render_checksums(checksums: &mut [(String, String)]) -> String {
+    checksums.sort();
+    checksums
+        .iter()
+        .map(|(path, hash)| format!("{}  {}\n", hash, path))
+        .collect()
+}
+
+/// Send a progress snapshot to the caller's callback, if any.
+fn report_progress(options: &PackOptions, progress: &PackProgress) {
+    if let Some(cb) = &options.on_progress {
+        cb(progress.clone());
+    }
+}
+
 /// Convert SystemTime to zip DateTime, preserving file modification times.
 fn system_time_to_zip_datetime(time: std::time::SystemTime) -> Option<ZipDateTime> {
     let duration = time.duration_since(std::time::UNIX_EPOCH).ok()?;
@@ -1036,6 +1362,53 @@ fn days_to_ymd(days: i64) -> (i32, u32, u32) {
     (y as i32, m, d)
 }
 
+/// Estimate the deflated size of a file's contents, as `pack_bundle` would store it.
+///
+/// Files over `ESTIMATE_SAMPLE_THRESHOLD` are estimated from the compression ratio of a
+/// leading sample, so the result for those is approximate.
+pub fn estimate_compressed_size(contents: &[u8]) -> u64 {
+    let sample = if contents.len() > ESTIMATE_SAMPLE_THRESHOLD {
+        &contents[..ESTIMATE_SAMPLE_SIZE]
+    } else {
+        contents
+    };
+
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    let compressed = encoder
+        .write_all(sample)
+        .and_then(|_| encoder.finish())
+        .map(|out| out.len() as u64)
+        .unwrap_or(sample.len() as u64);
+
+    if sample.len() == contents.len() {
+        compressed
+    } else {
+        compressed * contents.len() as u64 / sample.len() as u64
+    }
+}
+
+/// Estimate the size of a zip bundle built from collected entries.
+///
+/// Adds zip header overhead for every entry to the deflated file sizes. Extra fields
+/// and compression details mean the packed bundle can differ by a few bytes per entry.
+pub fn estimate_bundle_size(entries: &[BundleEntry]) -> u64 {
+    let files: u64 = entries
+        .iter()
+        .map(|entry| {
+            // Names are stored in both headers; directories get a trailing slash
+            let name_len = entry.relative_path.len() as u64 + u64::from(entry.is_dir);
+            let data = if entry.is_dir {
+                0
+            } else {
+                estimate_compressed_size(&entry.contents)
+            };
+            ZIP_ENTRY_OVERHEAD + 2 * name_len + data
+        })
+        .sum();
+
+    files + ZIP_END_OVERHEAD
+}
+
 /// Compute SHA-256 checksum of data and return as hex string.
 pub fn compute_sha256(data: &[u8]) -> String {
     let mut hasher = Sha256::new();
@@ -1043,6 +1416,14 @@ pub fn compute_sha256(data: &[u8]) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Compute SHA-256 checksum of a file without reading it into memory.
+pub fn compute_file_sha256(path: &Path) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 /// Extract all icons from manifest.
 ///
 /// Processes both the legacy `icon` field and the `icons` array.
@@ -1132,6 +1513,12 @@ pub fn read_manifest_from_bundle(
     Ok((manifest, manifest_bytes))
 }
 
+/// Check if a path names a bundle file (`.mcpb` or `.mcpbx`, case-insensitive).
+pub fn is_bundle_file(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    lower.ends_with(&format!(".{}", MCPB_EXT)) || lower.ends_with(&format!(".{}", MCPBX_EXT))
+}
+
 /// Extract icons from an MCPB bundle (ZIP file).
 ///
 /// Reads the manifest from the bundle and extracts all referenced icons.
@@ -1329,6 +1716,19 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_compute_file_sha256_matches_in_memory() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bundle.mcpb");
+        let content = vec![7u8; 200 * 1024];
+        std::fs::write(&path, &content).unwrap();
+
+        assert_eq!(
+            compute_file_sha256(&path).unwrap(),
+            compute_sha256(&content)
+        );
+    }
+
     #[test]
     fn test_is_builtin_ignored() {
         let dir = TempDir::new().unwrap();
@@ -1493,4 +1893,328 @@ mod tests {
         // Cleanup
         std::fs::remove_file(&result.output_path).ok();
     }
+
+    #[test]
+    fn test_pack_reports_progress() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("index.js"), "// entry").unwrap();
+
+        let manifest = r#"{
+            "manifest_version": "0.3",
+            "name": "test-pack-progress",
+            "version": "1.0.0",
+            "server": { "type": "node" }
+        }"#;
+        std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let options = PackOptions {
+            validate: false,
+            on_progress: Some(Arc::new(move |p: PackProgress| {
+                sink.lock().unwrap().push(p)
+            })),
+            ..Default::default()
+        };
+
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        let events = events.lock().unwrap();
+
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].stage, PackStage::Started);
+        assert_eq!(events[0].files_done, 0);
+        assert!(matches!(events[1].stage, PackStage::FileAdded(_)));
+        assert_eq!(events[1].files_done, 1);
+
+        let last = events.last().unwrap();
+        assert_eq!(last.stage, PackStage::Finished);
+        assert_eq!(last.files_done, 2);
+        assert_eq!(last.total_files, 2);
+        assert_eq!(last.bytes_done, result.total_size);
+
+        std::fs::remove_file(&result.output_path).ok();
+    }
+
+    #[test]
+    fn test_pack_emit_checksums() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("index.js"), "// entry").unwrap();
+        std::fs::write(dir.path().join(CHECKSUMS_FILE), "stale").unwrap();
+
+        let manifest = r#"{
+            "manifest_version": "0.3",
+            "name": "test-pack-checksums",
+            "version": "1.0.0",
+            "server": { "type": "node" }
+        }"#;
+        std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+
+        let options = PackOptions {
+            validate: false,
+            emit_checksums: true,
+            ..Default::default()
+        };
+
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        let mut archive = zip::ZipArchive::new(File::open(&result.output_path).unwrap()).unwrap();
+        let mut listing = String::new();
+        archive
+            .by_name(CHECKSUMS_FILE)
+            .unwrap()
+            .read_to_string(&mut listing)
+            .unwrap();
+
+        let expected = format!(
+            "{}  index.js\n{}  manifest.json\n",
+            compute_sha256(b"// entry"),
+            compute_sha256(manifest.as_bytes())
+        );
+        assert_eq!(listing, expected);
+
+        std::fs::remove_file(&result.output_path).ok();
+    }
+
+    #[test]
+    fn test_pack_ignore_overrides() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".mcpbignore"), "*.log\n").unwrap();
+
+        let exclude = vec!["fixtures/".to_string(), "*.tmp".to_string()];
+        let include = vec!["keep.log".to_string(), "keep.tmp".to_string()];
+        let matcher = build_pack_ignore_matcher(dir.path(), &exclude, &include).unwrap();
+        let ignored = |path: &str, is_dir| {
+            matcher
+                .matched_path_or_any_parents(Path::new(path), is_dir)
+                .is_ignore()
+        };
+
+        assert!(ignored("debug.log", false));
+        assert!(!ignored("keep.log", false));
+        assert!(ignored("fixtures", true));
+        assert!(ignored("fixtures/data.json", false));
+        assert!(ignored("scratch.tmp", false));
+        assert!(!ignored("keep.tmp", false));
+
+        let matched = matcher.matched_path_or_any_parents(Path::new("scratch.tmp"), false);
+        assert_eq!(
+            describe_ignored("scratch.tmp".to_string(), matched.inner().copied()),
+            "scratch.tmp (--exclude *.tmp)"
+        );
+    }
+
+    #[test]
+    fn test_pack_manifest_override() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("packages/tool")).unwrap();
+        std::fs::create_dir_all(dir.path().join("server")).unwrap();
+        std::fs::write(dir.path().join("server/index.js"), "// entry").unwrap();
+
+        let manifest = r#"{
+            "manifest_version": "0.3",
+            "name": "test-pack-manifest-override",
+            "version": "1.0.0",
+            "description": "Test tool",
+            "author": { "name": "Test" },
+            "server": {
+                "type": "node",
+                "entry_point": "server/index.js",
+                "mcp_config": { "command": "node", "args": [] }
+            }
+        }"#;
+        std::fs::write(dir.path().join("packages/tool/manifest.json"), manifest).unwrap();
+
+        let options = PackOptions {
+            manifest: Some(PathBuf::from("packages/tool/manifest.json")),
+            ..Default::default()
+        };
+
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        assert_eq!(result.file_count, 2); // manifest.json + server/index.js
+
+        let mut archive = zip::ZipArchive::new(File::open(&result.output_path).unwrap()).unwrap();
+        let mut bundled = String::new();
+        archive
+            .by_name(MCPB_MANIFEST_FILE)
+            .unwrap()
+            .read_to_string(&mut bundled)
+            .unwrap();
+        assert_eq!(bundled, manifest);
+        assert!(archive.by_name("packages/tool/manifest.json").is_err());
+
+        std::fs::remove_file(&result.output_path).ok();
+    }
+
+    #[test]
+    fn test_pack_manifest_outside_dir() {
+        let outer = TempDir::new().unwrap();
+        let dir = outer.path().join("project");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(outer.path().join("manifest.json"), "{}").unwrap();
+
+        let options = PackOptions {
+            manifest: Some(PathBuf::from("../manifest.json")),
+            ..Default::default()
+        };
+
+        let result = pack_bundle(&dir, &options);
+        assert!(matches!(result, Err(PackError::ManifestOutsideDir { .. })));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pack_skips_escaping_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let outside = TempDir::new().unwrap();
+        std::fs::write(outside.path().join("secret.txt"), "secret").unwrap();
+
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("index.js"), "// entry").unwrap();
+        symlink(
+            outside.path().join("secret.txt"),
+            dir.path().join("secret.txt"),
+        )
+        .unwrap();
+        symlink(outside.path(), dir.path().join("linked")).unwrap();
+        symlink(dir.path().join("index.js"), dir.path().join("alias.js")).unwrap();
+
+        let manifest = r#"{
+            "manifest_version": "0.3",
+            "name": "test-pack-symlinks",
+            "version": "1.0.0",
+            "server": { "type": "node" }
+        }"#;
+        std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+
+        let options = PackOptions {
+            validate: false,
+            ..Default::default()
+        };
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        let mut skipped = result.skipped_symlinks.clone();
+        skipped.sort();
+        assert_eq!(skipped, vec!["linked", "secret.txt"]);
+        assert_eq!(result.file_count, 3); // manifest.json, index.js, alias.js
+        std::fs::remove_file(&result.output_path).ok();
+
+        let options = PackOptions {
+            validate: false,
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        assert!(result.skipped_symlinks.is_empty());
+        assert_eq!(result.file_count, 5); // + secret.txt, linked/secret.txt
+        std::fs::remove_file(&result.output_path).ok();
+    }
+
+    #[test]
+    fn test_estimate_compressed_size() {
+        let repetitive = "hello world\n".repeat(1000);
+        let estimate = estimate_compressed_size(repetitive.as_bytes());
+        assert!(estimate > 0);
+        assert!(estimate < repetitive.len() as u64 / 10);
+
+        // Large files are extrapolated from a sample of the same content
+        let large = vec![b'a'; ESTIMATE_SAMPLE_THRESHOLD + 1];
+        let sampled = estimate_compressed_size(&large);
+        assert!(sampled > 0);
+        assert!(sampled < large.len() as u64 / 100);
+    }
+
+    #[test]
+    fn test_estimate_bundle_size_close_to_packed() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("manifest.json"),
+            r#"{"manifest_version": "0.3", "name": "estimate", "version": "1.0.0", "server": {"type": "node", "entry_point": "server/index.js"}}"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("server")).unwrap();
+        std::fs::write(
+            dir.path().join("server/index.js"),
+            "console.log('estimate');\n".repeat(200),
+        )
+        .unwrap();
+
+        let collected = collect_bundle_files(dir.path(), &CollectOptions::default()).unwrap();
+        let estimate = estimate_bundle_size(&collected.entries);
+
+        let options = PackOptions {
+            validate: false,
+            ..Default::default()
+        };
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        std::fs::remove_file(&result.output_path).ok();
+
+        let diff = estimate.abs_diff(result.compressed_size);
+        assert!(
+            diff * 10 <= result.compressed_size,
+            "estimate {} too far from packed {}",
+            estimate,
+            result.compressed_size
+        );
+    }
+
+    #[test]
+    fn test_pack_relaxed_manifest_json5() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("manifest.json5"),
+            "{\n  // Hand-written\n  \"manifest_version\": \"0.3\",\n  \"name\": \"relaxed\",\n  \"version\": \"1.0.0\",\n  \"server\": {\"type\": \"node\", \"entry_point\": \"index.js\",},\n}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("index.js"), "").unwrap();
+
+        let options = PackOptions {
+            validate: false,
+            ..Default::default()
+        };
+        let result = pack_bundle(dir.path(), &options).unwrap();
+        let bytes = std::fs::read(&result.output_path).unwrap();
+        std::fs::remove_file(&result.output_path).ok();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        assert!(archive.by_name("manifest.json5").is_err());
+        let mut manifest = String::new();
+        archive
+            .by_name(MCPB_MANIFEST_FILE)
+            .unwrap()
+            .read_to_string(&mut manifest)
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+        assert_eq!(value["name"], "relaxed");
+    }
+
+    #[test]
+    fn test_find_missing_platform_binaries() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("dist")).unwrap();
+        std::fs::write(dir.path().join("dist/tool-linux-x64"), "").unwrap();
+
+        let manifest = serde_json::json!({
+            "server": {"mcp_config": {"command": "node"}},
+            "_meta": {"store.tool.mcpb": {"mcp_config": {"platform_overrides": {
+                "linux-x64": {"command": "${__dirname}/dist/tool-linux-x64"},
+                "darwin-arm64": {"command": "${__dirname}/dist/tool-darwn-arm64"}
+            }}}}
+        });
+        let platforms = ["linux-x64", "darwin-arm64", "win32-x64"].map(String::from);
+
+        assert_eq!(
+            find_missing_platform_binaries(dir.path(), &manifest, &platforms),
+            [(
+                "darwin-arm64".to_string(),
+                "dist/tool-darwn-arm64".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_is_enclosed() {
+        assert!(is_enclosed(Path::new("server/index.js")));
+        assert!(!is_enclosed(Path::new("../secret")));
+        assert!(!is_enclosed(Path::new("/etc/passwd")));
+        assert!(!is_enclosed(Path::new("./index.js")));
+    }
 }
@@ -0,0 +1,280 @@
+//! Machine-readable progress events.
+//!
+//! With `--progress json`, long-running commands (`install`, `download`, `publish`)
+//! emit one JSON object per line on stderr as they work, and their progress bars are
+//! hidden so the two don't interleave. Stdout is left for the command's regular output.
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget};
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Whether `--progress json` is active.
+static PROGRESS_JSON: AtomicBool = AtomicBool::new(false);
+
+/// How often transfer progress is reported while a bar is running.
+const TRANSFER_REPORT_INTERVAL: Duration = Duration::from_millis(500);
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A progress event, serialized as `{"event": "<kind>", ...}`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    /// Looking up a tool reference.
+    Resolving { name: String },
+
+    /// Bytes downloaded so far (`total` is 0 when unknown).
+    Downloading {
+        name: String,
+        bytes: u64,
+        total: u64,
+    },
+
+    /// Unpacking a bundle into the tools directory.
+    Extracting { name: String },
+
+    /// A tool was installed.
+    Installed {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+    },
+
+    /// A bundle was downloaded to disk.
+    Downloaded { name: String, path: String },
+
+    /// Building the bundle(s) to publish.
+    Packing { name: String },
+
+    /// Bytes uploaded so far for one file.
+    Uploading {
+        name: String,
+        bytes: u64,
+        total: u64,
+    },
+
+    /// A version was published.
+    Published { name: String, version: String },
+
+    /// An item failed.
+    Failed { name: String, error: String },
+}
+
+/// Which transfer event [`track_transfer`] reports.
+#[derive(Debug, Clone, Copy)]
+pub enum Transfer {
+    /// Report as [`ProgressEvent::Downloading`].
+    Download,
+
+    /// Report as [`ProgressEvent::Uploading`].
+    Upload,
+}
+
+/// Handle for a bar tracked by [`track_transfer`].
+///
+/// Call [`finish`](Self::finish) once the transfer is done. Dropping the handle instead
+/// stops the reports without a final event.
+pub struct TransferTracker {
+    pb: ProgressBar,
+    name: String,
+    transfer: Transfer,
+    state: Arc<Mutex<TrackerState>>,
+}
+
+/// Reporting state shared between a [`TransferTracker`] and its poller.
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// Last position reported.
+    last: Option<u64>,
+
+    /// Set once the tracker finished or was dropped; the poller exits when it sees it.
+    done: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl TransferTracker {
+    /// Report the bar's final position now and stop the periodic reports.
+    ///
+    /// Call this before emitting the next phase's events so they come out in order.
+    pub fn finish(self) {
+        report_transfer(&self.state, &self.pb, &self.name, self.transfer, true);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Enable or disable JSON progress events for the rest of the process.
+pub fn set_progress_json(enabled: bool) {
+    PROGRESS_JSON.store(enabled, Ordering::SeqCst);
+}
+
+/// Whether JSON progress events are enabled.
+pub fn is_progress_json() -> bool {
+    PROGRESS_JSON.load(Ordering::SeqCst)
+}
+
+/// Write an event to stderr if JSON progress is enabled.
+pub fn emit(event: ProgressEvent) {
+    if is_progress_json()
+        && let Ok(line) = serde_json::to_string(&event)
+    {
+        eprintln!("{}", line);
+    }
+}
+
+/// A progress bar that stays hidden in JSON progress mode.
+pub fn progress_bar(len: u64) -> ProgressBar {
+    if is_progress_json() {
+        ProgressBar::with_draw_target(Some(len), ProgressDrawTarget::hidden())
+    } else {
+        ProgressBar::new(len)
+    }
+}
+
+/// A multi-progress container that stays hidden in JSON progress mode.
+///
+/// Bars added to it inherit its draw target.
+pub fn multi_progress() -> MultiProgress {
+    if is_progress_json() {
+        MultiProgress::with_draw_target(ProgressDrawTarget::hidden())
+    } else {
+        MultiProgress::new()
+    }
+}
+
+/// Periodically report a bar's position as transfer events until the returned tracker
+/// is finished or dropped, or the bar itself is dropped.
+///
+/// Reports are only sent when JSON progress is enabled. Must be called within a Tokio
+/// runtime.
+#[must_use = "reports stop when the tracker is dropped"]
+pub fn track_transfer(pb: &ProgressBar, name: String, transfer: Transfer) -> TransferTracker {
+    let state = Arc::new(Mutex::new(TrackerState::default()));
+
+    if is_progress_json() {
+        let weak = pb.downgrade();
+        let state = Arc::clone(&state);
+        let name = name.clone();
+        tokio::spawn(async move {
+            loop {
+                let Some(pb) = weak.upgrade() else { break };
+                if !report_transfer(&state, &pb, &name, transfer, false) {
+                    break;
+                }
+                drop(pb);
+                tokio::time::sleep(TRANSFER_REPORT_INTERVAL).await;
+            }
+        });
+    }
+
+    TransferTracker {
+        pb: pb.clone(),
+        name,
+        transfer,
+        state,
+    }
+}
+
+/// Emit a transfer event if the bar moved since the last one, marking the tracker done
+/// when `last` is set. Returns `false` once the tracker is done, without emitting.
+///
+/// The state lock is held while emitting so reports can't be reordered.
+fn report_transfer(
+    state: &Mutex<TrackerState>,
+    pb: &ProgressBar,
+    name: &str,
+    transfer: Transfer,
+    last: bool,
+) -> bool {
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    if state.done {
+        return false;
+    }
+
+    let (bytes, total) = (pb.position(), pb.length().unwrap_or(0));
+    if state.last != Some(bytes) {
+        state.last = Some(bytes);
+        let name = name.to_string();
+        emit(match transfer {
+            Transfer::Download => ProgressEvent::Downloading { name, bytes, total },
+            Transfer::Upload => ProgressEvent::Uploading { name, bytes, total },
+        });
+    }
+    state.done = last;
+    true
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Drop for TransferTracker {
+    fn drop(&mut self) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).done = true;
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_shape() {
+        let event = ProgressEvent::Downloading {
+            name: "acme/bash".into(),
+            bytes: 10,
+            total: 100,
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({"event": "downloading", "name": "acme/bash", "bytes": 10, "total": 100})
+        );
+
+        let event = ProgressEvent::Installed {
+            name: "acme/bash".into(),
+            version: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"event":"installed","name":"acme/bash"}"#
+        );
+    }
+
+    #[test]
+    fn test_finished_tracker_stops_reports() {
+        let pb = ProgressBar::hidden();
+        pb.set_length(10);
+        pb.set_position(4);
+
+        let tracker = track_transfer(&pb, "acme/bash".into(), Transfer::Download);
+        let state = Arc::clone(&tracker.state);
+        tracker.finish();
+
+        assert_eq!(state.lock().unwrap().last, Some(4));
+        assert!(state.lock().unwrap().done);
+        assert!(!report_transfer(
+            &state,
+            &pb,
+            "acme/bash",
+            Transfer::Download,
+            false
+        ));
+    }
+}
@@ -34,6 +34,8 @@ pub struct McpbPromptResult {
     pub name: String,
     pub mode: InitMode,
     pub is_rust: bool,
+    /// Scaffold Node.js servers in TypeScript.
+    pub typescript: bool,
     pub description: Option<String>,
     pub license: Option<String>,
     pub author: Option<String>,
@@ -53,11 +55,29 @@ pub struct McpbPrefill {
     pub server_type: Option<McpbServerType>,
     pub transport: Option<McpbTransport>,
     pub package_manager: Option<PackageManager>,
+    pub typescript: bool,
     pub description: Option<String>,
     pub license: Option<String>,
     pub author: Option<String>,
 }
 
+/// Values detected from an existing project, offered as editable defaults.
+pub struct MigratePrefill {
+    pub name: Option<String>,
+    pub server_type: McpbServerType,
+    pub entry_point: Option<String>,
+    pub transport: McpbTransport,
+    pub package_manager: Option<PackageManager>,
+}
+
+/// Result from the interactive migration prompt.
+pub struct MigratePromptResult {
+    pub name: String,
+    pub entry_point: Option<String>,
+    pub transport: McpbTransport,
+    pub package_manager: Option<PackageManager>,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -103,7 +123,7 @@ pub fn init_theme() {
             // Restore cursor and terminal state
             let term = Term::stderr();
             let _ = term.show_cursor();
-            std::process::exit(130); // Standard exit code for Ctrl+C
+            std::process::exit(crate::error::EXIT_CANCELLED);
         });
     }
 }
@@ -187,6 +207,21 @@ fn prompt_transport(prefill: Option<McpbTransport>) -> ToolResult<McpbTransport>
     })
 }
 
+/// Prompt for the Node.js scaffold language unless TypeScript was requested.
+fn prompt_typescript(prefill: bool) -> ToolResult<bool> {
+    if prefill {
+        return Ok(true);
+    }
+
+    let lang: &str = map_cancelled(
+        select("Language")
+            .item("typescript", "TypeScript", "Compile src/ to dist/ with tsc")
+            .item("javascript", "JavaScript", "Run server/index.js directly")
+            .interact(),
+    )?;
+    Ok(lang == "typescript")
+}
+
 /// Run interactive prompts for MCPB package initialization.
 ///
 /// Skips prompts for any values already provided in the prefill struct.
@@ -380,6 +415,17 @@ pub fn prompt_init_mcpb(
             }
 
             (InitMode::Reference { transport }, false)
+        } else if let Some(pm) = prefill.package_manager {
+            // The package manager already decides the server type
+            let transport = prompt_transport(prefill.transport)?;
+            (
+                InitMode::Bundle {
+                    server_type: pm.server_type(),
+                    transport,
+                    package_manager: Some(pm),
+                },
+                false,
+            )
         } else {
             let server_type_str: &str = map_cancelled(
                 select("Server type")
@@ -409,6 +455,13 @@ pub fn prompt_init_mcpb(
         }
     };
 
+    // Language for Node.js bundles
+    let typescript = if mode.server_type() == Some(McpbServerType::Node) {
+        prompt_typescript(prefill.typescript)?
+    } else {
+        false
+    };
+
     // Description (optional) - skip if prefilled
     let description = if prefill.description.is_some() {
         prefill.description
@@ -446,6 +499,7 @@ pub fn prompt_init_mcpb(
         name,
         mode,
         is_rust,
+        typescript,
         description,
         license,
         author,
@@ -455,6 +509,115 @@ pub fn prompt_init_mcpb(
     })
 }
 
+/// Run interactive prompts to review and correct detected migration values.
+///
+/// Every prompt is pre-filled with the detected value so accepting the defaults
+/// produces the same manifest as a non-interactive migration.
+pub fn prompt_migrate(prefill: MigratePrefill) -> ToolResult<MigratePromptResult> {
+    init_theme();
+
+    map_cancelled(intro("tool init"))?;
+
+    let default_name = prefill.name.unwrap_or_default();
+    let name: String = map_cancelled(
+        input("Package name")
+            .placeholder("my-tool")
+            .default_input(&default_name)
+            .validate(|input: &String| {
+                if input.is_empty() {
+                    Err("Package name is required")
+                } else if !is_valid_package_name(input) {
+                    Err("Must be 3-64 lowercase letters, numbers, and hyphens, starting with a letter")
+                } else {
+                    Ok(())
+                }
+            })
+            .interact(),
+    )?;
+
+    let entry: String = map_cancelled(
+        input("Entry point")
+            .placeholder("path relative to the project root")
+            .default_input(prefill.entry_point.as_deref().unwrap_or(""))
+            .required(prefill.entry_point.is_some())
+            .interact(),
+    )?;
+    let entry_point = Some(entry.trim().to_string()).filter(|e| !e.is_empty());
+
+    let transport: McpbTransport = map_cancelled(
+        select("Transport")
+            .item(
+                McpbTransport::Stdio,
+                "Stdio",
+                "Communicate via stdin/stdout",
+            )
+            .item(
+                McpbTransport::Http,
+                "HTTP",
+                "Run HTTP server, connect via HTTP [mcpbx]",
+            )
+            .initial_value(prefill.transport)
+            .interact(),
+    )?;
+
+    let package_manager = match prefill.package_manager {
+        Some(PackageManager::Node(detected)) => {
+            let pm: NodePackageManager = map_cancelled(
+                select("Package manager")
+                    .item(NodePackageManager::Npm, "npm", "Node Package Manager")
+                    .item(
+                        NodePackageManager::Pnpm,
+                        "pnpm",
+                        "Fast, disk space efficient",
+                    )
+                    .item(
+                        NodePackageManager::Bun,
+                        "bun",
+                        "All-in-one JavaScript runtime",
+                    )
+                    .item(NodePackageManager::Yarn, "yarn", "Yarn package manager")
+                    .initial_value(detected)
+                    .interact(),
+            )?;
+            Some(PackageManager::Node(pm))
+        }
+        Some(PackageManager::Python(detected)) => {
+            let pm: PythonPackageManager = map_cancelled(
+                select("Package manager")
+                    .item(
+                        PythonPackageManager::Uv,
+                        "uv",
+                        "Fast Python package manager",
+                    )
+                    .item(
+                        PythonPackageManager::Pip,
+                        "pip",
+                        "Standard Python package installer",
+                    )
+                    .item(
+                        PythonPackageManager::Poetry,
+                        "poetry",
+                        "Dependency management and packaging",
+                    )
+                    .initial_value(detected)
+                    .interact(),
+            )?;
+            Some(PackageManager::Python(pm))
+        }
+        // Nothing detected: ask (no-op for binary servers)
+        None => prompt_package_manager(prefill.server_type, None)?,
+    };
+
+    map_cancelled(outro("Configuration complete!"))?;
+
+    Ok(MigratePromptResult {
+        name,
+        entry_point,
+        transport,
+        package_manager,
+    })
+}
+
 /// Try to get author name from git config.
 pub fn get_git_author_name() -> Option<String> {
     Command::new("git")
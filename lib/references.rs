@@ -1,6 +1,8 @@
 //! Plugin reference system.
 //!
 //! Provides a unified way to reference tools using the format: `[<namespace>/]<name>[@<version>]`.
+//!
+//! The version may also be a dist-tag such as `beta`; references without one use `latest`.
 
 use crate::error::{ToolError, ToolResult};
 use regex::Regex;
@@ -22,6 +24,9 @@ const NAMESPACE_PATTERN: &str = r"^[a-z][a-z0-9-]{2,63}$";
 /// Rules: 3-64 chars, starts with lowercase letter, contains only lowercase letters, digits, hyphens
 const NAME_PATTERN: &str = r"^[a-z][a-z0-9-]{2,63}$";
 
+/// Regex pattern for dist-tags (e.g. `latest`, `beta`, `next`).
+const TAG_PATTERN: &str = r"^[a-z][a-z0-9-]{0,63}$";
+
 /// Compiled namespace regex.
 static NAMESPACE_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(NAMESPACE_PATTERN).expect("Invalid regex"));
@@ -30,6 +35,10 @@ static NAMESPACE_REGEX: LazyLock<Regex> =
 static NAME_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(NAME_PATTERN).expect("Invalid regex"));
 
+/// Compiled dist-tag regex.
+static TAG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(TAG_PATTERN).expect("Invalid regex"));
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -50,6 +59,10 @@ pub struct PluginRef {
 
     /// Raw version string as provided (without semver interpretation).
     version_str: Option<String>,
+
+    /// Dist-tag, when the part after `@` is a tag rather than a version.
+    #[serde(default)]
+    tag: Option<String>,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -63,24 +76,32 @@ impl PluginRef {
             return Err(ToolError::InvalidReference("Empty reference".into()));
         }
 
-        // Split by '@' to separate version
-        let (base, version, version_str) = if let Some(at_pos) = input.rfind('@') {
+        // Split by '@' to separate version or tag
+        let (base, version, version_str, tag) = if let Some(at_pos) = input.rfind('@') {
             let ver_str = &input[at_pos + 1..];
             if ver_str.is_empty() {
                 return Err(ToolError::InvalidReference(
                     "Empty version after '@'".into(),
                 ));
             }
-            let version = VersionReq::parse(ver_str).map_err(|e| {
-                ToolError::InvalidReference(format!("Invalid version '{}': {}", ver_str, e))
-            })?;
+            let (version, tag) = match VersionReq::parse(ver_str) {
+                Ok(version) => (Some(version), None),
+                Err(_) if TAG_REGEX.is_match(ver_str) => (None, Some(ver_str.to_string())),
+                Err(e) => {
+                    return Err(ToolError::InvalidReference(format!(
+                        "Invalid version '{}': {}",
+                        ver_str, e
+                    )));
+                }
+            };
             (
                 input[..at_pos].to_string(),
-                Some(version),
+                version,
                 Some(ver_str.to_string()),
+                tag,
             )
         } else {
-            (input.to_string(), None, None)
+            (input.to_string(), None, None, None)
         };
 
         // Split by '/' to separate namespace
@@ -122,6 +143,7 @@ impl PluginRef {
             name,
             version,
             version_str,
+            tag,
         })
     }
 
@@ -134,6 +156,7 @@ impl PluginRef {
             name,
             version: None,
             version_str: None,
+            tag: None,
         })
     }
 
@@ -148,6 +171,7 @@ impl PluginRef {
     /// Set the version requirement for this reference.
     pub fn with_version(mut self, version: VersionReq) -> Self {
         self.version = Some(version);
+        self.tag = None;
         self
     }
 
@@ -171,6 +195,11 @@ impl PluginRef {
         self.version_str.as_deref()
     }
 
+    /// Get the dist-tag of this reference (e.g. `beta` in `ns/tool@beta`).
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
     /// Check if this is a local reference (no namespace).
     pub fn is_local(&self) -> bool {
         self.namespace.is_none()
@@ -204,6 +233,17 @@ impl PluginRef {
         Ok(())
     }
 
+    /// Validate a dist-tag name; tags can't look like versions.
+    pub fn validate_tag(tag: &str) -> ToolResult<()> {
+        if VersionReq::parse(tag).is_ok() || !TAG_REGEX.is_match(tag) {
+            return Err(ToolError::InvalidReference(format!(
+                "Tag '{}' must start with lowercase letter, contain only lowercase letters, numbers, and hyphens, and not be a version",
+                tag
+            )));
+        }
+        Ok(())
+    }
+
     /// Validate a name segment.
     fn validate_name(name: &str) -> ToolResult<()> {
         if name.len() < 3 {
@@ -253,3 +293,35 @@ impl FromStr for PluginRef {
         PluginRef::parse(s)
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag() {
+        let plugin_ref = PluginRef::parse("acme/my-tool@beta").unwrap();
+        assert_eq!(plugin_ref.tag(), Some("beta"));
+        assert!(plugin_ref.version().is_none());
+        assert_eq!(plugin_ref.to_string(), "acme/my-tool@beta");
+
+        let plugin_ref = PluginRef::parse("acme/my-tool@1.2.0").unwrap();
+        assert!(plugin_ref.tag().is_none());
+        assert!(plugin_ref.version().is_some());
+
+        assert!(PluginRef::parse("acme/my-tool@Beta!").is_err());
+    }
+
+    #[test]
+    fn test_validate_tag() {
+        assert!(PluginRef::validate_tag("beta").is_ok());
+        assert!(PluginRef::validate_tag("next-2").is_ok());
+        assert!(PluginRef::validate_tag("1.2.0").is_err());
+        assert!(PluginRef::validate_tag("x").is_err());
+        assert!(PluginRef::validate_tag("Beta").is_err());
+    }
+}
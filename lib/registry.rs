@@ -1,17 +1,22 @@
 //! Registry client for tool.store.
 
-use crate::constants::{REGISTRY_TOKEN_ENV, get_registry_url};
+use crate::constants::{
+    DEFAULT_DIST_TAG, REGISTRY_TOKEN_ENV, TOOL_CA_BUNDLE_ENV, TOOL_OFFLINE_ENV, TOOL_PROXY_ENV,
+    get_registry_url,
+};
 use crate::error::{ToolError, ToolResult};
 use bytes::Bytes;
 use futures_util::StreamExt;
 use http_body::{Body as HttpBody, Frame, SizeHint};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
-use tokio::io::AsyncWriteExt;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
 
 //--------------------------------------------------------------------------------------------------
 // Constants
@@ -20,6 +25,36 @@ use tokio::io::AsyncWriteExt;
 /// API version prefix.
 const API_PREFIX: &str = "/api/v1";
 
+/// Header carrying a one-time password for publishing.
+const OTP_HEADER: &str = "X-Registry-OTP";
+
+/// API error code returned when publishing requires a one-time password.
+const OTP_REQUIRED_CODE: &str = "OTP_REQUIRED";
+
+/// Chunk size for streaming uploads.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Default time allowed to establish a connection to the registry.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default time allowed for a whole API request, including downloading the response.
+///
+/// Bundle downloads and uploads aren't bounded by it, since a large bundle on a slow
+/// link can legitimately take longer; they use [`DEFAULT_READ_TIMEOUT`] instead.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default time a request may go without receiving any data before it is abandoned.
+pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Number of search results per page when `--limit` is not given.
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+/// Set by `--frozen` to forbid all network access for the process.
+static NETWORK_FROZEN: AtomicBool = AtomicBool::new(false);
+
+/// Set by `--proxy`; takes precedence over `TOOL_PROXY` and the standard proxy variables.
+static PROXY_OVERRIDE: OnceLock<String> = OnceLock::new();
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -44,8 +79,36 @@ pub struct RegistryClient {
     /// Optional authentication token.
     auth_token: Option<String>,
 
-    /// HTTP client.
-    http: Client,
+    /// One-time password sent with publish requests.
+    otp: Option<String>,
+
+    /// Settings the HTTP client was built from.
+    settings: HttpSettings,
+
+    /// HTTP client, or why it couldn't be built (reported on the first request).
+    http: Result<Client, String>,
+
+    /// HTTP client for bundle transfers, without the whole-request timeout.
+    transfer_http: Result<Client, String>,
+}
+
+/// Connection settings for the registry HTTP client.
+#[derive(Debug, Clone)]
+struct HttpSettings {
+    /// Time allowed to establish a connection.
+    connect_timeout: Duration,
+
+    /// Time allowed for a whole API request.
+    request_timeout: Duration,
+
+    /// Time allowed between reads of a response.
+    read_timeout: Duration,
+
+    /// Explicit proxy URL. Without one, `HTTPS_PROXY`, `HTTP_PROXY`, and `NO_PROXY` apply.
+    proxy: Option<String>,
+
+    /// PEM file of extra CA certificates to trust.
+    ca_bundle: Option<PathBuf>,
 }
 
 /// User info returned from auth validation.
@@ -57,6 +120,8 @@ pub struct UserInfoResponse {
     pub email: Option<String>,
     /// Display name.
     pub display_name: Option<String>,
+    /// Token expiry (RFC 3339), if the token expires.
+    pub expires_at: Option<String>,
 }
 
 /// Search result from the registry.
@@ -72,11 +137,58 @@ pub struct SearchResult {
     pub latest_version: Option<String>,
     /// Total download count.
     pub total_downloads: i64,
+    /// Categories the publisher assigned (`_meta.store.tool.mcpb.categories`).
+    pub categories: Vec<String>,
+}
+
+/// Sort order for registry search results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Most downloaded first.
+    #[default]
+    Downloads,
+    /// Alphabetical by `namespace/name`.
+    Name,
+    /// Most recently updated first.
+    Updated,
+}
+
+/// Filters, paging and ordering for a registry search.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// Only tools in all of these categories.
+    pub categories: Vec<String>,
+    /// Only tools tagged with all of these keywords.
+    pub keywords: Vec<String>,
+    /// Results per page.
+    pub limit: usize,
+    /// 1-based page number.
+    pub page: usize,
+    /// Result order.
+    pub sort: SearchSort,
+}
+
+/// One page of registry search results.
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    /// Results on this page.
+    pub results: Vec<SearchResult>,
+    /// Total matches across all pages, when the registry reports it.
+    pub total: Option<u64>,
+    /// Whether a later page has more results.
+    pub has_more: bool,
 }
 
 #[derive(Debug, Deserialize)]
 struct SearchResponse {
     data: Vec<SearchResultItem>,
+    #[serde(default)]
+    pagination: Option<SearchPagination>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchPagination {
+    total: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -92,6 +204,8 @@ struct ArtifactSummary {
     #[serde(default)]
     total_downloads: i64,
     latest_version: Option<SearchVersionInfo>,
+    #[serde(default)]
+    categories: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -113,6 +227,11 @@ pub struct ArtifactDetails {
     /// Total download count.
     #[serde(default)]
     pub total_downloads: i64,
+    /// Visibility (`public` or `private`).
+    pub visibility: Option<String>,
+    /// Dist-tags mapped to the version they point at (e.g. `beta` -> `2.0.0-beta.1`).
+    #[serde(default)]
+    pub dist_tags: std::collections::BTreeMap<String, String>,
 }
 
 /// File info for a bundle in the version.
@@ -156,6 +275,16 @@ pub struct VersionInfo {
     pub files: Option<std::collections::HashMap<String, FileInfo>>,
     /// The manifest JSON (included when fetching version details).
     pub manifest: Option<serde_json::Value>,
+    /// When this version was published (RFC 3339).
+    pub published_at: Option<String>,
+    /// Whether the publisher yanked this version; new installs skip it.
+    #[serde(default)]
+    pub yanked: bool,
+    /// Whether the publisher deprecated this version.
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Publisher's explanation for the deprecation.
+    pub deprecation_message: Option<String>,
 }
 
 /// File specification for upload initiation.
@@ -215,6 +344,9 @@ struct PublishVersionRequest {
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     icons: Option<Vec<IconInfo>>,
+    tag: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provenance: Option<serde_json::Value>,
 }
 
 /// A streaming body with known size that reports upload progress.
@@ -286,24 +418,159 @@ impl<F: Fn(u64) + Send + Sync + 'static> HttpBody for SizedProgressBody<F> {
     }
 }
 
+/// A streaming body that reads a file from disk and reports upload progress.
+///
+/// Like [`SizedProgressBody`] it reports an exact size so Content-Length is set, but only
+/// one chunk of the file is held in memory at a time.
+pub struct SizedFileBody<F> {
+    file: tokio::fs::File,
+    size: u64,
+    position: u64,
+    buf: Vec<u8>,
+    on_progress: Arc<F>,
+}
+
+impl<F> SizedFileBody<F> {
+    /// Open a file as a sized progress body.
+    pub async fn open(
+        path: &Path,
+        chunk_size: usize,
+        on_progress: Arc<F>,
+    ) -> std::io::Result<Self> {
+        let file = tokio::fs::File::open(path).await?;
+        let size = file.metadata().await?.len();
+        Ok(Self {
+            file,
+            size,
+            position: 0,
+            buf: vec![0; chunk_size],
+            on_progress,
+        })
+    }
+}
+
+impl<F: Fn(u64) + Send + Sync + 'static> HttpBody for SizedFileBody<F> {
+    type Data = Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+
+        if this.position >= this.size {
+            return Poll::Ready(None);
+        }
+
+        let remaining = usize::try_from(this.size - this.position).unwrap_or(usize::MAX);
+        let len = remaining.min(this.buf.len());
+        let mut read_buf = ReadBuf::new(&mut this.buf[..len]);
+        match Pin::new(&mut this.file).poll_read(cx, &mut read_buf) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(Ok(())) if read_buf.filled().is_empty() => {
+                // The file shrank after its size was recorded
+                Poll::Ready(Some(Err(std::io::ErrorKind::UnexpectedEof.into())))
+            }
+            Poll::Ready(Ok(())) => {
+                let chunk = Bytes::copy_from_slice(read_buf.filled());
+                this.position += chunk.len() as u64;
+                (this.on_progress)(this.position);
+                Poll::Ready(Some(Ok(Frame::data(chunk))))
+            }
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.position >= self.size
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        SizeHint::with_exact(self.size - self.position)
+    }
+}
+
+/// Sends a request, logging its method, URL, status, and timing at debug level.
+trait SendLogged {
+    async fn send_logged(self) -> reqwest::Result<reqwest::Response>;
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
 
+impl HttpSettings {
+    /// Build an HTTP client from these settings.
+    ///
+    /// `request_timeout` bounds each whole request; transfer clients pass `None` and
+    /// rely on the read timeout to end stalled connections.
+    fn build(&self, request_timeout: Option<Duration>) -> Result<Client, String> {
+        let mut builder = Client::builder()
+            .http1_only() // Force HTTP/1.1 - R2 handles it better than HTTP/2
+            .connect_timeout(self.connect_timeout)
+            .read_timeout(self.read_timeout);
+        if let Some(timeout) = request_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| format!("Invalid proxy URL '{}': {}", proxy, e))?
+                .no_proxy(reqwest::NoProxy::from_env());
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(path) = &self.ca_bundle {
+            let pem = std::fs::read(path).map_err(|e| {
+                format!(
+                    "Failed to read CA bundle {} ({}): {}",
+                    path.display(),
+                    TOOL_CA_BUNDLE_ENV,
+                    e
+                )
+            })?;
+            let certs = reqwest::Certificate::from_pem_bundle(&pem)
+                .map_err(|e| format!("Invalid CA bundle {}: {}", path.display(), e))?;
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        builder
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))
+    }
+}
+
 impl RegistryClient {
     /// Create a new registry client with default configuration.
+    ///
+    /// Requests use [`DEFAULT_CONNECT_TIMEOUT`], [`DEFAULT_REQUEST_TIMEOUT`] (API calls
+    /// only), and [`DEFAULT_READ_TIMEOUT`], the proxy
+    /// from `--proxy`, `TOOL_PROXY`, or the standard proxy variables, and the extra CA
+    /// certificates in `TOOL_CA_BUNDLE`.
     pub fn new() -> Self {
         let url = get_registry_url();
         let auth_token = std::env::var(REGISTRY_TOKEN_ENV).ok();
+        let settings = HttpSettings {
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+            proxy: PROXY_OVERRIDE
+                .get()
+                .cloned()
+                .or_else(|| non_empty_env(TOOL_PROXY_ENV)),
+            ca_bundle: non_empty_env(TOOL_CA_BUNDLE_ENV).map(PathBuf::from),
+        };
 
         Self {
             url,
             auth_token,
-            http: Client::builder()
-                .http1_only() // Force HTTP/1.1 - R2 handles it better than HTTP/2
-                .connect_timeout(std::time::Duration::from_secs(30))
-                .build()
-                .expect("Failed to create HTTP client"),
+            otp: None,
+            http: settings.build(Some(settings.request_timeout)),
+            transfer_http: settings.build(None),
+            settings,
         }
     }
 
@@ -313,12 +580,68 @@ impl RegistryClient {
         self
     }
 
+    /// Set the connect timeout and the overall timeout for API requests.
+    pub fn with_timeout(mut self, connect: Duration, request: Duration) -> Self {
+        self.settings.connect_timeout = connect;
+        self.settings.request_timeout = request;
+        self.rebuild_clients();
+        self
+    }
+
+    /// Send all requests through the given proxy URL.
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.settings.proxy = Some(proxy.into());
+        self.rebuild_clients();
+        self
+    }
+
+    /// Rebuild both HTTP clients after the settings change.
+    fn rebuild_clients(&mut self) {
+        self.http = self.settings.build(Some(self.settings.request_timeout));
+        self.transfer_http = self.settings.build(None);
+    }
+
     /// Set the authentication token.
     pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
         self.auth_token = Some(token.into());
         self
     }
 
+    /// Set the one-time password sent with publish requests.
+    pub fn with_otp(mut self, otp: impl Into<String>) -> Self {
+        self.set_otp(otp);
+        self
+    }
+
+    /// Replace the one-time password, e.g. after prompting for a new code.
+    pub fn set_otp(&mut self, otp: impl Into<String>) {
+        self.otp = Some(otp.into());
+    }
+
+    /// Attach the one-time password header, if set.
+    fn otp_header(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.otp {
+            Some(otp) => request.header(OTP_HEADER, otp),
+            None => request,
+        }
+    }
+
+    /// Get the HTTP client, refusing when network access is frozen.
+    fn client(&self) -> ToolResult<&Client> {
+        ensure_network("Registry request")?;
+        self.http
+            .as_ref()
+            .map_err(|e| ToolError::Generic(e.clone()))
+    }
+
+    /// Get the HTTP client for bundle downloads and uploads.
+    fn transfer_client(&self) -> ToolResult<&Client> {
+        ensure_network("Registry request")?;
+        self.transfer_http
+            .as_ref()
+            .map_err(|e| ToolError::Generic(e.clone()))
+    }
+
     /// Get the registry URL.
     pub fn registry_url(&self) -> &str {
         &self.url
@@ -358,6 +681,27 @@ impl RegistryClient {
         self.auth_token.is_some()
     }
 
+    /// Check that the registry answers HTTP requests.
+    ///
+    /// Any non-5xx response counts as reachable; the request carries no auth.
+    pub async fn ping(&self) -> ToolResult<()> {
+        let response = self
+            .client()?
+            .get(&self.url)
+            .send_logged()
+            .await
+            .map_err(|e| network_error("Failed to reach registry", e))?;
+
+        if response.status().is_server_error() {
+            return Err(ToolError::Generic(format!(
+                "Registry returned {}",
+                response.status()
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Validate the auth token and return user info.
     pub async fn validate_token(&self) -> ToolResult<UserInfoResponse> {
         let token = self
@@ -368,12 +712,12 @@ impl RegistryClient {
         let url = format!("{}{}/identity", self.url, API_PREFIX);
 
         let response = self
-            .http
+            .client()?
             .get(&url)
             .bearer_auth(token)
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to validate token: {}", e)))?;
+            .map_err(|e| network_error("Failed to validate token", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -402,21 +746,21 @@ impl RegistryClient {
             self.url, API_PREFIX, namespace, name
         );
 
-        let mut request = self.http.get(&url);
+        let mut request = self.client()?.get(&url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to fetch artifact: {}", e)))?;
+            .map_err(|e| network_error("Failed to fetch artifact", e))?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
-            return Err(ToolError::Generic(format!(
-                "Tool {}/{} not found in registry",
-                namespace, name
-            )));
+            return Err(ToolError::NotFound {
+                kind: "Tool".into(),
+                reference: format!("{}/{}", namespace, name),
+            });
         }
 
         if !response.status().is_success() {
@@ -441,15 +785,15 @@ impl RegistryClient {
             self.url, API_PREFIX, namespace, name
         );
 
-        let mut request = self.http.get(&url);
+        let mut request = self.client()?.get(&url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to list versions: {}", e)))?;
+            .map_err(|e| network_error("Failed to list versions", e))?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
             return Ok(Vec::new());
@@ -490,21 +834,21 @@ impl RegistryClient {
             self.url, API_PREFIX, namespace, name, version
         );
 
-        let mut request = self.http.get(&url);
+        let mut request = self.client()?.get(&url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to fetch version: {}", e)))?;
+            .map_err(|e| network_error("Failed to fetch version", e))?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
-            return Err(ToolError::Generic(format!(
-                "Version {} not found for {}/{}",
-                version, namespace, name
-            )));
+            return Err(ToolError::NotFound {
+                kind: "Version".into(),
+                reference: format!("{}/{}@{}", namespace, name, version),
+            });
         }
 
         if !response.status().is_success() {
@@ -530,11 +874,11 @@ impl RegistryClient {
         pb: &indicatif::ProgressBar,
     ) -> ToolResult<u64> {
         let response = self
-            .http
+            .transfer_client()?
             .get(url)
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Download failed: {}", e)))?;
+            .map_err(|e| network_error("Download failed", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -559,8 +903,7 @@ impl RegistryClient {
         let mut stream = response.bytes_stream();
 
         while let Some(chunk) = stream.next().await {
-            let chunk =
-                chunk.map_err(|e| ToolError::Generic(format!("Failed to read chunk: {}", e)))?;
+            let chunk = chunk.map_err(|e| network_error("Failed to read chunk", e))?;
             file.write_all(&chunk)
                 .await
                 .map_err(|e| ToolError::Generic(format!("Failed to write chunk: {}", e)))?;
@@ -581,26 +924,29 @@ impl RegistryClient {
             self.url, API_PREFIX, namespace, name
         );
 
-        let mut request = self.http.get(&url);
+        let mut request = self.client()?.get(&url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to check artifact: {}", e)))?;
+            .map_err(|e| network_error("Failed to check artifact", e))?;
 
         Ok(response.status().is_success())
     }
 
     /// Create a new artifact in the registry.
+    ///
+    /// `visibility` is `public` or `private`; the registry default applies when `None`.
     pub async fn create_artifact(
         &self,
         namespace: &str,
         name: &str,
         description: Option<&str>,
         category_slugs: Option<Vec<String>>,
+        visibility: Option<&str>,
     ) -> ToolResult<()> {
         let token = self
             .auth_token
@@ -616,16 +962,17 @@ impl RegistryClient {
             "artifact_type": "tool",
             "description": description,
             "category_slugs": category_slugs,
+            "visibility": visibility,
         });
 
         let response = self
-            .http
+            .client()?
             .post(&url)
             .bearer_auth(token)
             .json(&body)
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to create artifact: {}", e)))?;
+            .map_err(|e| network_error("Failed to create artifact", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -663,13 +1010,12 @@ impl RegistryClient {
         };
 
         let response = self
-            .http
-            .post(&url)
+            .otp_header(self.client()?.post(&url))
             .bearer_auth(token)
             .json(&body)
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to init upload: {}", e)))?;
+            .map_err(|e| network_error("Failed to init upload", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -689,12 +1035,12 @@ impl RegistryClient {
         // The presigned URL is generated without Content-Type in the signature,
         // and R2 auto-detects Content-Type from file extension.
         let response = self
-            .http
+            .transfer_client()?
             .put(upload_url)
             .body(content.to_vec())
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to upload bundle: {}", e)))?;
+            .map_err(|e| network_error("Failed to upload bundle", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -715,6 +1061,8 @@ impl RegistryClient {
     /// which is required for presigned URLs where Content-Length is signed.
     ///
     /// Retries up to 3 times on transient connection errors (broken pipe, connection reset).
+    /// The content is held in memory; large bundles on disk should use
+    /// [`Self::upload_file_with_progress`].
     pub async fn upload_bundle_with_progress<F>(
         &self,
         upload_url: &str,
@@ -724,33 +1072,72 @@ impl RegistryClient {
     where
         F: Fn(u64) + Send + Sync + 'static,
     {
-        const MAX_RETRIES: u32 = 3;
         let on_progress = Arc::new(on_progress);
+        self.put_with_retries(upload_url, || {
+            // Create a sized body that reports progress as chunks are consumed.
+            let body = SizedProgressBody::new_with_arc(
+                content.to_vec(),
+                UPLOAD_CHUNK_SIZE,
+                Arc::clone(&on_progress),
+            );
+            async move { Ok(reqwest::Body::wrap(body)) }
+        })
+        .await
+    }
+
+    /// Upload a file from disk to a presigned URL with progress tracking.
+    ///
+    /// The file is streamed in chunks, so memory use stays bounded for large bundles.
+    /// Progress and retries behave as in [`Self::upload_bundle_with_progress`].
+    pub async fn upload_file_with_progress<F>(
+        &self,
+        upload_url: &str,
+        path: &Path,
+        on_progress: F,
+    ) -> ToolResult<()>
+    where
+        F: Fn(u64) + Send + Sync + 'static,
+    {
+        let on_progress = Arc::new(on_progress);
+        self.put_with_retries(upload_url, || {
+            let on_progress = Arc::clone(&on_progress);
+            async move {
+                let body = SizedFileBody::open(path, UPLOAD_CHUNK_SIZE, on_progress)
+                    .await
+                    .map_err(|e| {
+                        ToolError::Generic(format!("Failed to read {}: {}", path.display(), e))
+                    })?;
+                Ok(reqwest::Body::wrap(body))
+            }
+        })
+        .await
+    }
+
+    /// PUT a body to a presigned URL, retrying connection errors with backoff.
+    ///
+    /// `make_body` is called for each attempt so progress restarts from zero.
+    async fn put_with_retries<M, Fut>(&self, upload_url: &str, make_body: M) -> ToolResult<()>
+    where
+        M: Fn() -> Fut,
+        Fut: std::future::Future<Output = ToolResult<reqwest::Body>>,
+    {
+        const MAX_RETRIES: u32 = 3;
         let mut last_error = None;
 
         for attempt in 0..MAX_RETRIES {
             if attempt > 0 {
                 // Wait before retry with exponential backoff
-                tokio::time::sleep(std::time::Duration::from_millis(500 * (1 << attempt))).await;
+                tokio::time::sleep(Duration::from_millis(500 * (1 << attempt))).await;
             }
 
-            // Reset progress on retry
-            let progress_clone = Arc::clone(&on_progress);
-
-            // Create a sized body that reports progress as chunks are consumed.
-            let body = SizedProgressBody::new_with_arc(
-                content.to_vec(),
-                64 * 1024, // 64KB chunks
-                progress_clone,
-            );
+            let body = make_body().await?;
 
-            // Wrap our custom body for use with reqwest.
             // Don't set Content-Type - R2 auto-detects from file extension.
             let result = self
-                .http
+                .transfer_client()?
                 .put(upload_url)
-                .body(reqwest::Body::wrap(body))
-                .send()
+                .body(body)
+                .send_logged()
                 .await;
 
             match result {
@@ -772,21 +1159,20 @@ impl RegistryClient {
                         last_error = Some(e);
                         continue;
                     }
-                    return Err(ToolError::Generic(format!(
-                        "Failed to upload bundle: {:?}",
-                        e
-                    )));
+                    return Err(network_error("Failed to upload bundle", e));
                 }
             }
         }
 
-        Err(ToolError::Generic(format!(
+        Err(ToolError::Network(format!(
             "Failed to upload bundle after {} retries: {:?}",
             MAX_RETRIES, last_error
         )))
     }
 
-    /// Publish a version after upload.
+    /// Publish a version after upload, pointing `tag` at it.
+    ///
+    /// `provenance` is an in-toto statement describing how the bundle was built.
     #[allow(clippy::too_many_arguments)]
     pub async fn publish_version(
         &self,
@@ -798,6 +1184,8 @@ impl RegistryClient {
         manifest: serde_json::Value,
         description: Option<&str>,
         icons: Option<Vec<IconInfo>>,
+        tag: &str,
+        provenance: Option<serde_json::Value>,
     ) -> ToolResult<PublishResult> {
         let token = self
             .auth_token
@@ -816,16 +1204,17 @@ impl RegistryClient {
             manifest,
             description: description.map(String::from),
             icons,
+            tag: tag.to_string(),
+            provenance,
         };
 
         let response = self
-            .http
-            .post(&url)
+            .otp_header(self.client()?.post(&url))
             .bearer_auth(token)
             .json(&body)
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to publish version: {}", e)))?;
+            .map_err(|e| network_error("Failed to publish version", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -839,6 +1228,69 @@ impl RegistryClient {
             .map_err(|e| ToolError::Generic(format!("Failed to parse publish response: {}", e)))
     }
 
+    /// Yank a published version so new installs skip it.
+    pub async fn yank_version(&self, namespace: &str, name: &str, version: &str) -> ToolResult<()> {
+        self.post_version_action(
+            namespace,
+            name,
+            version,
+            "yank",
+            serde_json::json!({}),
+            "Yank",
+        )
+        .await
+    }
+
+    /// Deprecate a published version with a message shown to anyone installing it.
+    pub async fn deprecate_version(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: &str,
+        message: &str,
+    ) -> ToolResult<()> {
+        let body = serde_json::json!({ "message": message });
+        self.post_version_action(namespace, name, version, "deprecate", body, "Deprecate")
+            .await
+    }
+
+    /// POST an authenticated action (`yank`, `deprecate`) on a published version.
+    async fn post_version_action(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: &str,
+        action: &str,
+        body: serde_json::Value,
+        operation: &str,
+    ) -> ToolResult<()> {
+        let token = self.auth_token.as_ref().ok_or_else(|| {
+            ToolError::Generic("Authentication required. Run `tool login` first.".into())
+        })?;
+
+        let url = format!(
+            "{}{}/artifacts/{}/{}/versions/{}/{}",
+            self.url, API_PREFIX, namespace, name, version, action
+        );
+
+        let response = self
+            .client()?
+            .post(&url)
+            .bearer_auth(token)
+            .json(&body)
+            .send_logged()
+            .await
+            .map_err(|e| network_error(&format!("Failed to {} version", action), e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(parse_api_error(status, &body, operation));
+        }
+
+        Ok(())
+    }
+
     /// Fetch a tool from the registry.
     ///
     /// Returns the bundle content and version if found.
@@ -854,20 +1306,24 @@ impl RegistryClient {
 
         let name = plugin_ref.name();
 
-        // Resolve version - find matching version or get latest
-        let resolved_version = match plugin_ref.version() {
-            Some(req) => {
-                // Find latest version matching the requirement
-                match self.get_matching_version(namespace, name, req).await? {
-                    Some(v) => v,
-                    None => return Ok(None),
+        // Resolve version - resolve tag, find matching version, or get latest
+        let resolved_version = if let Some(tag) = plugin_ref.tag() {
+            self.resolve_tag(namespace, name, tag).await?
+        } else {
+            match plugin_ref.version() {
+                Some(req) => {
+                    // Find latest version matching the requirement
+                    match self.get_matching_version(namespace, name, req).await? {
+                        Some(v) => v,
+                        None => return Ok(None),
+                    }
                 }
-            }
-            None => {
-                // Get latest version
-                match self.get_latest_version(namespace, name).await? {
-                    Some(v) => v,
-                    None => return Ok(None),
+                None => {
+                    // Get latest version
+                    match self.get_latest_version(namespace, name).await? {
+                        Some(v) => v,
+                        None => return Ok(None),
+                    }
                 }
             }
         };
@@ -878,15 +1334,15 @@ impl RegistryClient {
             self.url, API_PREFIX, namespace, name, resolved_version
         );
 
-        let mut request = self.http.get(&download_url);
+        let mut request = self.transfer_client()?.get(&download_url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to fetch tool: {}", e)))?;
+            .map_err(|e| network_error("Failed to fetch tool", e))?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
             return Ok(None);
@@ -904,7 +1360,7 @@ impl RegistryClient {
         let content = response
             .bytes()
             .await
-            .map_err(|e| ToolError::Generic(format!("Failed to read tool content: {}", e)))?;
+            .map_err(|e| network_error("Failed to read tool content", e))?;
 
         Ok(Some((content.to_vec(), resolved_version)))
     }
@@ -927,6 +1383,26 @@ impl RegistryClient {
         Ok(None)
     }
 
+    /// Resolve a dist-tag (e.g. `beta`) to the version it points at.
+    ///
+    /// `latest` falls back to the artifact's latest version when the registry reports no tags.
+    pub async fn resolve_tag(&self, namespace: &str, name: &str, tag: &str) -> ToolResult<String> {
+        let artifact = self.get_artifact(namespace, name).await?;
+        if let Some(version) = artifact.dist_tags.get(tag) {
+            return Ok(version.clone());
+        }
+        if tag == DEFAULT_DIST_TAG
+            && let Some(latest) = artifact.latest_version
+        {
+            return Ok(latest.version);
+        }
+
+        Err(ToolError::Generic(format!(
+            "Tag '{}' not found for {}/{}",
+            tag, namespace, name
+        )))
+    }
+
     /// Get the latest version of a tool.
     async fn get_latest_version(&self, namespace: &str, name: &str) -> ToolResult<Option<String>> {
         let artifact = self.get_artifact(namespace, name).await?;
@@ -934,25 +1410,34 @@ impl RegistryClient {
     }
 
     /// Search for tools in the registry.
-    pub async fn search(&self, query: &str, limit: Option<usize>) -> ToolResult<Vec<SearchResult>> {
-        let per_page = limit.unwrap_or(20);
-        let url = format!(
-            "{}{}/search?q={}&artifact_type=tool&page=1&per_page={}",
+    ///
+    /// Without a total from the registry, a full page is assumed to have more after it.
+    pub async fn search(&self, query: &str, options: &SearchOptions) -> ToolResult<SearchPage> {
+        let mut url = format!(
+            "{}{}/search?q={}&artifact_type=tool&page={}&per_page={}&sort={}",
             self.url,
             API_PREFIX,
             urlencoding::encode(query),
-            per_page
+            options.page,
+            options.limit,
+            options.sort
         );
+        for category in &options.categories {
+            url.push_str(&format!("&category={}", urlencoding::encode(category)));
+        }
+        for keyword in &options.keywords {
+            url.push_str(&format!("&keyword={}", urlencoding::encode(keyword)));
+        }
 
-        let mut request = self.http.get(&url);
+        let mut request = self.client()?.get(&url);
         if let Some(token) = &self.auth_token {
             request = request.bearer_auth(token);
         }
 
         let response = request
-            .send()
+            .send_logged()
             .await
-            .map_err(|e| ToolError::Generic(format!("Search failed: {}", e)))?;
+            .map_err(|e| network_error("Search failed", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -968,7 +1453,7 @@ impl RegistryClient {
             .await
             .map_err(|e| ToolError::Generic(format!("Failed to parse search results: {}", e)))?;
 
-        Ok(search_response
+        let results: Vec<SearchResult> = search_response
             .data
             .into_iter()
             .map(|item| SearchResult {
@@ -977,8 +1462,21 @@ impl RegistryClient {
                 description: item.artifact.description,
                 latest_version: item.artifact.latest_version.map(|v| v.version),
                 total_downloads: item.artifact.total_downloads,
+                categories: item.artifact.categories,
             })
-            .collect())
+            .collect();
+
+        let total = search_response.pagination.map(|p| p.total);
+        let has_more = match total {
+            Some(total) => ((options.page - 1) * options.limit + results.len()) < total as usize,
+            None => results.len() >= options.limit,
+        };
+
+        Ok(SearchPage {
+            results,
+            total,
+            has_more,
+        })
     }
 }
 
@@ -986,6 +1484,58 @@ impl RegistryClient {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Forbid (or allow) all network access for the rest of the process.
+pub fn set_network_frozen(frozen: bool) {
+    NETWORK_FROZEN.store(frozen, Ordering::SeqCst);
+}
+
+/// Whether network access is disabled by `--frozen` or `TOOL_OFFLINE`.
+pub fn is_network_frozen() -> bool {
+    NETWORK_FROZEN.load(Ordering::SeqCst)
+        || std::env::var(TOOL_OFFLINE_ENV)
+            .map(|v| matches!(v.trim(), "1" | "true" | "yes"))
+            .unwrap_or(false)
+}
+
+/// Route registry requests through `url` for the rest of the process (`--proxy`).
+///
+/// Must be called before any [`RegistryClient`] is created; later calls are ignored.
+pub fn set_proxy(url: impl Into<String>) {
+    let _ = PROXY_OVERRIDE.set(url.into());
+}
+
+/// Value of an environment variable, if set and non-empty.
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// Wrap a failed registry request as a network error, with a hint when it timed out.
+fn network_error(context: &str, err: reqwest::Error) -> ToolError {
+    if err.is_timeout() {
+        ToolError::Network(format!(
+            "{}: the registry didn't respond in time ({}). Retry, or use --frozen to work offline with installed tools.",
+            context, err
+        ))
+    } else {
+        ToolError::Network(format!("{}: {}", context, err))
+    }
+}
+
+/// Return an error if network access is disabled.
+pub fn ensure_network(operation: &str) -> ToolResult<()> {
+    if is_network_frozen() {
+        return Err(ToolError::NetworkDisabled {
+            operation: operation.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Check whether a registry error asks for a one-time password.
+pub fn is_otp_required(error: &ToolError) -> bool {
+    matches!(error, ToolError::RegistryApi { code, .. } if code == OTP_REQUIRED_CODE)
+}
+
 /// Parse an API error response and create a formatted ToolError.
 ///
 /// Returns a structured error with the error code and message from the API,
@@ -1001,7 +1551,19 @@ pub fn parse_api_error(status: reqwest::StatusCode, body: &str, operation: &str)
         }
     } else {
         // Fallback for non-JSON or unexpected responses
-        ToolError::Generic(format!("{} failed ({}): {}", operation, status, body))
+        ToolError::RegistryApi {
+            operation: operation.to_string(),
+            code: status
+                .canonical_reason()
+                .map(|reason| reason.to_uppercase().replace(' ', "_"))
+                .unwrap_or_else(|| "HTTP_ERROR".into()),
+            message: if body.trim().is_empty() {
+                format!("{} failed ({})", operation, status)
+            } else {
+                body.trim().to_string()
+            },
+            status: status.as_u16(),
+        }
     }
 }
 
@@ -1014,3 +1576,128 @@ impl Default for RegistryClient {
         Self::new()
     }
 }
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            categories: Vec::new(),
+            keywords: Vec::new(),
+            limit: DEFAULT_SEARCH_LIMIT,
+            page: 1,
+            sort: SearchSort::default(),
+        }
+    }
+}
+
+impl SendLogged for reqwest::RequestBuilder {
+    async fn send_logged(self) -> reqwest::Result<reqwest::Response> {
+        let (client, request) = self.build_split();
+        let request = request?;
+
+        // Query strings can carry signatures (presigned upload URLs), so leave them out
+        let method = request.method().clone();
+        let mut url = request.url().clone();
+        url.set_query(None);
+
+        tracing::debug!("{} {}", method, url);
+        let start = Instant::now();
+        let result = client.execute(request).await;
+        let elapsed = start.elapsed().as_millis();
+        match result {
+            Ok(response) => {
+                tracing::debug!(
+                    "{} {} -> {} in {}ms",
+                    method,
+                    url,
+                    response.status(),
+                    elapsed
+                );
+                Ok(response)
+            }
+            Err(mut e) => {
+                // Errors carry the full URL in their message too
+                if let Some(error_url) = e.url_mut() {
+                    error_url.set_query(None);
+                }
+                tracing::debug!("{} {} failed after {}ms: {}", method, url, elapsed, e);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SearchSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchSort::Downloads => write!(f, "downloads"),
+            SearchSort::Name => write!(f, "name"),
+            SearchSort::Updated => write!(f, "updated"),
+        }
+    }
+}
+
+impl std::str::FromStr for SearchSort {
+    type Err = ToolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "downloads" => Ok(SearchSort::Downloads),
+            "name" => Ok(SearchSort::Name),
+            "updated" => Ok(SearchSort::Updated),
+            _ => Err(ToolError::Generic(format!(
+                "Invalid sort '{}'. Use downloads, name, or updated.",
+                s
+            ))),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_requests_go_through_proxy() {
+        // A bare-bones HTTP proxy that records the request line and answers 404
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+        let proxy = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n])
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        });
+
+        let client = RegistryClient::new()
+            .with_url("http://registry.example.invalid")
+            .with_proxy(proxy_url);
+        let err = client.get_artifact("acme", "bash").await.unwrap_err();
+
+        assert!(matches!(err, ToolError::NotFound { .. }), "{:?}", err);
+        assert_eq!(
+            proxy.await.unwrap(),
+            "GET http://registry.example.invalid/api/v1/artifacts/acme/bash HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn test_invalid_proxy_is_reported_on_request() {
+        let client = RegistryClient::new().with_proxy("not a url");
+        let err = client.client().unwrap_err();
+        assert!(err.to_string().contains("Invalid proxy URL"), "{}", err);
+    }
+}
@@ -2,12 +2,16 @@
 //!
 //! This module provides resolution of tools from the filesystem.
 //!
-//! By default, this resolver only searches the local filesystem. To enable
+//! By default, this resolver searches the default tools directory followed by any
+//! directories listed in `TOOL_PATH`, and only the local filesystem. To enable
 //! automatic fetching from remote registries when plugins aren't found locally,
 //! use [`with_auto_install`](FilePluginResolver::with_auto_install).
 
-use crate::constants::{DEFAULT_TOOLS_PATH, MCPB_MANIFEST_FILE};
+use crate::constants::{DEFAULT_TOOLS_PATH, MCPB_MANIFEST_FILE, get_extra_tool_paths};
 use crate::error::{ToolError, ToolResult};
+use crate::extract::{
+    ExtractLimits, extract_archive, restore_entry_point_mode, verify_entry_point,
+};
 use crate::mcpb::McpbManifest;
 use crate::references::PluginRef;
 use crate::registry::RegistryClient;
@@ -53,6 +57,7 @@ impl Default for FilePluginResolver {
             search_paths: vec![DEFAULT_TOOLS_PATH.clone()],
             auto_install: None,
         }
+        .with_search_paths(get_extra_tool_paths())
     }
 }
 
@@ -75,6 +80,22 @@ impl FilePluginResolver {
         self
     }
 
+    /// Add several search paths to the end, keeping their order (earlier wins).
+    ///
+    /// Paths already being searched are skipped.
+    pub fn with_search_paths(
+        mut self,
+        paths: impl IntoIterator<Item = impl Into<PathBuf>>,
+    ) -> Self {
+        for path in paths {
+            let path = path.into();
+            if !self.search_paths.contains(&path) {
+                self.search_paths.push(path);
+            }
+        }
+        self
+    }
+
     /// Enable auto-installation of tools from remote registries.
     ///
     /// When enabled, namespaced tools (e.g., `appcypher/filesystem`) that aren't found
@@ -94,7 +115,18 @@ impl FilePluginResolver {
         &self.search_paths
     }
 
+    /// The search path a resolved tool path lives under, if any.
+    pub fn search_path_for(&self, path: &Path) -> Option<&Path> {
+        self.search_paths
+            .iter()
+            .find(|search_path| path.starts_with(search_path))
+            .map(PathBuf::as_path)
+    }
+
     /// Resolve a tool by reference.
+    ///
+    /// Search paths are checked in order, so a tool in an earlier path shadows the same
+    /// tool in a later one.
     pub async fn resolve_tool(
         &self,
         reference: &str,
@@ -294,6 +326,8 @@ impl FilePluginResolver {
     }
 
     /// List all installed tools.
+    ///
+    /// A tool present in several search paths is listed once, for the earliest path.
     pub async fn list_tools(&self) -> ToolResult<Vec<PluginRef>> {
         let mut tools = Vec::new();
         let mut seen = std::collections::HashSet::new();
@@ -506,66 +540,25 @@ impl FilePluginResolver {
 
     /// Extract a ZIP bundle to a directory.
     fn extract_bundle(&self, content: &[u8], target_dir: &Path) -> ToolResult<()> {
-        use std::io::Read;
         use zip::ZipArchive;
 
         let cursor = std::io::Cursor::new(content);
         let mut archive = ZipArchive::new(cursor)
             .map_err(|e| ToolError::Generic(format!("Failed to read ZIP archive: {}", e)))?;
 
-        // Extract entries
-        for i in 0..archive.len() {
-            let mut entry = archive
-                .by_index(i)
-                .map_err(|e| ToolError::Generic(format!("Failed to read archive entry: {}", e)))?;
-
-            let entry_path = entry
-                .enclosed_name()
-                .ok_or_else(|| ToolError::Generic("Invalid entry path in archive".into()))?;
-
-            let dest_path = target_dir.join(entry_path);
-
-            // Create parent directories if needed
-            if let Some(parent) = dest_path.parent() {
-                std::fs::create_dir_all(parent).map_err(|e| {
-                    ToolError::Generic(format!("Failed to create directory {:?}: {}", parent, e))
-                })?;
-            }
-
-            // Get Unix permissions from ZIP entry (if available)
-            #[cfg(unix)]
-            let unix_mode = entry.unix_mode();
-
-            // Extract file or directory
-            if entry.is_dir() {
-                std::fs::create_dir_all(&dest_path).map_err(|e| {
-                    ToolError::Generic(format!("Failed to create directory {:?}: {}", dest_path, e))
-                })?;
-            } else {
-                let mut file_content = Vec::new();
-                entry.read_to_end(&mut file_content).map_err(|e| {
-                    ToolError::Generic(format!("Failed to read entry content: {}", e))
-                })?;
-
-                std::fs::write(&dest_path, &file_content).map_err(|e| {
-                    ToolError::Generic(format!("Failed to write file {:?}: {}", dest_path, e))
-                })?;
-
-                // Restore Unix permissions if available
-                #[cfg(unix)]
-                if let Some(mode) = unix_mode {
-                    use std::os::unix::fs::PermissionsExt;
-                    let permissions = std::fs::Permissions::from_mode(mode);
-                    std::fs::set_permissions(&dest_path, permissions).map_err(|e| {
-                        ToolError::Generic(format!(
-                            "Failed to set permissions on {:?}: {}",
-                            dest_path, e
-                        ))
-                    })?;
-                }
-            }
-        }
-
+        // Don't leave a partial install behind
+        extract_archive(&mut archive, target_dir, &ExtractLimits::from_env(), || {}).map_err(
+            |e| {
+                let _ = std::fs::remove_dir_all(target_dir);
+                ToolError::Generic(format!("Failed to extract bundle: {}", e))
+            },
+        )?;
+
+        verify_entry_point(target_dir).map_err(|e| {
+            let _ = std::fs::remove_dir_all(target_dir);
+            ToolError::Generic(format!("Invalid bundle: {}", e))
+        })?;
+        restore_entry_point_mode(target_dir)?;
         Ok(())
     }
 }
@@ -623,3 +616,71 @@ pub fn load_tool_from_path(path: &Path) -> ToolResult<ResolvedPlugin<McpbManifes
         abs_path.display()
     )))
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_tool(base: &Path, namespace: &str, name: &str, description: &str) {
+        let dir = base.join(namespace).join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        let manifest = serde_json::json!({
+            "manifest_version": "0.3",
+            "name": name,
+            "version": "1.0.0",
+            "description": description,
+            "author": { "name": "test" },
+            "server": {
+                "type": "node",
+                "entry_point": "server/index.js",
+                "mcp_config": { "command": "node", "args": ["server/index.js"] }
+            }
+        });
+        std::fs::write(dir.join(MCPB_MANIFEST_FILE), manifest.to_string()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_search_paths_prefer_earlier_entries() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        write_tool(first.path(), "acme", "shared", "first");
+        write_tool(second.path(), "acme", "shared", "second");
+        write_tool(second.path(), "acme", "vendored", "second");
+
+        let resolver = FilePluginResolver::new(Vec::<PathBuf>::new()).with_search_paths([
+            first.path(),
+            second.path(),
+            first.path(),
+        ]);
+        assert_eq!(resolver.search_paths().len(), 2);
+
+        let tools: Vec<String> = resolver
+            .list_tools()
+            .await
+            .unwrap()
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(tools.len(), 2);
+        assert!(tools.contains(&"acme/shared".to_string()));
+        assert!(tools.contains(&"acme/vendored".to_string()));
+
+        let shared = resolver.resolve_tool("acme/shared").await.unwrap().unwrap();
+        assert_eq!(shared.template.description.as_deref(), Some("first"));
+        assert_eq!(resolver.search_path_for(&shared.path), Some(first.path()));
+
+        let vendored = resolver
+            .resolve_tool("acme/vendored")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            resolver.search_path_for(&vendored.path),
+            Some(second.path())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Scaffold templates for MCPB packages.
 
-use crate::mcpb::{McpbTransport, PythonPackageManager};
+use crate::mcpb::{McpbServerType, McpbTransport, PythonPackageManager};
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -14,6 +14,16 @@ pub struct NodeScaffold {
     pub package_json: String,
 }
 
+/// Node.js TypeScript scaffold files.
+pub struct TypeScriptScaffold {
+    /// Content for src/index.ts
+    pub index_ts: String,
+    /// Content for tsconfig.json
+    pub tsconfig_json: String,
+    /// Content for package.json
+    pub package_json: String,
+}
+
 /// Python scaffold files.
 pub struct PythonScaffold {
     /// Content for server/main.py
@@ -34,6 +44,14 @@ pub struct RustScaffold {
     pub cargo_toml: String,
 }
 
+/// Container scaffold files.
+pub struct DockerScaffold {
+    /// Content for Dockerfile
+    pub dockerfile: String,
+    /// Content for .dockerignore
+    pub dockerignore: String,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -244,6 +262,175 @@ httpServer.listen(port, host, () => {{
     }
 }
 
+/// Generate Node.js TypeScript scaffold files.
+///
+/// Sources live in `src/` and compile to `dist/` via the `build` script.
+pub fn typescript_scaffold(name: &str, transport: McpbTransport) -> TypeScriptScaffold {
+    let server = format!(
+        r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+{transport_import}import {{ z }} from "zod";
+
+const server = new McpServer({{
+  name: "{name}",
+  version: "0.1.0",
+}});
+
+server.registerTool(
+  "hello",
+  {{
+    description: "Say hello",
+    inputSchema: {{
+      name: z.string().optional().describe("Name to greet"),
+    }},
+    outputSchema: {{
+      message: z.string().describe("The greeting message"),
+    }},
+  }},
+  async ({{ name: inputName }}) => {{
+    const message = inputName ? `Hello, ${{inputName}}!` : "Hello from {name}!";
+    const output = {{ message }};
+    return {{
+      content: [{{ type: "text", text: JSON.stringify(output) }}],
+      structuredContent: output,
+    }};
+  }}
+);
+"#,
+        transport_import = match transport {
+            McpbTransport::Stdio => {
+                "import { StdioServerTransport } from \"@modelcontextprotocol/sdk/server/stdio.js\";\n"
+            }
+            McpbTransport::Http => {
+                "import { StreamableHTTPServerTransport } from \"@modelcontextprotocol/sdk/server/streamableHttp.js\";\nimport { createServer } from \"node:http\";\nimport { randomUUID } from \"node:crypto\";\n"
+            }
+        }
+    );
+
+    let main = match transport {
+        McpbTransport::Stdio => format!(
+            r#"
+const transport = new StdioServerTransport();
+await server.connect(transport);
+
+console.error("{name} MCP server running...");
+"#
+        ),
+        McpbTransport::Http => format!(
+            r#"
+const transports: Record<string, StreamableHTTPServerTransport> = {{}};
+
+const httpServer = createServer(async (req, res) => {{
+  const url = new URL(req.url ?? "/", `http://${{req.headers.host}}`);
+
+  if (url.pathname !== "/mcp") {{
+    res.writeHead(404);
+    res.end("Not Found");
+    return;
+  }}
+
+  const sessionId = req.headers["mcp-session-id"] as string | undefined;
+  const existing = sessionId ? transports[sessionId] : undefined;
+
+  if (req.method === "POST") {{
+    let transport = existing;
+
+    if (!transport) {{
+      const created = new StreamableHTTPServerTransport({{
+        sessionIdGenerator: () => randomUUID(),
+        onsessioninitialized: (id) => {{
+          transports[id] = created;
+        }},
+      }});
+      created.onclose = () => {{
+        if (created.sessionId) delete transports[created.sessionId];
+      }};
+      await server.connect(created);
+      transport = created;
+    }}
+
+    let body = "";
+    for await (const chunk of req) body += chunk;
+    await transport.handleRequest(req, res, JSON.parse(body));
+  }} else if (req.method === "GET") {{
+    if (existing) {{
+      await existing.handleRequest(req, res);
+    }} else {{
+      res.writeHead(400);
+      res.end("No session");
+    }}
+  }} else if (req.method === "DELETE") {{
+    if (existing && sessionId) {{
+      await existing.handleRequest(req, res);
+      delete transports[sessionId];
+    }} else {{
+      res.writeHead(400);
+      res.end("No session");
+    }}
+  }} else {{
+    res.writeHead(405);
+    res.end("Method Not Allowed");
+  }}
+}});
+
+const portArg = process.argv.find((a) => a.startsWith("--port="));
+const port = portArg ? parseInt(portArg.split("=")[1]) : 3000;
+
+const hostArg = process.argv.find((a) => a.startsWith("--host="));
+const host = hostArg ? hostArg.split("=")[1] : "127.0.0.1";
+
+httpServer.listen(port, host, () => {{
+  console.error(`{name} running on http://${{host}}:${{port}}/mcp`);
+}});
+"#
+        ),
+    };
+
+    let index_ts = format!("#!/usr/bin/env node\n\n{server}{main}");
+
+    let tsconfig_json = r#"{
+  "compilerOptions": {
+    "target": "ES2022",
+    "module": "Node16",
+    "moduleResolution": "Node16",
+    "outDir": "dist",
+    "rootDir": "src",
+    "strict": true,
+    "esModuleInterop": true,
+    "skipLibCheck": true
+  },
+  "include": ["src"]
+}
+"#
+    .to_string();
+
+    let package_json = format!(
+        r#"{{
+  "name": "{name}",
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "build": "tsc",
+    "start": "node dist/index.js"
+  }},
+  "dependencies": {{
+    "@modelcontextprotocol/sdk": "^1.0.0",
+    "zod": "^3.24.0"
+  }},
+  "devDependencies": {{
+    "@types/node": "^22.0.0",
+    "typescript": "^5.6.0"
+  }}
+}}
+"#
+    );
+
+    TypeScriptScaffold {
+        index_ts,
+        tsconfig_json,
+        package_json,
+    }
+}
+
 /// Generate Python scaffold files.
 pub fn python_scaffold(
     name: &str,
@@ -686,6 +873,105 @@ tracing-subscriber = {{ version = "0.3", features = ["env-filter"] }}
     }
 }
 
+/// Generate Dockerfile and .dockerignore for a scaffolded server.
+///
+/// HTTP servers listen on `0.0.0.0:3000` inside the container; stdio servers are
+/// meant to be run with `docker run -i`. `Binary` is treated as a Rust project.
+pub fn docker_scaffold(
+    name: &str,
+    server_type: McpbServerType,
+    transport: McpbTransport,
+) -> DockerScaffold {
+    let http = transport == McpbTransport::Http;
+    let expose = if http { "EXPOSE 3000\n" } else { "" };
+
+    let dockerfile = match server_type {
+        McpbServerType::Node => {
+            // `npm start` runs the entry point for both JavaScript and TypeScript scaffolds
+            let cmd = if http {
+                r#"["npm", "start", "--", "--port=3000", "--host=0.0.0.0"]"#
+            } else {
+                r#"["npm", "start"]"#
+            };
+            format!(
+                r#"FROM node:20-slim
+
+WORKDIR /app
+
+COPY package*.json ./
+RUN npm install
+
+COPY . .
+RUN npm run build --if-present
+
+{expose}CMD {cmd}
+"#
+            )
+        }
+        McpbServerType::Python => {
+            let cmd = if http {
+                r#"["python", "server/main.py", "--port", "3000", "--host", "0.0.0.0"]"#
+            } else {
+                r#"["python", "server/main.py"]"#
+            };
+            format!(
+                r#"FROM python:3.12-slim
+
+WORKDIR /app
+
+COPY . .
+RUN if [ -f requirements.txt ]; then \
+        pip install --no-cache-dir -r requirements.txt; \
+    elif grep -q '^\[tool.poetry\]' pyproject.toml; then \
+        pip install --no-cache-dir poetry && \
+        poetry config virtualenvs.create false && \
+        poetry install --no-root; \
+    else \
+        pip install --no-cache-dir uv && \
+        uv pip install --system -r pyproject.toml; \
+    fi
+
+{expose}CMD {cmd}
+"#
+            )
+        }
+        McpbServerType::Binary => {
+            let cmd = if http {
+                format!(r#"["{name}", "--port", "3000", "--host", "0.0.0.0"]"#)
+            } else {
+                format!(r#"["{name}"]"#)
+            };
+            format!(
+                r#"FROM rust:1-slim AS builder
+
+WORKDIR /app
+
+COPY . .
+RUN cargo build --release
+
+FROM debian:bookworm-slim
+
+COPY --from=builder /app/target/release/{name} /usr/local/bin/{name}
+
+{expose}CMD {cmd}
+"#
+            )
+        }
+    };
+
+    let ignored = match server_type {
+        McpbServerType::Node => "node_modules/\ndist/\n",
+        McpbServerType::Python => ".venv/\n__pycache__/\n",
+        McpbServerType::Binary => "target/\ndist/\n",
+    };
+    let dockerignore = format!(".git/\n.DS_Store\n*.mcpb\n*.mcpbx\n{ignored}");
+
+    DockerScaffold {
+        dockerfile,
+        dockerignore,
+    }
+}
+
 /// Generate .mcpbignore content for Rust projects.
 pub fn rust_mcpbignore_template(_name: &str) -> String {
     r#"# OS files
@@ -715,6 +1001,16 @@ target/
     .to_string()
 }
 
+/// Generate .mcpbignore content for TypeScript projects.
+///
+/// Only the compiled `dist/` output is needed at runtime.
+pub fn typescript_mcpbignore_template() -> String {
+    format!(
+        "{}\n# TypeScript sources\nsrc/\ntsconfig.json\n",
+        mcpbignore_template()
+    )
+}
+
 /// Generate .mcpbignore content (same for all types).
 pub fn mcpbignore_template() -> &'static str {
     r#"# OS files
@@ -904,4 +1200,61 @@ mod tests {
         assert!(content.contains("target/"));
         assert!(content.contains("src/"));
     }
+
+    #[test]
+    fn test_docker_scaffold_node_http() {
+        let scaffold = docker_scaffold("my-tool", McpbServerType::Node, McpbTransport::Http);
+        assert!(scaffold.dockerfile.contains("FROM node:"));
+        assert!(scaffold.dockerfile.contains("npm install"));
+        assert!(scaffold.dockerfile.contains("EXPOSE 3000"));
+        assert!(scaffold.dockerfile.contains("--host=0.0.0.0"));
+        assert!(scaffold.dockerignore.contains("node_modules/"));
+    }
+
+    #[test]
+    fn test_docker_scaffold_python_http() {
+        let scaffold = docker_scaffold("my-tool", McpbServerType::Python, McpbTransport::Http);
+        assert!(scaffold.dockerfile.contains("FROM python:"));
+        assert!(scaffold.dockerfile.contains("requirements.txt"));
+        assert!(scaffold.dockerfile.contains("server/main.py"));
+        assert!(scaffold.dockerfile.contains("0.0.0.0"));
+        assert!(scaffold.dockerignore.contains(".venv/"));
+    }
+
+    #[test]
+    fn test_docker_scaffold_rust_stdio() {
+        let scaffold = docker_scaffold("my-tool", McpbServerType::Binary, McpbTransport::Stdio);
+        assert!(scaffold.dockerfile.contains("cargo build --release"));
+        assert!(scaffold.dockerfile.contains("/app/target/release/my-tool"));
+        assert!(scaffold.dockerfile.contains(r#"CMD ["my-tool"]"#));
+        assert!(!scaffold.dockerfile.contains("EXPOSE"));
+        assert!(scaffold.dockerignore.contains("target/"));
+    }
+
+    #[test]
+    fn test_typescript_scaffold_stdio() {
+        let scaffold = typescript_scaffold("my-tool", McpbTransport::Stdio);
+        assert!(scaffold.index_ts.contains("my-tool"));
+        assert!(scaffold.index_ts.contains("StdioServerTransport"));
+        assert!(scaffold.index_ts.contains("outputSchema"));
+        assert!(scaffold.tsconfig_json.contains("\"outDir\": \"dist\""));
+        assert!(scaffold.package_json.contains("\"build\": \"tsc\""));
+        assert!(scaffold.package_json.contains("dist/index.js"));
+        assert!(scaffold.package_json.contains("typescript"));
+        assert!(scaffold.package_json.contains("@modelcontextprotocol/sdk"));
+    }
+
+    #[test]
+    fn test_typescript_scaffold_http() {
+        let scaffold = typescript_scaffold("my-tool", McpbTransport::Http);
+        assert!(scaffold.index_ts.contains("StreamableHTTPServerTransport"));
+        assert!(
+            scaffold
+                .index_ts
+                .contains("Record<string, StreamableHTTPServerTransport>")
+        );
+        assert!(scaffold.index_ts.contains("/mcp"));
+        assert!(!scaffold.index_ts.contains("StdioServerTransport"));
+        assert!(scaffold.package_json.contains("@types/node"));
+    }
 }
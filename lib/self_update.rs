@@ -1,16 +1,21 @@
 //! Self-update and self-uninstall functionality for tool-cli.
 
+use crate::constants::{SELF_UPDATE_STATE_PATH, TOOL_UPDATE_PUBKEY_ENV};
 use crate::error::{ToolError, ToolResult};
+use crate::format::format_size;
 use crate::styles::Spinner;
 use colored::Colorize;
 use flate2::read::GzDecoder;
 use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::Client;
-use serde::Deserialize;
+use semver::Version;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::fmt;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use tar::Archive;
 
 //--------------------------------------------------------------------------------------------------
@@ -20,9 +25,23 @@ use tar::Archive;
 /// GitHub repository for releases.
 const GITHUB_REPO: &str = "zerocore-ai/tool-cli";
 
-/// GitHub releases API URL.
+/// GitHub API URL for the latest stable (non-prerelease) release.
 const RELEASES_API_URL: &str = "https://api.github.com/repos/zerocore-ai/tool-cli/releases/latest";
 
+/// GitHub API URL listing recent releases, including prereleases.
+const RELEASES_LIST_API_URL: &str =
+    "https://api.github.com/repos/zerocore-ai/tool-cli/releases?per_page=30";
+
+/// GitHub API URL prefix for looking up a release by version (`v<version>` tag).
+const RELEASE_BY_TAG_API_URL: &str =
+    "https://api.github.com/repos/zerocore-ai/tool-cli/releases/tags/v";
+
+/// Human-facing page listing all published releases.
+const RELEASES_PAGE_URL: &str = "https://github.com/zerocore-ai/tool-cli/releases";
+
+/// Extension of the minisign signature published alongside each release archive.
+const SIGNATURE_EXTENSION: &str = "minisig";
+
 /// Current version from Cargo.toml.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -30,11 +49,48 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// Release track `tool self update` follows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseChannel {
+    /// Published releases only.
+    #[default]
+    Stable,
+    /// Prereleases as well as published releases.
+    Beta,
+}
+
+/// Persisted `tool self update` preferences.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UpdateState {
+    /// Channel chosen with `--channel`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    channel: Option<ReleaseChannel>,
+    /// Binary kept by the last update for `tool self rollback`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    backup: Option<BackupInfo>,
+}
+
+/// Binary replaced by the last self-update.
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupInfo {
+    /// Version of the backed-up binary.
+    version: String,
+    /// SHA-256 of the backup, checked before restoring it.
+    sha256: String,
+}
+
 /// GitHub release information.
 #[derive(Debug, Deserialize)]
 struct GitHubRelease {
     /// Tag name (e.g., "v0.1.2").
     tag_name: String,
+    /// Whether the release is marked as a prerelease.
+    #[serde(default)]
+    prerelease: bool,
+    /// Whether the release is an unpublished draft.
+    #[serde(default)]
+    draft: bool,
     /// Release assets.
     assets: Vec<GitHubAsset>,
 }
@@ -57,10 +113,37 @@ pub struct UpdateCheckResult {
     pub current: String,
     /// Latest version available.
     pub latest: String,
+    /// Channel the latest version was taken from.
+    pub channel: ReleaseChannel,
     /// Whether an update is available.
     pub update_available: bool,
 }
 
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl std::str::FromStr for ReleaseChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stable" => Ok(ReleaseChannel::Stable),
+            "beta" => Ok(ReleaseChannel::Beta),
+            _ => Err(format!("Unknown channel: '{}'. Use 'stable' or 'beta'.", s)),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReleaseChannel::Stable => write!(f, "stable"),
+            ReleaseChannel::Beta => write!(f, "beta"),
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -88,22 +171,80 @@ fn get_platform() -> ToolResult<&'static str> {
     }
 }
 
-/// Fetch the latest release information from GitHub.
-async fn fetch_latest_release(client: &Client) -> ToolResult<GitHubRelease> {
+/// Fetch the newest release on a channel.
+///
+/// Stable uses GitHub's latest release, which excludes prereleases. Beta takes the
+/// highest version among recent releases, prereleases included.
+async fn fetch_release(client: &Client, channel: ReleaseChannel) -> ToolResult<GitHubRelease> {
+    match channel {
+        ReleaseChannel::Stable => fetch_github(client, RELEASES_API_URL)
+            .await?
+            .ok_or_else(no_releases_error),
+        ReleaseChannel::Beta => {
+            let releases: Vec<GitHubRelease> = fetch_github(client, RELEASES_LIST_API_URL)
+                .await?
+                .ok_or_else(no_releases_error)?;
+            releases
+                .into_iter()
+                .filter(|r| !r.draft)
+                .filter_map(|r| Some((Version::parse(parse_version(&r.tag_name)).ok()?, r)))
+                .max_by(|(a, _), (b, _)| a.cmp(b))
+                .map(|(_, r)| r)
+                .ok_or_else(|| ToolError::Generic("No beta releases found.".into()))
+        }
+    }
+}
+
+/// Fetch the release for an exact version.
+///
+/// The version must be valid semver; a missing or draft release is an error.
+async fn fetch_release_by_version(client: &Client, version: &str) -> ToolResult<GitHubRelease> {
+    let url = format!("{}{}", RELEASE_BY_TAG_API_URL, version);
+    match fetch_github::<GitHubRelease>(client, &url).await? {
+        Some(release) if !release.draft => Ok(release),
+        _ => Err(ToolError::Generic(format!(
+            "Version {} is not available. See {} for published releases.",
+            version, RELEASES_PAGE_URL
+        ))),
+    }
+}
+
+/// Validate a `--version` argument, returning it without any `v` prefix.
+fn validate_target_version(version: &str) -> ToolResult<&str> {
+    let version = parse_version(version);
+    Version::parse(version).map_err(|e| {
+        ToolError::Generic(format!(
+            "Invalid version '{}': {}. Expected a semantic version such as 1.2.3.",
+            version, e
+        ))
+    })?;
+    Ok(version)
+}
+
+/// Error for an empty or inaccessible release feed.
+fn no_releases_error() -> ToolError {
+    ToolError::Generic(
+        "No releases found. The repository may be private or has no published releases yet.".into(),
+    )
+}
+
+/// Fetch and parse a GitHub releases API endpoint. Returns `None` on 404.
+async fn fetch_github<T: serde::de::DeserializeOwned>(
+    client: &Client,
+    url: &str,
+) -> ToolResult<Option<T>> {
     let response = client
-        .get(RELEASES_API_URL)
+        .get(url)
         .header("User-Agent", format!("tool-cli/{}", VERSION))
         .header("Accept", "application/vnd.github.v3+json")
         .send()
         .await
-        .map_err(|e| ToolError::Generic(format!("Failed to fetch release info: {}", e)))?;
+        .map_err(|e| ToolError::Network(format!("Failed to fetch release info: {}", e)))?;
 
     if !response.status().is_success() {
         let status = response.status();
         if status.as_u16() == 404 {
-            return Err(ToolError::Generic(
-                "No releases found. The repository may be private or has no published releases yet.".into()
-            ));
+            return Ok(None);
         }
         return Err(ToolError::Generic(format!(
             "GitHub API error: HTTP {}",
@@ -112,8 +253,9 @@ async fn fetch_latest_release(client: &Client) -> ToolResult<GitHubRelease> {
     }
 
     response
-        .json::<GitHubRelease>()
+        .json::<T>()
         .await
+        .map(Some)
         .map_err(|e| ToolError::Generic(format!("Failed to parse release info: {}", e)))
 }
 
@@ -123,30 +265,64 @@ fn parse_version(tag: &str) -> &str {
 }
 
 /// Compare two semantic versions. Returns true if `latest` is newer than `current`.
+///
+/// Prereleases sort before their release (`0.3.0-beta.1` < `0.3.0`).
 fn is_newer_version(current: &str, latest: &str) -> bool {
-    let parse = |v: &str| -> Option<(u32, u32, u32)> {
-        let parts: Vec<&str> = v.split('.').collect();
-        if parts.len() >= 3 {
-            Some((
-                parts[0].parse().ok()?,
-                parts[1].parse().ok()?,
-                parts[2].split('-').next()?.parse().ok()?,
-            ))
-        } else {
-            None
-        }
+    match (Version::parse(current), Version::parse(latest)) {
+        (Ok(curr), Ok(lat)) => lat > curr,
+        _ => false,
+    }
+}
+
+/// Whether a version has a prerelease tag (`0.3.0-beta.1`).
+fn is_prerelease(version: &str) -> bool {
+    Version::parse(version).is_ok_and(|v| !v.pre.is_empty())
+}
+
+/// Load persisted update preferences, falling back to defaults.
+fn load_update_state() -> UpdateState {
+    fs::read_to_string(&*SELF_UPDATE_STATE_PATH)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Persist update preferences.
+fn save_update_state(state: &UpdateState) -> ToolResult<()> {
+    if let Some(parent) = SELF_UPDATE_STATE_PATH.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(
+        &*SELF_UPDATE_STATE_PATH,
+        serde_json::to_string_pretty(state)?,
+    )?;
+    Ok(())
+}
+
+/// Resolve the release channel to use.
+///
+/// An explicit `--channel` is saved as the preference for later updates; otherwise the
+/// saved preference is used, defaulting to stable.
+pub fn resolve_channel(requested: Option<&str>) -> ToolResult<ReleaseChannel> {
+    let mut state = load_update_state();
+    let Some(requested) = requested else {
+        return Ok(state.channel.unwrap_or_default());
     };
 
-    match (parse(current), parse(latest)) {
-        (Some(curr), Some(lat)) => lat > curr,
-        _ => false,
+    let channel: ReleaseChannel = requested.parse().map_err(ToolError::Generic)?;
+    if state.channel != Some(channel) {
+        state.channel = Some(channel);
+        save_update_state(&state)?;
     }
+    Ok(channel)
 }
 
-/// Check for available updates.
-pub async fn check_for_update() -> ToolResult<UpdateCheckResult> {
+/// Check for available updates on a channel.
+pub async fn check_for_update(channel: ReleaseChannel) -> ToolResult<UpdateCheckResult> {
+    crate::registry::ensure_network("Update check")?;
+
     let client = Client::new();
-    let release = fetch_latest_release(&client).await?;
+    let release = fetch_release(&client, channel).await?;
     let latest = parse_version(&release.tag_name).to_string();
     let current = VERSION.to_string();
     let update_available = is_newer_version(&current, &latest);
@@ -154,6 +330,7 @@ pub async fn check_for_update() -> ToolResult<UpdateCheckResult> {
     Ok(UpdateCheckResult {
         current,
         latest,
+        channel,
         update_available,
     })
 }
@@ -165,7 +342,7 @@ async fn download_with_progress(client: &Client, url: &str, size: u64) -> ToolRe
         .header("User-Agent", format!("tool-cli/{}", VERSION))
         .send()
         .await
-        .map_err(|e| ToolError::Generic(format!("Download failed: {}", e)))?;
+        .map_err(|e| ToolError::Network(format!("Download failed: {}", e)))?;
 
     if !response.status().is_success() {
         return Err(ToolError::Generic(format!(
@@ -193,40 +370,154 @@ async fn download_with_progress(client: &Client, url: &str, size: u64) -> ToolRe
     Ok(bytes.to_vec())
 }
 
-/// Download and verify checksum.
+/// Download the SHA-256 checksum published alongside a release archive.
 async fn download_checksum(
     client: &Client,
     version: &str,
     archive_name: &str,
-) -> ToolResult<Option<String>> {
+) -> ToolResult<String> {
     let checksum_url = format!(
         "https://github.com/{}/releases/download/v{}/{}.sha256",
         GITHUB_REPO, version, archive_name
     );
 
-    match client
+    let response = client
         .get(&checksum_url)
         .header("User-Agent", format!("tool-cli/{}", VERSION))
         .send()
         .await
-    {
-        Ok(response) if response.status().is_success() => {
-            let text = response.text().await.unwrap_or_default();
-            // Extract just the hash (first field, space-separated)
-            Ok(Some(
-                text.split_whitespace().next().unwrap_or("").to_string(),
-            ))
-        }
-        _ => Ok(None),
+        .map_err(|e| ToolError::Network(format!("Failed to download checksum: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(ToolError::Generic(format!(
+            "No checksum published for {} (HTTP {})",
+            archive_name,
+            response.status()
+        )));
     }
+
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ToolError::Generic(format!("Failed to read checksum: {}", e)))?;
+    parse_checksum(&text)
+        .map(str::to_string)
+        .ok_or_else(|| ToolError::Generic(format!("Malformed checksum for {}", archive_name)))
 }
 
-/// Verify SHA256 checksum.
-fn verify_checksum(data: &[u8], expected: &str) -> bool {
+/// Extract the hash from a `sha256sum`-style line (`<hash>  <file>`).
+fn parse_checksum(text: &str) -> Option<&str> {
+    text.split_whitespace()
+        .next()
+        .filter(|hash| hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Hex-encoded SHA-256 of some bytes.
+fn sha256_hex(data: &[u8]) -> String {
     let mut hasher = Sha256::new();
     hasher.update(data);
-    let actual = format!("{:x}", hasher.finalize());
-    actual == expected
+    format!("{:x}", hasher.finalize())
+}
+
+/// Verify SHA256 checksum.
+fn verify_checksum(data: &[u8], expected: &str) -> bool {
+    sha256_hex(data).eq_ignore_ascii_case(expected.trim())
+}
+
+/// Verify the archive's minisign signature against `TOOL_UPDATE_PUBKEY`.
+///
+/// Returns `Ok(false)` when no public key is configured. Once a key is set, a missing
+/// signature or `minisign` binary is an error rather than a silent skip.
+async fn verify_signature(
+    client: &Client,
+    release: &GitHubRelease,
+    archive_name: &str,
+    tarball: &[u8],
+) -> ToolResult<bool> {
+    let Ok(public_key) = std::env::var(TOOL_UPDATE_PUBKEY_ENV) else {
+        return Ok(false);
+    };
+
+    let signature_name = format!("{}.{}", archive_name, SIGNATURE_EXTENSION);
+    let asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == signature_name)
+        .ok_or_else(|| {
+            ToolError::Generic(format!(
+                "{} is set but no signature ({}) is published for this release",
+                TOOL_UPDATE_PUBKEY_ENV, signature_name
+            ))
+        })?;
+    let signature = download_with_progress(client, &asset.browser_download_url, asset.size).await?;
+
+    // minisign verifies files on disk
+    let dir = std::env::temp_dir().join(format!("tool-update-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir)?;
+    let archive_path = dir.join(archive_name);
+    let signature_path = dir.join(&signature_name);
+    let result = (|| {
+        fs::write(&archive_path, tarball)?;
+        fs::write(&signature_path, &signature)?;
+        Command::new("minisign")
+            .arg("-V")
+            .arg("-P")
+            .arg(public_key.trim())
+            .arg("-m")
+            .arg(&archive_path)
+            .arg("-x")
+            .arg(&signature_path)
+            .arg("-q")
+            .status()
+    })();
+    let _ = fs::remove_dir_all(&dir);
+
+    match result {
+        Ok(status) if status.success() => Ok(true),
+        Ok(_) => Err(ToolError::Generic("Signature verification failed".into())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ToolError::Generic(format!(
+            "{} is set but `minisign` was not found on PATH",
+            TOOL_UPDATE_PUBKEY_ENV
+        ))),
+        Err(e) => Err(ToolError::Generic(format!("Failed to run minisign: {}", e))),
+    }
+}
+
+/// Verify a downloaded archive's checksum and, if configured, its signature.
+async fn verify_download(
+    client: &Client,
+    release: &GitHubRelease,
+    version: &str,
+    archive_name: &str,
+    tarball: &[u8],
+) -> ToolResult<()> {
+    let spinner = Spinner::with_indent("Verifying checksum", 2);
+    let checksum = match download_checksum(client, version, archive_name).await {
+        Ok(checksum) => checksum,
+        Err(e) => {
+            spinner.fail(Some("Checksum unavailable"));
+            return Err(e);
+        }
+    };
+    if !verify_checksum(tarball, &checksum) {
+        spinner.fail(Some("Checksum mismatch"));
+        return Err(ToolError::Generic(format!(
+            "Checksum verification failed for {}",
+            archive_name
+        )));
+    }
+    spinner.succeed(Some("Checksum verified"));
+
+    match verify_signature(client, release, archive_name, tarball).await {
+        Ok(true) => println!("  {} Signature verified", "✓".bright_green()),
+        Ok(false) => {}
+        Err(e) => {
+            println!("  {} Signature not verified", "✗".bright_red());
+            return Err(e);
+        }
+    }
+
+    Ok(())
 }
 
 /// Extract binary from tarball.
@@ -260,7 +551,26 @@ fn extract_binary(tarball: &[u8]) -> ToolResult<Vec<u8>> {
 }
 
 /// Perform the self-update.
-pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
+///
+/// The downloaded archive must match its published SHA-256 checksum (and minisign
+/// signature, when `TOOL_UPDATE_PUBKEY` is set) before the current binary is replaced.
+/// `no_verify` skips both checks and is meant only for trusted local testing.
+///
+/// Moving from a beta build to an older stable release is a downgrade and requires `force`.
+///
+/// `target_version` pins an exact release instead of the channel's newest one. With
+/// `dry_run`, the resolved target and its download are reported and nothing is changed.
+pub async fn self_update(
+    target_version: Option<&str>,
+    channel: ReleaseChannel,
+    force: bool,
+    no_verify: bool,
+    dry_run: bool,
+) -> ToolResult<()> {
+    let target_version = target_version.map(validate_target_version).transpose()?;
+
+    crate::registry::ensure_network("Self-update")?;
+
     if cfg!(windows) {
         return Err(ToolError::Generic(
             "Self-update is not supported on Windows yet. Reinstall with: cargo install --git https://github.com/zerocore-ai/tool-cli --locked".into(),
@@ -271,7 +581,11 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
     let spinner = Spinner::with_indent("Checking for updates", 2);
 
     let client = Client::new();
-    let release = match fetch_latest_release(&client).await {
+    let release = match target_version {
+        Some(version) => fetch_release_by_version(&client, version).await,
+        None => fetch_release(&client, channel).await,
+    };
+    let release = match release {
         Ok(release) => {
             spinner.succeed(Some("Checked for updates"));
             release
@@ -281,10 +595,7 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
             return Err(e);
         }
     };
-    let latest_version = parse_version(&release.tag_name);
-
-    // Determine target version
-    let version = target_version.unwrap_or(latest_version);
+    let version = parse_version(&release.tag_name);
 
     // Check if update is needed
     if version == VERSION && target_version.is_none() {
@@ -297,7 +608,8 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
         return Ok(());
     }
 
-    if !is_newer_version(VERSION, version) && target_version.is_none() {
+    let downgrade = is_newer_version(version, VERSION);
+    if target_version.is_none() && downgrade && !is_prerelease(VERSION) {
         println!(
             "  {} Already up to date ({})",
             "✓".bright_green(),
@@ -307,11 +619,20 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
         return Ok(());
     }
 
+    if target_version.is_none() && downgrade && !force {
+        return Err(ToolError::Generic(format!(
+            "Version {} is newer than the latest {} release ({}). Rerun with --force to downgrade.",
+            VERSION, channel, version
+        )));
+    }
+
     println!(
-        "  {} Update available: {} → {}",
+        "  {} {} available: {} → {} ({})",
         "✓".bright_green(),
+        if downgrade { "Downgrade" } else { "Update" },
         VERSION.dimmed(),
-        version.bright_cyan()
+        version.bright_cyan(),
+        channel.to_string().bright_white()
     );
     println!();
 
@@ -336,6 +657,25 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
             ))
         })?;
 
+    if dry_run {
+        println!("  · Current: {}", VERSION.bright_white());
+        println!(
+            "  · Target: {} ({})",
+            version.bright_cyan(),
+            if target_version.is_some() {
+                "pinned".to_string()
+            } else {
+                channel.to_string()
+            }
+        );
+        println!("  · Download: {}", asset.browser_download_url.dimmed());
+        println!("  · Size: {}", format_size(asset.size).bright_white());
+        println!();
+        println!("  {} Dry run; nothing was changed", "✓".bright_green());
+        println!();
+        return Ok(());
+    }
+
     // Download
     println!(
         "  {} Downloading {}",
@@ -345,15 +685,21 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
     let tarball = download_with_progress(&client, &asset.browser_download_url, asset.size).await?;
     println!("  {} Downloaded", "✓".bright_green());
 
-    // Verify checksum if available
-    if let Some(expected_hash) = download_checksum(&client, version, &archive_name).await? {
-        let spinner = Spinner::with_indent("Verifying checksum", 2);
-        if verify_checksum(&tarball, &expected_hash) {
-            spinner.succeed(Some("Checksum verified"));
-        } else {
-            spinner.fail(Some("Checksum mismatch"));
-            return Err(ToolError::Generic("Checksum verification failed".into()));
-        }
+    // Verify before anything touches the installed binary
+    if no_verify {
+        println!(
+            "  {} Skipping checksum and signature verification (--no-verify)",
+            "⚠".bright_yellow()
+        );
+    } else if let Err(e) =
+        verify_download(&client, &release, version, &archive_name, &tarball).await
+    {
+        println!();
+        println!(
+            "  {} Update aborted; the current binary was not changed",
+            "✗".bright_red()
+        );
+        return Err(e);
     }
 
     // Extract binary
@@ -369,20 +715,120 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
         }
     };
 
-    // Replace current executable
+    // Replace current executable, keeping the old one for `tool self rollback`
     let exe_path = current_exe_path()?;
-    let temp_path = exe_path.with_extension("new");
-    let backup_path = exe_path.with_extension("backup");
+    let backup_path = backup_path(&exe_path);
 
     let spinner = Spinner::with_indent("Installing", 2);
+    let install_result =
+        replace_executable(&exe_path, &binary, Some(&backup_path)).and_then(|backup_sha256| {
+            let mut state = load_update_state();
+            state.backup = backup_sha256.map(|sha256| BackupInfo {
+                version: VERSION.to_string(),
+                sha256,
+            });
+            save_update_state(&state)
+        });
 
-    // Installation closure to handle all steps
-    let install_result: ToolResult<()> = (|| {
+    match install_result {
+        Ok(()) => {
+            spinner.succeed(Some("Installed"));
+        }
+        Err(e) => {
+            spinner.fail(None);
+            return Err(e);
+        }
+    }
+    println!();
+    println!(
+        "  {} Updated to version {}",
+        "✓".bright_green().bold(),
+        version.bright_cyan().bold()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// Restore the binary replaced by the last self-update.
+///
+/// The backup's checksum must match the one recorded when it was taken.
+pub async fn self_rollback() -> ToolResult<()> {
+    println!();
+
+    let exe_path = current_exe_path()?;
+    let backup_path = backup_path(&exe_path);
+    let mut state = load_update_state();
+
+    let backup = match (&state.backup, backup_path.exists()) {
+        (Some(backup), true) => backup,
+        _ => {
+            return Err(ToolError::Generic(format!(
+                "No previous version to roll back to ({} not found)",
+                backup_path.display()
+            )));
+        }
+    };
+
+    let spinner = Spinner::with_indent("Verifying backup", 2);
+    let binary = fs::read(&backup_path)?;
+    if !verify_checksum(&binary, &backup.sha256) {
+        spinner.fail(Some("Backup checksum mismatch"));
+        return Err(ToolError::Generic(format!(
+            "Backup {} does not match its recorded checksum; refusing to restore it",
+            backup_path.display()
+        )));
+    }
+    spinner.succeed(Some("Backup verified"));
+
+    let restored = backup.version.clone();
+    let spinner = Spinner::with_indent("Restoring", 2);
+    if let Err(e) = replace_executable(&exe_path, &binary, None) {
+        spinner.fail(None);
+        return Err(e);
+    }
+    spinner.succeed(Some("Restored"));
+
+    // The backup is now the running binary
+    let _ = fs::remove_file(&backup_path);
+    state.backup = None;
+    save_update_state(&state)?;
+
+    println!();
+    println!(
+        "  {} Rolled back {} → {}",
+        "✓".bright_green().bold(),
+        VERSION.dimmed(),
+        restored.bright_cyan().bold()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// Path of the backup kept next to the executable (`tool.bak`).
+fn backup_path(exe_path: &Path) -> PathBuf {
+    exe_path.with_extension("bak")
+}
+
+/// Atomically replace the executable at `exe_path` with `binary`.
+///
+/// The new binary is written next to the executable and renamed over it, so the path
+/// always holds a complete binary. With `backup_path`, the current binary is copied there
+/// first and its SHA-256 is returned.
+fn replace_executable(
+    exe_path: &Path,
+    binary: &[u8],
+    backup_path: Option<&Path>,
+) -> ToolResult<Option<String>> {
+    let temp_path = exe_path.with_extension("new");
+
+    let result = (|| {
         // Write new binary to temp file
         {
             let mut file = File::create(&temp_path)
                 .map_err(|e| ToolError::Generic(format!("Failed to create temp file: {}", e)))?;
-            file.write_all(&binary)
+            file.write_all(binary)
                 .map_err(|e| ToolError::Generic(format!("Failed to write binary: {}", e)))?;
         }
 
@@ -394,48 +840,31 @@ pub async fn self_update(target_version: Option<&str>) -> ToolResult<()> {
                 .map_err(|e| ToolError::Generic(format!("Failed to set permissions: {}", e)))?;
         }
 
-        // Backup current, move new into place
-        if exe_path.exists() {
-            fs::rename(&exe_path, &backup_path).map_err(|e| {
-                ToolError::Generic(format!("Failed to backup current binary: {}", e))
-            })?;
-        }
-
-        if let Err(e) = fs::rename(&temp_path, &exe_path) {
-            // Restore backup on failure
-            if backup_path.exists() {
-                let _ = fs::rename(&backup_path, &exe_path);
+        // Copy the current binary aside; it stays in place until the rename
+        let backup_sha256 = match backup_path {
+            Some(backup_path) if exe_path.exists() => {
+                let current = fs::read(exe_path).map_err(|e| {
+                    ToolError::Generic(format!("Failed to backup current binary: {}", e))
+                })?;
+                fs::write(backup_path, &current).map_err(|e| {
+                    ToolError::Generic(format!("Failed to backup current binary: {}", e))
+                })?;
+                Some(sha256_hex(&current))
             }
-            return Err(ToolError::Generic(format!(
-                "Failed to install new binary: {}",
-                e
-            )));
-        }
+            _ => None,
+        };
 
-        // Remove backup
-        let _ = fs::remove_file(&backup_path);
+        // Rename over the executable in one step
+        fs::rename(&temp_path, exe_path)
+            .map_err(|e| ToolError::Generic(format!("Failed to install new binary: {}", e)))?;
 
-        Ok(())
+        Ok(backup_sha256)
     })();
 
-    match install_result {
-        Ok(()) => {
-            spinner.succeed(Some("Installed"));
-        }
-        Err(e) => {
-            spinner.fail(None);
-            return Err(e);
-        }
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
     }
-    println!();
-    println!(
-        "  {} Updated to version {}",
-        "✓".bright_green().bold(),
-        version.bright_cyan().bold()
-    );
-    println!();
-
-    Ok(())
+    result
 }
 
 /// Uninstall tool-cli.
@@ -474,6 +903,7 @@ pub async fn self_uninstall(skip_confirm: bool) -> ToolResult<()> {
     // On Unix, we can remove a running executable - it stays in memory until process exits
     fs::remove_file(&exe_path)
         .map_err(|e| ToolError::Generic(format!("Failed to remove binary: {}", e)))?;
+    let _ = fs::remove_file(backup_path(&exe_path));
 
     println!(
         "  {} Removed {}",
@@ -489,3 +919,83 @@ pub async fn self_uninstall(skip_confirm: bool) -> ToolResult<()> {
 
     Ok(())
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    #[test]
+    fn parse_checksum_reads_sha256sum_lines() {
+        let line = format!("{}  tool-0.2.0-linux-x86_64.tar.gz\n", HELLO_SHA256);
+        assert_eq!(parse_checksum(&line), Some(HELLO_SHA256));
+        assert_eq!(parse_checksum(HELLO_SHA256), Some(HELLO_SHA256));
+        assert_eq!(parse_checksum(""), None);
+        assert_eq!(parse_checksum("<html>Not Found</html>"), None);
+    }
+
+    #[test]
+    fn is_newer_version_orders_prereleases() {
+        assert!(is_newer_version("0.2.0", "0.3.0-beta.1"));
+        assert!(is_newer_version("0.3.0-beta.1", "0.3.0"));
+        assert!(is_newer_version("0.3.0-beta.1", "0.3.0-beta.2"));
+        assert!(!is_newer_version("0.3.0-beta.1", "0.2.0"));
+        assert!(!is_newer_version("0.2.0", "not-a-version"));
+    }
+
+    #[test]
+    fn validate_target_version_requires_semver() {
+        assert_eq!(validate_target_version("v0.2.0").unwrap(), "0.2.0");
+        assert_eq!(
+            validate_target_version("0.3.0-beta.1").unwrap(),
+            "0.3.0-beta.1"
+        );
+        assert!(validate_target_version("0.2").is_err());
+        assert!(validate_target_version("latest").is_err());
+    }
+
+    #[test]
+    fn release_channel_parses_case_insensitively() {
+        assert_eq!("Beta".parse::<ReleaseChannel>(), Ok(ReleaseChannel::Beta));
+        assert_eq!(
+            "stable".parse::<ReleaseChannel>(),
+            Ok(ReleaseChannel::Stable)
+        );
+        assert!("nightly".parse::<ReleaseChannel>().is_err());
+        assert!(is_prerelease("0.3.0-beta.1"));
+        assert!(!is_prerelease("0.3.0"));
+    }
+
+    #[test]
+    fn replace_executable_keeps_verifiable_backup() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let exe = dir.path().join("tool");
+        fs::write(&exe, b"old").unwrap();
+
+        let backup = backup_path(&exe);
+        let sha256 = replace_executable(&exe, b"new", Some(&backup))
+            .unwrap()
+            .unwrap();
+        assert_eq!(fs::read(&exe).unwrap(), b"new");
+        assert_eq!(fs::read(&backup).unwrap(), b"old");
+        assert!(verify_checksum(b"old", &sha256));
+        assert!(!exe.with_extension("new").exists());
+
+        // Restoring reuses the same swap without taking another backup
+        assert_eq!(replace_executable(&exe, b"old", None).unwrap(), None);
+        assert_eq!(fs::read(&exe).unwrap(), b"old");
+    }
+
+    #[test]
+    fn verify_checksum_rejects_mismatch() {
+        assert!(verify_checksum(b"hello", HELLO_SHA256));
+        assert!(verify_checksum(b"hello", &HELLO_SHA256.to_uppercase()));
+        assert!(!verify_checksum(b"hello!", HELLO_SHA256));
+        assert!(!verify_checksum(b"hello", ""));
+    }
+}
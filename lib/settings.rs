@@ -0,0 +1,72 @@
+//! Global settings read from `~/.tool/settings.json`.
+//!
+//! Settings are for behaviour that applies across tools; per-tool values live under
+//! `tool config`. Environment variables override the file so a single command can opt in
+//! or out.
+
+use crate::constants::DEFAULT_SETTINGS_PATH;
+use serde::Deserialize;
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Contents of the global settings file.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Hard-link identical installed files to the shared object store.
+    pub dedup: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Settings {
+    /// Load `~/.tool/settings.json`, or the defaults if it doesn't exist.
+    pub fn load() -> Self {
+        Self::load_from(&DEFAULT_SETTINGS_PATH)
+    }
+
+    /// Load settings from `path`. A missing file gives the defaults; an unreadable or
+    /// invalid one is logged and ignored rather than failing every command.
+    pub fn load_from(path: &Path) -> Self {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(e) => {
+                tracing::warn!("Failed to read {}: {}", path.display(), e);
+                return Self::default();
+            }
+        };
+
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring invalid {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("settings.json");
+        assert!(!Settings::load_from(&path).dedup);
+
+        std::fs::write(&path, r#"{"dedup": true}"#).unwrap();
+        assert!(Settings::load_from(&path).dedup);
+
+        std::fs::write(&path, "{not json").unwrap();
+        assert!(!Settings::load_from(&path).dedup);
+    }
+}
@@ -1,8 +1,10 @@
 //! CLI styles for clap.
 
+use crate::constants::NO_COLOR_ENV;
 use clap::builder::styling::{AnsiColor, Color, Style, Styles};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::io::IsTerminal;
 use std::time::Duration;
 
 //--------------------------------------------------------------------------------------------------
@@ -41,6 +43,9 @@ impl Spinner {
     pub fn with_indent(message: impl Into<String>, indent: usize) -> Self {
         let message = message.into();
         let pb = ProgressBar::new_spinner();
+        if crate::progress::is_progress_json() {
+            pb.set_draw_target(indicatif::ProgressDrawTarget::hidden());
+        }
         let template = format!("{:indent$}{{spinner:.cyan}} {{msg}}", "", indent = indent);
         pb.set_style(
             ProgressStyle::default_spinner()
@@ -135,6 +140,32 @@ macro_rules! examples_section {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Whether colors were turned off by `--no-color` or a non-empty `NO_COLOR`.
+pub fn no_color_requested(no_color_flag: bool) -> bool {
+    no_color_flag || std::env::var_os(NO_COLOR_ENV).is_some_and(|v| !v.is_empty())
+}
+
+/// Configure colored output for the whole process. Call once, before printing anything.
+///
+/// With `--no-color` or `NO_COLOR`, colors are off everywhere: `colored` strings and
+/// indicatif progress/spinner templates. Otherwise colors stay on only while stdout is a
+/// terminal, so piped or redirected output carries no escape sequences.
+pub fn init_colors(no_color_flag: bool) {
+    if no_color_requested(no_color_flag) {
+        disable_colors();
+    } else if !std::io::stdout().is_terminal() {
+        colored::control::set_override(false);
+        console::set_colors_enabled(false);
+    }
+}
+
+/// Turn off ANSI colors for `colored` output and indicatif templates on both streams.
+pub fn disable_colors() {
+    colored::control::set_override(false);
+    console::set_colors_enabled(false);
+    console::set_colors_enabled_stderr(false);
+}
+
 pub fn styles() -> Styles {
     Styles::styled()
         .header(
@@ -165,3 +196,28 @@ pub fn styles() -> Styles {
                 .fg_color(Some(Color::Ansi(AnsiColor::Green))),
         )
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disable_colors_strips_escape_sequences() {
+        disable_colors();
+
+        let output = format!(
+            "  {} {} {}",
+            "✓".bright_green(),
+            "tool".bold(),
+            "done".dimmed()
+        );
+        assert_eq!(output, "  ✓ tool done");
+
+        let styled = console::style("working").cyan().to_string();
+        assert!(!styled.contains('\x1b'));
+    }
+}
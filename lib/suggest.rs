@@ -1,6 +1,8 @@
 //! Fuzzy matching utilities for tool suggestions.
 
 use rmcp::service::ServiceError;
+use serde_json::Value;
+use std::collections::BTreeMap;
 use strsim::jaro_winkler;
 
 //--------------------------------------------------------------------------------------------------
@@ -268,6 +270,111 @@ pub fn extract_params_from_schema(input_schema: &serde_json::Value) -> Vec<Param
     params
 }
 
+/// Check call arguments against a tool's input schema.
+///
+/// Coerces CLI values whose JSON type doesn't match the schema where unambiguous
+/// (e.g. `"5"` for a number, `123` for a string) and returns a message per problem.
+pub fn check_arguments_against_schema(
+    arguments: &mut BTreeMap<String, Value>,
+    input_schema: &Value,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    let properties = input_schema.get("properties").and_then(|p| p.as_object());
+
+    for param in extract_params_from_schema(input_schema) {
+        if param.required && !arguments.contains_key(&param.name) {
+            problems.push(format!(
+                "missing required parameter '{}' ({})",
+                param.name, param.param_type
+            ));
+        }
+    }
+
+    for (name, value) in arguments.iter_mut() {
+        let Some(prop) = properties.and_then(|p| p.get(name)) else {
+            if input_schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                problems.push(format!("unknown parameter '{}'", name));
+            }
+            continue;
+        };
+
+        let expected = schema_types(prop);
+        if expected.is_empty() || expected.iter().any(|t| value_matches_type(value, t)) {
+            continue;
+        }
+
+        match expected.iter().find_map(|t| coerce_value(value, t)) {
+            Some(coerced) => *value = coerced,
+            None => problems.push(format!(
+                "parameter '{}' expects {}, got {}",
+                name,
+                expected.join(" or "),
+                value_type_name(value)
+            )),
+        }
+    }
+
+    problems
+}
+
+/// Get the declared type(s) of a schema property.
+fn schema_types(prop: &Value) -> Vec<&str> {
+    match prop.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Check whether a value satisfies a JSON Schema type.
+fn value_matches_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+/// Convert a CLI value to a schema type when the conversion is lossless.
+fn coerce_value(value: &Value, schema_type: &str) -> Option<Value> {
+    match (schema_type, value) {
+        ("string", Value::Number(n)) => Some(Value::String(n.to_string())),
+        ("string", Value::Bool(b)) => Some(Value::String(b.to_string())),
+        ("number", Value::String(s)) => s
+            .trim()
+            .parse::<serde_json::Number>()
+            .ok()
+            .map(Value::Number),
+        ("integer", Value::String(s)) => s.trim().parse::<i64>().ok().map(Value::from),
+        ("boolean", Value::String(s)) => match s.trim() {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Name of a JSON value's type as used in JSON Schema.
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -375,4 +482,59 @@ mod tests {
         assert_eq!(params[2].name, "limit");
         assert!(!params[2].required);
     }
+
+    #[test]
+    fn test_check_arguments_against_schema() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "path": { "type": "string" },
+                "limit": { "type": "integer" },
+                "recursive": { "type": "boolean" },
+                "tags": { "type": "array" }
+            },
+            "required": ["path"]
+        });
+
+        // Missing required parameter
+        let mut args = BTreeMap::new();
+        let problems = check_arguments_against_schema(&mut args, &schema);
+        assert_eq!(problems, vec!["missing required parameter 'path' (string)"]);
+
+        // CLI values are coerced to the declared types
+        let mut args = BTreeMap::from([
+            ("path".to_string(), serde_json::json!(123)),
+            ("limit".to_string(), serde_json::json!("10")),
+            ("recursive".to_string(), serde_json::json!("true")),
+        ]);
+        assert!(check_arguments_against_schema(&mut args, &schema).is_empty());
+        assert_eq!(args["path"], serde_json::json!("123"));
+        assert_eq!(args["limit"], serde_json::json!(10));
+        assert_eq!(args["recursive"], serde_json::json!(true));
+
+        // Values that can't be coerced are reported
+        let mut args = BTreeMap::from([
+            ("path".to_string(), serde_json::json!("/tmp")),
+            ("tags".to_string(), serde_json::json!("a")),
+        ]);
+        assert_eq!(
+            check_arguments_against_schema(&mut args, &schema),
+            vec!["parameter 'tags' expects array, got string"]
+        );
+    }
+
+    #[test]
+    fn test_check_arguments_rejects_unknown_when_closed() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": { "path": { "type": "string" } },
+            "additionalProperties": false
+        });
+
+        let mut args = BTreeMap::from([("pth".to_string(), serde_json::json!("/tmp"))]);
+        assert_eq!(
+            check_arguments_against_schema(&mut args, &schema),
+            vec!["unknown parameter 'pth'"]
+        );
+    }
 }
@@ -1,9 +1,12 @@
-//! Tree view generator for CLI commands.
+//! Tree view generator for CLI commands and bundle files.
 //!
-//! Generates a colored tree view of all commands and options.
+//! Generates a colored tree view of all commands and options, and a plain file tree
+//! with per-file sizes for bundle previews.
 
+use crate::format::format_size;
 use clap::Command;
 use colored::*;
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 //--------------------------------------------------------------------------------------------------
@@ -18,6 +21,22 @@ pub struct TreeBuilder {
     prefix_cache: Vec<String>,
 }
 
+/// A tree of files with sizes, rendered with directories listed before files.
+#[derive(Debug, Default)]
+pub struct FileTree {
+    root: FileNode,
+}
+
+/// A directory or file within a [`FileTree`].
+#[derive(Debug, Default)]
+struct FileNode {
+    /// File size in bytes (`None` for directories).
+    size: Option<u64>,
+
+    /// Child entries keyed by name.
+    children: BTreeMap<String, FileNode>,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -463,6 +482,84 @@ impl TreeBuilder {
     }
 }
 
+impl FileTree {
+    /// Create an empty file tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file by its `/`-separated relative path.
+    pub fn insert(&mut self, path: &str, size: u64) {
+        let mut node = &mut self.root;
+        for part in path.split('/').filter(|p| !p.is_empty()) {
+            node = node.children.entry(part.to_string()).or_default();
+        }
+        node.size = Some(size);
+    }
+
+    /// Total size of all files in bytes.
+    pub fn total_size(&self) -> u64 {
+        self.root.total_size()
+    }
+
+    /// Render the tree under `root_name`, with sizes aligned in a column.
+    pub fn render(&self, root_name: &str) -> String {
+        let mut lines = Vec::new();
+        self.root.collect_lines("", &mut lines);
+
+        let width = lines
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut output = format!("{}\n", root_name);
+        for (label, size) in lines {
+            let padding = width - label.chars().count();
+            writeln!(
+                &mut output,
+                "{}{:padding$}  {:>8}",
+                label,
+                "",
+                format_size(size)
+            )
+            .unwrap();
+        }
+        output
+    }
+}
+
+impl FileNode {
+    fn is_dir(&self) -> bool {
+        self.size.is_none()
+    }
+
+    fn total_size(&self) -> u64 {
+        self.size.unwrap_or(0) + self.children.values().map(|c| c.total_size()).sum::<u64>()
+    }
+
+    fn collect_lines(&self, prefix: &str, lines: &mut Vec<(String, u64)>) {
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by_key(|(_, node)| !node.is_dir());
+
+        let count = children.len();
+        for (i, (name, node)) in children.into_iter().enumerate() {
+            let is_last = i + 1 == count;
+            let branch = if is_last { "└── " } else { "├── " };
+            let suffix = if node.is_dir() { "/" } else { "" };
+            lines.push((
+                format!("{}{}{}{}", prefix, branch, name, suffix),
+                node.total_size(),
+            ));
+
+            if node.is_dir() {
+                let child_prefix = if is_last { "    " } else { "│   " };
+                node.collect_lines(&format!("{}{}", prefix, child_prefix), lines);
+            }
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions
 //--------------------------------------------------------------------------------------------------
@@ -535,3 +632,35 @@ fn find_deepest_command_from_args(cmd: &Command, args: &[String]) -> (Vec<String
 
     (path, current_cmd)
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_tree_render() {
+        let mut tree = FileTree::new();
+        tree.insert("manifest.json", 120);
+        tree.insert("server/index.js", 2_500);
+        tree.insert("server/lib/util.js", 300);
+        tree.insert("README.md", 40);
+
+        assert_eq!(tree.total_size(), 2_960);
+
+        let rendered = tree.render("my-tool/");
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "my-tool/");
+        assert!(lines[1].starts_with("├── server/"));
+        assert!(lines[1].ends_with("2.8 KB"));
+        assert!(lines[2].starts_with("│   ├── lib/"));
+        assert!(lines[3].starts_with("│   │   └── util.js"));
+        assert!(lines[4].starts_with("│   └── index.js"));
+        assert!(lines[5].starts_with("├── README.md"));
+        assert!(lines[6].starts_with("└── manifest.json"));
+        assert!(lines[6].ends_with("120 B"));
+    }
+}
@@ -1,6 +1,6 @@
 //! Validation error and warning codes.
 
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use std::fmt;
 
 //--------------------------------------------------------------------------------------------------
@@ -99,6 +99,10 @@ pub enum ErrorCode {
     /// E021: Icon src field is required and cannot be empty.
     #[serde(rename = "E021")]
     MissingIconSrc,
+
+    /// E022: mcp_config declares fields that conflict with the server transport.
+    #[serde(rename = "E022")]
+    TransportConfigConflict,
 }
 
 /// Validation warning codes.
@@ -178,8 +182,26 @@ pub enum WarningCode {
     /// W019: Icon file is not PNG format (MCPB spec recommends PNG).
     #[serde(rename = "W019")]
     NonPngIcon,
+
+    /// W020: Declared tool, prompt or resource is not exposed by the running server.
+    #[serde(rename = "W020")]
+    DeclaredCapabilityMissing,
+
+    /// W021: Running server exposes a tool, prompt or resource the manifest doesn't declare.
+    #[serde(rename = "W021")]
+    UndeclaredCapability,
+
+    /// W022: Declared tool input schema differs from the running server's.
+    #[serde(rename = "W022")]
+    ToolSignatureMismatch,
 }
 
+/// Code for issues reported by custom validators.
+///
+/// Displayed as `X001`, `X002`, ... so they never collide with built-in `E`/`W` codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CustomCode(pub u16);
+
 /// A validation code that can be either an error or warning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(untagged)]
@@ -188,6 +210,8 @@ pub enum ValidationCode {
     Error(ErrorCode),
     /// A warning code.
     Warning(WarningCode),
+    /// A code from a custom validator (error or warning depending on where it's reported).
+    Custom(CustomCode),
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -219,6 +243,7 @@ impl fmt::Display for ErrorCode {
             ErrorCode::ExtraFieldsInStandardField => "E019",
             ErrorCode::InvalidIconSize => "E020",
             ErrorCode::MissingIconSrc => "E021",
+            ErrorCode::TransportConfigConflict => "E022",
         };
         write!(f, "{}", code)
     }
@@ -245,16 +270,32 @@ impl fmt::Display for WarningCode {
             WarningCode::MissingMcpbIgnore => "W017",
             WarningCode::ReservedScriptName => "W018",
             WarningCode::NonPngIcon => "W019",
+            WarningCode::DeclaredCapabilityMissing => "W020",
+            WarningCode::UndeclaredCapability => "W021",
+            WarningCode::ToolSignatureMismatch => "W022",
         };
         write!(f, "{}", code)
     }
 }
 
+impl fmt::Display for CustomCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X{:03}", self.0)
+    }
+}
+
+impl Serialize for CustomCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl fmt::Display for ValidationCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ValidationCode::Error(e) => write!(f, "{}", e),
             ValidationCode::Warning(w) => write!(f, "{}", w),
+            ValidationCode::Custom(c) => write!(f, "{}", c),
         }
     }
 }
@@ -270,3 +311,9 @@ impl From<WarningCode> for ValidationCode {
         ValidationCode::Warning(code)
     }
 }
+
+impl From<CustomCode> for ValidationCode {
+    fn from(code: CustomCode) -> Self {
+        ValidationCode::Custom(code)
+    }
+}
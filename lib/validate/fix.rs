@@ -0,0 +1,163 @@
+//! Automatic fixes for common, mechanical manifest issues.
+
+use serde_json::Value;
+
+use super::validators::is_valid_package_name;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Manifest version written when the field is missing.
+pub const DEFAULT_MANIFEST_VERSION: &str = "0.3";
+
+/// Tool version written when the field is missing.
+pub const DEFAULT_TOOL_VERSION: &str = "0.1.0";
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A single change applied by [`fix_manifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestFix {
+    /// Field path that was changed (e.g. `icons[0].src`).
+    pub field: String,
+
+    /// Previous value, or `None` if the field was missing.
+    pub before: Option<String>,
+
+    /// New value.
+    pub after: String,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Apply safe, unambiguous fixes to a raw manifest.
+///
+/// Only touches fields whose correct value can be derived mechanically. Semantic
+/// fields like the entry point are left for the author to fix.
+pub fn fix_manifest(raw: &mut Value) -> Vec<ManifestFix> {
+    let mut fixes = Vec::new();
+    let Some(obj) = raw.as_object_mut() else {
+        return fixes;
+    };
+
+    if !obj.get("manifest_version").is_some_and(Value::is_string) {
+        obj.insert(
+            "manifest_version".into(),
+            Value::String(DEFAULT_MANIFEST_VERSION.into()),
+        );
+        fixes.push(ManifestFix {
+            field: "manifest_version".into(),
+            before: None,
+            after: DEFAULT_MANIFEST_VERSION.into(),
+        });
+    }
+
+    match obj.get("version") {
+        None => {
+            obj.insert("version".into(), Value::String(DEFAULT_TOOL_VERSION.into()));
+            fixes.push(ManifestFix {
+                field: "version".into(),
+                before: None,
+                after: DEFAULT_TOOL_VERSION.into(),
+            });
+        }
+        Some(Value::String(version)) => {
+            if let Some(fixed) = fix_version(version) {
+                fixes.push(ManifestFix {
+                    field: "version".into(),
+                    before: Some(version.clone()),
+                    after: fixed.clone(),
+                });
+                obj.insert("version".into(), Value::String(fixed));
+            }
+        }
+        Some(_) => {}
+    }
+
+    if let Some(Value::String(name)) = obj.get("name")
+        && let Some(fixed) = fix_name(name)
+    {
+        fixes.push(ManifestFix {
+            field: "name".into(),
+            before: Some(name.clone()),
+            after: fixed.clone(),
+        });
+        obj.insert("name".into(), Value::String(fixed));
+    }
+
+    if let Some(icon) = obj.get_mut("icon") {
+        fix_path(icon, "icon", &mut fixes);
+    }
+
+    if let Some(Value::Array(icons)) = obj.get_mut("icons") {
+        for (i, icon) in icons.iter_mut().enumerate() {
+            if let Some(src) = icon.get_mut("src") {
+                fix_path(src, &format!("icons[{}].src", i), &mut fixes);
+            }
+        }
+    }
+
+    fixes
+}
+
+/// Strip a leading `v` and surrounding whitespace if that yields valid semver.
+fn fix_version(version: &str) -> Option<String> {
+    if semver::Version::parse(version).is_ok() {
+        return None;
+    }
+
+    let trimmed = version.trim();
+    let trimmed = trimmed
+        .strip_prefix('v')
+        .or_else(|| trimmed.strip_prefix('V'))
+        .unwrap_or(trimmed);
+    semver::Version::parse(trimmed)
+        .ok()
+        .map(|_| trimmed.to_string())
+}
+
+/// Lowercase a name and replace separators with dashes if that yields a valid name.
+fn fix_name(name: &str) -> Option<String> {
+    if is_valid_package_name(name) {
+        return None;
+    }
+
+    let mut fixed = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        match c {
+            'a'..='z' | '0'..='9' => fixed.push(c),
+            'A'..='Z' => fixed.push(c.to_ascii_lowercase()),
+            '-' | '_' | ' ' | '.' if !fixed.is_empty() && !fixed.ends_with('-') => fixed.push('-'),
+            '-' | '_' | ' ' | '.' => {}
+            // Anything else can't be mapped without guessing
+            _ => return None,
+        }
+    }
+    let fixed = fixed.trim_end_matches('-').to_string();
+
+    is_valid_package_name(&fixed).then_some(fixed)
+}
+
+/// Strip trailing slashes from a file path value.
+fn fix_path(value: &mut Value, field: &str, fixes: &mut Vec<ManifestFix>) {
+    let Value::String(path) = value else {
+        return;
+    };
+
+    let trimmed = path.trim_end_matches(['/', '\\']);
+    if trimmed.len() == path.len() || trimmed.is_empty() {
+        return;
+    }
+
+    fixes.push(ManifestFix {
+        field: field.to_string(),
+        before: Some(path.clone()),
+        after: trimmed.to_string(),
+    });
+    *value = Value::String(trimmed.to_string());
+}
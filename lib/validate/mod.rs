@@ -1,7 +1,11 @@
 //! Tool manifest validation for MCPB format.
 
 mod codes;
+mod fix;
+mod registry;
 mod result;
+mod sarif;
+mod spans;
 
 pub mod validators;
 
@@ -12,6 +16,11 @@ mod tests;
 // Re-Exports
 //--------------------------------------------------------------------------------------------------
 
-pub use codes::{ErrorCode, ValidationCode, WarningCode};
+pub use codes::{CustomCode, ErrorCode, ValidationCode, WarningCode};
+pub use fix::{DEFAULT_MANIFEST_VERSION, DEFAULT_TOOL_VERSION, ManifestFix, fix_manifest};
+pub use registry::{ManifestValidator, register_validator};
 pub use result::{ValidationIssue, ValidationResult};
-pub use validators::{is_valid_package_name, validate_manifest};
+pub use sarif::to_sarif;
+pub(crate) use spans::annotate_positions;
+pub(crate) use validators::validate_manifest_fields;
+pub use validators::{is_valid_package_name, validate_manifest, validate_manifest_content};
@@ -0,0 +1,72 @@
+//! Registration of custom manifest validators.
+//!
+//! Downstream binaries can enforce their own policies (e.g. a mandatory license) by
+//! registering a [`ManifestValidator`]. Registered validators run after the built-in
+//! checks in [`validate_manifest`](super::validate_manifest) and should report issues
+//! with [`CustomCode`](super::CustomCode)s.
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::mcpb::McpbManifest;
+
+use super::result::ValidationResult;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A custom manifest check that runs alongside the built-in validators.
+pub trait ManifestValidator: Send + Sync {
+    /// Check the manifest, pushing issues to `result.errors` or `result.warnings`.
+    fn validate(&self, dir: &Path, manifest: &McpbManifest, result: &mut ValidationResult);
+}
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Validators registered by the embedding binary.
+static CUSTOM_VALIDATORS: RwLock<Vec<Arc<dyn ManifestValidator>>> = RwLock::new(Vec::new());
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Register a validator to run on every manifest validation.
+pub fn register_validator(validator: impl ManifestValidator + 'static) {
+    CUSTOM_VALIDATORS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Arc::new(validator));
+}
+
+/// Run all registered validators.
+pub(crate) fn run_custom_validators(
+    dir: &Path,
+    manifest: &McpbManifest,
+    result: &mut ValidationResult,
+) {
+    // Clone the list so validators may themselves register or validate
+    let validators = CUSTOM_VALIDATORS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+
+    for validator in validators {
+        validator.validate(dir, manifest, result);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl<F> ManifestValidator for F
+where
+    F: Fn(&Path, &McpbManifest, &mut ValidationResult) + Send + Sync,
+{
+    fn validate(&self, dir: &Path, manifest: &McpbManifest, result: &mut ValidationResult) {
+        self(dir, manifest, result)
+    }
+}
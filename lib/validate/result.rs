@@ -35,6 +35,14 @@ pub struct ValidationIssue {
     /// Optional help suggestion.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help: Option<String>,
+
+    /// 1-based line in manifest.json of the offending key or value, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    /// 1-based column in manifest.json of the offending key or value, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -52,3 +60,36 @@ impl ValidationResult {
         self.errors.is_empty() && self.warnings.is_empty()
     }
 }
+
+impl ValidationIssue {
+    /// Create an issue without help or a source position.
+    pub fn new(
+        code: impl Into<ValidationCode>,
+        message: impl Into<String>,
+        location: impl Into<String>,
+        details: impl Into<String>,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            location: location.into(),
+            details: details.into(),
+            help: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Attach a help suggestion.
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Attach the 1-based line and column of the offending key or value.
+    pub fn with_position(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+}
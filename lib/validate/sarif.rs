@@ -0,0 +1,122 @@
+//! SARIF output for validation results (for code scanning integrations).
+
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+use super::result::{ValidationIssue, ValidationResult};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// SARIF specification version emitted.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF JSON schema location.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Convert a validation result into a SARIF log.
+///
+/// `manifest_uri` is the manifest path reported to the consumer and `content` its source,
+/// used to place each issue on a best-effort line. With `strict`, warnings are reported
+/// as errors.
+pub fn to_sarif(
+    result: &ValidationResult,
+    manifest_uri: &str,
+    content: &str,
+    strict: bool,
+) -> Value {
+    let issues = result.errors.iter().map(|e| ("error", e)).chain(
+        result
+            .warnings
+            .iter()
+            .map(|w| (if strict { "error" } else { "warning" }, w)),
+    );
+
+    let mut rules = BTreeMap::new();
+    let mut results = Vec::new();
+    for (level, issue) in issues {
+        let rule_id = issue.code.to_string();
+        rules.entry(rule_id.clone()).or_insert_with(|| {
+            json!({
+                "id": rule_id,
+                "shortDescription": { "text": issue.message },
+            })
+        });
+
+        results.push(json!({
+            "ruleId": rule_id,
+            "level": level,
+            "message": { "text": result_message(issue) },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": manifest_uri },
+                    "region": region(issue, content),
+                },
+                "logicalLocations": [{ "fullyQualifiedName": issue.location }],
+            }],
+        }));
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Source region for an issue, falling back to a line search when no position is known.
+fn region(issue: &ValidationIssue, content: &str) -> Value {
+    match (issue.line, issue.column) {
+        (Some(line), Some(column)) => json!({ "startLine": line, "startColumn": column }),
+        _ => json!({ "startLine": find_issue_line(content, &issue.location) }),
+    }
+}
+
+/// Message text combining the issue details and help.
+fn result_message(issue: &ValidationIssue) -> String {
+    let mut text = format!("{}: {}", issue.message, issue.details);
+    if let Some(help) = &issue.help {
+        text.push_str(&format!(" (help: {})", help));
+    }
+    text
+}
+
+/// Best-effort 1-based line of the innermost key named in an issue location.
+///
+/// Locations look like `manifest.json:server.mcp_config`; falls back to line 1.
+pub(crate) fn find_issue_line(content: &str, location: &str) -> usize {
+    let Some((_, path)) = location.split_once(':') else {
+        return 1;
+    };
+
+    // Search for each key in turn so nested keys are found under their parent
+    let mut line = 0;
+    let lines: Vec<&str> = content.lines().collect();
+    for key in path.split('.') {
+        let key = key.split('[').next().unwrap_or(key);
+        if key.is_empty() {
+            continue;
+        }
+        let needle = format!("\"{}\"", key);
+        match lines[line..].iter().position(|l| l.contains(&needle)) {
+            Some(offset) => line += offset,
+            None => break,
+        }
+    }
+
+    line + 1
+}
@@ -0,0 +1,333 @@
+//! Source positions for issue locations in manifest.json.
+//!
+//! A small position-tracking JSON scanner: `serde_json` drops spans, and locating an
+//! issue only needs the structure plus where each key and element starts.
+
+use super::result::ValidationResult;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// 1-based line and column.
+type Position = (usize, usize);
+
+/// A JSON value with the positions of its keys and elements.
+#[derive(Debug)]
+enum Node {
+    /// Entries as (key, key position, value).
+    Object(Vec<(String, Position, Node)>),
+
+    /// Elements as (element position, value).
+    Array(Vec<(Position, Node)>),
+
+    /// String value (kept for `[name="x"]` selectors).
+    String(String),
+
+    /// Number, boolean or null.
+    Other,
+}
+
+/// One step of an issue location path.
+#[derive(Debug, PartialEq)]
+enum Segment {
+    /// Unquoted key part; consecutive parts may join into a dotted key.
+    Key(String),
+
+    /// Bracketed key (`["store.tool.mcpb"]`).
+    QuotedKey(String),
+
+    /// Array index (`[0]`).
+    Index(usize),
+
+    /// Array element whose `key` equals `value` (`[name="x"]`).
+    Select(String, String),
+}
+
+/// Position-tracking scanner over manifest source.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl<'a> Scanner<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            bytes: content.as_bytes(),
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn position(&self) -> Position {
+        (self.line, self.column)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.offset += 1;
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else if byte & 0xC0 != 0x80 {
+            // Count characters, not UTF-8 continuation bytes
+            self.column += 1;
+        }
+        Some(byte)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'{' => self.object(),
+            b'[' => self.array(),
+            b'"' => self.string().map(Node::String),
+            _ => {
+                while self
+                    .peek()
+                    .is_some_and(|b| !matches!(b, b',' | b'}' | b']') && !b.is_ascii_whitespace())
+                {
+                    self.bump();
+                }
+                Some(Node::Other)
+            }
+        }
+    }
+
+    fn object(&mut self) -> Option<Node> {
+        self.bump();
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek()? == b'}' {
+                self.bump();
+                break;
+            }
+
+            let position = self.position();
+            let key = self.string()?;
+            self.skip_whitespace();
+            if self.bump()? != b':' {
+                return None;
+            }
+            let value = self.value()?;
+            entries.push((key, position, value));
+
+            self.skip_whitespace();
+            match self.bump()? {
+                b',' => {}
+                b'}' => break,
+                _ => return None,
+            }
+        }
+        Some(Node::Object(entries))
+    }
+
+    fn array(&mut self) -> Option<Node> {
+        self.bump();
+        let mut elements = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek()? == b']' {
+                self.bump();
+                break;
+            }
+
+            let position = self.position();
+            elements.push((position, self.value()?));
+
+            self.skip_whitespace();
+            match self.bump()? {
+                b',' => {}
+                b']' => break,
+                _ => return None,
+            }
+        }
+        Some(Node::Array(elements))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.bump()? != b'"' {
+            return None;
+        }
+        let mut bytes = Vec::new();
+        loop {
+            match self.bump()? {
+                b'"' => break,
+                b'\\' => match self.bump()? {
+                    b'n' => bytes.push(b'\n'),
+                    b't' => bytes.push(b'\t'),
+                    b'r' => bytes.push(b'\r'),
+                    other => bytes.push(other),
+                },
+                byte => bytes.push(byte),
+            }
+        }
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+impl Node {
+    /// Resolve a location path, returning the position of the deepest matched step.
+    fn locate(&self, segments: &[Segment]) -> Option<Position> {
+        let mut node = self;
+        let mut found = None;
+        let mut i = 0;
+
+        while i < segments.len() {
+            let step = match (node, &segments[i]) {
+                (Node::Object(entries), Segment::Key(_)) => {
+                    // Longest run of unquoted parts that names a key (e.g. `store.tool.mcpb`)
+                    let run = segments[i..]
+                        .iter()
+                        .take_while(|s| matches!(s, Segment::Key(_)))
+                        .count();
+                    (1..=run).rev().find_map(|len| {
+                        let key = join_keys(&segments[i..i + len]);
+                        entries
+                            .iter()
+                            .find(|(k, _, _)| *k == key)
+                            .map(|(_, pos, value)| (len, *pos, value))
+                    })
+                }
+                (Node::Object(entries), Segment::QuotedKey(key)) => entries
+                    .iter()
+                    .find(|(k, _, _)| k == key)
+                    .map(|(_, pos, value)| (1, *pos, value)),
+                (Node::Array(elements), Segment::Index(index)) => {
+                    elements.get(*index).map(|(pos, value)| (1, *pos, value))
+                }
+                (Node::Array(elements), Segment::Select(key, expected)) => elements
+                    .iter()
+                    .find(|(_, value)| match value {
+                        Node::Object(entries) => entries.iter().any(|(k, _, v)| {
+                            k == key && matches!(v, Node::String(s) if s == expected)
+                        }),
+                        _ => false,
+                    })
+                    .map(|(pos, value)| (1, *pos, value)),
+                _ => None,
+            };
+
+            let Some((consumed, position, next)) = step else {
+                break;
+            };
+            found = Some(position);
+            node = next;
+            i += consumed;
+        }
+
+        found
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Fill in `line`/`column` for issues whose location points into the manifest.
+pub(crate) fn annotate_positions(content: &str, result: &mut ValidationResult) {
+    let mut scanner = Scanner::new(content);
+    let Some(root) = scanner.value() else {
+        return;
+    };
+
+    for issue in result.errors.iter_mut().chain(result.warnings.iter_mut()) {
+        if issue.line.is_some() {
+            continue;
+        }
+
+        let path = issue
+            .location
+            .strip_prefix("manifest.json:")
+            .unwrap_or(&issue.location);
+        if let Some((line, column)) = root.locate(&parse_location(path)) {
+            issue.line = Some(line);
+            issue.column = Some(column);
+        }
+    }
+}
+
+/// Split a location like `_meta["store.tool.mcpb"].tools[0]` into segments.
+fn parse_location(path: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = path;
+
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix('[') {
+            let Some(end) = bracket_end(inner) else {
+                break;
+            };
+            let content = &inner[..end];
+            rest = &inner[end + 1..];
+
+            if let Some(key) = unquote(content) {
+                segments.push(Segment::QuotedKey(key.to_string()));
+            } else if let Ok(index) = content.parse() {
+                segments.push(Segment::Index(index));
+            } else if let Some((key, value)) = content.split_once('=')
+                && let Some(value) = unquote(value)
+            {
+                segments.push(Segment::Select(key.to_string(), value.to_string()));
+            } else {
+                break;
+            }
+        } else {
+            rest = rest.strip_prefix('.').unwrap_or(rest);
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            if end > 0 {
+                segments.push(Segment::Key(rest[..end].to_string()));
+            }
+            rest = &rest[end..];
+        }
+    }
+
+    segments
+}
+
+/// Index of the `]` closing a bracket, skipping over quoted text.
+fn bracket_end(inner: &str) -> Option<usize> {
+    let mut quoted = false;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ']' if !quoted => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Strip surrounding double quotes.
+fn unquote(s: &str) -> Option<&str> {
+    s.strip_prefix('"')?.strip_suffix('"')
+}
+
+/// Join consecutive unquoted key parts with dots.
+fn join_keys(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .filter_map(|s| match s {
+            Segment::Key(key) => Some(key.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
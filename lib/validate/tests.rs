@@ -1,8 +1,14 @@
 //! Validation tests.
 
-use super::codes::{ErrorCode, ValidationCode};
+use super::codes::{CustomCode, ErrorCode, ValidationCode, WarningCode};
+use super::fix::fix_manifest;
+use super::registry::register_validator;
+use super::result::ValidationIssue;
+use super::sarif::{find_issue_line, to_sarif};
+use super::spans::annotate_positions;
 use super::validators::fields::is_valid_package_name;
 use super::validators::validate_manifest;
+use crate::mcpb::McpbManifest;
 use tempfile::TempDir;
 
 #[test]
@@ -185,3 +191,467 @@ fn test_valid_manifest_with_warnings() {
     // But should have warnings for missing: author.email, license, icon, node_modules
     assert!(!result.warnings.is_empty());
 }
+
+#[test]
+fn test_reserved_script_names_include_os_variants() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": { "type": "binary", "mcp_config": { "command": "my-tool" } },
+        "_meta": {
+            "store.tool.mcpb": {
+                "scripts": { "build:windows": "build.cmd", "validate:linux": "make check" }
+            }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+
+    let reserved: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|w| w.code == ValidationCode::Warning(WarningCode::ReservedScriptName))
+        .map(|w| w.location.as_str())
+        .collect();
+    assert_eq!(reserved, ["_meta.store.tool.mcpb.scripts.validate:linux"]);
+}
+
+#[test]
+fn test_stdio_transport_missing_command() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "type": "node",
+            "entry_point": "server/index.js",
+            "mcp_config": { "args": ["server/index.js"] }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.code == ValidationCode::Error(ErrorCode::MissingCommand))
+    );
+}
+
+#[test]
+fn test_stdio_transport_with_url_conflicts() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "type": "node",
+            "entry_point": "server/index.js",
+            "mcp_config": {
+                "command": "node",
+                "args": ["server/index.js"],
+                "url": "http://localhost:3000/mcp"
+            }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.code == ValidationCode::Error(ErrorCode::TransportConfigConflict))
+    );
+}
+
+#[test]
+fn test_http_transport_missing_url() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "type": "node",
+            "transport": "http",
+            "entry_point": "server/index.js",
+            "mcp_config": { "command": "node", "args": ["server/index.js"] }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.code == ValidationCode::Error(ErrorCode::MissingUrl))
+    );
+}
+
+#[test]
+fn test_http_reference_with_command_conflicts() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "transport": "http",
+            "mcp_config": {
+                "command": "npx",
+                "url": "https://api.example.com/mcp"
+            }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.code == ValidationCode::Error(ErrorCode::TransportConfigConflict))
+    );
+}
+
+#[test]
+fn test_http_bundle_with_command_and_url_is_consistent() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "type": "node",
+            "transport": "http",
+            "entry_point": "server/index.js",
+            "mcp_config": {
+                "command": "node",
+                "args": ["server/index.js"],
+                "url": "http://localhost:3000/mcp"
+            }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+    assert!(!result.errors.iter().any(|e| matches!(
+        e.code,
+        ValidationCode::Error(
+            ErrorCode::MissingCommand | ErrorCode::MissingUrl | ErrorCode::TransportConfigConflict
+        )
+    )));
+}
+
+#[test]
+fn test_fix_manifest_mechanical_issues() {
+    let mut raw = serde_json::json!({
+        "name": "My_Tool",
+        "version": "v1.2.0",
+        "server": { "type": "node", "entry_point": "server/" },
+        "icon": "icon.png/",
+        "icons": [{ "src": "assets/icon.png//" }]
+    });
+
+    let fixes = fix_manifest(&mut raw);
+    let fields: Vec<_> = fixes.iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        [
+            "manifest_version",
+            "version",
+            "name",
+            "icon",
+            "icons[0].src"
+        ]
+    );
+
+    assert_eq!(raw["manifest_version"], "0.3");
+    assert_eq!(raw["version"], "1.2.0");
+    assert_eq!(raw["name"], "my-tool");
+    assert_eq!(raw["icon"], "icon.png");
+    assert_eq!(raw["icons"][0]["src"], "assets/icon.png");
+
+    // Entry points are never touched
+    assert_eq!(raw["server"]["entry_point"], "server/");
+
+    // Fixing is idempotent
+    assert!(fix_manifest(&mut raw).is_empty());
+}
+
+#[test]
+fn test_fix_manifest_leaves_ambiguous_values() {
+    let mut raw = serde_json::json!({
+        "manifest_version": "0.3",
+        "name": "tool@1",
+        "version": "latest"
+    });
+
+    assert!(fix_manifest(&mut raw).is_empty());
+    assert_eq!(raw["name"], "tool@1");
+    assert_eq!(raw["version"], "latest");
+
+    let mut raw = serde_json::json!({ "manifest_version": "0.3" });
+    let fixes = fix_manifest(&mut raw);
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(fixes[0].before, None);
+    assert_eq!(raw["version"], "0.1.0");
+}
+
+#[test]
+fn test_custom_validator_runs_with_builtin_checks() {
+    // Validators are global, so only act on this test's manifest
+    register_validator(
+        |_: &std::path::Path,
+         manifest: &crate::mcpb::McpbManifest,
+         result: &mut super::ValidationResult| {
+            if manifest.name.as_deref() == Some("custom-policy-tool") && manifest.license.is_none()
+            {
+                result.errors.push(ValidationIssue::new(
+                    CustomCode(1),
+                    "license required by policy",
+                    "manifest.json",
+                    "field `license` is mandatory",
+                ));
+            }
+        },
+    );
+
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+        "manifest_version": "0.3",
+        "name": "custom-policy-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": { "type": "node", "entry_point": "index.js", "mcp_config": { "command": "node" } }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    std::fs::write(dir.path().join("index.js"), "").unwrap();
+
+    let result = validate_manifest(dir.path());
+    let custom: Vec<_> = result
+        .errors
+        .iter()
+        .filter(|e| e.code == ValidationCode::Custom(CustomCode(1)))
+        .collect();
+    assert_eq!(custom.len(), 1);
+    assert_eq!(custom[0].code.to_string(), "X001");
+    assert_eq!(serde_json::to_value(custom[0].code).unwrap(), "X001");
+}
+
+#[test]
+fn test_sarif_output() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+  "manifest_version": "0.3",
+  "name": "My-Tool",
+  "version": "1.0.0",
+  "description": "A tool",
+  "author": { "name": "Test" },
+  "server": { "type": "node", "entry_point": "index.js", "mcp_config": { "command": "node" } }
+}"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    std::fs::write(dir.path().join("index.js"), "").unwrap();
+    let result = validate_manifest(dir.path());
+
+    let sarif = to_sarif(&result, "manifest.json", manifest, false);
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    let name_issue = results.iter().find(|r| r["ruleId"] == "E003").unwrap();
+    assert_eq!(name_issue["level"], "error");
+    let location = &name_issue["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "manifest.json");
+    assert_eq!(location["region"]["startLine"], 3);
+
+    // Warnings become errors in strict mode
+    let strict = to_sarif(&result, "manifest.json", manifest, true);
+    let levels: Vec<_> = strict["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["level"].as_str().unwrap())
+        .collect();
+    assert!(levels.iter().all(|l| *l == "error"));
+}
+
+#[test]
+fn test_find_issue_line() {
+    let content = "{\n  \"name\": \"x\",\n  \"server\": {\n    \"type\": \"node\",\n    \"mcp_config\": {}\n  }\n}";
+    assert_eq!(find_issue_line(content, "manifest.json:name"), 2);
+    assert_eq!(
+        find_issue_line(content, "manifest.json:server.mcp_config"),
+        5
+    );
+    assert_eq!(find_issue_line(content, "manifest.json:tools[0].name"), 1);
+    assert_eq!(find_issue_line(content, "manifest.json"), 1);
+}
+
+#[test]
+fn test_issue_positions() {
+    let dir = TempDir::new().unwrap();
+    let manifest = r#"{
+  "manifest_version": "0.3",
+  "name": "My-Tool",
+  "version": "1.0.0",
+  "description": "A tool",
+  "author": { "name": "Test" },
+  "server": {
+    "type": "node",
+    "entry_point": "missing.js",
+    "mcp_config": { "command": "node" }
+  }
+}"#;
+    std::fs::write(dir.path().join("manifest.json"), manifest).unwrap();
+    let result = validate_manifest(dir.path());
+
+    let name = result
+        .errors
+        .iter()
+        .find(|e| e.code == ValidationCode::Error(ErrorCode::InvalidPackageName))
+        .unwrap();
+    assert_eq!((name.line, name.column), (Some(3), Some(3)));
+
+    let entry = result
+        .errors
+        .iter()
+        .find(|e| e.code == ValidationCode::Error(ErrorCode::FileNotFound))
+        .unwrap();
+    assert_eq!((entry.line, entry.column), (Some(9), Some(5)));
+}
+
+#[test]
+fn test_issue_positions_for_selectors() {
+    let content = r#"{
+  "_meta": { "store.tool.mcpb": { "scripts": { "build": "make" } } },
+  "tools": [
+    { "name": "a" },
+    { "name": "b" }
+  ]
+}"#;
+    let issue = |location: &str| ValidationIssue::new(ErrorCode::InvalidJson, "", location, "");
+
+    let mut result = super::ValidationResult {
+        errors: vec![
+            issue("manifest.json:tools[name=\"b\"]"),
+            issue("manifest.json:tools[0].name"),
+            issue("_meta.store.tool.mcpb.scripts.build"),
+            issue("manifest.json:_meta[\"store.tool.mcpb\"].scripts"),
+            issue("manifest.json:license"),
+            issue("manifest.json"),
+        ],
+        warnings: Vec::new(),
+    };
+    annotate_positions(content, &mut result);
+
+    let positions: Vec<_> = result.errors.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(
+        positions,
+        [
+            (Some(5), Some(5)),
+            (Some(4), Some(7)),
+            (Some(2), Some(48)),
+            (Some(2), Some(35)),
+            (None, None),
+            (None, None),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_json_position() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("manifest.json"), "{\n  \"name\": ,\n}").unwrap();
+    let result = validate_manifest(dir.path());
+    assert_eq!(result.errors[0].line, Some(2));
+}
+
+#[test]
+fn test_validate_in_memory() {
+    let manifest: McpbManifest = serde_json::from_str(
+        r#"{
+        "manifest_version": "0.3",
+        "name": "MyTool",
+        "version": "not-semver",
+        "description": "A tool",
+        "author": { "name": "Test" },
+        "server": {
+            "type": "node",
+            "entry_point": "server/index.js",
+            "mcp_config": { "command": "node", "args": [], "url": "https://example.com/mcp" }
+        },
+        "tools": [
+            { "name": "search", "description": "Search" },
+            { "name": "search", "description": "Search again" }
+        ]
+    }"#,
+    )
+    .unwrap();
+
+    let result = manifest.validate();
+    let codes: Vec<_> = result.errors.iter().map(|e| e.code).collect();
+    for code in [
+        ErrorCode::InvalidPackageName,
+        ErrorCode::InvalidVersion,
+        ErrorCode::TransportConfigConflict,
+        ErrorCode::DuplicateToolName,
+    ] {
+        assert!(codes.contains(&ValidationCode::Error(code)));
+    }
+
+    // Nothing is read from disk, so the missing entry point isn't reported
+    assert!(!codes.contains(&ValidationCode::Error(ErrorCode::FileNotFound)));
+    assert!(
+        !result
+            .warnings
+            .iter()
+            .any(|w| w.code == ValidationCode::Warning(WarningCode::MissingMcpbIgnore))
+    );
+}
+
+#[test]
+fn test_validate_in_memory_matches_directory() {
+    let dir = TempDir::new().unwrap();
+    let content = r#"{
+        "manifest_version": "0.3",
+        "name": "my-tool",
+        "version": "1.0.0",
+        "description": "A tool",
+        "author": { "name": "Test", "email": "test@example.com" },
+        "license": "MIT",
+        "icon": "icon.png",
+        "server": {
+            "type": "binary",
+            "entry_point": "bin/my-tool",
+            "mcp_config": { "command": "${__dirname}/bin/my-tool" }
+        }
+    }"#;
+    std::fs::write(dir.path().join("manifest.json"), content).unwrap();
+    std::fs::write(dir.path().join(".mcpbignore"), "").unwrap();
+    std::fs::create_dir(dir.path().join("bin")).unwrap();
+    std::fs::write(dir.path().join("bin/my-tool"), "").unwrap();
+    std::fs::write(dir.path().join("icon.png"), "").unwrap();
+
+    let manifest: McpbManifest = serde_json::from_str(content).unwrap();
+    let in_memory = manifest.validate();
+    let on_disk = validate_manifest(dir.path());
+
+    let codes = |issues: &[ValidationIssue]| issues.iter().map(|i| i.code).collect::<Vec<_>>();
+    assert_eq!(codes(&in_memory.errors), codes(&on_disk.errors));
+    assert_eq!(codes(&in_memory.warnings), codes(&on_disk.warnings));
+}
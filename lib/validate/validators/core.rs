@@ -1,11 +1,12 @@
 //! Core validation entry point and helpers.
 
-use crate::constants::MCPB_MANIFEST_FILE;
-use crate::mcpb::McpbManifest;
+use crate::mcpb::{McpbManifest, find_manifest_path, from_str_relaxed, strip_jsonc};
 use std::path::Path;
 
 use super::super::codes::ErrorCode;
+use super::super::registry::run_custom_validators;
 use super::super::result::{ValidationIssue, ValidationResult};
+use super::super::spans::annotate_positions;
 use super::fields::{
     validate_file_references, validate_formats, validate_icons, validate_required_fields,
 };
@@ -13,10 +14,11 @@ use super::platforms::{
     validate_binary_override_paths, validate_compatibility_platforms, validate_platform_alignment,
     validate_platform_override_keys,
 };
-use super::recommended::validate_recommended_fields;
+use super::recommended::{validate_bundle_contents, validate_recommended_fields};
 use super::scripts::validate_script_names;
 use super::standard::validate_standard_fields;
 use super::tools::validate_tools;
+use super::transport::validate_transport_config;
 use super::variables::validate_variable_references;
 
 //--------------------------------------------------------------------------------------------------
@@ -29,26 +31,27 @@ pub fn validate_manifest(dir: &Path) -> ValidationResult {
 
     // 1. Check directory exists
     if !dir.exists() {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::ManifestNotFound.into(),
-            message: "directory not found".into(),
-            location: dir.display().to_string(),
-            details: "directory does not exist".into(),
-            help: None,
-        });
+        result.errors.push(ValidationIssue::new(
+            ErrorCode::ManifestNotFound,
+            "directory not found",
+            dir.display().to_string(),
+            "directory does not exist",
+        ));
         return result;
     }
 
-    // 2. Check manifest.json exists
-    let manifest_path = dir.join(MCPB_MANIFEST_FILE);
+    // 2. Check manifest.json (or manifest.json5) exists
+    let manifest_path = find_manifest_path(dir);
     if !manifest_path.exists() {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::ManifestNotFound.into(),
-            message: "manifest not found".into(),
-            location: dir.display().to_string(),
-            details: "manifest.json does not exist".into(),
-            help: Some("run `tool init` to create one".into()),
-        });
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::ManifestNotFound,
+                "manifest not found",
+                dir.display().to_string(),
+                "manifest.json does not exist",
+            )
+            .with_help("run `tool init` to create one"),
+        );
         return result;
     }
 
@@ -56,84 +59,119 @@ pub fn validate_manifest(dir: &Path) -> ValidationResult {
     let content = match std::fs::read_to_string(&manifest_path) {
         Ok(c) => c,
         Err(e) => {
-            result.errors.push(ValidationIssue {
-                code: ErrorCode::InvalidJson.into(),
-                message: "cannot read manifest".into(),
-                location: "manifest.json".into(),
-                details: format!("failed to read file: {}", e),
-                help: None,
-            });
+            result.errors.push(ValidationIssue::new(
+                ErrorCode::InvalidJson,
+                "cannot read manifest",
+                "manifest.json",
+                format!("failed to read file: {}", e),
+            ));
             return result;
         }
     };
 
+    validate_manifest_content(dir, &content)
+}
+
+/// Validate manifest JSON content as if it were the manifest.json in `dir`.
+///
+/// File references are resolved against `dir`, so edits can be checked before writing.
+/// Comments and trailing commas are accepted.
+pub fn validate_manifest_content(dir: &Path, content: &str) -> ValidationResult {
+    let mut result = ValidationResult::default();
+
     // 4. Parse JSON (both as typed and raw for field validation)
-    let manifest: McpbManifest = match serde_json::from_str(&content) {
+    let manifest: McpbManifest = match from_str_relaxed(content) {
         Ok(m) => m,
         Err(e) => {
-            result.errors.push(ValidationIssue {
-                code: ErrorCode::InvalidJson.into(),
-                message: "invalid JSON".into(),
-                location: "manifest.json".into(),
-                details: format!("parse error: {}", e),
-                help: Some("check JSON syntax".into()),
-            });
+            result.errors.push(
+                ValidationIssue::new(
+                    ErrorCode::InvalidJson,
+                    "invalid JSON",
+                    "manifest.json",
+                    format!("parse error: {}", e),
+                )
+                .with_help("check JSON syntax")
+                .with_position(e.line(), e.column()),
+            );
             return result;
         }
     };
 
     // Parse as raw JSON for extra field detection
-    let raw_json: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let raw_json: serde_json::Value = from_str_relaxed(content).unwrap();
 
-    // 5. Validate required fields
-    validate_required_fields(&manifest, &mut result);
+    // 5. Validate the manifest's own fields
+    validate_manifest_fields(&manifest, &raw_json, &mut result);
 
-    // 6. Validate field formats
-    validate_formats(&manifest, &mut result);
-
-    // 7. Validate file references
+    // 6. Validate file references
     validate_file_references(dir, &manifest, &mut result);
 
-    // 8. Validate icon formats (size, PNG format)
-    validate_icons(&manifest, &mut result);
+    // 7. Check for .mcpbignore and bundled dependencies (warnings)
+    validate_bundle_contents(dir, &manifest, &mut result);
+
+    // 8. Validate binary paths in platform_overrides exist
+    validate_binary_override_paths(dir, &manifest, &raw_json, &mut result);
+
+    // 9. Run validators registered by the embedding binary
+    run_custom_validators(dir, &manifest, &mut result);
 
-    // 9. Validate variable references
-    validate_variable_references(&manifest, &mut result);
+    // 10. Point each issue at its key or value in the source
+    annotate_positions(&strip_jsonc(content), &mut result);
 
-    // 10. Check for recommended fields (warnings)
-    validate_recommended_fields(dir, &manifest, &mut result);
+    result
+}
 
-    // 11. Validate tools declarations (with raw JSON for extra field detection)
-    validate_tools(&manifest, &raw_json, &mut result);
+/// Run every check that needs only the manifest, not the files beside it.
+///
+/// `raw_json` is the manifest as written, so fields the typed manifest drops can be flagged.
+pub(crate) fn validate_manifest_fields(
+    manifest: &McpbManifest,
+    raw_json: &serde_json::Value,
+    result: &mut ValidationResult,
+) {
+    // 1. Validate required fields
+    validate_required_fields(manifest, result);
 
-    // 12. Validate all standard-defined fields for extra fields
-    validate_standard_fields(&raw_json, &mut result);
+    // 2. Validate field formats
+    validate_formats(manifest, result);
 
-    // 13. Validate platform override keys
-    validate_platform_override_keys(&manifest, &mut result);
+    // 3. Validate icon formats (size, PNG format)
+    validate_icons(manifest, result);
 
-    // 14. Validate platform override alignment (tool.store namespace covers spec-level)
-    validate_platform_alignment(&raw_json, &mut result);
+    // 4. Validate variable references
+    validate_variable_references(manifest, result);
 
-    // 15. Validate binary paths in platform_overrides exist
-    validate_binary_override_paths(dir, &manifest, &raw_json, &mut result);
+    // 5. Check for recommended fields (warnings)
+    validate_recommended_fields(manifest, result);
 
-    // 16. Validate compatibility.platforms matches platform_overrides
-    validate_compatibility_platforms(&raw_json, &mut result);
+    // 6. Validate tools declarations (with raw JSON for extra field detection)
+    validate_tools(manifest, raw_json, result);
 
-    // 17. Validate script names don't conflict with built-in subcommands
-    validate_script_names(&raw_json, &mut result);
+    // 7. Validate all standard-defined fields for extra fields
+    validate_standard_fields(raw_json, result);
 
-    result
+    // 8. Validate platform override keys
+    validate_platform_override_keys(manifest, result);
+
+    // 9. Validate platform override alignment (tool.store namespace covers spec-level)
+    validate_platform_alignment(raw_json, result);
+
+    // 10. Validate compatibility.platforms matches platform_overrides
+    validate_compatibility_platforms(raw_json, result);
+
+    // 11. Validate script names don't conflict with built-in subcommands
+    validate_script_names(raw_json, result);
+
+    // 12. Validate declared transport matches the mcp_config fields it requires
+    validate_transport_config(manifest, result);
 }
 
 /// Helper to add a missing required field error.
 pub fn missing_field(result: &mut ValidationResult, location: &str, field: &str) {
-    result.errors.push(ValidationIssue {
-        code: ErrorCode::MissingRequiredField.into(),
-        message: "missing required field".into(),
-        location: location.into(),
-        details: format!("field `{}` is required", field),
-        help: None,
-    });
+    result.errors.push(ValidationIssue::new(
+        ErrorCode::MissingRequiredField,
+        "missing required field",
+        location,
+        format!("field `{}` is required", field),
+    ));
 }
@@ -55,23 +55,27 @@ pub fn validate_required_fields(manifest: &McpbManifest, result: &mut Validation
         manifest.server.entry_point.is_none() && manifest.server.server_type.is_none();
 
     if manifest.server.entry_point.is_none() && !is_reference_mode {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::MissingEntryPoint.into(),
-            message: "missing entry point".into(),
-            location: "manifest.json:server".into(),
-            details: "`entry_point` is required".into(),
-            help: Some("add `entry_point` field to server config".into()),
-        });
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::MissingEntryPoint,
+                "missing entry point",
+                "manifest.json:server",
+                "`entry_point` is required",
+            )
+            .with_help("add `entry_point` field to server config"),
+        );
     }
 
     if manifest.server.mcp_config.is_none() {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::MissingMcpConfig.into(),
-            message: "missing mcp_config".into(),
-            location: "manifest.json:server".into(),
-            details: "`mcp_config` is required".into(),
-            help: Some("add `mcp_config` with command, args, and env".into()),
-        });
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::MissingMcpConfig,
+                "missing mcp_config",
+                "manifest.json:server",
+                "`mcp_config` is required",
+            )
+            .with_help("add `mcp_config` with command, args, and env"),
+        );
     }
 }
 
@@ -79,39 +83,37 @@ pub fn validate_required_fields(manifest: &McpbManifest, result: &mut Validation
 pub fn validate_formats(manifest: &McpbManifest, result: &mut ValidationResult) {
     // Check manifest_version
     if manifest.manifest_version != "0.3" {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::DeprecatedManifestVersion.into(),
-            message: "deprecated manifest version".into(),
-            location: "manifest.json:manifest_version".into(),
-            details: format!("`{}` is not the current version", manifest.manifest_version),
-            help: Some("update to \"0.3\"".into()),
-        });
+        result.warnings.push(
+            ValidationIssue::new(
+                WarningCode::DeprecatedManifestVersion,
+                "deprecated manifest version",
+                "manifest.json:manifest_version",
+                format!("`{}` is not the current version", manifest.manifest_version),
+            )
+            .with_help("update to \"0.3\""),
+        );
     }
 
     // Validate name format
     if let Some(name) = &manifest.name
         && !is_valid_package_name(name)
     {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::InvalidPackageName.into(),
-            message: "invalid package name".into(),
-            location: "manifest.json:name".into(),
-            details: format!("`{}` must be 3-64 lowercase alphanumeric chars with hyphens, starting with a letter", name),
-            help: Some("use format: my-package-name (3-64 chars)".into()),
-        });
+        result.errors.push(ValidationIssue::new(ErrorCode::InvalidPackageName, "invalid package name", "manifest.json:name", format!("`{}` must be 3-64 lowercase alphanumeric chars with hyphens, starting with a letter", name)).with_help("use format: my-package-name (3-64 chars)"));
     }
 
     // Validate semver
     if let Some(version) = &manifest.version
         && semver::Version::parse(version).is_err()
     {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::InvalidVersion.into(),
-            message: "invalid version".into(),
-            location: "manifest.json:version".into(),
-            details: format!("`{}` is not valid semver", version),
-            help: Some("use format: MAJOR.MINOR.PATCH (e.g., 1.0.0)".into()),
-        });
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::InvalidVersion,
+                "invalid version",
+                "manifest.json:version",
+                format!("`{}` is not valid semver", version),
+            )
+            .with_help("use format: MAJOR.MINOR.PATCH (e.g., 1.0.0)"),
+        );
     }
 }
 
@@ -143,16 +145,15 @@ pub fn validate_file_references(
                 && !entry_point.ends_with(&format!(".{}", ext))
                 && let Some(ref server_type) = manifest.server.server_type
             {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::EntryPointExtensionMismatch.into(),
-                    message: "entry point extension mismatch".into(),
-                    location: "manifest.json:server.entry_point".into(),
-                    details: format!(
+                result.warnings.push(ValidationIssue::new(
+                    WarningCode::EntryPointExtensionMismatch,
+                    "entry point extension mismatch",
+                    "manifest.json:server.entry_point",
+                    format!(
                         "`{}` doesn't have .{} extension for {} type",
                         entry_point, ext, server_type
                     ),
-                    help: None,
-                });
+                ));
             }
         }
     }
@@ -210,13 +211,15 @@ pub fn validate_icons(manifest: &McpbManifest, result: &mut ValidationResult) {
 
             // Error: src is required and cannot be empty
             if icon_entry.src.is_empty() {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::MissingIconSrc.into(),
-                    message: "icon src is required".into(),
-                    location: format!("manifest.json:{}.src", field_prefix),
-                    details: "`src` field is required and cannot be empty".into(),
-                    help: Some("add a path to the icon file (e.g., \"icon.png\")".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::MissingIconSrc,
+                        "icon src is required",
+                        format!("manifest.json:{}.src", field_prefix),
+                        "`src` field is required and cannot be empty",
+                    )
+                    .with_help("add a path to the icon file (e.g., \"icon.png\")"),
+                );
             } else {
                 // Validate format if src is present
                 validate_icon_format(&icon_entry.src, &format!("{}.src", field_prefix), result);
@@ -226,16 +229,18 @@ pub fn validate_icons(manifest: &McpbManifest, result: &mut ValidationResult) {
             if let Some(size) = &icon_entry.size
                 && !SIZE_PATTERN.is_match(size)
             {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::InvalidIconSize.into(),
-                    message: "invalid icon size format".into(),
-                    location: format!("manifest.json:{}.size", field_prefix),
-                    details: format!(
-                        "`{}` is not valid. Must be WIDTHxHEIGHT (e.g., \"32x32\")",
-                        size
-                    ),
-                    help: Some("use format: WIDTHxHEIGHT (e.g., \"16x16\", \"128x128\")".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::InvalidIconSize,
+                        "invalid icon size format",
+                        format!("manifest.json:{}.size", field_prefix),
+                        format!(
+                            "`{}` is not valid. Must be WIDTHxHEIGHT (e.g., \"32x32\")",
+                            size
+                        ),
+                    )
+                    .with_help("use format: WIDTHxHEIGHT (e.g., \"16x16\", \"128x128\")"),
+                );
             }
         }
     }
@@ -246,13 +251,15 @@ fn validate_icon_format(src: &str, field: &str, result: &mut ValidationResult) {
     // Skip URL validation for https:// icons
     if src.starts_with("https://") {
         if !src.to_lowercase().ends_with(".png") {
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::NonPngIcon.into(),
-                message: "icon should be PNG format".into(),
-                location: format!("manifest.json:{}", field),
-                details: format!("`{}` is not a .png file", src),
-                help: Some("MCPB spec recommends PNG format for icons".into()),
-            });
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::NonPngIcon,
+                    "icon should be PNG format",
+                    format!("manifest.json:{}", field),
+                    format!("`{}` is not a .png file", src),
+                )
+                .with_help("MCPB spec recommends PNG format for icons"),
+            );
         }
         return;
     }
@@ -269,23 +276,27 @@ fn validate_icon_format(src: &str, field: &str, result: &mut ValidationResult) {
         }
         Some(ext) => {
             // Warning: non-PNG format
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::NonPngIcon.into(),
-                message: "icon should be PNG format".into(),
-                location: format!("manifest.json:{}", field),
-                details: format!("`{}` uses .{} format", src, ext),
-                help: Some("MCPB spec recommends PNG format for icons".into()),
-            });
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::NonPngIcon,
+                    "icon should be PNG format",
+                    format!("manifest.json:{}", field),
+                    format!("`{}` uses .{} format", src, ext),
+                )
+                .with_help("MCPB spec recommends PNG format for icons"),
+            );
         }
         None => {
             // Warning: no extension
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::NonPngIcon.into(),
-                message: "icon should be PNG format".into(),
-                location: format!("manifest.json:{}", field),
-                details: format!("`{}` has no file extension", src),
-                help: Some("MCPB spec recommends PNG format for icons".into()),
-            });
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::NonPngIcon,
+                    "icon should be PNG format",
+                    format!("manifest.json:{}", field),
+                    format!("`{}` has no file extension", src),
+                )
+                .with_help("MCPB spec recommends PNG format for icons"),
+            );
         }
     }
 }
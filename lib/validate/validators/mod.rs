@@ -7,6 +7,7 @@ mod recommended;
 mod scripts;
 mod standard;
 mod tools;
+mod transport;
 mod variables;
 
 pub mod fields;
@@ -15,5 +16,6 @@ pub mod fields;
 // Re-Exports
 //--------------------------------------------------------------------------------------------------
 
-pub use core::validate_manifest;
+pub(crate) use core::validate_manifest_fields;
+pub use core::{validate_manifest, validate_manifest_content};
 pub use fields::is_valid_package_name;
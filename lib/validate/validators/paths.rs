@@ -47,13 +47,15 @@ pub fn validate_file_path(
 ) {
     // Check for path traversal
     if !is_path_safe(dir, path) {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::PathTraversal.into(),
-            message: "path escapes package directory".into(),
-            location: format!("{}:{}", manifest_file, field),
-            details: format!("`{}` references a path outside the package", path),
-            help: Some("use a relative path within the package directory".into()),
-        });
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::PathTraversal,
+                "path escapes package directory",
+                format!("{}:{}", manifest_file, field),
+                format!("`{}` references a path outside the package", path),
+            )
+            .with_help("use a relative path within the package directory"),
+        );
         return;
     }
 
@@ -65,19 +67,21 @@ pub fn validate_file_path(
         let exe_path = dir.join(format!("{}.exe", path));
 
         if has_extension || !exe_path.exists() {
-            result.errors.push(ValidationIssue {
-                code: ErrorCode::FileNotFound.into(),
-                message: format!(
-                    "{} not found",
-                    field.split('.').next_back().unwrap_or(field)
-                ),
-                location: format!("{}:{}", manifest_file, field),
-                details: format!("file `{}` does not exist", path),
-                help: Some(format!(
+            result.errors.push(
+                ValidationIssue::new(
+                    ErrorCode::FileNotFound,
+                    format!(
+                        "{} not found",
+                        field.split('.').next_back().unwrap_or(field)
+                    ),
+                    format!("{}:{}", manifest_file, field),
+                    format!("file `{}` does not exist", path),
+                )
+                .with_help(format!(
                     "add the file or remove the {} field",
                     field.split('.').next_back().unwrap_or(field)
                 )),
-            });
+            );
         }
     }
 }
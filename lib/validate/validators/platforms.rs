@@ -67,45 +67,43 @@ fn validate_platform_key(key: &str, location: &str, result: &mut ValidationResul
     // Check for OS-arch format (tool.store extension)
     if let Some((os, arch)) = key.split_once('-') {
         if !VALID_OS_VALUES.contains(&os) {
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::InvalidPlatformKey.into(),
-                message: "invalid platform OS".into(),
-                location: format!("manifest.json:{}.{}", location, key),
-                details: format!(
-                    "`{}` has invalid OS `{}`, expected one of: {}",
-                    key,
-                    os,
-                    VALID_OS_VALUES.join(", ")
-                ),
-                help: Some("use darwin, linux, or win32".into()),
-            });
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::InvalidPlatformKey,
+                    "invalid platform OS",
+                    format!("manifest.json:{}.{}", location, key),
+                    format!(
+                        "`{}` has invalid OS `{}`, expected one of: {}",
+                        key,
+                        os,
+                        VALID_OS_VALUES.join(", ")
+                    ),
+                )
+                .with_help("use darwin, linux, or win32"),
+            );
         }
         if !VALID_ARCH_VALUES.contains(&arch) {
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::InvalidPlatformKey.into(),
-                message: "invalid platform architecture".into(),
-                location: format!("manifest.json:{}.{}", location, key),
-                details: format!(
-                    "`{}` has invalid arch `{}`, expected one of: {}",
-                    key,
-                    arch,
-                    VALID_ARCH_VALUES.join(", ")
-                ),
-                help: Some("use arm64 or x86_64".into()),
-            });
+            result.warnings.push(
+                ValidationIssue::new(
+                    WarningCode::InvalidPlatformKey,
+                    "invalid platform architecture",
+                    format!("manifest.json:{}.{}", location, key),
+                    format!(
+                        "`{}` has invalid arch `{}`, expected one of: {}",
+                        key,
+                        arch,
+                        VALID_ARCH_VALUES.join(", ")
+                    ),
+                )
+                .with_help("use arm64 or x86_64"),
+            );
         }
     } else {
         // Neither OS-only nor OS-arch format
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::InvalidPlatformKey.into(),
-            message: "invalid platform key format".into(),
-            location: format!("manifest.json:{}.{}", location, key),
-            details: format!(
+        result.warnings.push(ValidationIssue::new(WarningCode::InvalidPlatformKey, "invalid platform key format", format!("manifest.json:{}.{}", location, key), format!(
                 "`{}` is not a valid platform key, expected OS (darwin/linux/win32) or OS-arch (darwin-arm64)",
                 key
-            ),
-            help: Some("use format: darwin, linux, win32, darwin-arm64, linux-x86_64, etc.".into()),
-        });
+            )).with_help("use format: darwin, linux, win32, darwin-arm64, linux-x86_64, etc."));
     }
 }
 
@@ -149,20 +147,14 @@ pub fn validate_platform_alignment(raw_json: &serde_json::Value, result: &mut Va
             .any(|p| p.starts_with(&format!("{}-", spec_os)));
 
         if !has_coverage {
-            result.warnings.push(ValidationIssue {
-                code: WarningCode::PlatformAlignmentMismatch.into(),
-                message: "missing tool.store namespace coverage".into(),
-                location: "manifest.json:_meta[\"store.tool.mcpb\"].mcp_config.platform_overrides"
-                    .to_string(),
-                details: format!(
+            result.warnings.push(ValidationIssue::new(WarningCode::PlatformAlignmentMismatch, "missing tool.store namespace coverage", "manifest.json:_meta[\"store.tool.mcpb\"].mcp_config.platform_overrides"
+                    .to_string(), format!(
                     "spec-level platform `{}` has no corresponding tool.store namespace overrides",
                     spec_os
-                ),
-                help: Some(format!(
+                )).with_help(format!(
                     "add `{}-arm64` and/or `{}-x86_64` to tool.store namespace platform_overrides",
                     spec_os, spec_os
-                )),
-            });
+                )));
         }
     }
 }
@@ -240,13 +232,15 @@ fn validate_binary_path(dir: &Path, command: &str, location: &str, result: &mut
 
     let full_path = dir.join(path);
     if !full_path.exists() {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::BinaryOverridePathNotFound.into(),
-            message: "binary path not found".into(),
-            location: location.to_string(),
-            details: format!("binary `{}` does not exist", path),
-            help: Some("ensure the binary is built before packing".into()),
-        });
+        result.warnings.push(
+            ValidationIssue::new(
+                WarningCode::BinaryOverridePathNotFound,
+                "binary path not found",
+                location.to_string(),
+                format!("binary `{}` does not exist", path),
+            )
+            .with_help("ensure the binary is built before packing"),
+        );
     }
 }
 
@@ -279,16 +273,10 @@ pub fn validate_compatibility_platforms(
     if !spec_compat.is_empty() && !spec_overrides.is_empty() {
         for platform in &spec_compat {
             if !spec_overrides.contains(platform) {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::CompatibilityPlatformMismatch.into(),
-                    message: "compatibility platform missing override".into(),
-                    location: "manifest.json:compatibility.platforms".into(),
-                    details: format!(
+                result.warnings.push(ValidationIssue::new(WarningCode::CompatibilityPlatformMismatch, "compatibility platform missing override", "manifest.json:compatibility.platforms", format!(
                         "`{}` listed in compatibility.platforms but not in platform_overrides",
                         platform
-                    ),
-                    help: Some("add a platform_override for this platform or remove from compatibility.platforms".into()),
-                });
+                    )).with_help("add a platform_override for this platform or remove from compatibility.platforms"));
             }
         }
     }
@@ -319,16 +307,10 @@ pub fn validate_compatibility_platforms(
     if !tool_store_compat.is_empty() && !tool_store_overrides.is_empty() {
         for platform in &tool_store_compat {
             if !tool_store_overrides.contains(platform) {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::CompatibilityPlatformMismatch.into(),
-                    message: "tool.store compatibility platform missing override".into(),
-                    location: "manifest.json:_meta[\"store.tool.mcpb\"].compatibility.platforms".into(),
-                    details: format!(
+                result.warnings.push(ValidationIssue::new(WarningCode::CompatibilityPlatformMismatch, "tool.store compatibility platform missing override", "manifest.json:_meta[\"store.tool.mcpb\"].compatibility.platforms", format!(
                         "`{}` listed in tool.store compatibility.platforms but not in platform_overrides",
                         platform
-                    ),
-                    help: Some("add a platform_override for this platform or remove from compatibility.platforms".into()),
-                });
+                    )).with_help("add a platform_override for this platform or remove from compatibility.platforms"));
             }
         }
     }
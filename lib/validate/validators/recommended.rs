@@ -11,11 +11,7 @@ use super::super::result::{ValidationIssue, ValidationResult};
 //--------------------------------------------------------------------------------------------------
 
 /// Validate recommended fields for publication.
-pub fn validate_recommended_fields(
-    dir: &Path,
-    manifest: &McpbManifest,
-    result: &mut ValidationResult,
-) {
+pub fn validate_recommended_fields(manifest: &McpbManifest, result: &mut ValidationResult) {
     // Check author email
     if manifest
         .author
@@ -23,61 +19,75 @@ pub fn validate_recommended_fields(
         .map(|a| a.email.is_none())
         .unwrap_or(true)
     {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::MissingAuthorEmail.into(),
-            message: "missing recommended field".into(),
-            location: "manifest.json".into(),
-            details: "field `author.email` is recommended for publication".into(),
-            help: None,
-        });
+        result.warnings.push(ValidationIssue::new(
+            WarningCode::MissingAuthorEmail,
+            "missing recommended field",
+            "manifest.json",
+            "field `author.email` is recommended for publication",
+        ));
     }
 
     // Check license
     if manifest.license.is_none() {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::MissingLicense.into(),
-            message: "missing recommended field".into(),
-            location: "manifest.json".into(),
-            details: "field `license` is recommended for publication".into(),
-            help: Some("add SPDX identifier like \"MIT\" or \"Apache-2.0\"".into()),
-        });
+        result.warnings.push(
+            ValidationIssue::new(
+                WarningCode::MissingLicense,
+                "missing recommended field",
+                "manifest.json",
+                "field `license` is recommended for publication",
+            )
+            .with_help("add SPDX identifier like \"MIT\" or \"Apache-2.0\""),
+        );
     }
 
     // Check icon
     if manifest.icon.is_none() && manifest.icons.is_none() {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::MissingIcon.into(),
-            message: "missing icon".into(),
-            location: "manifest.json".into(),
-            details: "no icon specified for the bundle".into(),
-            help: Some("add `icon` field for better presentation in clients".into()),
-        });
+        result.warnings.push(
+            ValidationIssue::new(
+                WarningCode::MissingIcon,
+                "missing icon",
+                "manifest.json",
+                "no icon specified for the bundle",
+            )
+            .with_help("add `icon` field for better presentation in clients"),
+        );
     }
+}
 
+/// Validate that the bundle directory has an ignore file and its dependencies installed.
+pub fn validate_bundle_contents(
+    dir: &Path,
+    manifest: &McpbManifest,
+    result: &mut ValidationResult,
+) {
     // Check .mcpbignore
     if !dir.join(".mcpbignore").exists() {
-        result.warnings.push(ValidationIssue {
-            code: WarningCode::MissingMcpbIgnore.into(),
-            message: "missing .mcpbignore".into(),
-            location: dir.display().to_string(),
-            details: "no .mcpbignore found, bundling files that are typically excluded".into(),
-            help: Some(
+        result.warnings.push(
+            ValidationIssue::new(
+                WarningCode::MissingMcpbIgnore,
+                "missing .mcpbignore",
+                dir.display().to_string(),
+                "no .mcpbignore found, bundling files that are typically excluded",
+            )
+            .with_help(
                 "create a .mcpbignore file to exclude unnecessary files from the bundle".into(),
             ),
-        });
+        );
     }
 
     // Check dependencies bundled (only for bundled tools with server type)
     match manifest.server.server_type {
         Some(McpbServerType::Node) => {
             if !dir.join("node_modules").exists() {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::DependenciesNotBundled.into(),
-                    message: "dependencies not bundled".into(),
-                    location: dir.display().to_string(),
-                    details: "`node_modules/` not found".into(),
-                    help: Some("run `npm install --production` before packing".into()),
-                });
+                result.warnings.push(
+                    ValidationIssue::new(
+                        WarningCode::DependenciesNotBundled,
+                        "dependencies not bundled",
+                        dir.display().to_string(),
+                        "`node_modules/` not found",
+                    )
+                    .with_help("run `npm install --production` before packing"),
+                );
             }
         }
         Some(McpbServerType::Python) => {
@@ -85,13 +95,15 @@ pub fn validate_recommended_fields(
                 || dir.join("server/venv").exists()
                 || dir.join(".venv").exists();
             if !has_deps {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::DependenciesNotBundled.into(),
-                    message: "dependencies not bundled".into(),
-                    location: dir.display().to_string(),
-                    details: "no Python dependencies found (server/lib/ or venv/)".into(),
-                    help: Some("bundle dependencies in server/lib/ or include venv/".into()),
-                });
+                result.warnings.push(
+                    ValidationIssue::new(
+                        WarningCode::DependenciesNotBundled,
+                        "dependencies not bundled",
+                        dir.display().to_string(),
+                        "no Python dependencies found (server/lib/ or venv/)",
+                    )
+                    .with_help("bundle dependencies in server/lib/ or include venv/"),
+                );
             }
         }
         Some(McpbServerType::Binary) | None => {}
@@ -14,21 +14,37 @@ const RESERVED_SUBCOMMANDS: &[&str] = &[
     "search",
     "install",
     "uninstall",
+    "prune",
     "list",
     "grep",
     "info",
+    "inspect",
     "call",
     "download",
     "validate",
+    "migrate",
+    "diff",
+    "vars",
+    "scripts",
     "pack",
+    "unpack",
     "run",
+    "logs",
+    "dev",
+    "version",
+    "versions",
     "publish",
+    "yank",
+    "deprecate",
     "login",
     "logout",
     "whoami",
     "self",
     "config",
     "host",
+    "manifest",
+    "doctor",
+    "security",
 ];
 
 //--------------------------------------------------------------------------------------------------
@@ -45,17 +61,21 @@ pub fn validate_script_names(raw_json: &serde_json::Value, result: &mut Validati
 
     if let Some(scripts) = scripts {
         for script_name in scripts.keys() {
-            if RESERVED_SUBCOMMANDS.contains(&script_name.as_str()) {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::ReservedScriptName.into(),
-                    message: "reserved script name".into(),
-                    location: format!("_meta.store.tool.mcpb.scripts.{}", script_name),
-                    details: format!(
-                        "script `{}` is shadowed by built-in `tool {}` and will never run",
-                        script_name, script_name
-                    ),
-                    help: Some("rename the script to avoid the conflict".into()),
-                });
+            // OS variants (`validate:windows`) are shadowed the same way
+            let base_name = script_name.split(':').next().unwrap_or(script_name);
+            if RESERVED_SUBCOMMANDS.contains(&base_name) {
+                result.warnings.push(
+                    ValidationIssue::new(
+                        WarningCode::ReservedScriptName,
+                        "reserved script name",
+                        format!("_meta.store.tool.mcpb.scripts.{}", script_name),
+                        format!(
+                            "script `{}` is shadowed by built-in `tool {}` and will never run",
+                            script_name, base_name
+                        ),
+                    )
+                    .with_help("rename the script to avoid the conflict"),
+                );
             }
         }
     }
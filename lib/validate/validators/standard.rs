@@ -80,20 +80,22 @@ pub fn check_extra_fields(
             .collect::<Vec<_>>()
             .join(", ");
 
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::ExtraFieldsInStandardField.into(),
-            message: format!("extra fields in {}", field_type),
-            location: location.to_string(),
-            details: format!(
-                "{} has fields {} which are not allowed in MCPB spec",
-                field_type, fields_str
-            ),
-            help: Some(format!(
+        result.errors.push(
+            ValidationIssue::new(
+                ErrorCode::ExtraFieldsInStandardField,
+                format!("extra fields in {}", field_type),
+                location.to_string(),
+                format!(
+                    "{} has fields {} which are not allowed in MCPB spec",
+                    field_type, fields_str
+                ),
+            )
+            .with_help(format!(
                 "{} only allows: {}",
                 field_type,
                 allowed.join(", ")
             )),
-        });
+        );
     }
 }
 
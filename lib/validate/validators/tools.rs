@@ -47,42 +47,48 @@ pub fn validate_tools(
 
             // Check name is non-empty
             if tool.name.is_empty() {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::ToolMissingName.into(),
-                    message: "tool missing name".into(),
-                    location: location.clone(),
-                    details: "tool `name` field is required and cannot be empty".into(),
-                    help: Some("add a unique name for this tool".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::ToolMissingName,
+                        "tool missing name",
+                        location.clone(),
+                        "tool `name` field is required and cannot be empty",
+                    )
+                    .with_help("add a unique name for this tool"),
+                );
             } else {
                 // Check for duplicate names
                 if !top_level_names.insert(tool.name.clone()) {
-                    result.errors.push(ValidationIssue {
-                        code: ErrorCode::DuplicateToolName.into(),
-                        message: "duplicate tool name".into(),
-                        location: location.clone(),
-                        details: format!("tool name `{}` is already declared", tool.name),
-                        help: Some("use unique names for each tool".into()),
-                    });
+                    result.errors.push(
+                        ValidationIssue::new(
+                            ErrorCode::DuplicateToolName,
+                            "duplicate tool name",
+                            location.clone(),
+                            format!("tool name `{}` is already declared", tool.name),
+                        )
+                        .with_help("use unique names for each tool"),
+                    );
                 }
             }
 
             // Check description is non-empty
             if tool.description.is_empty() {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::ToolMissingDescription.into(),
-                    message: "tool missing description".into(),
-                    location,
-                    details: format!(
-                        "tool `{}` is missing a description",
-                        if tool.name.is_empty() {
-                            format!("tools[{}]", i)
-                        } else {
-                            tool.name.clone()
-                        }
-                    ),
-                    help: Some("add a description explaining what the tool does".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::ToolMissingDescription,
+                        "tool missing description",
+                        location,
+                        format!(
+                            "tool `{}` is missing a description",
+                            if tool.name.is_empty() {
+                                format!("tools[{}]", i)
+                            } else {
+                                tool.name.clone()
+                            }
+                        ),
+                    )
+                    .with_help("add a description explaining what the tool does"),
+                );
             }
         }
     }
@@ -116,47 +122,45 @@ pub fn validate_tools(
 
             // Check name is non-empty
             if tool.name.is_empty() {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::ToolMissingName.into(),
-                    message: "static tool missing name".into(),
-                    location: location.clone(),
-                    details: "tool `name` field is required and cannot be empty".into(),
-                    help: Some("add a unique name for this tool".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::ToolMissingName,
+                        "static tool missing name",
+                        location.clone(),
+                        "tool `name` field is required and cannot be empty",
+                    )
+                    .with_help("add a unique name for this tool"),
+                );
             } else {
                 static_names.insert(tool.name.clone());
 
                 // Warn if static tool is not in top-level tools
                 if !top_level_names.contains(&tool.name) {
-                    result.warnings.push(ValidationIssue {
-                        code: WarningCode::StaticToolNotInTopLevel.into(),
-                        message: "static tool not in top-level".into(),
-                        location: location.clone(),
-                        details: format!(
+                    result.warnings.push(ValidationIssue::new(WarningCode::StaticToolNotInTopLevel, "static tool not in top-level", location.clone(), format!(
                             "tool `{}` in static_responses is not declared in top-level `tools`",
                             tool.name
-                        ),
-                        help: Some("add this tool to the top-level `tools` array".into()),
-                    });
+                        )).with_help("add this tool to the top-level `tools` array"));
                 }
             }
 
             // Check description is non-empty
             if tool.description.is_empty() {
-                result.errors.push(ValidationIssue {
-                    code: ErrorCode::ToolMissingDescription.into(),
-                    message: "static tool missing description".into(),
-                    location: location.clone(),
-                    details: format!(
-                        "tool `{}` is missing a description",
-                        if tool.name.is_empty() {
-                            format!("tools[{}]", i)
-                        } else {
-                            tool.name.clone()
-                        }
-                    ),
-                    help: Some("add a description explaining what the tool does".into()),
-                });
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::ToolMissingDescription,
+                        "static tool missing description",
+                        location.clone(),
+                        format!(
+                            "tool `{}` is missing a description",
+                            if tool.name.is_empty() {
+                                format!("tools[{}]", i)
+                            } else {
+                                tool.name.clone()
+                            }
+                        ),
+                    )
+                    .with_help("add a description explaining what the tool does"),
+                );
             }
 
             // Validate inputSchema if present
@@ -173,18 +177,12 @@ pub fn validate_tools(
         // Warn about top-level tools missing from static_responses
         for name in &top_level_names {
             if !static_names.contains(name) {
-                result.warnings.push(ValidationIssue {
-                    code: WarningCode::TopLevelToolMissingSchema.into(),
-                    message: "tool missing schema".into(),
-                    location: format!("manifest.json:tools[name=\"{}\"]", name),
-                    details: format!(
+                result.warnings.push(ValidationIssue::new(WarningCode::TopLevelToolMissingSchema, "tool missing schema", format!("manifest.json:tools[name=\"{}\"]", name), format!(
                         "tool `{}` is declared in top-level but has no schema in static_responses",
                         name
-                    ),
-                    help: Some(
+                    )).with_help(
                         "add this tool to static_responses[\"tools/list\"] with inputSchema".into(),
-                    ),
-                });
+                    ));
             }
         }
     }
@@ -199,20 +197,14 @@ fn validate_json_schema(
 ) {
     // Schema must be an object
     if !schema.is_object() {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::InvalidInputSchema.into(),
-            message: format!("invalid {}", field_name),
-            location: format!(
+        result.errors.push(ValidationIssue::new(ErrorCode::InvalidInputSchema, format!("invalid {}", field_name), format!(
                 "manifest.json:_meta[\"store.tool.mcpb\"][\"static_responses\"][\"tools/list\"].tools[name=\"{}\"].{}",
                 tool_name, field_name
-            ),
-            details: format!(
+            ), format!(
                 "`{}` must be a JSON Schema object, got {}",
                 field_name,
                 schema_type_name(schema)
-            ),
-            help: Some("use a valid JSON Schema object with `type`, `properties`, etc.".into()),
-        });
+            )).with_help("use a valid JSON Schema object with `type`, `properties`, etc."));
         return;
     }
 
@@ -223,32 +215,20 @@ fn validate_json_schema(
     if let Some(properties) = schema_obj.get("properties")
         && !properties.is_object()
     {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::InvalidInputSchema.into(),
-            message: format!("invalid {} properties", field_name),
-            location: format!(
+        result.errors.push(ValidationIssue::new(ErrorCode::InvalidInputSchema, format!("invalid {} properties", field_name), format!(
                 "manifest.json:_meta[\"store.tool.mcpb\"][\"static_responses\"][\"tools/list\"].tools[name=\"{}\"].{}.properties",
                 tool_name, field_name
-            ),
-            details: "`properties` must be an object".into(),
-            help: Some("define properties as key-value pairs of property schemas".into()),
-        });
+            ), "`properties` must be an object").with_help("define properties as key-value pairs of property schemas"));
     }
 
     // Validate required if present
     if let Some(required) = schema_obj.get("required")
         && !required.is_array()
     {
-        result.errors.push(ValidationIssue {
-            code: ErrorCode::InvalidInputSchema.into(),
-            message: format!("invalid {} required", field_name),
-            location: format!(
+        result.errors.push(ValidationIssue::new(ErrorCode::InvalidInputSchema, format!("invalid {} required", field_name), format!(
                 "manifest.json:_meta[\"store.tool.mcpb\"][\"static_responses\"][\"tools/list\"].tools[name=\"{}\"].{}.required",
                 tool_name, field_name
-            ),
-            details: "`required` must be an array of property names".into(),
-            help: Some("use an array of strings, e.g., [\"param1\", \"param2\"]".into()),
-        });
+            ), "`required` must be an array of property names").with_help("use an array of strings, e.g., [\"param1\", \"param2\"]"));
     }
 }
 
@@ -0,0 +1,119 @@
+//! Transport and mcp_config consistency validation.
+
+use crate::mcpb::{McpbManifest, McpbTransport};
+
+use super::super::codes::ErrorCode;
+use super::super::result::{ValidationIssue, ValidationResult};
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Validate that the declared transport matches the fields present in mcp_config.
+///
+/// - stdio requires `command` and must not declare HTTP-only fields (`url`, `headers`, `oauth_config`)
+/// - http requires `url`; bundled HTTP servers also need `command` to spawn the server,
+///   while reference-mode HTTP servers must not declare one
+pub fn validate_transport_config(manifest: &McpbManifest, result: &mut ValidationResult) {
+    let Some(mcp_config) = &manifest.server.mcp_config else {
+        return; // Missing mcp_config is reported by required field validation
+    };
+
+    let is_reference_mode =
+        manifest.server.entry_point.is_none() && manifest.server.server_type.is_none();
+
+    // A command may be supplied per-platform instead of at the base level
+    let has_command = mcp_config.command.is_some()
+        || mcp_config
+            .platform_overrides
+            .values()
+            .any(|o| o.command.is_some());
+    let has_url = mcp_config.url.is_some()
+        || mcp_config
+            .platform_overrides
+            .values()
+            .any(|o| o.url.is_some());
+
+    match manifest.server.transport {
+        McpbTransport::Stdio => {
+            if !has_command {
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::MissingCommand,
+                        "missing command for stdio transport",
+                        "manifest.json:server.mcp_config",
+                        "stdio transport requires `command` in mcp_config",
+                    )
+                    .with_help(if has_url {
+                        "set `server.transport` to \"http\" or add a `command`"
+                    } else {
+                        "add `command` (and `args`) to mcp_config"
+                    }),
+                );
+            }
+
+            let http_fields: Vec<&str> = [
+                ("url", has_url),
+                ("headers", !mcp_config.headers.is_empty()),
+                ("oauth_config", mcp_config.oauth_config.is_some()),
+            ]
+            .into_iter()
+            .filter_map(|(field, present)| present.then_some(field))
+            .collect();
+
+            if !http_fields.is_empty() {
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::TransportConfigConflict,
+                        "conflicting transport configuration",
+                        "manifest.json:server.mcp_config",
+                        format!(
+                            "transport is stdio but mcp_config declares HTTP-only field(s): {}",
+                            http_fields
+                                .iter()
+                                .map(|f| format!("`{}`", f))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    )
+                    .with_help("set `server.transport` to \"http\" or remove the HTTP-only fields"),
+                );
+            }
+        }
+        McpbTransport::Http => {
+            if !has_url {
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::MissingUrl,
+                        "missing url for http transport",
+                        "manifest.json:server.mcp_config",
+                        "http transport requires `url` in mcp_config",
+                    )
+                    .with_help(if has_command && is_reference_mode {
+                        "set `server.transport` to \"stdio\" or add a `url`"
+                    } else {
+                        "add `url` (e.g., \"http://localhost:3000/mcp\") to mcp_config"
+                    }),
+                );
+            }
+
+            if is_reference_mode {
+                if has_command {
+                    result.errors.push(ValidationIssue::new(ErrorCode::TransportConfigConflict, "conflicting transport configuration", "manifest.json:server.mcp_config.command", "remote http servers connect via `url`; `command` would never run").with_help(
+                            "remove `command`, or add `entry_point` and `type` to bundle the server",
+                        ));
+                }
+            } else if !has_command {
+                result.errors.push(
+                    ValidationIssue::new(
+                        ErrorCode::MissingCommand,
+                        "missing command for http server",
+                        "manifest.json:server.mcp_config",
+                        "bundled http servers require `command` to start the server",
+                    )
+                    .with_help("add `command` (and `args`) to mcp_config"),
+                );
+            }
+        }
+    }
+}
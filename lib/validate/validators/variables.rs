@@ -75,16 +75,10 @@ pub fn validate_variable_references(manifest: &McpbManifest, result: &mut Valida
                     let is_required = field.required.unwrap_or(false);
 
                     if !has_default && !is_required {
-                        result.warnings.push(ValidationIssue {
-                            code: WarningCode::ReferencedFieldNoDefault.into(),
-                            message: "referenced field has no default".into(),
-                            location: format!("manifest.json:user_config.{}", var_name),
-                            details: format!(
+                        result.warnings.push(ValidationIssue::new(WarningCode::ReferencedFieldNoDefault, "referenced field has no default", format!("manifest.json:user_config.{}", var_name), format!(
                                 "`{}` is used in mcp_config but has no default and isn't required",
                                 var_name
-                            ),
-                            help: Some("add a `default` value or set `required: true`".into()),
-                        });
+                            )).with_help("add a `default` value or set `required: true`"));
                         warned.insert(var_name.as_str());
                     }
                 }
@@ -106,13 +100,15 @@ fn check_variable_references(
         if user_config_keys.contains(&key.as_str()) {
             valid_vars.push(key);
         } else {
-            result.errors.push(ValidationIssue {
-                code: ErrorCode::InvalidVariableReference.into(),
-                message: "invalid variable reference".into(),
-                location: format!("manifest.json:server.{}", field),
-                details: format!("`${{user_config.{}}}` references undefined key", key),
-                help: Some(format!("add `{}` to user_config or fix the reference", key)),
-            });
+            result.errors.push(
+                ValidationIssue::new(
+                    ErrorCode::InvalidVariableReference,
+                    "invalid variable reference",
+                    format!("manifest.json:server.{}", field),
+                    format!("`${{user_config.{}}}` references undefined key", key),
+                )
+                .with_help(format!("add `{}` to user_config or fix the reference", key)),
+            );
         }
     }
     valid_vars
@@ -1,15 +1,17 @@
 //! Variable substitution utilities for MCP manifests.
 //!
-//! Handles `${__dirname}`, `${HOME}`, `${user_config.X}`, `${system_config.X}` and
-//! template functions like `${base64(value)}`, `${default(value, fallback)}` in
-//! mcp_config args, env, and header values.
+//! Handles `${__dirname}`, `${HOME}`, `${user_config.X}`, `${system_config.X}`, `${env.X}`,
+//! `:-` fallbacks like `${env.PORT:-8080}` and template functions like `${base64(value)}`,
+//! `${default(value, fallback)}` in mcp_config args, env, and header values.
 
 use crate::error::{ToolError, ToolResult};
 use crate::mcpb::{
     McpbSystemConfigField, McpbSystemConfigType, McpbUserConfigField, McpbUserConfigType,
 };
 use regex::Regex;
+use serde::Serialize;
 use std::collections::BTreeMap;
+use std::ops::Range;
 use std::sync::LazyLock;
 use std::time::SystemTime;
 
@@ -18,10 +20,10 @@ use std::time::SystemTime;
 //--------------------------------------------------------------------------------------------------
 
 /// Regex pattern for user_config variable references.
-pub const USER_CONFIG_VAR_PATTERN: &str = r"\$\{user_config\.(\w+)\}";
+pub const USER_CONFIG_VAR_PATTERN: &str = r"\$\{user_config\.(\w+)(?:\}|:-)";
 
 /// Regex pattern for system_config variable references.
-pub const SYSTEM_CONFIG_VAR_PATTERN: &str = r"\$\{system_config\.(\w+)\}";
+pub const SYSTEM_CONFIG_VAR_PATTERN: &str = r"\$\{system_config\.(\w+)(?:\}|:-)";
 
 /// Built-in variables that don't require config definition.
 pub const BUILTIN_VARS: &[&str] = &["__dirname", "HOME", "DESKTOP", "DOCUMENTS", "DOWNLOADS"];
@@ -34,9 +36,81 @@ static USER_CONFIG_REGEX: LazyLock<Regex> =
 static SYSTEM_CONFIG_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(SYSTEM_CONFIG_VAR_PATTERN).expect("Invalid regex pattern"));
 
-/// Regex for all variable patterns.
-static VAR_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"\$\{([^}]+)\}").expect("Invalid regex pattern"));
+/// Prefix for explicit environment variable references (`${env.PORT}`).
+const ENV_PREFIX: &str = "env.";
+
+/// Separator between a reference and its fallback (`${env.PORT:-8080}`).
+const FALLBACK_SEPARATOR: &str = ":-";
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Where a `${...}` reference gets its value from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VarSource {
+    /// `${user_config.X}`
+    UserConfig,
+
+    /// `${system_config.X}`
+    SystemConfig,
+
+    /// `${__dirname}`, `${HOME}` and other built-ins.
+    Builtin,
+
+    /// Any other name, looked up in the process environment.
+    Environment,
+
+    /// A literal or template function call.
+    Expression,
+}
+
+/// A `${...}` reference in a string and what it resolved to.
+#[derive(Debug, Clone, Serialize)]
+pub struct VarReference {
+    /// Expression inside `${...}`.
+    pub expr: String,
+
+    /// Where the value comes from.
+    pub source: VarSource,
+
+    /// Resolved value, or `None` if unresolved.
+    pub value: Option<String>,
+
+    /// Whether the value came from a `:-` fallback.
+    pub fallback: bool,
+
+    /// Evaluation error (e.g. unknown function), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Shell family a script runs under, which decides how values are quoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellKind {
+    /// `sh -c` and compatible shells (bash, zsh, ...).
+    Posix,
+
+    /// Windows `cmd /C`.
+    Cmd,
+
+    /// `powershell -Command` or `pwsh -Command`.
+    PowerShell,
+}
+
+/// Quoting state of a position in a shell command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShellQuote {
+    /// Unquoted.
+    None,
+
+    /// Inside `'...'`.
+    Single,
+
+    /// Inside `"..."`.
+    Double,
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -62,23 +136,98 @@ pub fn extract_system_config_vars(s: &str) -> Vec<String> {
 ///
 /// Finds all `${...}` patterns and evaluates the inner expression using
 /// `eval_expr`, which supports variable references, string literals,
-/// function calls, and nested function calls.
+/// function calls, nested function calls and `:-` fallbacks (which may
+/// themselves contain `${...}` references).
 pub fn substitute_vars(
     s: &str,
     dirname: &str,
     user_config: &BTreeMap<String, String>,
     system_config: &BTreeMap<String, String>,
 ) -> ToolResult<String> {
-    let mut result = s.to_string();
+    substitute_matching(
+        s,
+        dirname,
+        user_config,
+        system_config,
+        |_| true,
+        |_, value| value,
+    )
+}
+
+/// Substitute scaffold template placeholders like `${name}` and `${author}`.
+///
+/// Only references whose expression is exactly a key in `values` are replaced.
+/// Everything else (`${__dirname}`, `${user_config.X}`, JavaScript template
+/// literals, shell syntax) is left as-is so templates can contain them verbatim.
+pub fn substitute_template_vars(s: &str, values: &BTreeMap<&str, String>) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut last = 0;
+
+    for (range, inner) in find_var_refs(s) {
+        if let Some(value) = values.get(inner.trim()) {
+            result.push_str(&s[last..range.start]);
+            result.push_str(value);
+            last = range.end;
+        }
+    }
+    result.push_str(&s[last..]);
+
+    result
+}
+
+/// Substitute the variables available to manifest scripts.
+///
+/// Scripts can use built-ins (`${__dirname}`, `${HOME}`, ...) and `${env.X}`, with `:-`
+/// fallbacks. Other `${...}` forms are shell syntax (e.g. `${FOO%.txt}`) and are left
+/// for the shell. Substituted values are quoted for where they appear in the command,
+/// so a directory like `my tool; rm -rf ~` stays a single, inert word.
+pub fn substitute_script_vars(s: &str, dirname: &str, shell: ShellKind) -> ToolResult<String> {
+    let empty = BTreeMap::new();
+    substitute_matching(
+        s,
+        dirname,
+        &empty,
+        &empty,
+        |expr| {
+            let primary = split_fallback(expr)
+                .map_or(expr, |(primary, _)| primary)
+                .trim();
+            is_builtin_var(primary) || primary.starts_with(ENV_PREFIX)
+        },
+        |offset, value| quote_in_context(&value, shell, shell_quote_context(s, offset, shell)),
+    )
+}
+
+/// Quote a value as a single shell word.
+pub fn shell_quote(value: &str, shell: ShellKind) -> String {
+    quote_in_context(value, shell, ShellQuote::None)
+}
+
+/// Substitute the `${...}` references whose expression satisfies `matches`.
+///
+/// Each value is passed through `render` with the byte offset of its reference.
+fn substitute_matching(
+    s: &str,
+    dirname: &str,
+    user_config: &BTreeMap<String, String>,
+    system_config: &BTreeMap<String, String>,
+    matches: impl Fn(&str) -> bool,
+    render: impl Fn(usize, String) -> String,
+) -> ToolResult<String> {
+    let mut result = String::with_capacity(s.len());
     let mut errors = Vec::new();
+    let mut last = 0;
 
-    for cap in VAR_REGEX.captures_iter(s) {
-        let full_match = &cap[0];
-        let inner = &cap[1];
+    for (range, inner) in find_var_refs(s) {
+        if !matches(inner.trim()) {
+            continue;
+        }
+        result.push_str(&s[last..range.start]);
+        last = range.end;
 
         match eval_expr(inner, dirname, user_config, system_config) {
             Ok(Some(value)) => {
-                result = result.replace(full_match, &value);
+                result.push_str(&render(range.start, value));
             }
             Ok(None) => {
                 errors.push(format!("Undefined variable: {}", inner));
@@ -88,6 +237,7 @@ pub fn substitute_vars(
             }
         }
     }
+    result.push_str(&s[last..]);
 
     if !errors.is_empty() {
         return Err(ToolError::Generic(errors.join(", ")));
@@ -96,13 +246,208 @@ pub fn substitute_vars(
     Ok(result)
 }
 
+/// Resolve each `${...}` reference in a string individually.
+///
+/// Uses the same evaluation as [`substitute_vars`], but reports every reference
+/// instead of failing on the first undefined one.
+pub fn resolve_var_references(
+    s: &str,
+    dirname: &str,
+    user_config: &BTreeMap<String, String>,
+    system_config: &BTreeMap<String, String>,
+) -> Vec<VarReference> {
+    find_var_refs(s)
+        .into_iter()
+        .map(|(_, inner)| {
+            let expr = inner.trim().to_string();
+            let (value, error) = match eval_expr(&expr, dirname, user_config, system_config) {
+                Ok(value) => (value, None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            let fallback = split_fallback(&expr).is_some_and(|(primary, _)| {
+                !matches!(
+                    eval_expr(primary, dirname, user_config, system_config),
+                    Ok(Some(v)) if !v.is_empty()
+                )
+            });
+            VarReference {
+                source: classify_var_expr(&expr),
+                expr,
+                value,
+                fallback,
+                error,
+            }
+        })
+        .collect()
+}
+
+/// Classify where an expression inside `${...}` gets its value from.
+pub fn classify_var_expr(expr: &str) -> VarSource {
+    let expr = split_fallback(expr.trim()).map_or(expr, |(primary, _)| primary);
+    let expr = expr.trim();
+    if expr.starts_with("user_config.") {
+        VarSource::UserConfig
+    } else if expr.starts_with("system_config.") {
+        VarSource::SystemConfig
+    } else if is_builtin_var(expr) {
+        VarSource::Builtin
+    } else if expr.starts_with('\'') || expr.contains('(') {
+        VarSource::Expression
+    } else {
+        VarSource::Environment
+    }
+}
+
+/// Find the `${...}` references in a string as (byte range, inner expression).
+///
+/// Braces are matched so a fallback may contain references (`${env.A:-${env.B}}`).
+/// Empty and unterminated references are left as literal text.
+fn find_var_refs(s: &str) -> Vec<(Range<usize>, &str)> {
+    let bytes = s.as_bytes();
+    let mut refs = Vec::new();
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i] != b'$' || bytes[i + 1] != b'{' {
+            i += 1;
+            continue;
+        }
+
+        let mut depth = 1;
+        let mut end = i + 2;
+        while end < bytes.len() && depth > 0 {
+            match bytes[end] {
+                b'{' => depth += 1,
+                b'}' => depth -= 1,
+                _ => {}
+            }
+            end += 1;
+        }
+        if depth > 0 {
+            break;
+        }
+
+        let inner = &s[i + 2..end - 1];
+        if !inner.trim().is_empty() {
+            refs.push((i..end, inner));
+        }
+        i = end;
+    }
+
+    refs
+}
+
+/// Split `expr:-fallback` at the first top-level `:-`, ignoring quotes and nesting.
+fn split_fallback(expr: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    let mut in_quote = false;
+
+    for (i, c) in expr.char_indices() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '(' | '{' if !in_quote => depth += 1,
+            ')' | '}' if !in_quote => depth = depth.saturating_sub(1),
+            ':' if !in_quote && depth == 0 && expr[i..].starts_with(FALLBACK_SEPARATOR) => {
+                return Some((&expr[..i], &expr[i + FALLBACK_SEPARATOR.len()..]));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Quoting state at a byte offset of a script command.
+///
+/// POSIX shells track single and double quotes and backslash escapes; PowerShell escapes
+/// with backticks outside single quotes; `cmd` only has double quotes.
+fn shell_quote_context(cmd: &str, offset: usize, shell: ShellKind) -> ShellQuote {
+    let escape = match shell {
+        ShellKind::Posix => Some('\\'),
+        ShellKind::PowerShell => Some('`'),
+        ShellKind::Cmd => None,
+    };
+    let mut state = ShellQuote::None;
+    let mut escaped = false;
+
+    for c in cmd[..offset].chars() {
+        match (state, c) {
+            _ if escaped => escaped = false,
+            (ShellQuote::None | ShellQuote::Double, c) if Some(c) == escape => escaped = true,
+            (ShellQuote::None, '\'') if shell != ShellKind::Cmd => state = ShellQuote::Single,
+            (ShellQuote::Single, '\'') => state = ShellQuote::None,
+            (ShellQuote::None, '"') => state = ShellQuote::Double,
+            (ShellQuote::Double, '"') => state = ShellQuote::None,
+            _ => {}
+        }
+    }
+
+    state
+}
+
+/// Quote a value for insertion into a script command in the given quoting state.
+fn quote_in_context(value: &str, shell: ShellKind, context: ShellQuote) -> String {
+    // Characters that never need quoting (Windows shells split on `,` and `=`)
+    let safe = match shell {
+        ShellKind::Posix => "_-./:,+=@%",
+        ShellKind::Cmd | ShellKind::PowerShell => "_-./:\\",
+    };
+    let is_plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || safe.contains(c));
+
+    match (shell, context) {
+        (ShellKind::Posix, ShellQuote::None) if is_plain => value.to_string(),
+        (ShellKind::Posix, ShellQuote::None) => format!("'{}'", value.replace('\'', r"'\''")),
+        // Close the quote, add an escaped quote, reopen
+        (ShellKind::Posix, ShellQuote::Single) => value.replace('\'', r"'\''"),
+        (ShellKind::Posix, ShellQuote::Double) => escape_chars(value, '\\', &['\\', '"', '$', '`']),
+
+        // Windows paths can't contain `"`, so dropping them keeps the word intact
+        (ShellKind::Cmd, ShellQuote::None) if !is_plain => {
+            format!("\"{}\"", value.replace('"', ""))
+        }
+        (ShellKind::Cmd, _) => value.replace('"', ""),
+
+        (ShellKind::PowerShell, ShellQuote::None) if value.is_empty() => "''".to_string(),
+        // A quoted string ends the token in PowerShell, so escape in place instead
+        (ShellKind::PowerShell, ShellQuote::None) => {
+            let mut escaped = String::with_capacity(value.len());
+            for c in value.chars() {
+                if !c.is_ascii_alphanumeric() && !safe.contains(c) {
+                    escaped.push('`');
+                }
+                escaped.push(c);
+            }
+            escaped
+        }
+        (ShellKind::PowerShell, ShellQuote::Single) => value.replace('\'', "''"),
+        (ShellKind::PowerShell, ShellQuote::Double) => escape_chars(value, '`', &['`', '"', '$']),
+    }
+}
+
+/// Prefix each of `chars` in `value` with `escape`.
+fn escape_chars(value: &str, escape: char, chars: &[char]) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if chars.contains(&c) {
+            escaped.push(escape);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 /// Evaluate a template expression.
 ///
 /// Handles:
 /// - String literals: `'hello'`
-/// - Variable references: `user_config.X`, `system_config.X`, `__dirname`, `HOME`, etc.
+/// - Variable references: `user_config.X`, `system_config.X`, `env.X`, `__dirname`, `HOME`, etc.
 /// - Function calls: `base64(value)`, `concat(a, b)`, `default(value, fallback)`, etc.
 /// - Nested calls: `base64(concat(user_config.user, ':', user_config.pass))`
+/// - Fallbacks: `env.PORT:-8080`, used when the value is undefined or empty. The fallback
+///   is literal text and may contain `${...}` references.
 ///
 /// Returns `Ok(Some(value))` for resolved values, `Ok(None)` for undefined variables.
 fn eval_expr(
@@ -117,6 +462,14 @@ fn eval_expr(
         return Ok(Some(String::new()));
     }
 
+    // Fallback: value:-default
+    if let Some((primary, fallback)) = split_fallback(expr) {
+        return match eval_expr(primary, dirname, user_config, system_config)? {
+            Some(v) if !v.is_empty() => Ok(Some(v)),
+            _ => substitute_vars(fallback, dirname, user_config, system_config).map(Some),
+        };
+    }
+
     // String literal: 'value'
     if expr.starts_with('\'') && expr.ends_with('\'') && expr.len() >= 2 {
         return Ok(Some(expr[1..expr.len() - 1].to_string()));
@@ -163,6 +516,8 @@ fn resolve_var(
         Ok(user_config.get(key).cloned())
     } else if let Some(key) = name.strip_prefix("system_config.") {
         Ok(system_config.get(key).cloned())
+    } else if let Some(key) = name.strip_prefix(ENV_PREFIX) {
+        Ok(std::env::var(key).ok())
     } else {
         Ok(std::env::var(name).ok())
     }
@@ -511,6 +866,47 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    // -- resolve_var_references --
+
+    #[test]
+    fn resolve_references_reports_each_var() {
+        let (user, system) = make_config();
+        let refs = resolve_var_references(
+            "${user_config.username}:${user_config.missing}@${__dirname}/${upper('x')}",
+            "/dir",
+            &user,
+            &system,
+        );
+
+        let summary: Vec<_> = refs
+            .iter()
+            .map(|r| (r.expr.as_str(), r.source, r.value.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("user_config.username", VarSource::UserConfig, Some("alice")),
+                ("user_config.missing", VarSource::UserConfig, None),
+                ("__dirname", VarSource::Builtin, Some("/dir")),
+                ("upper('x')", VarSource::Expression, Some("X")),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_references_reports_errors() {
+        let (user, system) = make_config();
+        let refs = resolve_var_references("${nope(1)}", "/dir", &user, &system);
+        assert_eq!(refs[0].value, None);
+        assert!(
+            refs[0]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("Unknown template function")
+        );
+    }
+
     // -- split_args --
 
     #[test]
@@ -796,6 +1192,203 @@ mod tests {
         assert_eq!(result, "alice:3000");
     }
 
+    // -- Fallbacks and env --
+
+    const UNSET_ENV: &str = "TOOL_CLI_TEST_UNSET_VARIABLE";
+
+    #[test]
+    fn env_prefix_reads_environment() {
+        let (user, system) = make_config();
+        let path = std::env::var("PATH").unwrap();
+        let result = substitute_vars("${env.PATH}", "/dir", &user, &system).unwrap();
+        assert_eq!(result, path);
+    }
+
+    #[test]
+    fn env_prefix_missing_is_undefined() {
+        let (user, system) = make_config();
+        let expr = format!("${{env.{}}}", UNSET_ENV);
+        assert!(substitute_vars(&expr, "/dir", &user, &system).is_err());
+    }
+
+    #[test]
+    fn fallback_used_when_missing() {
+        let (user, system) = make_config();
+        let expr = format!("port=${{env.{}:-8080}}", UNSET_ENV);
+        let result = substitute_vars(&expr, "/dir", &user, &system).unwrap();
+        assert_eq!(result, "port=8080");
+    }
+
+    #[test]
+    fn fallback_used_when_empty() {
+        let (user, system) = make_config();
+        let result =
+            substitute_vars("${user_config.empty_val:-none}", "/dir", &user, &system).unwrap();
+        assert_eq!(result, "none");
+    }
+
+    #[test]
+    fn fallback_ignored_when_set() {
+        let (user, system) = make_config();
+        let result =
+            substitute_vars("${user_config.username:-bob}", "/dir", &user, &system).unwrap();
+        assert_eq!(result, "alice");
+    }
+
+    #[test]
+    fn fallback_may_be_empty() {
+        let (user, system) = make_config();
+        let result = substitute_vars("[${user_config.missing:-}]", "/dir", &user, &system).unwrap();
+        assert_eq!(result, "[]");
+    }
+
+    #[test]
+    fn fallback_with_nested_reference() {
+        let (user, system) = make_config();
+        let expr = format!(
+            "${{env.{}:-http://${{user_config.missing:-localhost}}:${{system_config.port}}}}",
+            UNSET_ENV
+        );
+        let result = substitute_vars(&expr, "/dir", &user, &system).unwrap();
+        assert_eq!(result, "http://localhost:3000");
+    }
+
+    #[test]
+    fn fallback_with_missing_nested_reference() {
+        let (user, system) = make_config();
+        let result = substitute_vars(
+            "${user_config.missing:-${user_config.also_missing}}",
+            "/dir",
+            &user,
+            &system,
+        );
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Undefined variable: user_config.also_missing"));
+    }
+
+    #[test]
+    fn fallback_on_function_call() {
+        let (user, system) = make_config();
+        let result = substitute_vars("${upper(user_config.missing):-n/a}", "/dir", &user, &system);
+        // Function arguments must still be defined; the fallback doesn't mask errors
+        assert!(result.is_err());
+
+        let result = substitute_vars("${lower('A:-B')}", "/dir", &user, &system).unwrap();
+        assert_eq!(result, "a:-b");
+    }
+
+    #[test]
+    fn unterminated_reference_left_as_is() {
+        let (user, system) = make_config();
+        let result = substitute_vars("${user_config.username} ${oops", "/dir", &user, &system);
+        assert_eq!(result.unwrap(), "alice ${oops");
+    }
+
+    #[test]
+    fn extract_vars_with_fallback() {
+        assert_eq!(
+            extract_user_config_vars("${user_config.port:-80} ${user_config.host}"),
+            ["port", "host"]
+        );
+    }
+
+    #[test]
+    fn resolve_references_reports_fallback() {
+        let (user, system) = make_config();
+        let refs = resolve_var_references(
+            "${user_config.missing:-x} ${user_config.username:-y}",
+            "/dir",
+            &user,
+            &system,
+        );
+        assert_eq!(refs[0].source, VarSource::UserConfig);
+        assert_eq!(refs[0].value.as_deref(), Some("x"));
+        assert!(refs[0].fallback);
+        assert!(!refs[1].fallback);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn script_vars_quote_unsafe_dirname() {
+        let dir = "/tmp/my tool; rm -rf ~";
+        assert_eq!(
+            substitute_script_vars("ls ${__dirname}/src", dir, ShellKind::Posix).unwrap(),
+            "ls '/tmp/my tool; rm -rf ~'/src"
+        );
+        assert_eq!(
+            substitute_script_vars("ls \"${__dirname}/src\"", "/a \"$(id)\"", ShellKind::Posix)
+                .unwrap(),
+            "ls \"/a \\\"\\$(id)\\\"/src\""
+        );
+        assert_eq!(
+            substitute_script_vars("ls '${__dirname}'", "/it's", ShellKind::Posix).unwrap(),
+            "ls '/it'\\''s'"
+        );
+    }
+
+    #[test]
+    fn script_vars_quote_for_windows_shells() {
+        let dir = r"C:\My Tools\it's";
+        assert_eq!(
+            substitute_script_vars(r"dir ${__dirname}\src", dir, ShellKind::Cmd).unwrap(),
+            r#"dir "C:\My Tools\it's"\src"#
+        );
+        assert_eq!(
+            substitute_script_vars(r"dir ${__dirname}\src", dir, ShellKind::PowerShell).unwrap(),
+            r"dir C:\My` Tools\it`'s\src"
+        );
+        assert_eq!(
+            substitute_script_vars(r#"dir "${__dirname}""#, "C:\\$x", ShellKind::PowerShell)
+                .unwrap(),
+            r#"dir "C:\`$x""#
+        );
+        assert_eq!(shell_quote(r"C:\tools", ShellKind::PowerShell), r"C:\tools");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn script_vars_run_with_crafted_dirname() {
+        let temp = tempfile::tempdir().unwrap();
+        for name in [
+            "with space",
+            "it's \"quoted\"",
+            "$(touch pwned)",
+            "a;touch pwned",
+        ] {
+            let dir = temp.path().join(name);
+            std::fs::create_dir(&dir).unwrap();
+            std::fs::write(dir.join("marker"), "ok").unwrap();
+
+            for script in [
+                "cat ${__dirname}/marker",
+                "cat \"${__dirname}/marker\"",
+                "cat '${__dirname}/marker'",
+            ] {
+                let cmd = substitute_script_vars(script, &dir.to_string_lossy(), ShellKind::Posix)
+                    .unwrap();
+                let output = std::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(&cmd)
+                    .current_dir(temp.path())
+                    .output()
+                    .unwrap();
+                assert_eq!(String::from_utf8_lossy(&output.stdout), "ok", "{}", cmd);
+            }
+        }
+        assert!(!temp.path().join("pwned").exists());
+    }
+
+    #[test]
+    fn script_vars_keep_shell_syntax() {
+        let result = substitute_script_vars(
+            "cd ${__dirname} && echo ${FOO} ${BAR%.txt} ${env.TOOL_CLI_TEST_UNSET_VARIABLE:-dev}",
+            "/dir",
+            ShellKind::Posix,
+        )
+        .unwrap();
+        assert_eq!(result, "cd /dir && echo ${FOO} ${BAR%.txt} dev");
+    }
+
     // -- Error cases --
 
     #[test]
@@ -824,4 +1417,23 @@ mod tests {
         let err = result.unwrap_err().to_string();
         assert!(err.contains("requires at least"));
     }
+
+    #[test]
+    fn test_substitute_template_vars() {
+        let mut values = BTreeMap::new();
+        values.insert("name", "my-tool".to_string());
+        values.insert("author", "Alice".to_string());
+
+        let input = r#"{"name": "${name}", "author": "${ author }", "args": ["${__dirname}/index.js", "${user_config.host}"]}"#;
+        assert_eq!(
+            substitute_template_vars(input, &values),
+            r#"{"name": "my-tool", "author": "Alice", "args": ["${__dirname}/index.js", "${user_config.host}"]}"#
+        );
+
+        // Unknown placeholders and template literals are untouched
+        assert_eq!(
+            substitute_template_vars("`Hello, ${inputName}` from ${name}", &values),
+            "`Hello, ${inputName}` from my-tool"
+        );
+    }
 }